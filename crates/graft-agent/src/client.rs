@@ -0,0 +1,537 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    os::unix::net::UnixStream,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use bytes::Bytes;
+
+use culprit::{Culprit, Result, ResultExt};
+use graft_client::{
+    ClientErr,
+    oracle::Oracle,
+    runtime::{
+        storage::{
+            page::PageStatus,
+            snapshot::Snapshot,
+            volume_state::{SyncDirection, VolumeConfig},
+        },
+        volume_reader::VolumeRead,
+        volume_writer::VolumeWrite,
+    },
+};
+use graft_core::{
+    PageCount, PageIdx, VolumeId,
+    lsn::LSN,
+    page::{Page, PageSize},
+};
+use graft_proto::{
+    agent::v1::{
+        AgentRequest, AgentResponse, CommitRequest, LocalSnapshot, OpenVolumeRequest,
+        PageStatusRequest, ReadPageRequest, SnapshotRequest, SyncWithRemoteRequest,
+        agent_request::Request, agent_response::Response,
+    },
+    decode_page_size, encode_page_size,
+    pagestore::v1::PageAtIdx,
+};
+use parking_lot::Mutex;
+use prost::Message;
+
+use crate::{
+    frame::{read_frame, write_frame},
+    proto::{snapshot_from_proto, snapshot_to_proto, sync_to_proto},
+};
+
+/// Extract the expected variant from an agent response
+macro_rules! expect_response {
+    ($response:expr, $variant:ident) => {
+        match $response {
+            Response::$variant(resp) => resp,
+            _ => return Err(Culprit::new(ClientErr::ProtobufDecodeErr)),
+        }
+    };
+}
+
+/// How long to wait for the agent to accept a request or send its response
+/// before giving up on the connection
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// `AgentClient` is a connection to a `graft-agent` process. It provides
+/// the same volume API as [`graft_client::runtime::runtime::Runtime`] while
+/// the agent owns local storage and syncs with the remote on behalf of every
+/// connected process.
+///
+/// If a request fails with an IO error the connection is dropped, and the next
+/// request reconnects to the agent.
+#[derive(Clone)]
+pub struct AgentClient {
+    path: Arc<PathBuf>,
+    timeout: Duration,
+    conn: Arc<Mutex<Option<UnixStream>>>,
+}
+
+impl AgentClient {
+    /// Connect to the agent listening on the Unix socket at `path`
+    pub fn connect<P: AsRef<Path>>(path: P) -> Result<Self, ClientErr> {
+        let client = Self {
+            path: Arc::new(path.as_ref().to_path_buf()),
+            timeout: DEFAULT_TIMEOUT,
+            conn: Default::default(),
+        };
+        let stream = client.open_stream().map_err(Culprit::from_err)?;
+        *client.conn.lock() = Some(stream);
+        Ok(client)
+    }
+
+    /// Set how long to wait for the agent to accept a request or send its
+    /// response. Takes effect on the next connection to the agent.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        *self.conn.lock() = None;
+        Self { timeout, ..self }
+    }
+
+    fn open_stream(&self) -> io::Result<UnixStream> {
+        let stream = UnixStream::connect(&*self.path)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        Ok(stream)
+    }
+
+    /// Send a request and wait for the response, connecting to the agent if
+    /// there is no open connection
+    fn exchange(&self, conn: &mut Option<UnixStream>, req: &AgentRequest) -> io::Result<Bytes> {
+        let stream = match conn {
+            Some(stream) => stream,
+            None => conn.insert(self.open_stream()?),
+        };
+        write_frame(stream, req)?;
+        read_frame(stream)?.ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
+
+    fn call(&self, request: Request) -> Result<Response, ClientErr> {
+        let mut conn = self.conn.lock();
+        let frame = match self.exchange(&mut conn, &AgentRequest { request: Some(request) }) {
+            Ok(frame) => frame,
+            Err(err) => {
+                // the stream may contain part of a request or a response
+                // which arrives after a timeout, so it can't be reused
+                *conn = None;
+                return Err(Culprit::from_err(err));
+            }
+        };
+        let response = AgentResponse::decode(frame).map_err(Culprit::from_err)?;
+        match response.response {
+            Some(Response::Err(err)) => Err(Culprit::new(ClientErr::GraftErr(err))),
+            Some(response) => Ok(response),
+            None => Err(Culprit::new(ClientErr::ProtobufDecodeErr)),
+        }
+    }
+
+    /// Open a volume through the agent. If the volume already exists in the
+    /// agent's storage it keeps its existing config, including its page size.
+    pub fn open_volume(
+        &self,
+        vid: &VolumeId,
+        config: VolumeConfig,
+    ) -> Result<AgentVolumeHandle, ClientErr> {
+        let req = OpenVolumeRequest {
+            vid: vid.copy_to_bytes(),
+            sync: sync_to_proto(config.sync()) as i32,
+            page_size: encode_page_size(config.page_size()),
+        };
+        let resp = expect_response!(self.call(Request::OpenVolume(req))?, OpenVolume);
+        Ok(AgentVolumeHandle {
+            vid: vid.clone(),
+            page_size: decode_page_size(resp.page_size).or_into_ctx()?,
+            client: self.clone(),
+        })
+    }
+}
+
+fn decode_snapshot(snapshot: Option<LocalSnapshot>) -> Result<Option<Snapshot>, ClientErr> {
+    snapshot
+        .map(snapshot_from_proto)
+        .transpose()
+        .or_ctx(|_| ClientErr::ProtobufDecodeErr)
+}
+
+/// A handle to a volume opened through a `graft-agent`
+#[derive(Clone)]
+pub struct AgentVolumeHandle {
+    vid: VolumeId,
+    page_size: PageSize,
+    client: AgentClient,
+}
+
+impl AgentVolumeHandle {
+    #[inline]
+    pub fn vid(&self) -> &VolumeId {
+        &self.vid
+    }
+
+    /// The size of every page in the volume
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    /// Retrieve the latest snapshot for the volume
+    pub fn snapshot(&self) -> Result<Option<Snapshot>, ClientErr> {
+        let req = SnapshotRequest { vid: self.vid.copy_to_bytes() };
+        let resp = expect_response!(self.client.call(Request::Snapshot(req))?, Snapshot);
+        decode_snapshot(resp.snapshot)
+    }
+
+    /// Open an `AgentVolumeReader` at the latest snapshot
+    pub fn reader(&self) -> Result<AgentVolumeReader, ClientErr> {
+        Ok(self.reader_at(self.snapshot()?))
+    }
+
+    /// Open an `AgentVolumeReader` at the provided snapshot
+    pub fn reader_at(&self, snapshot: Option<Snapshot>) -> AgentVolumeReader {
+        AgentVolumeReader {
+            vid: self.vid.clone(),
+            page_size: self.page_size,
+            snapshot,
+            client: self.client.clone(),
+        }
+    }
+
+    /// Open an `AgentVolumeWriter` at the latest snapshot
+    pub fn writer(&self) -> Result<AgentVolumeWriter, ClientErr> {
+        self.reader().map(AgentVolumeWriter::from)
+    }
+
+    /// Open an `AgentVolumeWriter` at the provided snapshot
+    pub fn writer_at(&self, snapshot: Option<Snapshot>) -> AgentVolumeWriter {
+        AgentVolumeWriter::from(self.reader_at(snapshot))
+    }
+
+    /// Ask the agent to sync this volume with the remote
+    pub fn sync_with_remote(&self, direction: SyncDirection) -> Result<(), ClientErr> {
+        let req = SyncWithRemoteRequest {
+            vid: self.vid.copy_to_bytes(),
+            direction: sync_to_proto(direction) as i32,
+        };
+        expect_response!(
+            self.client.call(Request::SyncWithRemote(req))?,
+            SyncWithRemote
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct AgentVolumeReader {
+    vid: VolumeId,
+    page_size: PageSize,
+    snapshot: Option<Snapshot>,
+    client: AgentClient,
+}
+
+impl AgentVolumeReader {
+    /// Upgrade this reader into a writer
+    pub fn upgrade(self) -> AgentVolumeWriter {
+        self.into()
+    }
+
+    fn read_page(
+        &self,
+        snapshot: &Snapshot,
+        req: ReadPageRequest,
+    ) -> Result<Option<Page>, ClientErr> {
+        let req = ReadPageRequest {
+            vid: self.vid.copy_to_bytes(),
+            snapshot: Some(snapshot_to_proto(snapshot)),
+            ..req
+        };
+        let resp = expect_response!(self.client.call(Request::ReadPage(req))?, ReadPage);
+        resp.page.map(Page::try_from).transpose().or_into_ctx()
+    }
+}
+
+impl VolumeRead for AgentVolumeReader {
+    #[inline]
+    fn vid(&self) -> &VolumeId {
+        &self.vid
+    }
+
+    #[inline]
+    fn snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+
+    #[inline]
+    fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
+        let Some(snapshot) = &self.snapshot else {
+            return Ok(Page::empty(self.page_size));
+        };
+
+        // first try to read the page from the agent's local storage
+        let req = ReadPageRequest {
+            pageidx: pageidx.to_u32(),
+            ..Default::default()
+        };
+        if let Some(page) = self.read_page(snapshot, req)? {
+            oracle.observe_cache_hit(pageidx);
+            return Ok(page);
+        }
+
+        // on a cache miss, ask the agent to fetch the page along with any
+        // pages predicted by the oracle
+        let req = ReadPageRequest {
+            pageidx: pageidx.to_u32(),
+            fetch: true,
+            prefetch: oracle
+                .predict_next(pageidx)
+                .map(|idx| idx.to_u32())
+                .collect(),
+            ..Default::default()
+        };
+        self.read_page(snapshot, req)?
+            .ok_or_else(|| Culprit::new(ClientErr::ProtobufDecodeErr))
+    }
+
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        let Some(snapshot) = &self.snapshot else {
+            return Ok(PageStatus::Empty(None));
+        };
+        let req = PageStatusRequest {
+            vid: self.vid.copy_to_bytes(),
+            snapshot: Some(snapshot_to_proto(snapshot)),
+            pageidx: pageidx.to_u32(),
+        };
+        let resp = expect_response!(self.client.call(Request::PageStatus(req))?, PageStatus);
+        let lsn = resp
+            .lsn
+            .map(LSN::try_from)
+            .transpose()
+            .or_ctx(|_| ClientErr::ProtobufDecodeErr)?;
+        match (resp.pending, resp.empty, lsn) {
+            (true, _, _) => Ok(PageStatus::Pending),
+            (false, true, lsn) => Ok(PageStatus::Empty(lsn)),
+            (false, false, Some(lsn)) => Ok(PageStatus::Available(lsn)),
+            (false, false, None) => Err(Culprit::new(ClientErr::ProtobufDecodeErr)),
+        }
+    }
+}
+
+/// A writer which buffers changes locally and sends them to the agent on
+/// commit
+pub struct AgentVolumeWriter {
+    pages: PageCount,
+    reader: AgentVolumeReader,
+    memtable: BTreeMap<PageIdx, Page>,
+}
+
+impl AgentVolumeWriter {
+    pub fn pages(&self) -> PageCount {
+        self.pages
+    }
+}
+
+impl From<AgentVolumeReader> for AgentVolumeWriter {
+    fn from(reader: AgentVolumeReader) -> Self {
+        let pages = reader.snapshot().map_or(PageCount::ZERO, |s| s.pages());
+        Self {
+            pages,
+            reader,
+            memtable: Default::default(),
+        }
+    }
+}
+
+impl VolumeRead for AgentVolumeWriter {
+    #[inline]
+    fn vid(&self) -> &VolumeId {
+        self.reader.vid()
+    }
+
+    #[inline]
+    fn snapshot(&self) -> Option<&Snapshot> {
+        self.reader.snapshot()
+    }
+
+    #[inline]
+    fn page_size(&self) -> PageSize {
+        self.reader.page_size()
+    }
+
+    /// Read a page; supports read your own writes (RYOW)
+    fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
+        if let Some(page) = self.memtable.get(&pageidx) {
+            oracle.observe_cache_hit(pageidx);
+            return Ok(page.clone());
+        }
+        self.reader.read(oracle, pageidx)
+    }
+
+    /// Read a page's status; supports read your own writes (RYOW)
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        if self.memtable.contains_key(&pageidx) {
+            return Ok(PageStatus::Dirty);
+        }
+        self.reader.status(pageidx)
+    }
+}
+
+impl VolumeWrite for AgentVolumeWriter {
+    type CommitOutput = AgentVolumeReader;
+
+    fn write(&mut self, pageidx: PageIdx, page: Page) {
+        self.pages = self.pages.max(pageidx.pages());
+        self.memtable.insert(pageidx, page);
+    }
+
+    fn truncate(&mut self, pages: PageCount) {
+        self.pages = pages;
+        match pages.last_index() {
+            Some(last) => self.memtable.retain(|idx, _| *idx <= last),
+            None => self.memtable.clear(),
+        }
+    }
+
+    fn commit(self) -> Result<AgentVolumeReader, ClientErr> {
+        let AgentVolumeWriter { pages, reader, memtable } = self;
+
+        // we have nothing to commit if the page count is equal to the snapshot
+        // pagecount *and* the memtable is empty
+        let snapshot_pagecount = reader.snapshot().map_or(PageCount::ZERO, |s| s.pages());
+        if pages == snapshot_pagecount && memtable.is_empty() {
+            return Ok(reader);
+        }
+
+        let req = CommitRequest {
+            vid: reader.vid.copy_to_bytes(),
+            snapshot: reader.snapshot.as_ref().map(snapshot_to_proto),
+            page_count: pages.into(),
+            pages: memtable
+                .into_iter()
+                .map(|(pageidx, page)| PageAtIdx::new(pageidx, page))
+                .collect(),
+        };
+        let resp = expect_response!(reader.client.call(Request::Commit(req))?, Commit);
+        Ok(AgentVolumeReader {
+            snapshot: decode_snapshot(resp.snapshot)?,
+            ..reader
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixListener;
+
+    use graft_client::{
+        ClientPair, MetastoreClient, NetClient, PagestoreClient,
+        oracle::NoopOracle,
+        runtime::{runtime::Runtime, storage::Storage},
+    };
+    use graft_core::{
+        gid::ClientId,
+        page::{EMPTY_PAGE, Page, PageSize},
+        pageidx,
+    };
+
+    use crate::AgentServer;
+
+    use super::*;
+
+    #[graft_test::test]
+    fn test_agent_read_write() {
+        // the agent never talks to the remote in this test
+        let net = NetClient::new(None);
+        let clients = ClientPair::new(
+            MetastoreClient::new("http://127.0.0.1:1".parse().unwrap(), net.clone()),
+            PagestoreClient::new("http://127.0.0.1:1".parse().unwrap(), net),
+        );
+        let storage = Storage::open_memory().unwrap();
+        let runtime = Runtime::new(ClientId::random(), clients, storage);
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("agent.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        AgentServer::new(runtime.clone()).spawn(listener).unwrap();
+
+        let mut oracle = NoopOracle;
+        let vid = VolumeId::random();
+        let page = Page::test_filled(0x42);
+
+        // volumes must be opened before they can be used
+        let client = AgentClient::connect(&socket).unwrap();
+        let err = AgentVolumeHandle {
+            vid: vid.clone(),
+            page_size: PageSize::DEFAULT,
+            client: client.clone(),
+        }
+        .snapshot()
+        .unwrap_err();
+        assert!(matches!(err.ctx(), ClientErr::GraftErr(_)));
+
+        let handle = client
+            .open_volume(&vid, VolumeConfig::new(SyncDirection::Disabled))
+            .unwrap();
+        assert_eq!(handle.snapshot().unwrap(), None);
+
+        // write through the first connection, verifying RYOW
+        let mut writer = handle.writer().unwrap();
+        writer.write(pageidx!(2), page.clone());
+        assert_eq!(writer.read(&mut oracle, pageidx!(2)).unwrap(), page);
+        assert_eq!(writer.status(pageidx!(2)).unwrap(), PageStatus::Dirty);
+        let reader = writer.commit().unwrap();
+        let snapshot = reader.snapshot().unwrap().clone();
+        assert_eq!(snapshot.local(), 1);
+        assert_eq!(snapshot.pages(), 2);
+
+        // the commit is visible to another connection, which opens the
+        // volume with its existing config
+        let client2 = AgentClient::connect(&socket).unwrap();
+        let page_size = PageSize::try_from(8192_u32).unwrap();
+        let handle2 = client2
+            .open_volume(
+                &vid,
+                VolumeConfig::new(SyncDirection::Both).with_page_size(page_size),
+            )
+            .unwrap();
+        assert_eq!(handle2.page_size(), PageSize::DEFAULT);
+        assert_eq!(
+            runtime.volume_config(&vid).unwrap(),
+            Some(VolumeConfig::new(SyncDirection::Disabled))
+        );
+        assert_eq!(handle2.snapshot().unwrap(), Some(snapshot));
+        let reader2 = handle2.reader().unwrap();
+        assert_eq!(reader2.read(&mut oracle, pageidx!(2)).unwrap(), page);
+        assert_eq!(reader2.read(&mut oracle, pageidx!(1)).unwrap(), EMPTY_PAGE);
+        assert!(matches!(
+            reader2.status(pageidx!(2)).unwrap(),
+            PageStatus::Available(_)
+        ));
+
+        // truncating drops pages from the volume
+        let mut writer = reader2.upgrade();
+        writer.truncate(PageCount::new(1));
+        let reader2 = writer.commit().unwrap();
+        assert_eq!(reader2.snapshot().unwrap().pages(), 1);
+        assert_eq!(handle.snapshot().unwrap(), reader2.snapshot().cloned());
+
+        // a failed request drops the connection, and the next request
+        // reconnects to the agent
+        client
+            .conn
+            .lock()
+            .as_ref()
+            .unwrap()
+            .shutdown(std::net::Shutdown::Both)
+            .unwrap();
+        handle.snapshot().unwrap_err();
+        assert!(client.conn.lock().is_none());
+        assert_eq!(handle.snapshot().unwrap(), reader2.snapshot().cloned());
+    }
+}
@@ -0,0 +1,46 @@
+use std::io::{self, ErrorKind, Read, Write};
+
+use bytes::{Bytes, BytesMut};
+use graft_core::byte_unit::ByteUnit;
+use prost::Message;
+
+/// The largest message which may be sent over the agent socket
+pub const MAX_FRAME_SIZE: ByteUnit = ByteUnit::from_mb(64);
+
+/// Write a length prefixed message to the stream
+pub fn write_frame<W: Write, M: Message>(w: &mut W, msg: &M) -> io::Result<()> {
+    let len = msg.encoded_len();
+    if len > MAX_FRAME_SIZE.as_usize() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("message exceeds {MAX_FRAME_SIZE}"),
+        ));
+    }
+    let mut buf = Vec::with_capacity(4 + len);
+    buf.extend_from_slice(&(len as u32).to_be_bytes());
+    msg.encode(&mut buf)
+        .expect("buffer has sufficient capacity");
+    w.write_all(&buf)?;
+    w.flush()
+}
+
+/// Read a length prefixed message from the stream. Returns `None` if the
+/// stream was closed before the next message started.
+pub fn read_frame<R: Read>(r: &mut R) -> io::Result<Option<Bytes>> {
+    let mut len = [0; 4];
+    match r.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_SIZE.as_usize() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("message exceeds {MAX_FRAME_SIZE}"),
+        ));
+    }
+    let mut buf = BytesMut::zeroed(len);
+    r.read_exact(&mut buf)?;
+    Ok(Some(buf.freeze()))
+}
@@ -0,0 +1,18 @@
+//! `graft-agent` shares a single Graft data directory between many processes
+//! on the same machine.
+//!
+//! Local storage can only be opened by one process at a time. The agent owns
+//! the [`graft_client::runtime::storage::Storage`] and sync task, and serves
+//! volume reads and commits to local processes over a Unix domain socket.
+//! Processes use [`AgentClient`] in place of a
+//! [`graft_client::runtime::runtime::Runtime`]; its readers and writers
+//! implement [`graft_client::runtime::volume_reader::VolumeRead`] and
+//! [`graft_client::runtime::volume_writer::VolumeWrite`].
+
+pub mod client;
+mod frame;
+mod proto;
+pub mod server;
+
+pub use client::{AgentClient, AgentVolumeHandle, AgentVolumeReader, AgentVolumeWriter};
+pub use server::{AgentErr, AgentServer};
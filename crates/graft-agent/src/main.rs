@@ -0,0 +1,101 @@
+use std::{os::unix::net::UnixListener, path::PathBuf, time::Duration};
+
+use config::{Config, FileFormat};
+use graft_agent::AgentServer;
+use graft_client::{
+    ClientPair, MetastoreClient, NetClient, PagestoreClient,
+    runtime::{runtime::Runtime, storage::Storage},
+};
+use graft_core::ClientId;
+use graft_tracing::{TracingConsumer, init_tracing};
+use serde::Deserialize;
+use url::Url;
+
+fn default_metastore() -> Url {
+    "http://127.0.0.1:3001".parse().unwrap()
+}
+
+fn default_pagestore() -> Url {
+    "http://127.0.0.1:3000".parse().unwrap()
+}
+
+fn default_data_dir() -> PathBuf {
+    platform_dirs::AppDirs::new(Some("graft"), true)
+        .expect("must specify explicit data_dir on this platform")
+        .data_dir
+}
+
+fn default_autosync() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct AgentConfig {
+    #[serde(default = "default_metastore")]
+    metastore: Url,
+
+    #[serde(default = "default_pagestore")]
+    pagestore: Url,
+
+    #[serde(default = "default_data_dir")]
+    data_dir: PathBuf,
+
+    /// the path of the Unix socket to listen on, defaults to `agent.sock`
+    /// inside the data dir
+    socket: Option<PathBuf>,
+
+    #[serde(default = "default_autosync")]
+    autosync: bool,
+
+    #[serde(default = "ClientId::random")]
+    client_id: ClientId,
+
+    token: Option<String>,
+}
+
+fn main() {
+    let config = Config::builder()
+        .add_source(config::File::new("graft-agent.toml", FileFormat::Toml).required(false))
+        .add_source(
+            std::env::var("GRAFT_AGENT_CONFIG")
+                .ok()
+                .map(|path| config::File::new(&path, FileFormat::Toml).required(true))
+                .into_iter()
+                .collect::<Vec<_>>(),
+        )
+        .add_source(config::Environment::with_prefix("GRAFT_AGENT"))
+        .build()
+        .expect("failed to load config");
+    let config: AgentConfig = config
+        .try_deserialize()
+        .expect("failed to deserialize config");
+
+    init_tracing(TracingConsumer::Server, Some(config.client_id.short()));
+    tracing::info!("starting Graft agent");
+
+    let client = NetClient::new(config.token);
+    let metastore_client = MetastoreClient::new(config.metastore, client.clone());
+    let pagestore_client = PagestoreClient::new(config.pagestore, client);
+    let clients = ClientPair::new(metastore_client, pagestore_client);
+
+    let socket = config
+        .socket
+        .unwrap_or_else(|| config.data_dir.join("agent.sock"));
+    let storage = Storage::open(&config.data_dir).expect("failed to open storage");
+    let runtime = Runtime::new(config.client_id, clients, storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, config.autosync, "graft-sync")
+        .expect("failed to start sync task");
+
+    // storage is locked by this process, so any existing socket is stale
+    if socket.exists() {
+        std::fs::remove_file(&socket).expect("failed to remove stale socket");
+    }
+    let listener = UnixListener::bind(&socket).expect("failed to bind socket");
+    tracing::info!("listening on {}", socket.display());
+
+    if let Err(err) = AgentServer::new(runtime).serve(listener) {
+        tracing::error!("agent server failed: {err}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,53 @@
+//! Conversions between the agent protocol and graft-client types
+
+use culprit::Result;
+use graft_client::runtime::storage::{
+    snapshot::{RemoteMapping, Snapshot},
+    volume_state::SyncDirection,
+};
+use graft_core::lsn::{InvalidLSN, LSN};
+use graft_proto::agent::v1::{self as proto, LocalSnapshot};
+
+pub fn snapshot_to_proto(snapshot: &Snapshot) -> LocalSnapshot {
+    LocalSnapshot {
+        local: snapshot.local().into(),
+        remote: snapshot.remote().map(Into::into),
+        remote_local: snapshot.remote_local().map(Into::into),
+        pages: snapshot.pages().into(),
+    }
+}
+
+pub fn snapshot_from_proto(snapshot: LocalSnapshot) -> Result<Snapshot, InvalidLSN> {
+    let remote = match (snapshot.remote, snapshot.remote_local) {
+        (Some(remote), Some(local)) => {
+            RemoteMapping::new(LSN::try_from(remote)?, LSN::try_from(local)?)
+        }
+        _ => RemoteMapping::default(),
+    };
+    Ok(Snapshot::new(
+        LSN::try_from(snapshot.local)?,
+        remote,
+        snapshot.pages.into(),
+    ))
+}
+
+pub fn sync_to_proto(sync: SyncDirection) -> proto::SyncDirection {
+    match sync {
+        SyncDirection::Disabled => proto::SyncDirection::Disabled,
+        SyncDirection::Push => proto::SyncDirection::Push,
+        SyncDirection::Pull => proto::SyncDirection::Pull,
+        SyncDirection::Both => proto::SyncDirection::Both,
+    }
+}
+
+/// Convert a protobuf `SyncDirection`, treating unspecified as disabled
+pub fn sync_from_proto(sync: proto::SyncDirection) -> SyncDirection {
+    match sync {
+        proto::SyncDirection::Unspecified | proto::SyncDirection::Disabled => {
+            SyncDirection::Disabled
+        }
+        proto::SyncDirection::Push => SyncDirection::Push,
+        proto::SyncDirection::Pull => SyncDirection::Pull,
+        proto::SyncDirection::Both => SyncDirection::Both,
+    }
+}
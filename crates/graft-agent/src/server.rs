@@ -0,0 +1,281 @@
+use std::{
+    collections::HashMap,
+    io,
+    os::unix::net::{UnixListener, UnixStream},
+    sync::Arc,
+    thread::JoinHandle,
+};
+
+use bytes::Bytes;
+use culprit::{Culprit, Result, ResultExt};
+use graft_client::{
+    ClientErr,
+    oracle::Oracle,
+    runtime::{
+        runtime::Runtime,
+        storage::{page::PageStatus, volume_state::VolumeConfig},
+        volume_handle::VolumeHandle,
+        volume_reader::VolumeRead,
+        volume_writer::VolumeWrite,
+    },
+};
+use graft_core::{
+    PageIdx, VolumeId, gid::GidParseErr, lsn::InvalidLSN, page::PageSizeErr,
+    page_idx::ConvertToPageIdxErr,
+};
+use graft_proto::{
+    GraftErrCode,
+    agent::v1::{
+        AgentRequest, AgentResponse, CommitRequest, CommitResponse, OpenVolumeRequest,
+        OpenVolumeResponse, PageStatusRequest, PageStatusResponse, ReadPageRequest,
+        ReadPageResponse, SnapshotRequest, SnapshotResponse, SyncWithRemoteRequest,
+        SyncWithRemoteResponse, agent_request::Request, agent_response::Response,
+    },
+    common::v1::GraftErr,
+    decode_page_size, encode_page_size,
+};
+use parking_lot::Mutex;
+use prost::Message;
+use thiserror::Error;
+
+use crate::{
+    frame::{read_frame, write_frame},
+    proto::{snapshot_from_proto, snapshot_to_proto, sync_from_proto},
+};
+
+#[derive(Debug, Error)]
+pub enum AgentErr {
+    #[error("client error: {0}")]
+    ClientErr(#[from] ClientErr),
+
+    #[error("invalid request: {0}")]
+    InvalidRequest(&'static str),
+
+    #[error("volume {0} has not been opened")]
+    VolumeNotOpen(VolumeId),
+
+    #[error("failed to parse VolumeId: {0}")]
+    GidParseErr(#[from] GidParseErr),
+
+    #[error("invalid LSN: {0}")]
+    InvalidLSN(#[from] InvalidLSN),
+
+    #[error("invalid page index")]
+    ConvertToPageIdxErr(#[from] ConvertToPageIdxErr),
+
+    #[error("invalid page size")]
+    PageSizeErr(#[from] PageSizeErr),
+}
+
+impl From<&AgentErr> for GraftErr {
+    fn from(err: &AgentErr) -> Self {
+        let code = match err {
+            // errors from the remote are passed through as is
+            AgentErr::ClientErr(ClientErr::GraftErr(err)) => return err.clone(),
+            AgentErr::ClientErr(_) => GraftErrCode::Server,
+            _ => GraftErrCode::Client,
+        };
+        GraftErr {
+            code: code as i32,
+            message: err.to_string(),
+        }
+    }
+}
+
+/// `AgentServer` shares a single Graft [`Runtime`] with many local processes.
+/// Each connection is served by a dedicated thread which handles one request
+/// at a time.
+#[derive(Clone)]
+pub struct AgentServer {
+    runtime: Runtime,
+    handles: Arc<Mutex<HashMap<VolumeId, VolumeHandle>>>,
+}
+
+impl AgentServer {
+    pub fn new(runtime: Runtime) -> Self {
+        Self { runtime, handles: Default::default() }
+    }
+
+    /// Accept and serve connections until the listener fails
+    pub fn serve(&self, listener: UnixListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept()?;
+            let server = self.clone();
+            std::thread::Builder::new()
+                .name("graft-agent-conn".into())
+                .spawn(move || {
+                    if let Err(err) = server.serve_connection(stream) {
+                        tracing::warn!("agent connection failed: {err}");
+                    }
+                })?;
+        }
+    }
+
+    /// Serve connections on a background thread
+    pub fn spawn(&self, listener: UnixListener) -> io::Result<JoinHandle<io::Result<()>>> {
+        let server = self.clone();
+        std::thread::Builder::new()
+            .name("graft-agent".into())
+            .spawn(move || server.serve(listener))
+    }
+
+    fn serve_connection(&self, mut stream: UnixStream) -> io::Result<()> {
+        while let Some(frame) = read_frame(&mut stream)? {
+            let response = match AgentRequest::decode(frame) {
+                Ok(req) => self.handle(req),
+                Err(err) => {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+                }
+            };
+            let response = match response {
+                Ok(response) => response,
+                Err(err) => {
+                    tracing::debug!("agent request failed: {err:?}");
+                    Response::Err(err.ctx().into())
+                }
+            };
+            write_frame(&mut stream, &AgentResponse { response: Some(response) })?;
+        }
+        Ok(())
+    }
+
+    fn handle(&self, req: AgentRequest) -> Result<Response, AgentErr> {
+        match req.request {
+            Some(Request::OpenVolume(req)) => self.open_volume(req).map(Response::OpenVolume),
+            Some(Request::Snapshot(req)) => self.snapshot(req).map(Response::Snapshot),
+            Some(Request::ReadPage(req)) => self.read_page(req).map(Response::ReadPage),
+            Some(Request::PageStatus(req)) => self.page_status(req).map(Response::PageStatus),
+            Some(Request::Commit(req)) => self.commit(req).map(Response::Commit),
+            Some(Request::SyncWithRemote(req)) => {
+                self.sync_with_remote(req).map(Response::SyncWithRemote)
+            }
+            None => Err(Culprit::new(AgentErr::InvalidRequest("missing request"))),
+        }
+    }
+
+    fn volume(&self, vid: Bytes) -> Result<VolumeHandle, AgentErr> {
+        let vid = VolumeId::try_from(vid).or_into_ctx()?;
+        match self.handles.lock().get(&vid) {
+            Some(handle) => Ok(handle.clone()),
+            None => Err(Culprit::new(AgentErr::VolumeNotOpen(vid))),
+        }
+    }
+
+    fn open_volume(&self, req: OpenVolumeRequest) -> Result<OpenVolumeResponse, AgentErr> {
+        let vid = VolumeId::try_from(req.vid.clone()).or_into_ctx()?;
+
+        // volumes shared with other clients keep their existing config, the
+        // requested config is only used to create new volumes
+        let config = match self.runtime.volume_config(&vid).or_into_ctx()? {
+            Some(config) => config,
+            None => {
+                let page_size = decode_page_size(req.page_size).or_into_ctx()?;
+                VolumeConfig::new(sync_from_proto(req.sync())).with_page_size(page_size)
+            }
+        };
+        let handle = self.runtime.open_volume(&vid, config).or_into_ctx()?;
+        let page_size = encode_page_size(handle.page_size());
+        self.handles.lock().insert(vid, handle);
+        Ok(OpenVolumeResponse { page_size })
+    }
+
+    fn snapshot(&self, req: SnapshotRequest) -> Result<SnapshotResponse, AgentErr> {
+        let handle = self.volume(req.vid)?;
+        let snapshot = handle.snapshot().or_into_ctx()?;
+        Ok(SnapshotResponse {
+            snapshot: snapshot.as_ref().map(snapshot_to_proto),
+        })
+    }
+
+    fn read_page(&self, req: ReadPageRequest) -> Result<ReadPageResponse, AgentErr> {
+        let handle = self.volume(req.vid)?;
+        let snapshot = req
+            .snapshot
+            .map(snapshot_from_proto)
+            .transpose()
+            .or_into_ctx()?;
+        let reader = handle.reader_at(snapshot);
+        let pageidx = PageIdx::try_from(req.pageidx).or_into_ctx()?;
+
+        // let the client consult its oracle before fetching a missing page
+        if !req.fetch
+            && reader.snapshot().is_some_and(|s| s.remote().is_some())
+            && reader.status(pageidx).or_into_ctx()? == PageStatus::Pending
+        {
+            return Ok(ReadPageResponse { page: None });
+        }
+
+        let prefetch = req
+            .prefetch
+            .into_iter()
+            .map(PageIdx::try_from)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .or_into_ctx()?;
+        let page = reader
+            .read(&mut PrefetchOracle(prefetch), pageidx)
+            .or_into_ctx()?;
+        Ok(ReadPageResponse { page: Some(page.into()) })
+    }
+
+    fn page_status(&self, req: PageStatusRequest) -> Result<PageStatusResponse, AgentErr> {
+        let handle = self.volume(req.vid)?;
+        let snapshot = req
+            .snapshot
+            .map(snapshot_from_proto)
+            .transpose()
+            .or_into_ctx()?;
+        let pageidx = PageIdx::try_from(req.pageidx).or_into_ctx()?;
+        let status = handle.reader_at(snapshot).status(pageidx).or_into_ctx()?;
+        let mut resp = PageStatusResponse::default();
+        match status {
+            PageStatus::Pending | PageStatus::Dirty => resp.pending = true,
+            PageStatus::Empty(lsn) => {
+                resp.empty = true;
+                resp.lsn = lsn.map(Into::into);
+            }
+            PageStatus::Available(lsn) => resp.lsn = Some(lsn.into()),
+        }
+        Ok(resp)
+    }
+
+    fn commit(&self, req: CommitRequest) -> Result<CommitResponse, AgentErr> {
+        let handle = self.volume(req.vid)?;
+        let snapshot = req
+            .snapshot
+            .map(snapshot_from_proto)
+            .transpose()
+            .or_into_ctx()?;
+        let mut writer = handle.writer_at(snapshot);
+        for page in req.pages {
+            writer.write(page.pageidx().or_into_ctx()?, page.page().or_into_ctx()?);
+        }
+        writer.truncate(req.page_count.into());
+        let reader = writer.commit().or_into_ctx()?;
+        Ok(CommitResponse {
+            snapshot: reader.snapshot().map(snapshot_to_proto),
+        })
+    }
+
+    fn sync_with_remote(
+        &self,
+        req: SyncWithRemoteRequest,
+    ) -> Result<SyncWithRemoteResponse, AgentErr> {
+        let direction = sync_from_proto(req.direction());
+        let handle = self.volume(req.vid)?;
+        handle.sync_with_remote(direction).or_into_ctx()?;
+        Ok(SyncWithRemoteResponse {})
+    }
+}
+
+/// Fetches the pages predicted by the client's oracle
+struct PrefetchOracle(Vec<PageIdx>);
+
+impl Oracle for PrefetchOracle {
+    fn observe_cache_hit(&mut self, _pageidx: PageIdx) {
+        // cache hits are observed by the client
+    }
+
+    fn predict_next(&mut self, _pageidx: PageIdx) -> impl Iterator<Item = PageIdx> {
+        std::mem::take(&mut self.0).into_iter()
+    }
+}
@@ -0,0 +1,292 @@
+/* Generated by cbindgen from crates/graft-client-ffi. Do not edit by hand. */
+
+#ifndef GRAFT_CLIENT_H
+#define GRAFT_CLIENT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/// The default size of a Graft page in bytes. Volumes may be opened with a
+/// different page size, which is returned by `graft_volume_page_size`.
+#define GRAFT_PAGE_SIZE 4096
+
+typedef enum GraftResult {
+  GRAFT_RESULT_OK = 0,
+  /// A required argument was null or otherwise invalid
+  GRAFT_RESULT_INVALID_ARGUMENT = 1,
+  /// The Graft client returned an error
+  GRAFT_RESULT_CLIENT = 2,
+  /// The transaction conflicted with a concurrent commit to the same volume
+  GRAFT_RESULT_CONCURRENT_WRITE = 3,
+  /// The sync task failed to start or stop
+  GRAFT_RESULT_SYNC_TASK = 4,
+  /// Graft panicked while handling the call
+  GRAFT_RESULT_PANIC = 5,
+} GraftResult;
+
+typedef enum GraftSyncDirection {
+  GRAFT_SYNC_DIRECTION_DISABLED = 0,
+  GRAFT_SYNC_DIRECTION_PUSH = 1,
+  GRAFT_SYNC_DIRECTION_PULL = 2,
+  GRAFT_SYNC_DIRECTION_BOTH = 3,
+} GraftSyncDirection;
+
+typedef enum GraftVolumeStatus {
+  GRAFT_VOLUME_STATUS_OK = 0,
+  /// The last commit graft attempted to push to the server was rejected
+  GRAFT_VOLUME_STATUS_REJECTED_COMMIT = 1,
+  /// The local and remote volume state have diverged
+  GRAFT_VOLUME_STATUS_CONFLICT = 2,
+  /// The volume was interrupted in the middle of a push operation
+  GRAFT_VOLUME_STATUS_INTERRUPTED_PUSH = 3,
+} GraftVolumeStatus;
+
+typedef struct GraftReader GraftReader;
+
+typedef struct GraftRuntime GraftRuntime;
+
+typedef struct GraftVolume GraftVolume;
+
+typedef struct GraftWriter GraftWriter;
+
+/// Configuration used to construct a `GraftRuntime`
+typedef struct GraftRuntimeConfig {
+  /// The metastore root URL
+  const char *metastore;
+  /// The pagestore root URL
+  const char *pagestore;
+  /// The directory used to store local volume state. If null, volume state
+  /// is kept in memory and lost when the runtime is freed.
+  const char *data_dir;
+  /// An optional API token
+  const char *token;
+  /// An optional client id. If null, a random client id is generated.
+  const char *client_id;
+} GraftRuntimeConfig;
+
+/// A volume snapshot
+typedef struct GraftSnapshot {
+  /// The local LSN of the snapshot
+  uint64_t local_lsn;
+  /// The remote LSN of the snapshot, or 0 if the snapshot has never been
+  /// synced with the remote
+  uint64_t remote_lsn;
+  /// The number of pages in the snapshot
+  uint32_t pages;
+} GraftSnapshot;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/// Returns a description of the last error which occurred on the calling
+/// thread, or null if no error has occurred. The returned string is valid
+/// until the next failing call on the same thread.
+const char *graft_last_error(void);
+
+/// Create a new runtime. On success, `*out` is set to a runtime which must be
+/// released with `graft_runtime_free`.
+///
+/// # Safety
+/// `config` must point to a valid `GraftRuntimeConfig` whose strings are
+/// either null or valid nul-terminated strings. `out` must be valid for writes.
+GraftResult graft_runtime_new(const GraftRuntimeConfig *config, GraftRuntime **out);
+
+/// Release a runtime. The sync task is not stopped automatically; call
+/// `graft_runtime_shutdown_sync` first for a clean shutdown.
+///
+/// # Safety
+/// `runtime` must be null or a pointer returned by `graft_runtime_new` which
+/// has not yet been freed.
+void graft_runtime_free(GraftRuntime *runtime);
+
+/// Start the background sync task
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer.
+GraftResult graft_runtime_start_sync(const GraftRuntime *runtime,
+                                     uint64_t refresh_interval_ms,
+                                     bool autosync);
+
+/// Stop the background sync task, waiting up to `timeout_ms` for it to exit
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer.
+GraftResult graft_runtime_shutdown_sync(const GraftRuntime *runtime, uint64_t timeout_ms);
+
+/// Retrieve whether the sync task automatically syncs volumes. The sync task
+/// must be running.
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer and `out` must be valid for writes.
+GraftResult graft_runtime_get_autosync(const GraftRuntime *runtime, bool *out);
+
+/// Set whether the sync task automatically syncs volumes. The sync task must
+/// be running.
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer.
+GraftResult graft_runtime_set_autosync(const GraftRuntime *runtime, bool autosync);
+
+/// Open a volume. If `vid` is null, a new random volume id is generated. On
+/// success, `*out` is set to a volume which must be released with
+/// `graft_volume_free`.
+///
+/// `sync` must be one of the `GraftSyncDirection` values.
+///
+/// If `page_size` is 0, the volume keeps the page size it was previously
+/// opened with, or `GRAFT_PAGE_SIZE` if it's new. Otherwise `page_size` must
+/// be a supported page size, and may only differ from the volume's existing
+/// page size while the volume is empty.
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer, `vid` must be null or a valid
+/// nul-terminated string, and `out` must be valid for writes.
+GraftResult graft_volume_open(const GraftRuntime *runtime,
+                              const char *vid,
+                              uint32_t sync,
+                              uint32_t page_size,
+                              GraftVolume **out);
+
+/// Release a volume
+///
+/// # Safety
+/// `volume` must be null or a pointer returned by `graft_volume_open` which has
+/// not yet been freed.
+void graft_volume_free(GraftVolume *volume);
+
+/// Copy the volume id into `buf` as a nul-terminated string. `*out_len` is
+/// set to the buffer size required to hold the id including the nul
+/// terminator. Returns `GRAFT_RESULT_INVALID_ARGUMENT` if `buf` is too small.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer, `buf` must be valid for writes of
+/// `len` bytes, and `out_len` must be null or valid for writes.
+GraftResult graft_volume_id(const GraftVolume *volume, char *buf, size_t len, size_t *out_len);
+
+/// Retrieve the size of every page in the volume. Buffers passed to page read
+/// and write functions must be exactly this size.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+GraftResult graft_volume_page_size(const GraftVolume *volume, uint32_t *out);
+
+/// Retrieve the volume's status
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+GraftResult graft_volume_status(const GraftVolume *volume, GraftVolumeStatus *out);
+
+/// Retrieve the volume's latest snapshot. `*out_present` is set to false if
+/// the volume is empty, in which case `*out` is zeroed.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` and `out_present` must be
+/// valid for writes.
+GraftResult graft_volume_snapshot(const GraftVolume *volume, GraftSnapshot *out, bool *out_present);
+
+/// Sync the volume with the remote, blocking until the sync completes. The
+/// sync task must be running. `direction` must be one of the
+/// `GraftSyncDirection` values.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer.
+GraftResult graft_volume_sync(const GraftVolume *volume, uint32_t direction);
+
+/// Reset the volume to the remote, rolling back any pending local commits.
+/// The sync task must be running.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer.
+GraftResult graft_volume_reset_to_remote(const GraftVolume *volume);
+
+/// Open a read transaction at the volume's latest snapshot. On success,
+/// `*out` is set to a reader which must be released with `graft_reader_free`.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+GraftResult graft_reader_open(const GraftVolume *volume, GraftReader **out);
+
+/// Release a reader
+///
+/// # Safety
+/// `reader` must be null or a pointer returned by this library which has not
+/// yet been freed.
+void graft_reader_free(GraftReader *reader);
+
+/// Retrieve the snapshot backing a reader. `*out_present` is set to false if
+/// the reader is reading an empty volume.
+///
+/// # Safety
+/// `reader` must be a valid reader pointer and `out` and `out_present` must be
+/// valid for writes.
+GraftResult graft_reader_snapshot(const GraftReader *reader, GraftSnapshot *out, bool *out_present);
+
+/// Read a page into `buf`, fetching it from the remote if needed. `len` must
+/// equal the volume's page size.
+///
+/// # Safety
+/// `reader` must be a valid reader pointer and `buf` must be valid for writes
+/// of `len` bytes.
+GraftResult graft_reader_read(GraftReader *reader, uint32_t pageidx, uint8_t *buf, size_t len);
+
+/// Open a write transaction at the volume's latest snapshot. On success,
+/// `*out` is set to a writer which must be committed with
+/// `graft_writer_commit` or released with `graft_writer_free`.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+GraftResult graft_writer_open(const GraftVolume *volume, GraftWriter **out);
+
+/// Release a writer, discarding any uncommitted writes
+///
+/// # Safety
+/// `writer` must be null or a pointer returned by `graft_writer_open` which
+/// has not yet been freed or committed.
+void graft_writer_free(GraftWriter *writer);
+
+/// Read a page into `buf`. Reads observe the writer's uncommitted writes.
+/// `len` must equal the volume's page size.
+///
+/// # Safety
+/// `writer` must be a valid writer pointer and `buf` must be valid for writes
+/// of `len` bytes.
+GraftResult graft_writer_read(GraftWriter *writer, uint32_t pageidx, uint8_t *buf, size_t len);
+
+/// Write a page. `len` must equal the volume's page size.
+///
+/// # Safety
+/// `writer` must be a valid writer pointer and `buf` must be valid for reads
+/// of `len` bytes.
+GraftResult graft_writer_write(GraftWriter *writer,
+                               uint32_t pageidx,
+                               const uint8_t *buf,
+                               size_t len);
+
+/// Truncate the volume to the provided number of pages
+///
+/// # Safety
+/// `writer` must be a valid writer pointer.
+GraftResult graft_writer_truncate(GraftWriter *writer, uint32_t pages);
+
+/// Retrieve the number of pages in the volume, including uncommitted writes
+///
+/// # Safety
+/// `writer` must be a valid writer pointer and `out` must be valid for writes.
+GraftResult graft_writer_pages(const GraftWriter *writer, uint32_t *out);
+
+/// Commit a writer. The writer is consumed whether or not the commit
+/// succeeds and must not be used again. If `out_reader` is not null, on
+/// success it is set to a reader at the newly committed snapshot.
+///
+/// # Safety
+/// `writer` must be a pointer returned by `graft_writer_open` which has not
+/// yet been freed or committed. `out_reader` must be null or valid for writes.
+GraftResult graft_writer_commit(GraftWriter *writer, GraftReader **out_reader);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* GRAFT_CLIENT_H */
@@ -0,0 +1,1163 @@
+//! A C ABI for the Graft client.
+//!
+//! Every fallible function returns a `GraftResult`. When a function fails, a
+//! description of the error can be retrieved on the same thread via
+//! `graft_last_error`. Handles returned by this library are owned by the
+//! caller and must be released using the matching `*_free` function.
+//!
+//! `GraftRuntime` and `GraftVolume` handles may be shared between threads.
+//! `GraftReader` and `GraftWriter` handles must only be used by one thread at a
+//! time.
+//!
+//! The C header lives in `include/graft_client.h` and is generated by
+//! cbindgen; regenerate it with `just run ffi header`.
+
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString, c_char},
+    fmt::Display,
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr, slice,
+    time::Duration,
+};
+
+use culprit::Culprit;
+use graft_client::{
+    ClientErr, ClientPair, MetastoreClient, NetClient, PagestoreClient,
+    oracle::LeapOracle,
+    runtime::{
+        runtime::Runtime,
+        storage::{
+            Storage, StorageErr,
+            snapshot::Snapshot,
+            volume_state::{SyncDirection, VolumeConfig, VolumeStatus},
+        },
+        volume_handle::VolumeHandle,
+        volume_reader::{VolumeRead, VolumeReader},
+        volume_writer::{VolumeWrite, VolumeWriter},
+    },
+};
+use graft_core::{
+    ClientId, PageCount, PageIdx, VolumeId,
+    page::{PAGESIZE, Page, PageSize},
+};
+use url::Url;
+
+/// The default size of a Graft page in bytes. Volumes may be opened with a
+/// different page size, which is returned by `graft_volume_page_size`.
+pub const GRAFT_PAGE_SIZE: usize = 4096;
+const _: () = assert!(GRAFT_PAGE_SIZE == PAGESIZE.as_usize());
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraftResult {
+    Ok = 0,
+
+    /// A required argument was null or otherwise invalid
+    InvalidArgument = 1,
+
+    /// The Graft client returned an error
+    Client = 2,
+
+    /// The transaction conflicted with a concurrent commit to the same volume
+    ConcurrentWrite = 3,
+
+    /// The sync task failed to start or stop
+    SyncTask = 4,
+
+    /// Graft panicked while handling the call
+    Panic = 5,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraftSyncDirection {
+    Disabled = 0,
+    Push = 1,
+    Pull = 2,
+    Both = 3,
+}
+
+impl TryFrom<u32> for GraftSyncDirection {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(GraftSyncDirection::Disabled),
+            1 => Ok(GraftSyncDirection::Push),
+            2 => Ok(GraftSyncDirection::Pull),
+            3 => Ok(GraftSyncDirection::Both),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<GraftSyncDirection> for SyncDirection {
+    fn from(value: GraftSyncDirection) -> Self {
+        match value {
+            GraftSyncDirection::Disabled => SyncDirection::Disabled,
+            GraftSyncDirection::Push => SyncDirection::Push,
+            GraftSyncDirection::Pull => SyncDirection::Pull,
+            GraftSyncDirection::Both => SyncDirection::Both,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraftVolumeStatus {
+    Ok = 0,
+
+    /// The last commit graft attempted to push to the server was rejected
+    RejectedCommit = 1,
+
+    /// The local and remote volume state have diverged
+    Conflict = 2,
+
+    /// The volume was interrupted in the middle of a push operation
+    InterruptedPush = 3,
+}
+
+impl From<VolumeStatus> for GraftVolumeStatus {
+    fn from(value: VolumeStatus) -> Self {
+        match value {
+            VolumeStatus::Ok => GraftVolumeStatus::Ok,
+            VolumeStatus::RejectedCommit => GraftVolumeStatus::RejectedCommit,
+            VolumeStatus::Conflict => GraftVolumeStatus::Conflict,
+            VolumeStatus::InterruptedPush => GraftVolumeStatus::InterruptedPush,
+        }
+    }
+}
+
+/// Configuration used to construct a `GraftRuntime`
+#[repr(C)]
+pub struct GraftRuntimeConfig {
+    /// The metastore root URL
+    pub metastore: *const c_char,
+
+    /// The pagestore root URL
+    pub pagestore: *const c_char,
+
+    /// The directory used to store local volume state. If null, volume state
+    /// is kept in memory and lost when the runtime is freed.
+    pub data_dir: *const c_char,
+
+    /// An optional API token
+    pub token: *const c_char,
+
+    /// An optional client id. If null, a random client id is generated.
+    pub client_id: *const c_char,
+}
+
+/// A volume snapshot
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GraftSnapshot {
+    /// The local LSN of the snapshot
+    pub local_lsn: u64,
+
+    /// The remote LSN of the snapshot, or 0 if the snapshot has never been
+    /// synced with the remote
+    pub remote_lsn: u64,
+
+    /// The number of pages in the snapshot
+    pub pages: u32,
+}
+
+impl From<&Snapshot> for GraftSnapshot {
+    fn from(snapshot: &Snapshot) -> Self {
+        Self {
+            local_lsn: snapshot.local().into(),
+            remote_lsn: snapshot.remote().map_or(0, u64::from),
+            pages: snapshot.pages().to_u32(),
+        }
+    }
+}
+
+pub struct GraftRuntime {
+    runtime: Runtime,
+}
+
+pub struct GraftVolume {
+    handle: VolumeHandle,
+}
+
+pub struct GraftReader {
+    reader: VolumeReader,
+    oracle: LeapOracle,
+}
+
+pub struct GraftWriter {
+    writer: VolumeWriter,
+    oracle: LeapOracle,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+struct FfiErr(GraftResult, String);
+
+impl FfiErr {
+    fn invalid(msg: impl Display) -> Self {
+        Self(GraftResult::InvalidArgument, msg.to_string())
+    }
+}
+
+impl From<Culprit<ClientErr>> for FfiErr {
+    fn from(err: Culprit<ClientErr>) -> Self {
+        let code = match err.ctx() {
+            ClientErr::StorageErr(StorageErr::ConcurrentWrite) => GraftResult::ConcurrentWrite,
+            _ => GraftResult::Client,
+        };
+        Self(code, err.to_string())
+    }
+}
+
+fn set_last_error(msg: String) {
+    // interior nul bytes would truncate the message, so replace them
+    let msg = CString::new(msg.replace('\0', " ")).expect("nul bytes removed");
+    LAST_ERROR.with(|last| last.replace(Some(msg)));
+}
+
+/// Run an FFI call, recording any error or panic as the last error
+fn ffi_call(f: impl FnOnce() -> Result<(), FfiErr>) -> GraftResult {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => GraftResult::Ok,
+        Ok(Err(FfiErr(code, msg))) => {
+            set_last_error(msg);
+            code
+        }
+        Err(panic) => {
+            let msg = match panic.downcast_ref::<&'static str>() {
+                Some(s) => s,
+                None => match panic.downcast_ref::<String>() {
+                    Some(s) => &s[..],
+                    None => "unknown panic",
+                },
+            };
+            set_last_error(format!("graft panic: {msg}"));
+            GraftResult::Panic
+        }
+    }
+}
+
+/// # Safety
+/// ptr must be null or point to a valid nul-terminated string which outlives 'a
+unsafe fn opt_str_arg<'a>(ptr: *const c_char, name: &str) -> Result<Option<&'a str>, FfiErr> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map(Some)
+        .map_err(|_| FfiErr::invalid(format!("{name} must be valid UTF-8")))
+}
+
+/// # Safety
+/// ptr must be null or point to a valid nul-terminated string which outlives 'a
+unsafe fn str_arg<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, FfiErr> {
+    unsafe { opt_str_arg(ptr, name) }?.ok_or_else(|| FfiErr::invalid(format!("{name} is null")))
+}
+
+/// # Safety
+/// ptr must be null or point to a valid T which outlives 'a
+unsafe fn ref_arg<'a, T>(ptr: *const T, name: &str) -> Result<&'a T, FfiErr> {
+    unsafe { ptr.as_ref() }.ok_or_else(|| FfiErr::invalid(format!("{name} is null")))
+}
+
+/// # Safety
+/// ptr must be null or point to a valid T which outlives 'a and is not aliased
+unsafe fn mut_arg<'a, T>(ptr: *mut T, name: &str) -> Result<&'a mut T, FfiErr> {
+    unsafe { ptr.as_mut() }.ok_or_else(|| FfiErr::invalid(format!("{name} is null")))
+}
+
+/// # Safety
+/// out must be null or valid for writes of T
+unsafe fn write_out<T>(out: *mut T, value: T, name: &str) -> Result<(), FfiErr> {
+    if out.is_null() {
+        return Err(FfiErr::invalid(format!("{name} is null")));
+    }
+    unsafe { out.write(value) };
+    Ok(())
+}
+
+fn pageidx_arg(pageidx: u32) -> Result<PageIdx, FfiErr> {
+    PageIdx::try_from(pageidx).map_err(|err| FfiErr::invalid(format!("pageidx: {err}")))
+}
+
+/// Sync directions are passed as integers as C may pass any value for an enum
+fn sync_arg(direction: u32, name: &str) -> Result<SyncDirection, FfiErr> {
+    GraftSyncDirection::try_from(direction)
+        .map(SyncDirection::from)
+        .map_err(|value| FfiErr::invalid(format!("{name}: invalid sync direction {value}")))
+}
+
+fn page_len_arg(page_size: PageSize, len: usize) -> Result<(), FfiErr> {
+    if len != page_size.as_usize() {
+        return Err(FfiErr::invalid(format!(
+            "buf must be exactly {} bytes; got {len}",
+            page_size.as_usize()
+        )));
+    }
+    Ok(())
+}
+
+/// # Safety
+/// buf must be null or valid for writes of `len` bytes
+unsafe fn read_page_into(
+    read: &impl VolumeRead,
+    oracle: &mut LeapOracle,
+    pageidx: u32,
+    buf: *mut u8,
+    len: usize,
+) -> Result<(), FfiErr> {
+    if buf.is_null() {
+        return Err(FfiErr::invalid("buf is null"));
+    }
+    page_len_arg(read.page_size(), len)?;
+    let page = read.read(oracle, pageidx_arg(pageidx)?)?;
+    let buf = unsafe { slice::from_raw_parts_mut(buf, len) };
+    buf.copy_from_slice(&page);
+    Ok(())
+}
+
+/// Returns a description of the last error which occurred on the calling
+/// thread, or null if no error has occurred. The returned string is valid
+/// until the next failing call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn graft_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |s| s.as_ptr()))
+}
+
+/// Create a new runtime. On success, `*out` is set to a runtime which must be
+/// released with `graft_runtime_free`.
+///
+/// # Safety
+/// `config` must point to a valid `GraftRuntimeConfig` whose strings are
+/// either null or valid nul-terminated strings. `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_runtime_new(
+    config: *const GraftRuntimeConfig,
+    out: *mut *mut GraftRuntime,
+) -> GraftResult {
+    ffi_call(|| {
+        let config = unsafe { ref_arg(config, "config") }?;
+        let metastore: Url = unsafe { str_arg(config.metastore, "metastore") }?
+            .parse()
+            .map_err(|err| FfiErr::invalid(format!("invalid metastore url: {err}")))?;
+        let pagestore: Url = unsafe { str_arg(config.pagestore, "pagestore") }?
+            .parse()
+            .map_err(|err| FfiErr::invalid(format!("invalid pagestore url: {err}")))?;
+        let token = unsafe { opt_str_arg(config.token, "token") }?;
+        let cid = match unsafe { opt_str_arg(config.client_id, "client_id") }? {
+            Some(cid) => cid
+                .parse()
+                .map_err(|err| FfiErr::invalid(format!("invalid client_id: {err}")))?,
+            None => ClientId::random(),
+        };
+
+        let storage = match unsafe { opt_str_arg(config.data_dir, "data_dir") }? {
+            Some(path) => Storage::open(path),
+            None => Storage::open_memory(),
+        }
+        .map_err(|err| FfiErr(GraftResult::Client, err.to_string()))?;
+
+        let client = NetClient::new(token.map(str::to_owned));
+        let clients = ClientPair::new(
+            MetastoreClient::new(metastore, client.clone()),
+            PagestoreClient::new(pagestore, client),
+        );
+
+        let runtime = Box::new(GraftRuntime {
+            runtime: Runtime::new(cid, clients, storage),
+        });
+        unsafe { write_out(out, Box::into_raw(runtime), "out") }
+    })
+}
+
+/// Release a runtime. The sync task is not stopped automatically; call
+/// `graft_runtime_shutdown_sync` first for a clean shutdown.
+///
+/// # Safety
+/// `runtime` must be null or a pointer returned by `graft_runtime_new` which
+/// has not yet been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_runtime_free(runtime: *mut GraftRuntime) {
+    if !runtime.is_null() {
+        drop(unsafe { Box::from_raw(runtime) });
+    }
+}
+
+/// Start the background sync task
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_runtime_start_sync(
+    runtime: *const GraftRuntime,
+    refresh_interval_ms: u64,
+    autosync: bool,
+) -> GraftResult {
+    ffi_call(|| {
+        let runtime = unsafe { ref_arg(runtime, "runtime") }?;
+        runtime
+            .runtime
+            .start_sync_task(
+                Duration::from_millis(refresh_interval_ms),
+                8,
+                autosync,
+                "graft-sync",
+            )
+            .map_err(|err| FfiErr(GraftResult::SyncTask, err.to_string()))
+    })
+}
+
+/// Stop the background sync task, waiting up to `timeout_ms` for it to exit
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_runtime_shutdown_sync(
+    runtime: *const GraftRuntime,
+    timeout_ms: u64,
+) -> GraftResult {
+    ffi_call(|| {
+        let runtime = unsafe { ref_arg(runtime, "runtime") }?;
+        runtime
+            .runtime
+            .shutdown_sync_task(Duration::from_millis(timeout_ms))
+            .map_err(|err| FfiErr(GraftResult::SyncTask, err.to_string()))
+    })
+}
+
+/// Retrieve whether the sync task automatically syncs volumes. The sync task
+/// must be running.
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_runtime_get_autosync(
+    runtime: *const GraftRuntime,
+    out: *mut bool,
+) -> GraftResult {
+    ffi_call(|| {
+        let runtime = unsafe { ref_arg(runtime, "runtime") }?;
+        unsafe { write_out(out, runtime.runtime.get_autosync(), "out") }
+    })
+}
+
+/// Set whether the sync task automatically syncs volumes. The sync task must
+/// be running.
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_runtime_set_autosync(
+    runtime: *const GraftRuntime,
+    autosync: bool,
+) -> GraftResult {
+    ffi_call(|| {
+        let runtime = unsafe { ref_arg(runtime, "runtime") }?;
+        runtime.runtime.set_autosync(autosync);
+        Ok(())
+    })
+}
+
+/// Open a volume. If `vid` is null, a new random volume id is generated. On
+/// success, `*out` is set to a volume which must be released with
+/// `graft_volume_free`.
+///
+/// `sync` must be one of the `GraftSyncDirection` values.
+///
+/// If `page_size` is 0, the volume keeps the page size it was previously
+/// opened with, or `GRAFT_PAGE_SIZE` if it's new. Otherwise `page_size` must
+/// be a supported page size, and may only differ from the volume's existing
+/// page size while the volume is empty.
+///
+/// # Safety
+/// `runtime` must be a valid runtime pointer, `vid` must be null or a valid
+/// nul-terminated string, and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_open(
+    runtime: *const GraftRuntime,
+    vid: *const c_char,
+    sync: u32,
+    page_size: u32,
+    out: *mut *mut GraftVolume,
+) -> GraftResult {
+    ffi_call(|| {
+        let runtime = unsafe { ref_arg(runtime, "runtime") }?;
+        let vid = match unsafe { opt_str_arg(vid, "vid") }? {
+            Some(vid) => vid
+                .parse()
+                .map_err(|err| FfiErr::invalid(format!("invalid vid: {err}")))?,
+            None => VolumeId::random(),
+        };
+        let page_size = match page_size {
+            0 => runtime
+                .runtime
+                .volume_config(&vid)?
+                .map_or(PageSize::DEFAULT, |config| config.page_size()),
+            size => PageSize::try_from(size)
+                .map_err(|err| FfiErr::invalid(format!("page_size: {err}")))?,
+        };
+        let config = VolumeConfig::new(sync_arg(sync, "sync")?).with_page_size(page_size);
+        let handle = runtime.runtime.open_volume(&vid, config)?;
+        let volume = Box::new(GraftVolume { handle });
+        unsafe { write_out(out, Box::into_raw(volume), "out") }
+    })
+}
+
+/// Release a volume
+///
+/// # Safety
+/// `volume` must be null or a pointer returned by `graft_volume_open` which has
+/// not yet been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_free(volume: *mut GraftVolume) {
+    if !volume.is_null() {
+        drop(unsafe { Box::from_raw(volume) });
+    }
+}
+
+/// Copy the volume id into `buf` as a nul-terminated string. `*out_len` is
+/// set to the buffer size required to hold the id including the nul
+/// terminator. Returns `GRAFT_RESULT_INVALID_ARGUMENT` if `buf` is too small.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer, `buf` must be valid for writes of
+/// `len` bytes, and `out_len` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_id(
+    volume: *const GraftVolume,
+    buf: *mut c_char,
+    len: usize,
+    out_len: *mut usize,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        let vid = volume.handle.vid().pretty();
+        let required = vid.len() + 1;
+        if !out_len.is_null() {
+            unsafe { out_len.write(required) };
+        }
+        if buf.is_null() || len < required {
+            return Err(FfiErr::invalid(format!(
+                "buf must be at least {required} bytes"
+            )));
+        }
+        let buf = unsafe { slice::from_raw_parts_mut(buf.cast::<u8>(), required) };
+        buf[..vid.len()].copy_from_slice(vid.as_bytes());
+        buf[vid.len()] = 0;
+        Ok(())
+    })
+}
+
+/// Retrieve the size of every page in the volume. Buffers passed to page read
+/// and write functions must be exactly this size.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_page_size(
+    volume: *const GraftVolume,
+    out: *mut u32,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        unsafe { write_out(out, volume.handle.page_size().as_u32(), "out") }
+    })
+}
+
+/// Retrieve the volume's status
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_status(
+    volume: *const GraftVolume,
+    out: *mut GraftVolumeStatus,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        let status = volume.handle.status()?;
+        unsafe { write_out(out, status.into(), "out") }
+    })
+}
+
+/// Retrieve the volume's latest snapshot. `*out_present` is set to false if
+/// the volume is empty, in which case `*out` is zeroed.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` and `out_present` must be
+/// valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_snapshot(
+    volume: *const GraftVolume,
+    out: *mut GraftSnapshot,
+    out_present: *mut bool,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        let snapshot = volume.handle.snapshot()?;
+        unsafe { write_snapshot(snapshot.as_ref(), out, out_present) }
+    })
+}
+
+/// # Safety
+/// out and `out_present` must be null or valid for writes
+unsafe fn write_snapshot(
+    snapshot: Option<&Snapshot>,
+    out: *mut GraftSnapshot,
+    out_present: *mut bool,
+) -> Result<(), FfiErr> {
+    unsafe {
+        write_out(out, snapshot.map(Into::into).unwrap_or_default(), "out")?;
+        write_out(out_present, snapshot.is_some(), "out_present")
+    }
+}
+
+/// Sync the volume with the remote, blocking until the sync completes. The
+/// sync task must be running. `direction` must be one of the
+/// `GraftSyncDirection` values.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_sync(
+    volume: *const GraftVolume,
+    direction: u32,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        let direction = sync_arg(direction, "direction")?;
+        Ok(volume.handle.sync_with_remote(direction)?)
+    })
+}
+
+/// Reset the volume to the remote, rolling back any pending local commits.
+/// The sync task must be running.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_volume_reset_to_remote(volume: *const GraftVolume) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        Ok(volume.handle.reset_to_remote()?)
+    })
+}
+
+/// Open a read transaction at the volume's latest snapshot. On success,
+/// `*out` is set to a reader which must be released with `graft_reader_free`.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_reader_open(
+    volume: *const GraftVolume,
+    out: *mut *mut GraftReader,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        let reader = Box::new(GraftReader {
+            reader: volume.handle.reader()?,
+            oracle: LeapOracle::default(),
+        });
+        unsafe { write_out(out, Box::into_raw(reader), "out") }
+    })
+}
+
+/// Release a reader
+///
+/// # Safety
+/// `reader` must be null or a pointer returned by this library which has not
+/// yet been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_reader_free(reader: *mut GraftReader) {
+    if !reader.is_null() {
+        drop(unsafe { Box::from_raw(reader) });
+    }
+}
+
+/// Retrieve the snapshot backing a reader. `*out_present` is set to false if
+/// the reader is reading an empty volume.
+///
+/// # Safety
+/// `reader` must be a valid reader pointer and `out` and `out_present` must be
+/// valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_reader_snapshot(
+    reader: *const GraftReader,
+    out: *mut GraftSnapshot,
+    out_present: *mut bool,
+) -> GraftResult {
+    ffi_call(|| {
+        let reader = unsafe { ref_arg(reader, "reader") }?;
+        unsafe { write_snapshot(reader.reader.snapshot(), out, out_present) }
+    })
+}
+
+/// Read a page into `buf`, fetching it from the remote if needed. `len` must
+/// equal the volume's page size.
+///
+/// # Safety
+/// `reader` must be a valid reader pointer and `buf` must be valid for writes
+/// of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_reader_read(
+    reader: *mut GraftReader,
+    pageidx: u32,
+    buf: *mut u8,
+    len: usize,
+) -> GraftResult {
+    ffi_call(|| {
+        let reader = unsafe { mut_arg(reader, "reader") }?;
+        unsafe { read_page_into(&reader.reader, &mut reader.oracle, pageidx, buf, len) }
+    })
+}
+
+/// Open a write transaction at the volume's latest snapshot. On success,
+/// `*out` is set to a writer which must be committed with
+/// `graft_writer_commit` or released with `graft_writer_free`.
+///
+/// # Safety
+/// `volume` must be a valid volume pointer and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_open(
+    volume: *const GraftVolume,
+    out: *mut *mut GraftWriter,
+) -> GraftResult {
+    ffi_call(|| {
+        let volume = unsafe { ref_arg(volume, "volume") }?;
+        let writer = Box::new(GraftWriter {
+            writer: volume.handle.writer()?,
+            oracle: LeapOracle::default(),
+        });
+        unsafe { write_out(out, Box::into_raw(writer), "out") }
+    })
+}
+
+/// Release a writer, discarding any uncommitted writes
+///
+/// # Safety
+/// `writer` must be null or a pointer returned by `graft_writer_open` which
+/// has not yet been freed or committed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_free(writer: *mut GraftWriter) {
+    if !writer.is_null() {
+        drop(unsafe { Box::from_raw(writer) });
+    }
+}
+
+/// Read a page into `buf`. Reads observe the writer's uncommitted writes.
+/// `len` must equal the volume's page size.
+///
+/// # Safety
+/// `writer` must be a valid writer pointer and `buf` must be valid for writes
+/// of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_read(
+    writer: *mut GraftWriter,
+    pageidx: u32,
+    buf: *mut u8,
+    len: usize,
+) -> GraftResult {
+    ffi_call(|| {
+        let writer = unsafe { mut_arg(writer, "writer") }?;
+        unsafe { read_page_into(&writer.writer, &mut writer.oracle, pageidx, buf, len) }
+    })
+}
+
+/// Write a page. `len` must equal the volume's page size.
+///
+/// # Safety
+/// `writer` must be a valid writer pointer and `buf` must be valid for reads
+/// of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_write(
+    writer: *mut GraftWriter,
+    pageidx: u32,
+    buf: *const u8,
+    len: usize,
+) -> GraftResult {
+    ffi_call(|| {
+        let writer = unsafe { mut_arg(writer, "writer") }?;
+        let pageidx = pageidx_arg(pageidx)?;
+        if buf.is_null() {
+            return Err(FfiErr::invalid("buf is null"));
+        }
+        page_len_arg(writer.writer.page_size(), len)?;
+        let buf = unsafe { slice::from_raw_parts(buf, len) };
+        let page = Page::try_from(buf).map_err(|err| FfiErr::invalid(err.to_string()))?;
+        writer.writer.write(pageidx, page);
+        Ok(())
+    })
+}
+
+/// Truncate the volume to the provided number of pages
+///
+/// # Safety
+/// `writer` must be a valid writer pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_truncate(
+    writer: *mut GraftWriter,
+    pages: u32,
+) -> GraftResult {
+    ffi_call(|| {
+        let writer = unsafe { mut_arg(writer, "writer") }?;
+        writer.writer.truncate(PageCount::new(pages));
+        Ok(())
+    })
+}
+
+/// Retrieve the number of pages in the volume, including uncommitted writes
+///
+/// # Safety
+/// `writer` must be a valid writer pointer and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_pages(
+    writer: *const GraftWriter,
+    out: *mut u32,
+) -> GraftResult {
+    ffi_call(|| {
+        let writer = unsafe { ref_arg(writer, "writer") }?;
+        unsafe { write_out(out, writer.writer.pages().to_u32(), "out") }
+    })
+}
+
+/// Commit a writer. The writer is consumed whether or not the commit
+/// succeeds and must not be used again. If `out_reader` is not null, on
+/// success it is set to a reader at the newly committed snapshot.
+///
+/// # Safety
+/// `writer` must be a pointer returned by `graft_writer_open` which has not
+/// yet been freed or committed. `out_reader` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn graft_writer_commit(
+    writer: *mut GraftWriter,
+    out_reader: *mut *mut GraftReader,
+) -> GraftResult {
+    ffi_call(|| {
+        if writer.is_null() {
+            return Err(FfiErr::invalid("writer is null"));
+        }
+        let GraftWriter { writer, oracle } = *unsafe { Box::from_raw(writer) };
+        let reader = writer.commit()?;
+        if !out_reader.is_null() {
+            let reader = Box::new(GraftReader { reader, oracle });
+            unsafe { out_reader.write(Box::into_raw(reader)) };
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr::null_mut;
+
+    use super::*;
+
+    fn open_runtime() -> *mut GraftRuntime {
+        let config = GraftRuntimeConfig {
+            metastore: c"http://127.0.0.1:3001".as_ptr(),
+            pagestore: c"http://127.0.0.1:3000".as_ptr(),
+            data_dir: ptr::null(),
+            token: ptr::null(),
+            client_id: ptr::null(),
+        };
+        let mut runtime = null_mut();
+        assert_eq!(
+            unsafe { graft_runtime_new(&config, &mut runtime) },
+            GraftResult::Ok
+        );
+        runtime
+    }
+
+    fn last_error() -> String {
+        let err = graft_last_error();
+        assert!(!err.is_null());
+        unsafe { CStr::from_ptr(err) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[graft_test::test]
+    fn test_ffi_read_write() {
+        let runtime = open_runtime();
+        let mut volume = null_mut();
+        unsafe {
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    ptr::null(),
+                    GraftSyncDirection::Disabled as u32,
+                    0,
+                    &mut volume
+                ),
+                GraftResult::Ok
+            );
+
+            // the volume id round trips through the C string
+            let mut vid = [0 as c_char; 64];
+            let mut len = 0;
+            assert_eq!(
+                graft_volume_id(volume, vid.as_mut_ptr(), vid.len(), &mut len),
+                GraftResult::Ok
+            );
+            let vid_str = CStr::from_ptr(vid.as_ptr()).to_str().unwrap();
+            assert_eq!(vid_str.len() + 1, len);
+            assert_eq!(vid_str, (*volume).handle.vid().pretty());
+
+            // an empty volume has no snapshot
+            let mut snapshot = GraftSnapshot::default();
+            let mut present = true;
+            assert_eq!(
+                graft_volume_snapshot(volume, &mut snapshot, &mut present),
+                GraftResult::Ok
+            );
+            assert!(!present);
+
+            // write a page and commit
+            let page = [0x42u8; GRAFT_PAGE_SIZE];
+            let mut writer = null_mut();
+            assert_eq!(graft_writer_open(volume, &mut writer), GraftResult::Ok);
+            assert_eq!(
+                graft_writer_write(writer, 1, page.as_ptr(), page.len()),
+                GraftResult::Ok
+            );
+            let mut pages = 0;
+            assert_eq!(graft_writer_pages(writer, &mut pages), GraftResult::Ok);
+            assert_eq!(pages, 1);
+            let mut reader = null_mut();
+            assert_eq!(graft_writer_commit(writer, &mut reader), GraftResult::Ok);
+
+            // read the page back
+            let mut buf = [0u8; GRAFT_PAGE_SIZE];
+            assert_eq!(
+                graft_reader_read(reader, 1, buf.as_mut_ptr(), buf.len()),
+                GraftResult::Ok
+            );
+            assert_eq!(buf, page);
+            assert_eq!(
+                graft_reader_snapshot(reader, &mut snapshot, &mut present),
+                GraftResult::Ok
+            );
+            assert!(present);
+            assert_eq!(
+                snapshot,
+                GraftSnapshot { local_lsn: 1, remote_lsn: 0, pages: 1 }
+            );
+            graft_reader_free(reader);
+
+            let mut status = GraftVolumeStatus::Conflict;
+            assert_eq!(graft_volume_status(volume, &mut status), GraftResult::Ok);
+            assert_eq!(status, GraftVolumeStatus::Ok);
+
+            graft_volume_free(volume);
+            graft_runtime_free(runtime);
+        }
+    }
+
+    #[graft_test::test]
+    fn test_ffi_invalid_sync_direction() {
+        let runtime = open_runtime();
+        let mut volume = null_mut();
+        unsafe {
+            assert_eq!(
+                graft_volume_open(runtime, ptr::null(), 4, 0, &mut volume),
+                GraftResult::InvalidArgument
+            );
+            assert!(last_error().contains("invalid sync direction 4"));
+            assert!(volume.is_null());
+
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    ptr::null(),
+                    GraftSyncDirection::Disabled as u32,
+                    0,
+                    &mut volume
+                ),
+                GraftResult::Ok
+            );
+            assert_eq!(
+                graft_volume_sync(volume, u32::MAX),
+                GraftResult::InvalidArgument
+            );
+            assert!(last_error().contains("direction"));
+
+            graft_volume_free(volume);
+            graft_runtime_free(runtime);
+        }
+    }
+
+    #[graft_test::test]
+    fn test_ffi_page_size() {
+        let runtime = open_runtime();
+        let mut volume = null_mut();
+        unsafe {
+            // unsupported page sizes are rejected
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    ptr::null(),
+                    GraftSyncDirection::Both as u32,
+                    1000,
+                    &mut volume
+                ),
+                GraftResult::InvalidArgument
+            );
+            assert!(last_error().contains("page_size"));
+
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    ptr::null(),
+                    GraftSyncDirection::Both as u32,
+                    8192,
+                    &mut volume
+                ),
+                GraftResult::Ok
+            );
+            let mut page_size = 0;
+            assert_eq!(
+                graft_volume_page_size(volume, &mut page_size),
+                GraftResult::Ok
+            );
+            assert_eq!(page_size, 8192);
+
+            // buffers must match the volume's page size
+            let small = [0x42u8; GRAFT_PAGE_SIZE];
+            let page = [0x42u8; 8192];
+            let mut writer = null_mut();
+            assert_eq!(graft_writer_open(volume, &mut writer), GraftResult::Ok);
+            assert_eq!(
+                graft_writer_write(writer, 1, small.as_ptr(), small.len()),
+                GraftResult::InvalidArgument
+            );
+            assert!(last_error().contains("buf must be exactly 8192 bytes"));
+            assert_eq!(
+                graft_writer_write(writer, 1, page.as_ptr(), page.len()),
+                GraftResult::Ok
+            );
+            let mut reader = null_mut();
+            assert_eq!(graft_writer_commit(writer, &mut reader), GraftResult::Ok);
+
+            let mut buf = [0u8; 8192];
+            assert_eq!(
+                graft_reader_read(reader, 1, buf.as_mut_ptr(), GRAFT_PAGE_SIZE),
+                GraftResult::InvalidArgument
+            );
+            assert_eq!(
+                graft_reader_read(reader, 1, buf.as_mut_ptr(), buf.len()),
+                GraftResult::Ok
+            );
+            assert_eq!(buf, page);
+            graft_reader_free(reader);
+
+            // reopening the volume without a page size keeps the existing one
+            let mut vid = [0 as c_char; 64];
+            assert_eq!(
+                graft_volume_id(volume, vid.as_mut_ptr(), vid.len(), null_mut()),
+                GraftResult::Ok
+            );
+            let mut reopened = null_mut();
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    vid.as_ptr(),
+                    GraftSyncDirection::Both as u32,
+                    0,
+                    &mut reopened
+                ),
+                GraftResult::Ok
+            );
+            assert_eq!(
+                graft_volume_page_size(reopened, &mut page_size),
+                GraftResult::Ok
+            );
+            assert_eq!(page_size, 8192);
+
+            graft_volume_free(reopened);
+            graft_volume_free(volume);
+            graft_runtime_free(runtime);
+        }
+    }
+
+    #[graft_test::test]
+    fn test_ffi_errors() {
+        let runtime = open_runtime();
+        let mut volume = null_mut();
+        unsafe {
+            // null arguments are rejected
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    ptr::null(),
+                    GraftSyncDirection::Both as u32,
+                    0,
+                    null_mut()
+                ),
+                GraftResult::InvalidArgument
+            );
+            assert!(last_error().contains("out is null"));
+
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    c"bad".as_ptr(),
+                    GraftSyncDirection::Both as u32,
+                    0,
+                    &mut volume
+                ),
+                GraftResult::InvalidArgument
+            );
+            assert!(last_error().contains("invalid vid"));
+
+            assert_eq!(
+                graft_volume_open(
+                    runtime,
+                    ptr::null(),
+                    GraftSyncDirection::Both as u32,
+                    0,
+                    &mut volume
+                ),
+                GraftResult::Ok
+            );
+
+            // page index 0 is invalid
+            let mut reader = null_mut();
+            let mut buf = [0u8; GRAFT_PAGE_SIZE];
+            assert_eq!(graft_reader_open(volume, &mut reader), GraftResult::Ok);
+            assert_eq!(
+                graft_reader_read(reader, 0, buf.as_mut_ptr(), buf.len()),
+                GraftResult::InvalidArgument
+            );
+            graft_reader_free(reader);
+
+            // concurrent writers conflict
+            let mut writer1 = null_mut();
+            let mut writer2 = null_mut();
+            assert_eq!(graft_writer_open(volume, &mut writer1), GraftResult::Ok);
+            assert_eq!(graft_writer_open(volume, &mut writer2), GraftResult::Ok);
+            assert_eq!(
+                graft_writer_write(writer1, 1, buf.as_ptr(), buf.len()),
+                GraftResult::Ok
+            );
+            assert_eq!(
+                graft_writer_write(writer2, 1, buf.as_ptr(), buf.len()),
+                GraftResult::Ok
+            );
+            assert_eq!(graft_writer_commit(writer1, null_mut()), GraftResult::Ok);
+            assert_eq!(
+                graft_writer_commit(writer2, null_mut()),
+                GraftResult::ConcurrentWrite
+            );
+
+            graft_volume_free(volume);
+            graft_runtime_free(runtime);
+        }
+    }
+}
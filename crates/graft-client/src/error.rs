@@ -14,6 +14,14 @@ pub enum ClientErr {
     #[error("http request failed: {0}")]
     HttpErr(#[from] ureq::Error),
 
+    #[cfg(feature = "tokio")]
+    #[error("http request failed: {0}")]
+    ReqwestErr(#[from] reqwest::Error),
+
+    #[cfg(feature = "tokio")]
+    #[error("response body exceeds {0}")]
+    BodyExceedsLimit(graft_core::byte_unit::ByteUnit),
+
     #[error("failed to decode protobuf message")]
     ProtobufDecodeErr,
 
@@ -31,6 +39,15 @@ pub enum ClientErr {
 
     #[error("invalid page size")]
     PageSizeErr(#[from] PageSizeErr),
+
+    #[error("savepoint has been released or rolled back")]
+    InvalidSavepoint,
+
+    #[error("timed out waiting for the volume to reach the requested remote LSN")]
+    RemoteLsnTimeout,
+
+    #[error("invalid bundle: {0}")]
+    InvalidBundle(&'static str),
 }
 
 impl From<http::Error> for ClientErr {
@@ -67,6 +84,46 @@ impl ClientErr {
         }
     }
 
+    pub(crate) fn is_name_not_found(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::NameNotFound,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the request failed because the Volume has been deleted
+    pub fn is_volume_deleted(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::VolumeDeleted,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the request failed because it expected a different
+    /// version of the resource it was updating
+    pub fn is_version_conflict(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::VersionConflict,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_tag_not_found(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::TagNotFound,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the request failed because the tag already points at
+    /// another LSN
+    pub fn is_tag_exists(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::TagExists,
+            _ => false,
+        }
+    }
+
     pub(crate) fn is_commit_rejected(&self) -> bool {
         match self {
             Self::GraftErr(err) => err.code() == GraftErrCode::CommitRejected,
@@ -81,12 +138,14 @@ impl ClientErr {
         }
     }
 
-    pub(crate) fn is_network_err(&self) -> bool {
+    pub fn is_network_err(&self) -> bool {
         match self {
             Self::HttpErr(ureq::Error::Timeout(_)) => true,
             Self::HttpErr(ureq::Error::ConnectionFailed) => true,
             Self::HttpErr(ureq::Error::ConnectProxyFailed(_)) => true,
             Self::HttpErr(ureq::Error::Io(_)) => true,
+            #[cfg(feature = "tokio")]
+            Self::ReqwestErr(err) => err.is_timeout() || err.is_connect() || err.is_request(),
             Self::GraftErr(err) => err.code() == GraftErrCode::ServiceUnavailable,
             _ => false,
         }
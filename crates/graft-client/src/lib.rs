@@ -3,11 +3,14 @@
 mod error;
 mod metastore;
 mod net;
+#[cfg(feature = "tokio")]
+pub mod nonblocking;
 pub mod oracle;
 mod pagestore;
 mod pair;
 
 pub mod runtime {
+    pub mod bundle;
     pub mod runtime;
     pub mod storage;
     pub mod sync;
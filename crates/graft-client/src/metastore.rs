@@ -1,15 +1,26 @@
 use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
-use graft_core::{VolumeId, gid::ClientId, lsn::LSN, page_count::PageCount};
+use graft_core::{
+    VolumeId, gid::ClientId, lsn::LSN, page::PageSize, page_count::PageCount, tag_name::TagName,
+    volume_name::VolumeName,
+};
 use graft_proto::{
-    common::v1::{Commit, LsnRange, SegmentInfo, Snapshot},
+    common::v1::{Commit, CommitMetadata, LsnRange, SegmentInfo, Snapshot},
+    encode_page_size,
     metastore::v1::{
-        CommitRequest, CommitResponse, PullCommitsRequest, PullCommitsResponse, PullGraftRequest,
-        PullGraftResponse, SnapshotRequest, SnapshotResponse,
+        CheckpointRequest, CheckpointResponse, CommitRequest, CommitResponse, CompactRequest,
+        CompactResponse, CreateTagRequest, CreateTagResponse, DeleteTagRequest, DeleteTagResponse,
+        DeleteVolumeRequest, DeleteVolumeResponse, ForkVolumeRequest, ForkVolumeResponse,
+        ListTagsRequest, ListTagsResponse, ListVolumesRequest, ListVolumesResponse,
+        PullCheckpointRequest, PullCheckpointResponse, PullCommitsRequest, PullCommitsResponse,
+        PullGraftRequest, PullGraftResponse, ResolveNameRequest, ResolveNameResponse,
+        RevertVolumeRequest, RevertVolumeResponse, SnapshotRequest, SnapshotResponse, Tag,
+        UndeleteVolumeRequest, UndeleteVolumeResponse, UpdateLabelsRequest, UpdateLabelsResponse,
+        VolumeLabels,
     },
 };
 use splinter_rs::SplinterRef;
-use std::ops::RangeBounds;
+use std::{collections::HashMap, ops::RangeBounds};
 use url::Url;
 
 use crate::NetClient;
@@ -35,6 +46,7 @@ impl MetastoreClient {
         let req = SnapshotRequest {
             vid: vid.copy_to_bytes(),
             lsn: lsn.map(Into::into),
+            tag: String::new(),
         };
         match self.client.send::<_, SnapshotResponse>(uri, req) {
             Ok(resp) => Ok(resp.snapshot),
@@ -53,6 +65,7 @@ impl MetastoreClient {
         let req = PullGraftRequest {
             vid: vid.copy_to_bytes(),
             range: Some(LsnRange::from_range(range)),
+            tag: String::new(),
         };
         match self.client.send::<_, PullGraftResponse>(uri, req) {
             Ok(resp) => {
@@ -84,13 +97,16 @@ impl MetastoreClient {
             .map(|resp| resp.commits)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn commit(
         &self,
         vid: &VolumeId,
         cid: &ClientId,
         snapshot_lsn: Option<LSN>,
         page_count: PageCount,
+        page_size: PageSize,
         segments: Vec<SegmentInfo>,
+        metadata: Option<CommitMetadata>,
     ) -> Result<Snapshot, Culprit<error::ClientErr>> {
         let uri = self.endpoint.build("/metastore/v1/commit")?;
         let req = CommitRequest {
@@ -99,9 +115,262 @@ impl MetastoreClient {
             snapshot_lsn: snapshot_lsn.map(Into::into),
             page_count: page_count.into(),
             segments,
+            page_size: encode_page_size(page_size),
+            metadata,
         };
         self.client
             .send::<_, CommitResponse>(uri, req)
             .map(|r| r.snapshot.expect("missing snapshot after commit"))
     }
+
+    /// Resolve a Volume name into a `VolumeId`. If `create` is true, a missing
+    /// name is registered to a new `VolumeId`, otherwise None is returned.
+    pub fn resolve_name(
+        &self,
+        name: &VolumeName,
+        create: bool,
+    ) -> Result<Option<VolumeId>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/resolve_name")?;
+        let req = ResolveNameRequest {
+            namespace: name.namespace().into(),
+            name: name.name().into(),
+            create,
+        };
+        match self.client.send::<_, ResolveNameResponse>(uri, req) {
+            Ok(resp) => VolumeId::try_from(resp.vid)
+                .or_ctx(|_| error::ClientErr::ProtobufDecodeErr)
+                .map(Some),
+            Err(err) if err.ctx().is_name_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Write a checkpoint for the Volume at its latest LSN, returning the LSN
+    /// of the Volume's latest checkpoint.
+    pub fn checkpoint(&self, vid: &VolumeId) -> Result<LSN, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/checkpoint")?;
+        let req = CheckpointRequest { vid: vid.copy_to_bytes() };
+        let resp = self.client.send::<_, CheckpointResponse>(uri, req)?;
+        LSN::try_from(resp.checkpoint_lsn).or_ctx(|_| error::ClientErr::ProtobufDecodeErr)
+    }
+
+    /// Retrieve the checkpoint written at the specified LSN along with its
+    /// version, returning None if no checkpoint exists.
+    pub fn pull_checkpoint(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<PullCheckpointResponse>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/pull_checkpoint")?;
+        let req = PullCheckpointRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+        };
+        match self.client.send::<_, PullCheckpointResponse>(uri, req) {
+            Ok(resp) => Ok(Some(resp)),
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replace the Volume's checkpoint at the specified LSN with one that
+    /// reads the pages in each of the provided segments from that segment.
+    /// Returns the new checkpoint along with its version.
+    pub fn compact(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+        segments: Vec<SegmentInfo>,
+    ) -> Result<CompactResponse, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/compact")?;
+        let req = CompactRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+            segments,
+        };
+        self.client.send::<_, CompactResponse>(uri, req)
+    }
+
+    /// Delete the Volume. The Volume may be undeleted until the returned
+    /// `purge_at` time, after which its data is purged.
+    pub fn delete_volume(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<DeleteVolumeResponse, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/delete_volume")?;
+        let req = DeleteVolumeRequest { vid: vid.copy_to_bytes() };
+        self.client.send::<_, DeleteVolumeResponse>(uri, req)
+    }
+
+    /// Restore a deleted Volume, returning its latest snapshot.
+    pub fn undelete_volume(&self, vid: &VolumeId) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/undelete_volume")?;
+        let req = UndeleteVolumeRequest { vid: vid.copy_to_bytes() };
+        self.client
+            .send::<_, UndeleteVolumeResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after undelete"))
+    }
+
+    /// List Volumes whose `VolumeId` starts with the prefix and which have all
+    /// of the provided labels, returning at most `limit` Volumes. Pass an empty
+    /// page token to start a listing, and the response's `next_page_token` to
+    /// continue it.
+    pub fn list_volumes(
+        &self,
+        prefix: &str,
+        limit: u32,
+        labels: HashMap<String, String>,
+        page_token: Bytes,
+    ) -> Result<ListVolumesResponse, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/list_volumes")?;
+        let req = ListVolumesRequest {
+            prefix: prefix.into(),
+            limit,
+            page_token,
+            labels,
+        };
+        self.client.send::<_, ListVolumesResponse>(uri, req)
+    }
+
+    /// Retrieve the Volume's labels, returning None if the Volume doesn't
+    /// exist.
+    pub fn labels(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<VolumeLabels>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/snapshot")?;
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: String::new(),
+        };
+        match self.client.send::<_, SnapshotResponse>(uri, req) {
+            Ok(resp) => Ok(Some(resp.labels.unwrap_or_default())),
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replace the Volume's labels if their current version matches
+    /// `expected_version`, returning the updated labels. Fails with a version
+    /// conflict if the labels were concurrently updated.
+    pub fn update_labels(
+        &self,
+        vid: &VolumeId,
+        labels: HashMap<String, String>,
+        expected_version: u64,
+    ) -> Result<VolumeLabels, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/update_labels")?;
+        let req = UpdateLabelsRequest {
+            vid: vid.copy_to_bytes(),
+            labels,
+            expected_version,
+        };
+        self.client
+            .send::<_, UpdateLabelsResponse>(uri, req)
+            .map(|r| r.labels.expect("missing labels after update"))
+    }
+
+    /// Create a new Volume containing the source Volume's pages at the
+    /// provided LSN, or the latest LSN if None. Returns the new Volume's
+    /// snapshot.
+    pub fn fork_volume(
+        &self,
+        source: &VolumeId,
+        lsn: Option<LSN>,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/fork_volume")?;
+        let req = ForkVolumeRequest {
+            source_vid: source.copy_to_bytes(),
+            lsn: lsn.map(Into::into),
+        };
+        self.client
+            .send::<_, ForkVolumeResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after fork"))
+    }
+
+    /// Revert the Volume to its state at `target_lsn` by committing a new
+    /// snapshot on top of the latest one. Returns the revert commit's snapshot.
+    pub fn revert_volume(
+        &self,
+        vid: &VolumeId,
+        cid: &ClientId,
+        target_lsn: LSN,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/revert_volume")?;
+        let req = RevertVolumeRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            target_lsn: target_lsn.into(),
+        };
+        self.client
+            .send::<_, RevertVolumeResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after revert"))
+    }
+
+    /// Retrieve the snapshot at the tagged LSN, returning None if the tag
+    /// doesn't exist.
+    pub fn snapshot_at_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+    ) -> Result<Option<Snapshot>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/snapshot")?;
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: tag.to_string(),
+        };
+        match self.client.send::<_, SnapshotResponse>(uri, req) {
+            Ok(resp) => Ok(resp.snapshot),
+            Err(err) if err.ctx().is_tag_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Point a tag at the Volume's snapshot at `lsn`. Fails if the tag
+    /// already points at another LSN.
+    pub fn create_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+        lsn: LSN,
+    ) -> Result<Tag, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/create_tag")?;
+        let req = CreateTagRequest {
+            vid: vid.copy_to_bytes(),
+            name: tag.to_string(),
+            lsn: lsn.into(),
+        };
+        self.client
+            .send::<_, CreateTagResponse>(uri, req)
+            .map(|r| r.tag.expect("missing tag after create"))
+    }
+
+    /// List the Volume's tags in order by name
+    pub fn list_tags(&self, vid: &VolumeId) -> Result<Vec<Tag>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/list_tags")?;
+        let req = ListTagsRequest { vid: vid.copy_to_bytes() };
+        self.client
+            .send::<_, ListTagsResponse>(uri, req)
+            .map(|r| r.tags)
+    }
+
+    /// Delete a tag, returning the deleted tag or None if it doesn't exist.
+    pub fn delete_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+    ) -> Result<Option<Tag>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/delete_tag")?;
+        let req = DeleteTagRequest {
+            vid: vid.copy_to_bytes(),
+            name: tag.to_string(),
+        };
+        match self.client.send::<_, DeleteTagResponse>(uri, req) {
+            Ok(resp) => Ok(resp.tag),
+            Err(err) if err.ctx().is_tag_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
 }
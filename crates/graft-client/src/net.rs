@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::{BufMut, Bytes, BytesMut};
 use culprit::{Culprit, ResultExt};
 use graft_core::byte_unit::ByteUnit;
 use graft_proto::common::v1::GraftErr;
 use http::{
-    HeaderName, HeaderValue, Uri,
+    HeaderName, HeaderValue, StatusCode, Uri,
     header::AUTHORIZATION,
     uri::{Builder, PathAndQuery},
 };
@@ -17,9 +17,10 @@ use crate::{USER_AGENT, error::ClientErr};
 
 use prost::Message;
 
-const CONTENT_TYPE: HeaderName = HeaderName::from_static("content-type");
-const APPLICATION_PROTOBUF: HeaderValue = HeaderValue::from_static("application/x-protobuf");
-const MAX_READ_SIZE: ByteUnit = ByteUnit::from_mb(8);
+pub(crate) const CONTENT_TYPE: HeaderName = HeaderName::from_static("content-type");
+pub(crate) const APPLICATION_PROTOBUF: HeaderValue =
+    HeaderValue::from_static("application/x-protobuf");
+pub(crate) const MAX_READ_SIZE: ByteUnit = ByteUnit::from_mb(8);
 
 #[derive(Debug, Clone)]
 pub(crate) struct EndpointBuilder {
@@ -110,18 +111,7 @@ impl NetClient {
         let status = resp.status();
         span.record("status", status.as_u16());
 
-        let content_type = resp.headers().get(CONTENT_TYPE);
-        if content_type != Some(&APPLICATION_PROTOBUF) {
-            return Err(
-                Culprit::new(ClientErr::ProtobufDecodeErr).with_note(format!(
-                    "expected content type '{}' but received {:?}",
-                    APPLICATION_PROTOBUF.to_str().unwrap(),
-                    content_type
-                )),
-            );
-        }
-
-        let success = (200..300).contains(&status.as_u16());
+        check_content_type(resp.headers().get(CONTENT_TYPE))?;
 
         // read the response into a Bytes object
         let reader = resp
@@ -131,37 +121,63 @@ impl NetClient {
         let mut writer = BytesMut::new().writer();
         std::io::copy(&mut reader.reader(), &mut writer).or_into_ctx()?;
         let body = writer.into_inner().freeze();
-        let body_size = ByteUnit::new(body.len() as u64);
-
-        if success {
-            Ok(Resp::decode(body).map_err(|err| {
-                let note = format!(
-                    "failed to decode response body into {} from buffer of size {}",
-                    type_name::<Resp>(),
-                    body_size
-                );
-                Culprit::from_err(err).with_note(note)
-            })?)
-        } else {
-            let err = GraftErr::decode(body).map_err(|err| {
-                let note = format!(
-                    "failed to decode response body into GraftErr from buffer of size {body_size}"
-                );
-                Culprit::from_err(err).with_note(note)
-            })?;
-
-            // 5xx errors are not expected from client requests unless the graft
-            // error signals that the service is temporarily unavailable
-            precept::expect_always_or_unreachable!(
-                !(500..600).contains(&status.as_u16()) || err.code() == graft_proto::GraftErrCode::ServiceUnavailable,
-                "client requests should not return 5xx errors",
-                {
-                    "status": status.as_u16(),
-                    "code": err.code().as_str_name(),
-                    "message": err.message
-                }
+
+        decode_response(status, body)
+    }
+}
+
+/// Verify that a response has the protobuf content type
+pub(crate) fn check_content_type(
+    content_type: Option<&HeaderValue>,
+) -> Result<(), Culprit<ClientErr>> {
+    if content_type != Some(&APPLICATION_PROTOBUF) {
+        return Err(
+            Culprit::new(ClientErr::ProtobufDecodeErr).with_note(format!(
+                "expected content type '{}' but received {:?}",
+                APPLICATION_PROTOBUF.to_str().unwrap(),
+                content_type
+            )),
+        );
+    }
+    Ok(())
+}
+
+/// Decode a response body into either Resp or a `GraftErr` depending on the
+/// response status
+pub(crate) fn decode_response<Resp: Message + Default>(
+    status: StatusCode,
+    body: Bytes,
+) -> Result<Resp, Culprit<ClientErr>> {
+    let body_size = ByteUnit::new(body.len() as u64);
+
+    if status.is_success() {
+        Ok(Resp::decode(body).map_err(|err| {
+            let note = format!(
+                "failed to decode response body into {} from buffer of size {}",
+                type_name::<Resp>(),
+                body_size
             );
-            Err(err.into())
-        }
+            Culprit::from_err(err).with_note(note)
+        })?)
+    } else {
+        let err = GraftErr::decode(body).map_err(|err| {
+            let note = format!(
+                "failed to decode response body into GraftErr from buffer of size {body_size}"
+            );
+            Culprit::from_err(err).with_note(note)
+        })?;
+
+        // 5xx errors are not expected from client requests unless the graft
+        // error signals that the service is temporarily unavailable
+        precept::expect_always_or_unreachable!(
+            !(500..600).contains(&status.as_u16()) || err.code() == graft_proto::GraftErrCode::ServiceUnavailable,
+            "client requests should not return 5xx errors",
+            {
+                "status": status.as_u16(),
+                "code": err.code().as_str_name(),
+                "message": err.message
+            }
+        );
+        Err(err.into())
     }
 }
@@ -0,0 +1,44 @@
+//! An async client API built on tokio, enabled by the `tokio` feature.
+//!
+//! This module mirrors the blocking API exposed by `graft_client` and
+//! `graft_client::runtime`, but performs network requests asynchronously and
+//! runs the sync task on the tokio runtime rather than a dedicated thread.
+//! Both APIs are backed by the same `Storage`, so a volume written using one
+//! API may be opened using the other.
+//!
+//! Reads from local storage run inline on the calling task, while commits and
+//! other writes to local storage are moved onto the blocking thread pool as
+//! they wait for local storage to durably persist the transaction.
+
+mod metastore;
+mod net;
+mod pagestore;
+mod pair;
+
+pub mod runtime;
+pub mod sync;
+pub mod volume_handle;
+pub mod volume_reader;
+pub mod volume_writer;
+
+pub use metastore::MetastoreClient;
+pub use net::NetClient;
+pub use pagestore::PagestoreClient;
+pub use pair::ClientPair;
+pub use runtime::Runtime;
+pub use volume_handle::VolumeHandle;
+pub use volume_reader::{VolumeRead, VolumeReader};
+pub use volume_writer::{VolumeWrite, VolumeWriter};
+
+/// Run a storage operation which waits on durable writes on the blocking
+/// thread pool, resuming any panic on the calling task.
+pub(crate) async fn spawn_blocking<T, F>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(err) => std::panic::resume_unwind(err.into_panic()),
+    }
+}
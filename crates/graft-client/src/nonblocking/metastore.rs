@@ -0,0 +1,392 @@
+use bytes::Bytes;
+use culprit::{Culprit, ResultExt};
+use graft_core::{
+    VolumeId, gid::ClientId, lsn::LSN, page::PageSize, page_count::PageCount, tag_name::TagName,
+    volume_name::VolumeName,
+};
+use graft_proto::{
+    common::v1::{Commit, CommitMetadata, LsnRange, SegmentInfo, Snapshot},
+    encode_page_size,
+    metastore::v1::{
+        CheckpointRequest, CheckpointResponse, CommitRequest, CommitResponse, CompactRequest,
+        CompactResponse, CreateTagRequest, CreateTagResponse, DeleteTagRequest, DeleteTagResponse,
+        DeleteVolumeRequest, DeleteVolumeResponse, ForkVolumeRequest, ForkVolumeResponse,
+        ListTagsRequest, ListTagsResponse, ListVolumesRequest, ListVolumesResponse,
+        PullCheckpointRequest, PullCheckpointResponse, PullCommitsRequest, PullCommitsResponse,
+        PullGraftRequest, PullGraftResponse, ResolveNameRequest, ResolveNameResponse,
+        RevertVolumeRequest, RevertVolumeResponse, SnapshotRequest, SnapshotResponse, Tag,
+        UndeleteVolumeRequest, UndeleteVolumeResponse, UpdateLabelsRequest, UpdateLabelsResponse,
+        VolumeLabels,
+    },
+};
+use splinter_rs::SplinterRef;
+use std::{collections::HashMap, ops::RangeBounds};
+use url::Url;
+
+use super::NetClient;
+use crate::{error, net::EndpointBuilder};
+
+/// The async equivalent of `graft_client::MetastoreClient`
+#[derive(Debug, Clone)]
+pub struct MetastoreClient {
+    endpoint: EndpointBuilder,
+    client: NetClient,
+}
+
+impl MetastoreClient {
+    pub fn new(root: Url, client: NetClient) -> Self {
+        Self { endpoint: root.into(), client }
+    }
+
+    pub async fn snapshot(
+        &self,
+        vid: &VolumeId,
+        lsn: Option<LSN>,
+    ) -> Result<Option<Snapshot>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/snapshot")?;
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.map(Into::into),
+            tag: String::new(),
+        };
+        match self.client.send::<_, SnapshotResponse>(uri, req).await {
+            Ok(resp) => Ok(resp.snapshot),
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub async fn pull_graft<R: RangeBounds<LSN>>(
+        &self,
+        vid: &VolumeId,
+        range: R,
+    ) -> Result<Option<(Snapshot, LsnRange, SplinterRef<Bytes>)>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/pull_graft")?;
+        let req = PullGraftRequest {
+            vid: vid.copy_to_bytes(),
+            range: Some(LsnRange::from_range(range)),
+            tag: String::new(),
+        };
+        match self.client.send::<_, PullGraftResponse>(uri, req).await {
+            Ok(resp) => {
+                let snapshot = resp.snapshot.expect("snapshot is missing");
+                let range = resp.range.expect("range is missing");
+                let graft = SplinterRef::from_bytes(resp.graft).or_into_ctx()?;
+                Ok(Some((snapshot, range, graft)))
+            }
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn pull_commits<R>(
+        &self,
+        vid: &VolumeId,
+        range: R,
+    ) -> Result<Vec<Commit>, Culprit<error::ClientErr>>
+    where
+        R: RangeBounds<LSN>,
+    {
+        let uri = self.endpoint.build("/metastore/v1/pull_commits")?;
+        let req = PullCommitsRequest {
+            vid: vid.copy_to_bytes(),
+            range: Some(LsnRange::from_range(range)),
+        };
+        self.client
+            .send::<_, PullCommitsResponse>(uri, req)
+            .await
+            .map(|resp| resp.commits)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn commit(
+        &self,
+        vid: &VolumeId,
+        cid: &ClientId,
+        snapshot_lsn: Option<LSN>,
+        page_count: PageCount,
+        page_size: PageSize,
+        segments: Vec<SegmentInfo>,
+        metadata: Option<CommitMetadata>,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/commit")?;
+        let req = CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn: snapshot_lsn.map(Into::into),
+            page_count: page_count.into(),
+            segments,
+            page_size: encode_page_size(page_size),
+            metadata,
+        };
+        self.client
+            .send::<_, CommitResponse>(uri, req)
+            .await
+            .map(|r| r.snapshot.expect("missing snapshot after commit"))
+    }
+
+    /// Resolve a Volume name into a `VolumeId`. If `create` is true, a missing
+    /// name is registered to a new `VolumeId`, otherwise None is returned.
+    pub async fn resolve_name(
+        &self,
+        name: &VolumeName,
+        create: bool,
+    ) -> Result<Option<VolumeId>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/resolve_name")?;
+        let req = ResolveNameRequest {
+            namespace: name.namespace().into(),
+            name: name.name().into(),
+            create,
+        };
+        match self.client.send::<_, ResolveNameResponse>(uri, req).await {
+            Ok(resp) => VolumeId::try_from(resp.vid)
+                .or_ctx(|_| error::ClientErr::ProtobufDecodeErr)
+                .map(Some),
+            Err(err) if err.ctx().is_name_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Write a checkpoint for the Volume at its latest LSN, returning the LSN
+    /// of the Volume's latest checkpoint.
+    pub async fn checkpoint(&self, vid: &VolumeId) -> Result<LSN, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/checkpoint")?;
+        let req = CheckpointRequest { vid: vid.copy_to_bytes() };
+        let resp = self.client.send::<_, CheckpointResponse>(uri, req).await?;
+        LSN::try_from(resp.checkpoint_lsn).or_ctx(|_| error::ClientErr::ProtobufDecodeErr)
+    }
+
+    /// Retrieve the checkpoint written at the specified LSN along with its
+    /// version, returning None if no checkpoint exists.
+    pub async fn pull_checkpoint(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<PullCheckpointResponse>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/pull_checkpoint")?;
+        let req = PullCheckpointRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+        };
+        match self
+            .client
+            .send::<_, PullCheckpointResponse>(uri, req)
+            .await
+        {
+            Ok(resp) => Ok(Some(resp)),
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replace the Volume's checkpoint at the specified LSN with one that
+    /// reads the pages in each of the provided segments from that segment.
+    /// Returns the new checkpoint along with its version.
+    pub async fn compact(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+        segments: Vec<SegmentInfo>,
+    ) -> Result<CompactResponse, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/compact")?;
+        let req = CompactRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+            segments,
+        };
+        self.client.send::<_, CompactResponse>(uri, req).await
+    }
+
+    /// Delete the Volume. The Volume may be undeleted until the returned
+    /// `purge_at` time, after which its data is purged.
+    pub async fn delete_volume(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<DeleteVolumeResponse, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/delete_volume")?;
+        let req = DeleteVolumeRequest { vid: vid.copy_to_bytes() };
+        self.client.send::<_, DeleteVolumeResponse>(uri, req).await
+    }
+
+    /// Restore a deleted Volume, returning its latest snapshot.
+    pub async fn undelete_volume(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/undelete_volume")?;
+        let req = UndeleteVolumeRequest { vid: vid.copy_to_bytes() };
+        self.client
+            .send::<_, UndeleteVolumeResponse>(uri, req)
+            .await
+            .map(|r| r.snapshot.expect("missing snapshot after undelete"))
+    }
+
+    /// List Volumes whose `VolumeId` starts with the prefix and which have all
+    /// of the provided labels, returning at most `limit` Volumes. Pass an empty
+    /// page token to start a listing, and the response's `next_page_token` to
+    /// continue it.
+    pub async fn list_volumes(
+        &self,
+        prefix: &str,
+        limit: u32,
+        labels: HashMap<String, String>,
+        page_token: Bytes,
+    ) -> Result<ListVolumesResponse, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/list_volumes")?;
+        let req = ListVolumesRequest {
+            prefix: prefix.into(),
+            limit,
+            page_token,
+            labels,
+        };
+        self.client.send::<_, ListVolumesResponse>(uri, req).await
+    }
+
+    /// Retrieve the Volume's labels, returning None if the Volume doesn't
+    /// exist.
+    pub async fn labels(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<VolumeLabels>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/snapshot")?;
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: String::new(),
+        };
+        match self.client.send::<_, SnapshotResponse>(uri, req).await {
+            Ok(resp) => Ok(Some(resp.labels.unwrap_or_default())),
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replace the Volume's labels if their current version matches
+    /// `expected_version`, returning the updated labels. Fails with a version
+    /// conflict if the labels were concurrently updated.
+    pub async fn update_labels(
+        &self,
+        vid: &VolumeId,
+        labels: HashMap<String, String>,
+        expected_version: u64,
+    ) -> Result<VolumeLabels, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/update_labels")?;
+        let req = UpdateLabelsRequest {
+            vid: vid.copy_to_bytes(),
+            labels,
+            expected_version,
+        };
+        self.client
+            .send::<_, UpdateLabelsResponse>(uri, req)
+            .await
+            .map(|r| r.labels.expect("missing labels after update"))
+    }
+
+    /// Create a new Volume containing the source Volume's pages at the
+    /// provided LSN, or the latest LSN if None. Returns the new Volume's
+    /// snapshot.
+    pub async fn fork_volume(
+        &self,
+        source: &VolumeId,
+        lsn: Option<LSN>,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/fork_volume")?;
+        let req = ForkVolumeRequest {
+            source_vid: source.copy_to_bytes(),
+            lsn: lsn.map(Into::into),
+        };
+        self.client
+            .send::<_, ForkVolumeResponse>(uri, req)
+            .await
+            .map(|r| r.snapshot.expect("missing snapshot after fork"))
+    }
+
+    /// Revert the Volume to its state at `target_lsn` by committing a new
+    /// snapshot on top of the latest one. Returns the revert commit's snapshot.
+    pub async fn revert_volume(
+        &self,
+        vid: &VolumeId,
+        cid: &ClientId,
+        target_lsn: LSN,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/revert_volume")?;
+        let req = RevertVolumeRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            target_lsn: target_lsn.into(),
+        };
+        self.client
+            .send::<_, RevertVolumeResponse>(uri, req)
+            .await
+            .map(|r| r.snapshot.expect("missing snapshot after revert"))
+    }
+
+    /// Retrieve the snapshot at the tagged LSN, returning None if the tag
+    /// doesn't exist.
+    pub async fn snapshot_at_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+    ) -> Result<Option<Snapshot>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/snapshot")?;
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: tag.to_string(),
+        };
+        match self.client.send::<_, SnapshotResponse>(uri, req).await {
+            Ok(resp) => Ok(resp.snapshot),
+            Err(err) if err.ctx().is_tag_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Point a tag at the Volume's snapshot at `lsn`. Fails if the tag
+    /// already points at another LSN.
+    pub async fn create_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+        lsn: LSN,
+    ) -> Result<Tag, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/create_tag")?;
+        let req = CreateTagRequest {
+            vid: vid.copy_to_bytes(),
+            name: tag.to_string(),
+            lsn: lsn.into(),
+        };
+        self.client
+            .send::<_, CreateTagResponse>(uri, req)
+            .await
+            .map(|r| r.tag.expect("missing tag after create"))
+    }
+
+    /// List the Volume's tags in order by name
+    pub async fn list_tags(&self, vid: &VolumeId) -> Result<Vec<Tag>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/list_tags")?;
+        let req = ListTagsRequest { vid: vid.copy_to_bytes() };
+        self.client
+            .send::<_, ListTagsResponse>(uri, req)
+            .await
+            .map(|r| r.tags)
+    }
+
+    /// Delete a tag, returning the deleted tag or None if it doesn't exist.
+    pub async fn delete_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+    ) -> Result<Option<Tag>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/delete_tag")?;
+        let req = DeleteTagRequest {
+            vid: vid.copy_to_bytes(),
+            name: tag.to_string(),
+        };
+        match self.client.send::<_, DeleteTagResponse>(uri, req).await {
+            Ok(resp) => Ok(resp.tag),
+            Err(err) if err.ctx().is_tag_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use bytes::BytesMut;
+use culprit::{Culprit, ResultExt};
+use http::{Uri, header::AUTHORIZATION};
+use prost::Message;
+use std::time::Duration;
+use tracing::{Instrument, field};
+
+use crate::{
+    USER_AGENT,
+    error::ClientErr,
+    net::{APPLICATION_PROTOBUF, CONTENT_TYPE, MAX_READ_SIZE, check_content_type, decode_response},
+};
+
+/// The async equivalent of `graft_client::NetClient`
+#[derive(Debug, Clone)]
+pub struct NetClient {
+    api_token: Option<String>,
+    client: reqwest::Client,
+}
+
+impl NetClient {
+    pub fn new(api_token: Option<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .pool_idle_timeout(Duration::from_secs(300))
+            .connect_timeout(Duration::from_secs(60))
+            .read_timeout(Duration::from_secs(60))
+            .timeout(Duration::from_secs(300))
+            .build()
+            .expect("failed to build http client");
+        Self::new_with_client(api_token, client)
+    }
+
+    /// Create a `NetClient` using a preconfigured `reqwest::Client`
+    pub fn new_with_client(api_token: Option<String>, client: reqwest::Client) -> Self {
+        Self { api_token, client }
+    }
+
+    pub(crate) async fn send<Msg: Message, Resp: Message + Default>(
+        &self,
+        uri: Uri,
+        msg: Msg,
+    ) -> Result<Resp, Culprit<ClientErr>> {
+        let span = tracing::trace_span!(
+            "NetClient::send",
+            path = uri.path(),
+            status = field::Empty,
+            err = field::Empty
+        );
+
+        async {
+            let req = self
+                .client
+                .post(uri.to_string())
+                .header(CONTENT_TYPE, APPLICATION_PROTOBUF)
+                .body(msg.encode_to_vec());
+
+            let req = if let Some(token) = &self.api_token {
+                req.header(AUTHORIZATION, format!("Bearer {token}"))
+            } else {
+                req
+            };
+
+            let mut resp = match req.send().await {
+                Ok(resp) => resp,
+                Err(err) => {
+                    tracing::Span::current().record("err", err.to_string());
+                    return Err(err.into());
+                }
+            };
+
+            let status = resp.status();
+            tracing::Span::current().record("status", status.as_u16());
+
+            check_content_type(resp.headers().get(CONTENT_TYPE))?;
+
+            // read the response into a Bytes object
+            let mut body = BytesMut::new();
+            while let Some(chunk) = resp.chunk().await.or_into_ctx()? {
+                if (body.len() + chunk.len()) as u64 > MAX_READ_SIZE.as_u64() {
+                    return Err(Culprit::new(ClientErr::BodyExceedsLimit(MAX_READ_SIZE)));
+                }
+                body.extend_from_slice(&chunk);
+            }
+
+            decode_response(status, body.freeze())
+        }
+        .instrument(span)
+        .await
+    }
+}
@@ -0,0 +1,123 @@
+use bytes::Bytes;
+use culprit::Culprit;
+use graft_core::lsn::LSN;
+use graft_core::{VolumeId, page::PageSize, page_count::PageCount, tag_name::TagName};
+use graft_proto::{
+    common::v1::SegmentInfo,
+    encode_page_size,
+    pagestore::v1::{
+        PageAtIdx, ReadPagesRequest, ReadPagesResponse, WritePagesRequest, WritePagesResponse,
+    },
+};
+use std::sync::atomic::{AtomicU32, Ordering};
+use url::Url;
+
+use super::NetClient;
+use crate::{ClientErr, net::EndpointBuilder};
+
+/// The async equivalent of `graft_client::PagestoreClient`
+#[derive(Debug)]
+pub struct PagestoreClient {
+    endpoint: EndpointBuilder,
+    client: NetClient,
+    pages_read_count: AtomicU32,
+}
+
+impl PagestoreClient {
+    pub fn new(root: Url, client: NetClient) -> Self {
+        Self {
+            endpoint: root.into(),
+            client,
+            pages_read_count: AtomicU32::new(0),
+        }
+    }
+
+    pub async fn read_pages(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+        graft: Bytes,
+    ) -> Result<Vec<PageAtIdx>, Culprit<ClientErr>> {
+        let req = ReadPagesRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+            graft,
+            tag: String::new(),
+        };
+        self.send_read_pages(req).await
+    }
+
+    /// Read pages from the Volume's snapshot at the tagged LSN
+    pub async fn read_pages_at_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+        graft: Bytes,
+    ) -> Result<Vec<PageAtIdx>, Culprit<ClientErr>> {
+        let req = ReadPagesRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: 0,
+            graft,
+            tag: tag.to_string(),
+        };
+        self.send_read_pages(req).await
+    }
+
+    async fn send_read_pages(
+        &self,
+        req: ReadPagesRequest,
+    ) -> Result<Vec<PageAtIdx>, Culprit<ClientErr>> {
+        let uri = self.endpoint.build("/pagestore/v1/read_pages")?;
+        let result = self
+            .client
+            .send::<_, ReadPagesResponse>(uri, req)
+            .await
+            .map(|r| r.pages);
+
+        // Increment the counter with the number of pages read
+        if let Ok(ref pages) = result {
+            self.pages_read_count
+                .fetch_add(pages.len() as u32, Ordering::Relaxed);
+        }
+
+        result
+    }
+
+    pub async fn write_pages(
+        &self,
+        vid: &VolumeId,
+        page_size: PageSize,
+        pages: Vec<PageAtIdx>,
+    ) -> Result<Vec<SegmentInfo>, Culprit<ClientErr>> {
+        let uri = self.endpoint.build("/pagestore/v1/write_pages")?;
+        let req = WritePagesRequest {
+            vid: vid.copy_to_bytes(),
+            pages,
+            page_size: encode_page_size(page_size),
+        };
+        self.client
+            .send::<_, WritePagesResponse>(uri, req)
+            .await
+            .map(|r| r.segments)
+    }
+
+    /// Returns the total number of pages read by this client.
+    pub fn pages_read(&self) -> PageCount {
+        PageCount::new(self.pages_read_count.load(Ordering::Relaxed))
+    }
+
+    /// Resets the pages read counter to zero.
+    pub fn reset_pages_read(&self) {
+        self.pages_read_count.store(0, Ordering::Relaxed);
+    }
+}
+
+impl Clone for PagestoreClient {
+    fn clone(&self) -> Self {
+        Self {
+            endpoint: self.endpoint.clone(),
+            client: self.client.clone(),
+            pages_read_count: AtomicU32::new(0), // New counter for each clone
+        }
+    }
+}
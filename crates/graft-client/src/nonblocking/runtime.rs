@@ -0,0 +1,244 @@
+use culprit::{Culprit, Result, ResultExt};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use graft_core::{VolumeId, gid::ClientId, volume_name::VolumeName};
+
+use crate::{
+    ClientErr,
+    runtime::{
+        storage::{Storage, volume_state::VolumeConfig},
+        sync::{PushCoalescing, ShutdownErr, StartupErr, SyncTaskErr},
+    },
+};
+
+use super::{ClientPair, sync::SyncTaskHandle, volume_handle::VolumeHandle};
+
+/// The async equivalent of `graft_client::runtime::runtime::Runtime`
+#[derive(Clone)]
+pub struct Runtime {
+    cid: ClientId,
+    clients: Arc<ClientPair>,
+    storage: Arc<Storage>,
+    sync: SyncTaskHandle,
+}
+
+impl Runtime {
+    pub fn new(cid: ClientId, clients: ClientPair, storage: Storage) -> Self {
+        Self {
+            cid,
+            clients: Arc::new(clients),
+            storage: Arc::new(storage),
+            sync: SyncTaskHandle::default(),
+        }
+    }
+
+    pub fn cid(&self) -> &ClientId {
+        &self.cid
+    }
+
+    pub fn clients(&self) -> &ClientPair {
+        &self.clients
+    }
+
+    /// Start the sync task on the current tokio runtime.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime.
+    pub fn start_sync_task(
+        &self,
+        refresh_interval: Duration,
+        control_channel_size: usize,
+        autosync: bool,
+    ) -> Result<(), StartupErr> {
+        self.sync.spawn(
+            self.cid.clone(),
+            self.storage.clone(),
+            self.clients.clone(),
+            refresh_interval,
+            control_channel_size,
+            autosync,
+        )
+    }
+
+    pub async fn shutdown_sync_task(&self, timeout: Duration) -> Result<(), ShutdownErr> {
+        self.sync.shutdown_timeout(timeout).await
+    }
+
+    pub async fn get_autosync(&self) -> bool {
+        self.sync.rpc().get_autosync().await
+    }
+
+    pub async fn set_autosync(&self, autosync: bool) {
+        self.sync.rpc().set_autosync(autosync).await
+    }
+
+    pub async fn get_push_coalescing(&self) -> PushCoalescing {
+        self.sync.rpc().get_push_coalescing().await
+    }
+
+    /// Configure how autosync batches local commits into remote commits
+    pub async fn set_push_coalescing(&self, policy: PushCoalescing) {
+        self.sync.rpc().set_push_coalescing(policy).await
+    }
+
+    pub async fn drain_recent_sync_errors(&self) -> Vec<(Instant, Culprit<SyncTaskErr>)> {
+        self.sync.rpc().drain_recent_errors().await
+    }
+
+    pub fn volume_exists(&self, vid: VolumeId) -> Result<bool, ClientErr> {
+        self.storage.volume_exists(vid).or_into_ctx()
+    }
+
+    pub fn open_volume(
+        &self,
+        vid: &VolumeId,
+        config: VolumeConfig,
+    ) -> Result<VolumeHandle, ClientErr> {
+        let page_size = config.page_size();
+        self.storage.set_volume_config(vid, config).or_into_ctx()?;
+
+        Ok(VolumeHandle::new(
+            vid.clone(),
+            page_size,
+            self.clients.clone(),
+            self.storage.clone(),
+            self.sync.rpc(),
+        ))
+    }
+
+    /// Retrieve a volume's config, returning None if the volume has never
+    /// been opened
+    pub fn volume_config(&self, vid: &VolumeId) -> Result<Option<VolumeConfig>, ClientErr> {
+        self.storage.get_volume_config(vid).or_into_ctx()
+    }
+
+    /// Resolve a Volume name into a `VolumeId` using the metastore's name
+    /// registry. If `create` is true, a missing name is registered to a new
+    /// `VolumeId`, otherwise None is returned.
+    pub async fn resolve_volume_name(
+        &self,
+        name: &VolumeName,
+        create: bool,
+    ) -> Result<Option<VolumeId>, ClientErr> {
+        self.clients.metastore().resolve_name(name, create).await
+    }
+
+    /// Open a Volume by name, registering the name if it doesn't exist yet.
+    /// Resolving the name requires access to the metastore.
+    pub async fn open_volume_by_name(
+        &self,
+        name: &VolumeName,
+        config: VolumeConfig,
+    ) -> Result<VolumeHandle, ClientErr> {
+        let vid = self
+            .resolve_volume_name(name, true)
+            .await?
+            .expect("volume name is created if missing");
+        self.open_volume(&vid, config)
+    }
+
+    pub fn update_volume_config<U>(&self, vid: &VolumeId, f: U) -> Result<(), ClientErr>
+    where
+        U: FnMut(VolumeConfig) -> VolumeConfig,
+    {
+        self.storage.update_volume_config(vid, f).or_into_ctx()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graft_core::{
+        page::{EMPTY_PAGE, Page},
+        pageidx,
+    };
+
+    use crate::{
+        nonblocking::{VolumeRead, VolumeWrite},
+        oracle::NoopOracle,
+        runtime::storage::volume_state::SyncDirection,
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_async_read_write_sanity() {
+        let cid = ClientId::random();
+        let storage = Storage::open_memory().unwrap();
+        let runtime = Runtime::new(cid, ClientPair::test_empty(), storage);
+        let mut oracle = NoopOracle;
+
+        let vid = VolumeId::random();
+        let page = Page::test_filled(0x42);
+        let page2 = Page::test_filled(0x99);
+
+        let handle = runtime
+            .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap();
+        let local_changes = handle.subscribe_to_local_changes();
+
+        // open a reader and verify that no pages are returned
+        let reader = handle.reader().unwrap();
+        assert_eq!(reader.snapshot(), None);
+        assert_eq!(
+            reader.read(&mut oracle, pageidx!(1)).await.unwrap(),
+            EMPTY_PAGE
+        );
+
+        // open a writer and write a page, verify RYOW, then commit
+        let mut writer = handle.writer().unwrap();
+        writer.write(pageidx!(1), page.clone());
+        assert_eq!(writer.read(&mut oracle, pageidx!(1)).await.unwrap(), page);
+        let reader = writer.commit().await.unwrap();
+
+        // the commit should notify local subscribers
+        local_changes.notified().await;
+
+        // verify the new reader can read the page
+        assert_eq!(reader.read(&mut oracle, pageidx!(1)).await.unwrap(), page);
+        let snapshot = reader.snapshot().unwrap();
+        assert_eq!(snapshot.local(), 1);
+        assert_eq!(snapshot.pages(), 1);
+
+        // upgrade to a writer and write another page
+        let mut writer = reader.upgrade();
+        writer.write(pageidx!(2), page2.clone());
+        let reader = writer.commit().await.unwrap();
+
+        // verify the new reader can read both pages
+        assert_eq!(reader.read(&mut oracle, pageidx!(1)).await.unwrap(), page);
+        assert_eq!(reader.read(&mut oracle, pageidx!(2)).await.unwrap(), page2);
+        let snapshot = reader.snapshot().unwrap();
+        assert_eq!(snapshot.local(), 2);
+        assert_eq!(snapshot.pages(), 2);
+
+        // write a byte range straddling pages 2 and 3, then read it back
+        let pagesize = reader.page_size().as_usize();
+        let data = vec![0x11; pagesize];
+        let offset = (pagesize + pagesize / 2) as u64;
+        let mut writer = reader.upgrade();
+        writer.write_at(&mut oracle, offset, &data).await.unwrap();
+        assert_eq!(writer.pages(), 3);
+        let reader = writer.commit().await.unwrap();
+
+        let mut buf = vec![0xff; pagesize * 2];
+        reader
+            .read_at(&mut oracle, pagesize as u64, &mut buf)
+            .await
+            .unwrap();
+        assert!(buf[..pagesize / 2].iter().all(|&b| b == 0x99));
+        assert_eq!(&buf[pagesize / 2..][..pagesize], &data[..]);
+        assert!(buf[pagesize / 2 + pagesize..].iter().all(|&b| b == 0));
+
+        // every commit is still pending as the volume has no remote
+        let stats = handle.stats().unwrap();
+        assert_eq!(stats.pages, 3);
+        assert_eq!(stats.available_pages, 3);
+        assert_eq!(stats.pending_pages, 0);
+        assert_eq!(stats.pending_commits, 3);
+        assert_eq!(stats.last_push, None);
+    }
+}
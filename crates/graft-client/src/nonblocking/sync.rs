@@ -0,0 +1,447 @@
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{ClientId, VolumeId};
+use parking_lot::Mutex;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+
+use crate::{
+    ClientErr,
+    runtime::{
+        storage::{Storage, changeset::NotifySetSubscriber, volume_state::SyncDirection},
+        sync::{
+            ERROR_BACKOFF, PendingPushes, PushCoalescing, ShutdownErr, StartupErr, SyncTaskErr,
+            job::Job, panic_message, record_error, sync_jobs,
+        },
+    },
+};
+
+use super::ClientPair;
+
+#[derive(Debug)]
+pub enum SyncControl {
+    GetAutosync {
+        complete: oneshot::Sender<bool>,
+    },
+
+    SetAutosync {
+        autosync: bool,
+        complete: oneshot::Sender<()>,
+    },
+
+    GetPushCoalescing {
+        complete: oneshot::Sender<PushCoalescing>,
+    },
+
+    SetPushCoalescing {
+        policy: PushCoalescing,
+        complete: oneshot::Sender<()>,
+    },
+
+    Sync {
+        vid: VolumeId,
+        direction: SyncDirection,
+        complete: oneshot::Sender<Result<(), ClientErr>>,
+    },
+
+    ResetToRemote {
+        vid: VolumeId,
+        complete: oneshot::Sender<Result<(), ClientErr>>,
+    },
+
+    DrainRecentErrors {
+        complete: oneshot::Sender<Vec<(Instant, Culprit<SyncTaskErr>)>>,
+    },
+
+    Shutdown,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyncRpc {
+    control: Option<mpsc::Sender<SyncControl>>,
+}
+
+impl SyncRpc {
+    async fn must_call<T>(&self, msg: SyncControl, recv: oneshot::Receiver<T>) -> T {
+        self.control
+            .as_ref()
+            .expect("SyncRpc: control channel missing")
+            .send(msg)
+            .await
+            .expect("SyncRpc: control channel closed");
+        recv.await.expect("SyncRpc: response channel closed")
+    }
+
+    pub async fn get_autosync(&self) -> bool {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::GetAutosync { complete }, recv)
+            .await
+    }
+
+    pub async fn set_autosync(&self, autosync: bool) {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::SetAutosync { autosync, complete }, recv)
+            .await
+    }
+
+    pub async fn get_push_coalescing(&self) -> PushCoalescing {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::GetPushCoalescing { complete }, recv)
+            .await
+    }
+
+    pub async fn set_push_coalescing(&self, policy: PushCoalescing) {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::SetPushCoalescing { policy, complete }, recv)
+            .await
+    }
+
+    pub async fn sync(&self, vid: VolumeId, direction: SyncDirection) -> Result<(), ClientErr> {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::Sync { vid, direction, complete }, recv)
+            .await
+    }
+
+    /// Like `sync`, but stops waiting at `deadline`. Returns None if the
+    /// deadline passes first, in which case the sync completes in the
+    /// background.
+    pub async fn sync_until(
+        &self,
+        vid: VolumeId,
+        direction: SyncDirection,
+        deadline: tokio::time::Instant,
+    ) -> Option<Result<(), ClientErr>> {
+        tokio::time::timeout_at(deadline, self.sync(vid, direction))
+            .await
+            .ok()
+    }
+
+    pub async fn reset_to_remote(&self, vid: VolumeId) -> Result<(), ClientErr> {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::ResetToRemote { vid, complete }, recv)
+            .await
+    }
+
+    pub async fn drain_recent_errors(&self) -> Vec<(Instant, Culprit<SyncTaskErr>)> {
+        let (complete, recv) = oneshot::channel();
+        self.must_call(SyncControl::DrainRecentErrors { complete }, recv)
+            .await
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct SyncTaskHandle {
+    inner: Arc<Mutex<Option<SyncTaskHandleInner>>>,
+}
+
+struct SyncTaskHandleInner {
+    handle: JoinHandle<()>,
+    control: mpsc::Sender<SyncControl>,
+}
+
+impl SyncTaskHandle {
+    pub fn rpc(&self) -> SyncRpc {
+        let control = self
+            .inner
+            .lock()
+            .as_ref()
+            .map(|inner| inner.control.clone());
+        SyncRpc { control }
+    }
+
+    /// Spawn the sync task onto the current tokio runtime.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime.
+    pub fn spawn(
+        &self,
+        cid: ClientId,
+        storage: Arc<Storage>,
+        clients: Arc<ClientPair>,
+        refresh_interval: Duration,
+        control_channel_size: usize,
+        autosync: bool,
+    ) -> Result<(), StartupErr> {
+        let mut inner = self.inner.lock();
+        if inner.is_some() {
+            return Err(Culprit::new(StartupErr::AlreadyRunning));
+        }
+
+        let (control_tx, control_rx) = mpsc::channel(control_channel_size);
+        let commits = storage.local_changeset().subscribe_all_notify();
+
+        let task = SyncTask {
+            cid,
+            storage,
+            clients,
+            refresh_interval,
+            commits,
+            control: control_rx,
+            autosync,
+            pending_pushes: Default::default(),
+            recent_errors: Default::default(),
+        };
+
+        let handle = tokio::spawn(task.run());
+
+        inner.replace(SyncTaskHandleInner { handle, control: control_tx });
+        Ok(())
+    }
+
+    pub async fn shutdown_timeout(&self, timeout: Duration) -> Result<(), ShutdownErr> {
+        let Some(inner) = self.inner.lock().take() else {
+            return Err(Culprit::new(ShutdownErr::TaskNotRunning));
+        };
+
+        let shutdown = async {
+            if inner.control.send(SyncControl::Shutdown).await.is_err() {
+                tracing::warn!("sync task exited before receiving Shutdown message");
+            }
+            inner.handle.await
+        };
+
+        // wait for the task to complete or the timeout to elapse
+        match tokio::time::timeout(timeout, shutdown).await {
+            Ok(Ok(())) => {
+                tracing::debug!("sync task shutdown completed");
+                Ok(())
+            }
+            Ok(Err(err)) => {
+                tracing::error!(?err, "sync task shutdown error");
+                let msg = match err.try_into_panic() {
+                    Ok(panic) => format!("sync task panic: {}", panic_message(&panic)),
+                    Err(err) => format!("sync task join error: {err}"),
+                };
+                Err(Culprit::new_with_note(ShutdownErr::JoinError, msg))
+            }
+            Err(_) => {
+                tracing::warn!("timeout waiting for sync task to shutdown");
+                Err(Culprit::new(ShutdownErr::Timeout))
+            }
+        }
+    }
+}
+
+/// The async equivalent of `graft_client::runtime::sync::SyncTask`, which
+/// runs on the tokio runtime.
+pub struct SyncTask {
+    cid: ClientId,
+    storage: Arc<Storage>,
+    clients: Arc<ClientPair>,
+    refresh_interval: Duration,
+    commits: NotifySetSubscriber<VolumeId>,
+    control: mpsc::Receiver<SyncControl>,
+
+    /// when autosync is true, volumes will be automatically pushed and pulled
+    /// to the server when they change or every `refresh_interval`.
+    autosync: bool,
+
+    /// volumes with local commits waiting to be pushed
+    pending_pushes: PendingPushes,
+
+    recent_errors: Vec<(Instant, Culprit<SyncTaskErr>)>,
+}
+
+/// The current time according to tokio's clock, which may be paused in tests
+fn now() -> Instant {
+    tokio::time::Instant::now().into_std()
+}
+
+impl SyncTask {
+    async fn run(mut self) {
+        loop {
+            match self.run_inner().await {
+                Ok(()) => {
+                    tracing::debug!("sync task inner loop completed without error; shutting down");
+                    break;
+                }
+                Err(err) => {
+                    record_error(&mut self.recent_errors, err);
+                    tokio::time::sleep(ERROR_BACKOFF).await;
+                }
+            }
+        }
+    }
+
+    async fn run_inner(&mut self) -> Result<(), SyncTaskErr> {
+        let mut next_tick = now() + self.refresh_interval;
+        loop {
+            // wake up early if a coalesced push is due before the next refresh
+            let deadline = self
+                .pending_pushes
+                .next_deadline()
+                .map_or(next_tick, |deadline| deadline.min(next_tick));
+            let timeout = deadline.saturating_duration_since(now());
+
+            tokio::select! {
+                biased;
+
+                control = self.control.recv() => {
+                    match control {
+                        None | Some(SyncControl::Shutdown) => break,
+                        Some(control) => self.handle_control(control).await?,
+                    }
+                }
+
+                _ = self.commits.ready() => {
+                    let vids = self.commits.changed();
+                    if !vids.is_empty() {
+                        self.handle_commit(vids).await?;
+                    }
+                }
+
+                _ = tokio::time::sleep(timeout) => {
+                    let now = now();
+                    let due = self.pending_pushes.take_due(now);
+                    self.handle_push(due).await?;
+                    if now >= next_tick {
+                        next_tick = now + self.refresh_interval;
+                        self.handle_tick().await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_control(&mut self, msg: SyncControl) -> Result<(), SyncTaskErr> {
+        macro_rules! reply {
+            ($complete:ident, $result:expr) => {
+                $complete.send($result).map_err(|err| {
+                    Culprit::new_with_note(
+                        SyncTaskErr::CompletionChannelDisconnected,
+                        format!("SyncControl completion channel disconnected: {err:?}"),
+                    )
+                })
+            };
+        }
+
+        match msg {
+            SyncControl::GetAutosync { complete } => reply!(complete, self.autosync),
+            SyncControl::SetAutosync { autosync, complete } => {
+                self.autosync = autosync;
+                reply!(complete, ())
+            }
+            SyncControl::GetPushCoalescing { complete } => {
+                reply!(complete, self.pending_pushes.policy())
+            }
+            SyncControl::SetPushCoalescing { policy, complete } => {
+                self.pending_pushes.set_policy(policy);
+                reply!(complete, ())
+            }
+            SyncControl::Sync { vid, direction, complete } => {
+                // the caller may have stopped waiting for the sync to complete
+                if !complete.is_closed() {
+                    let _ = complete.send(self.sync_volume(vid, direction).await);
+                }
+                Ok(())
+            }
+            SyncControl::ResetToRemote { vid, complete } => {
+                reply!(complete, self.reset_volume_to_remote(vid).await)
+            }
+            SyncControl::DrainRecentErrors { complete } => {
+                reply!(complete, self.recent_errors.drain(..).collect())
+            }
+            SyncControl::Shutdown => {
+                unreachable!("shutdown message is handled in sync task select loop")
+            }
+        }
+    }
+
+    /// Sync a volume with the remote
+    /// If dir is `SyncDirection::Both`, this function will push before it pulls
+    async fn sync_volume(&mut self, vid: VolumeId, dir: SyncDirection) -> Result<(), ClientErr> {
+        if dir.matches(SyncDirection::Push) {
+            self.pending_pushes.remove(&vid);
+            let state = self.storage.volume_state(&vid).or_into_ctx()?;
+            if state.has_pending_commits() {
+                Job::push(vid.clone(), self.cid.clone())
+                    .run_async(&self.storage, &self.clients)
+                    .await
+                    .or_into_culprit("error while pushing volume")?;
+            }
+        }
+
+        if dir.matches(SyncDirection::Pull) {
+            Job::pull(vid)
+                .run_async(&self.storage, &self.clients)
+                .await
+                .or_into_culprit("error while pulling volume")?;
+        }
+
+        Ok(())
+    }
+
+    /// Reset the volume to the remote. This will cause all pending commits to
+    /// be rolled back and the volume status to be cleared.
+    async fn reset_volume_to_remote(&mut self, vid: VolumeId) -> Result<(), ClientErr> {
+        Job::pull_and_reset(vid)
+            .run_async(&self.storage, &self.clients)
+            .await
+            .or_into_culprit("error while resetting volume to the remote")
+    }
+
+    async fn handle_tick(&mut self) -> Result<(), SyncTaskErr> {
+        if !self.autosync {
+            return Ok(());
+        }
+        // volumes waiting on the coalescing policy are pulled but left for
+        // `handle_push` to push
+        let jobs = sync_jobs(
+            &self.storage,
+            &self.cid,
+            SyncDirection::Both,
+            None,
+            Some(&self.pending_pushes),
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+        for job in jobs {
+            job.run_async(&self.storage, &self.clients)
+                .await
+                .or_into_ctx()?;
+        }
+        Ok(())
+    }
+
+    async fn handle_commit(&mut self, vids: HashSet<VolumeId>) -> Result<(), SyncTaskErr> {
+        if !self.autosync {
+            return Ok(());
+        }
+        let ready = self
+            .pending_pushes
+            .record(&self.storage, vids, now())
+            .or_into_ctx()?;
+        self.handle_push(ready).await
+    }
+
+    async fn handle_push(&mut self, vids: HashSet<VolumeId>) -> Result<(), SyncTaskErr> {
+        if !self.autosync || vids.is_empty() {
+            return Ok(());
+        }
+        self.run_jobs(SyncDirection::Push, Some(vids)).await
+    }
+
+    async fn run_jobs(
+        &mut self,
+        sync: SyncDirection,
+        vids: Option<HashSet<VolumeId>>,
+    ) -> Result<(), SyncTaskErr> {
+        // collect the jobs up front as the storage iterator can't be held
+        // across await points
+        let jobs =
+            sync_jobs(&self.storage, &self.cid, sync, vids, None).collect::<Result<Vec<_>, _>>()?;
+        for job in jobs {
+            job.run_async(&self.storage, &self.clients)
+                .await
+                .or_into_ctx()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use std::{sync::Arc, time::Duration};
+
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{VolumeId, lsn::LSN, page::PageSize};
+use splinter_rs::Splinter;
+use tokio::sync::{Notify, mpsc::UnboundedReceiver};
+
+use crate::{
+    ClientErr,
+    runtime::{
+        storage::{
+            Storage,
+            snapshot::Snapshot,
+            stats::VolumeStats,
+            volume_state::{SyncDirection, VolumeStatus},
+        },
+        volume_handle::REMOTE_LSN_POLL_INTERVAL,
+    },
+};
+
+use super::{ClientPair, sync::SyncRpc, volume_reader::VolumeReader, volume_writer::VolumeWriter};
+
+/// The async equivalent of `graft_client::runtime::volume_handle::VolumeHandle`
+#[derive(Clone, Debug)]
+pub struct VolumeHandle {
+    vid: VolumeId,
+    page_size: PageSize,
+    clients: Arc<ClientPair>,
+    storage: Arc<Storage>,
+    sync_rpc: SyncRpc,
+}
+
+impl VolumeHandle {
+    pub(crate) fn new(
+        vid: VolumeId,
+        page_size: PageSize,
+        clients: Arc<ClientPair>,
+        storage: Arc<Storage>,
+        sync_rpc: SyncRpc,
+    ) -> Self {
+        Self {
+            vid,
+            page_size,
+            clients,
+            storage,
+            sync_rpc,
+        }
+    }
+
+    #[inline]
+    pub fn vid(&self) -> &VolumeId {
+        &self.vid
+    }
+
+    /// The size of every page in the volume
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    /// Retrieve the current volume status
+    pub fn status(&self) -> Result<VolumeStatus, ClientErr> {
+        self.storage.get_volume_status(&self.vid).or_into_ctx()
+    }
+
+    /// Retrieve the latest snapshot for the volume
+    pub fn snapshot(&self) -> Result<Option<Snapshot>, ClientErr> {
+        self.storage.snapshot(&self.vid).or_into_ctx()
+    }
+
+    /// Retrieve statistics about the volume's local storage and sync state.
+    /// This scans every page stored locally for the volume.
+    pub fn stats(&self) -> Result<VolumeStats, ClientErr> {
+        self.storage.volume_stats(&self.vid).or_into_ctx()
+    }
+
+    /// Open a `VolumeReader` at the latest snapshot
+    pub fn reader(&self) -> Result<VolumeReader, ClientErr> {
+        Ok(VolumeReader::new(
+            self.vid.clone(),
+            self.page_size,
+            self.snapshot()?,
+            self.clients.clone(),
+            self.storage.clone(),
+        ))
+    }
+
+    /// Open a `VolumeReader` at the provided snapshot
+    pub fn reader_at(&self, snapshot: Option<Snapshot>) -> VolumeReader {
+        VolumeReader::new(
+            self.vid.clone(),
+            self.page_size,
+            snapshot,
+            self.clients.clone(),
+            self.storage.clone(),
+        )
+    }
+
+    /// Open a `VolumeWriter` at the latest snapshot
+    pub fn writer(&self) -> Result<VolumeWriter, ClientErr> {
+        self.reader().map(VolumeWriter::from)
+    }
+
+    /// Open a `VolumeWriter` at the provided snapshot
+    pub fn writer_at(&self, snapshot: Option<Snapshot>) -> VolumeWriter {
+        VolumeWriter::from(self.reader_at(snapshot))
+    }
+
+    /// Subscribe to remote commits to this Volume
+    pub fn subscribe_to_remote_changes(&self) -> Arc<Notify> {
+        self.storage
+            .remote_changeset()
+            .subscribe_notify(self.vid.clone())
+    }
+
+    /// Subscribe to remote commits to this Volume which change any of the
+    /// provided pages. Each message contains the subset of `pages` changed by
+    /// a remote commit.
+    pub fn subscribe_pages(&self, pages: Splinter) -> UnboundedReceiver<Splinter> {
+        self.storage
+            .page_subscriptions()
+            .subscribe_tokio(self.vid.clone(), pages)
+    }
+
+    /// Subscribe to local commits to this Volume
+    pub fn subscribe_to_local_changes(&self) -> Arc<Notify> {
+        self.storage
+            .local_changeset()
+            .subscribe_notify(self.vid.clone())
+    }
+
+    /// Sync this volume with the remote. The returned future resolves once
+    /// the sync has completed, returning any error that occurs.
+    pub async fn sync_with_remote(&self, direction: SyncDirection) -> Result<(), ClientErr> {
+        self.sync_rpc
+            .sync(self.vid.clone(), direction)
+            .await
+            .or_into_ctx()
+    }
+
+    /// Wait until this volume has received a remote LSN greater than or equal
+    /// to `lsn`, pulling from the remote as needed. Returns the first snapshot
+    /// which includes the remote LSN, or `ClientErr::RemoteLsnTimeout` if
+    /// `timeout` elapses first.
+    pub async fn wait_for_remote_lsn(
+        &self,
+        lsn: LSN,
+        timeout: Duration,
+    ) -> Result<Snapshot, ClientErr> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        // subscribe before checking the snapshot to ensure we don't miss a
+        // remote commit
+        let remote_changes = self.subscribe_to_remote_changes();
+
+        let reached = || -> Result<Option<Snapshot>, ClientErr> {
+            Ok(self.snapshot()?.filter(|s| s.remote() >= Some(lsn)))
+        };
+
+        loop {
+            if let Some(snapshot) = reached()? {
+                return Ok(snapshot);
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Culprit::new_with_note(
+                    ClientErr::RemoteLsnTimeout,
+                    format!("timed out waiting for Volume {} to reach {lsn}", self.vid),
+                ));
+            }
+
+            // bound the pull by the remaining time, checking the deadline
+            // again if it runs out
+            let pull = self
+                .sync_rpc
+                .sync_until(self.vid.clone(), SyncDirection::Pull, deadline)
+                .await;
+            let Some(pull) = pull else { continue };
+            pull.or_into_ctx()?;
+            if let Some(snapshot) = reached()? {
+                return Ok(snapshot);
+            }
+
+            // wait for the next remote commit or poll interval before pulling again
+            let wait_until = deadline.min(tokio::time::Instant::now() + REMOTE_LSN_POLL_INTERVAL);
+            let _ = tokio::time::timeout_at(wait_until, remote_changes.notified()).await;
+        }
+    }
+
+    /// Roll back the newest local commits to this volume such that it matches
+    /// its snapshot at `to_lsn`, or is empty if `to_lsn` is None. Only commits
+    /// which have not yet started pushing to the remote may be rolled back.
+    /// The rollback is recorded at a new LSN, so LSNs are never reused.
+    pub fn rollback_local(&self, to_lsn: Option<LSN>) -> Result<Snapshot, ClientErr> {
+        self.storage.rollback_local(&self.vid, to_lsn).or_into_ctx()
+    }
+
+    /// Reset this volume to the remote. This will cause all pending commits to
+    /// be rolled back and the volume status to be cleared.
+    pub async fn reset_to_remote(&self) -> Result<(), ClientErr> {
+        self.sync_rpc
+            .reset_to_remote(self.vid.clone())
+            .await
+            .or_into_ctx()
+    }
+}
@@ -0,0 +1,201 @@
+use std::{future::Future, sync::Arc};
+
+use culprit::{Result, ResultExt};
+use graft_core::{
+    PageIdx, VolumeId,
+    lsn::LSN,
+    page::{Page, PageSize},
+};
+use tracing::{Instrument, field};
+
+use crate::{
+    ClientErr,
+    oracle::Oracle,
+    runtime::{
+        storage::{Storage, page::PageStatus, snapshot::Snapshot},
+        volume_reader::{LocalRead, PageFetch, PageSpan, page_status, read_local},
+    },
+};
+
+use super::{ClientPair, spawn_blocking, volume_writer::VolumeWriter};
+
+/// The async equivalent of `graft_client::runtime::volume_reader::VolumeRead`
+pub trait VolumeRead {
+    fn vid(&self) -> &VolumeId;
+
+    /// Retrieve the Volume snapshot backing this reader
+    fn snapshot(&self) -> Option<&Snapshot>;
+
+    /// The size of every page in the Volume
+    fn page_size(&self) -> PageSize;
+
+    /// Read a page from the snapshot, fetching it from the remote if needed
+    fn read<O: Oracle + Send>(
+        &self,
+        oracle: &mut O,
+        pageidx: PageIdx,
+    ) -> impl Future<Output = Result<Page, ClientErr>> + Send;
+
+    /// Retrieve a page's status
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr>;
+
+    /// Read `buf.len()` bytes starting at the byte `offset` into the volume.
+    /// See `graft_client::runtime::volume_reader::VolumeRead::read_at`.
+    fn read_at<O: Oracle + Send>(
+        &self,
+        oracle: &mut O,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> impl Future<Output = Result<(), ClientErr>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            for span in PageSpan::iter(self.page_size(), offset, buf.len()) {
+                let span = span?;
+                let page = self.read(oracle, span.pageidx).await?;
+                buf[span.buf].copy_from_slice(&page[span.page]);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VolumeReader {
+    vid: VolumeId,
+    page_size: PageSize,
+    snapshot: Option<Snapshot>,
+    clients: Arc<ClientPair>,
+    storage: Arc<Storage>,
+}
+
+impl VolumeReader {
+    pub(crate) fn new(
+        vid: VolumeId,
+        page_size: PageSize,
+        snapshot: Option<Snapshot>,
+        clients: Arc<ClientPair>,
+        storage: Arc<Storage>,
+    ) -> Self {
+        Self {
+            vid,
+            page_size,
+            snapshot,
+            clients,
+            storage,
+        }
+    }
+
+    /// Upgrade this reader into a writer
+    pub fn upgrade(self) -> VolumeWriter {
+        self.into()
+    }
+
+    /// decompose this reader into snapshot and storage
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        VolumeId,
+        PageSize,
+        Option<Snapshot>,
+        Arc<ClientPair>,
+        Arc<Storage>,
+    ) {
+        (
+            self.vid,
+            self.page_size,
+            self.snapshot,
+            self.clients,
+            self.storage,
+        )
+    }
+}
+
+impl VolumeRead for VolumeReader {
+    #[inline]
+    fn vid(&self) -> &VolumeId {
+        &self.vid
+    }
+
+    #[inline]
+    fn snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+
+    #[inline]
+    fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    async fn read<O: Oracle + Send>(
+        &self,
+        oracle: &mut O,
+        pageidx: PageIdx,
+    ) -> Result<Page, ClientErr> {
+        match read_local(
+            &self.storage,
+            oracle,
+            self.vid(),
+            self.snapshot(),
+            self.page_size,
+            pageidx,
+        )? {
+            LocalRead::Page(page) => Ok(page),
+            LocalRead::Fetch { remote_lsn, local_lsn } => fetch_page(
+                &self.clients,
+                &self.storage,
+                oracle,
+                self.vid(),
+                self.page_size,
+                remote_lsn,
+                local_lsn,
+                pageidx,
+            )
+            .await
+            .or_into_ctx(),
+        }
+    }
+
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        page_status(&self.storage, self.vid(), self.snapshot(), pageidx)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn fetch_page<O: Oracle>(
+    clients: &ClientPair,
+    storage: &Arc<Storage>,
+    oracle: &mut O,
+    vid: &VolumeId,
+    page_size: PageSize,
+    remote_lsn: LSN,
+    local_lsn: LSN,
+    pageidx: PageIdx,
+) -> Result<Page, ClientErr> {
+    let span = tracing::trace_span!(
+        "fetching page from pagestore",
+        ?vid,
+        %remote_lsn,
+        %local_lsn,
+        %pageidx,
+        num_pages=field::Empty,
+    );
+
+    async {
+        let fetch = PageFetch::prepare(storage, oracle, vid, page_size, local_lsn, pageidx)?;
+        tracing::Span::current().record("num_pages", fetch.len());
+
+        let response = clients
+            .pagestore()
+            .read_pages(vid, remote_lsn, fetch.graft())
+            .await?;
+
+        // storing the fetched pages waits on a durable write
+        let (storage, vid) = (storage.clone(), vid.clone());
+        spawn_blocking(move || fetch.complete(&storage, &vid, remote_lsn, response)).await
+    }
+    .instrument(span)
+    .await
+}
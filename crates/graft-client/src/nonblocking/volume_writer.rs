@@ -0,0 +1,205 @@
+use std::future::Future;
+
+use bytes::BytesMut;
+use culprit::{Result, ResultExt};
+use graft_core::{
+    PageIdx, VolumeId,
+    page::{Page, PageSize},
+    page_count::PageCount,
+};
+use graft_proto::common::v1::CommitMetadata;
+
+use crate::{
+    ClientErr,
+    oracle::Oracle,
+    runtime::{
+        storage::{memtable::Memtable, page::PageStatus, snapshot::Snapshot},
+        volume_reader::PageSpan,
+        volume_writer::{Savepoint, Savepoints},
+    },
+};
+
+use super::{
+    spawn_blocking,
+    volume_reader::{VolumeRead, VolumeReader},
+};
+
+/// The async equivalent of `graft_client::runtime::volume_writer::VolumeWrite`
+pub trait VolumeWrite {
+    type CommitOutput;
+
+    /// Write a page
+    fn write(&mut self, pageidx: PageIdx, page: Page);
+
+    /// Truncate the volume to a new page count.
+    /// This can be used to increase or decrease the Volume's size.
+    fn truncate(&mut self, pages: PageCount);
+
+    /// Write `data` starting at the byte `offset` into the volume.
+    /// See `graft_client::runtime::volume_writer::VolumeWrite::write_at`.
+    fn write_at<O: Oracle + Send>(
+        &mut self,
+        oracle: &mut O,
+        offset: u64,
+        data: &[u8],
+    ) -> impl Future<Output = Result<(), ClientErr>> + Send
+    where
+        Self: VolumeRead + Send + Sync,
+    {
+        async move {
+            let page_size = self.page_size();
+            for span in PageSpan::iter(page_size, offset, data.len()) {
+                let span = span?;
+                let page = if span.is_full_page(page_size) {
+                    Page::try_from(&data[span.buf]).or_into_ctx()?
+                } else {
+                    let mut page: BytesMut = self.read(oracle, span.pageidx).await?.into();
+                    page[span.page].copy_from_slice(&data[span.buf]);
+                    Page::try_from(page).or_into_ctx()?
+                };
+                self.write(span.pageidx, page);
+            }
+            Ok(())
+        }
+    }
+
+    /// Commit the transaction
+    fn commit(self) -> impl Future<Output = Result<Self::CommitOutput, ClientErr>> + Send;
+}
+
+#[derive(Debug)]
+pub struct VolumeWriter {
+    pages: PageCount,
+    reader: VolumeReader,
+    memtable: Memtable,
+    savepoints: Savepoints,
+    metadata: Option<CommitMetadata>,
+}
+
+impl VolumeWriter {
+    pub fn pages(&self) -> PageCount {
+        self.pages
+    }
+
+    /// Create a savepoint capturing all writes and truncations made so far.
+    /// Savepoints may be nested.
+    pub fn savepoint(&mut self) -> Savepoint {
+        self.savepoints.push(self.pages, &self.memtable)
+    }
+
+    /// Undo all writes and truncations made since `sp` was created. Any
+    /// savepoints created after `sp` are released, while `sp` itself remains
+    /// active.
+    pub fn rollback_to(&mut self, sp: Savepoint) -> Result<(), ClientErr> {
+        self.savepoints
+            .rollback_to(sp, &mut self.pages, &mut self.memtable)
+    }
+
+    /// Release `sp` and all savepoints created after it, keeping their changes
+    pub fn release(&mut self, sp: Savepoint) -> Result<(), ClientErr> {
+        self.savepoints.release(sp)
+    }
+
+    /// Attach metadata to the commit. The metadata is pushed to the remote
+    /// along with the commit, and is ignored if the commit has no changes.
+    pub fn set_commit_metadata(&mut self, metadata: CommitMetadata) {
+        self.metadata = Some(metadata);
+    }
+}
+
+impl From<VolumeReader> for VolumeWriter {
+    fn from(reader: VolumeReader) -> Self {
+        let pages = reader.snapshot().map_or(PageCount::ZERO, |s| s.pages());
+        Self {
+            pages,
+            reader,
+            memtable: Default::default(),
+            savepoints: Default::default(),
+            metadata: None,
+        }
+    }
+}
+
+impl VolumeRead for VolumeWriter {
+    #[inline]
+    fn vid(&self) -> &VolumeId {
+        self.reader.vid()
+    }
+
+    /// Access this writer's snapshot
+    #[inline]
+    fn snapshot(&self) -> Option<&Snapshot> {
+        self.reader.snapshot()
+    }
+
+    #[inline]
+    fn page_size(&self) -> PageSize {
+        self.reader.page_size()
+    }
+
+    /// Read a page; supports read your own writes (RYOW)
+    async fn read<O: Oracle + Send>(
+        &self,
+        oracle: &mut O,
+        pageidx: PageIdx,
+    ) -> Result<Page, ClientErr> {
+        if let Some(page) = self.memtable.get(pageidx) {
+            oracle.observe_cache_hit(pageidx);
+            return Ok(page.clone());
+        }
+        self.reader.read(oracle, pageidx).await
+    }
+
+    /// Read a page's status; supports read your own writes (RYOW)
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        if self.memtable.contains(pageidx) {
+            return Ok(PageStatus::Dirty);
+        }
+        self.reader.status(pageidx)
+    }
+}
+
+impl VolumeWrite for VolumeWriter {
+    type CommitOutput = VolumeReader;
+
+    fn write(&mut self, pageidx: PageIdx, page: Page) {
+        self.pages = self.pages.max(pageidx.pages());
+        self.memtable.insert(pageidx, page);
+    }
+
+    fn truncate(&mut self, pages: PageCount) {
+        self.pages = pages;
+        self.memtable.truncate(self.pages.last_index())
+    }
+
+    async fn commit(self) -> Result<VolumeReader, ClientErr> {
+        let (vid, page_size, snapshot, clients, storage) = self.reader.into_parts();
+
+        // we have nothing to commit if the page count is equal to the snapshot
+        // pagecount *and* the memtable is empty
+        let snapshot_pagecount = snapshot.as_ref().map_or(PageCount::ZERO, |s| s.pages());
+        let memtable_empty = self.memtable.is_empty();
+        if self.pages == snapshot_pagecount && memtable_empty {
+            return Ok(VolumeReader::new(
+                vid, page_size, snapshot, clients, storage,
+            ));
+        }
+
+        // committing waits for the storage backend to durably persist the
+        // transaction, so we move it off of the async runtime
+        let (pages, memtable, metadata) = (self.pages, self.memtable, self.metadata);
+        let snapshot = spawn_blocking({
+            let (vid, storage) = (vid.clone(), storage.clone());
+            move || storage.commit(&vid, snapshot, pages, memtable, metadata)
+        })
+        .await
+        .or_into_ctx()?;
+        Ok(VolumeReader::new(
+            vid,
+            page_size,
+            Some(snapshot),
+            clients,
+            storage,
+        ))
+    }
+}
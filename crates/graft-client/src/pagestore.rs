@@ -1,9 +1,10 @@
 use bytes::Bytes;
 use culprit::Culprit;
 use graft_core::lsn::LSN;
-use graft_core::{VolumeId, page_count::PageCount};
+use graft_core::{VolumeId, page::PageSize, page_count::PageCount, tag_name::TagName};
 use graft_proto::{
     common::v1::SegmentInfo,
+    encode_page_size,
     pagestore::v1::{
         PageAtIdx, ReadPagesRequest, ReadPagesResponse, WritePagesRequest, WritePagesResponse,
     },
@@ -36,12 +37,33 @@ impl PagestoreClient {
         lsn: LSN,
         graft: Bytes,
     ) -> Result<Vec<PageAtIdx>, Culprit<ClientErr>> {
-        let uri = self.endpoint.build("/pagestore/v1/read_pages")?;
         let req = ReadPagesRequest {
             vid: vid.copy_to_bytes(),
             lsn: lsn.into(),
             graft,
+            tag: String::new(),
+        };
+        self.send_read_pages(req)
+    }
+
+    /// Read pages from the Volume's snapshot at the tagged LSN
+    pub fn read_pages_at_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+        graft: Bytes,
+    ) -> Result<Vec<PageAtIdx>, Culprit<ClientErr>> {
+        let req = ReadPagesRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: 0,
+            graft,
+            tag: tag.to_string(),
         };
+        self.send_read_pages(req)
+    }
+
+    fn send_read_pages(&self, req: ReadPagesRequest) -> Result<Vec<PageAtIdx>, Culprit<ClientErr>> {
+        let uri = self.endpoint.build("/pagestore/v1/read_pages")?;
         let result = self
             .client
             .send::<_, ReadPagesResponse>(uri, req)
@@ -59,10 +81,15 @@ impl PagestoreClient {
     pub fn write_pages(
         &self,
         vid: &VolumeId,
+        page_size: PageSize,
         pages: Vec<PageAtIdx>,
     ) -> Result<Vec<SegmentInfo>, Culprit<ClientErr>> {
         let uri = self.endpoint.build("/pagestore/v1/write_pages")?;
-        let req = WritePagesRequest { vid: vid.copy_to_bytes(), pages };
+        let req = WritePagesRequest {
+            vid: vid.copy_to_bytes(),
+            pages,
+            page_size: encode_page_size(page_size),
+        };
         self.client
             .send::<_, WritePagesResponse>(uri, req)
             .map(|r| r.segments)
@@ -0,0 +1,256 @@
+//! Bundles allow volumes to be synced without network access.
+//!
+//! A bundle either contains a volume's pending local commits, which are
+//! committed to the remote when imported, or a range of remote commits, which
+//! are replicated into local storage when imported. Bundles are written to
+//! files using [`write_bundle`] and read back using [`read_bundle`].
+//!
+//! Exporting local commits doesn't change the exporting volume: the commits
+//! remain pending, and the volume refuses remote changes, until it's reset
+//! using [`VolumeHandle::reset_to_remote`] once the bundle has been imported.
+//!
+//! [`VolumeHandle::reset_to_remote`]: super::volume_handle::VolumeHandle::reset_to_remote
+
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    ops::RangeBounds,
+};
+
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{PageIdx, VolumeId, byte_unit::ByteUnit, gid::ClientId, lsn::LSN};
+use graft_proto::{
+    common::v1::{LsnRange, Snapshot as RemoteSnapshot},
+    decode_page_size, encode_page_size,
+};
+use prost::Message;
+use splinter_rs::SplinterRef;
+
+pub use graft_proto::bundle::v1::{Bundle, LocalCommits, RemoteCommits, bundle::Commits};
+
+use crate::{ClientErr, ClientPair};
+
+use super::{
+    storage::{Storage, page::PageValue},
+    sync::job::load_commit_pages,
+};
+
+/// Every bundle file starts with these bytes
+const BUNDLE_MAGIC: &[u8; 8] = b"GRAFTBN1";
+
+/// The largest bundle accepted by [`read_bundle`]
+pub const MAX_BUNDLE_SIZE: ByteUnit = ByteUnit::from_gb(1);
+
+/// Write a bundle to a file or other writer
+pub fn write_bundle<W: Write>(w: &mut W, bundle: &Bundle) -> Result<(), ClientErr> {
+    w.write_all(BUNDLE_MAGIC).map_err(Culprit::from_err)?;
+    w.write_all(&bundle.encode_to_vec())
+        .map_err(Culprit::from_err)?;
+    w.flush().map_err(Culprit::from_err)
+}
+
+/// Read a bundle previously written by [`write_bundle`], rejecting bundles
+/// larger than [`MAX_BUNDLE_SIZE`]
+pub fn read_bundle<R: Read>(r: &mut R) -> Result<Bundle, ClientErr> {
+    read_bundle_with_limit(r, MAX_BUNDLE_SIZE)
+}
+
+/// Read a bundle previously written by [`write_bundle`], rejecting bundles
+/// larger than `limit`
+pub fn read_bundle_with_limit<R: Read>(r: &mut R, limit: ByteUnit) -> Result<Bundle, ClientErr> {
+    let mut magic = [0; BUNDLE_MAGIC.len()];
+    r.read_exact(&mut magic).map_err(Culprit::from_err)?;
+    if &magic != BUNDLE_MAGIC {
+        return Err(Culprit::new(ClientErr::InvalidBundle("not a Graft bundle")));
+    }
+
+    // read one byte past the limit to detect oversized bundles
+    let max_len = limit.as_u64().saturating_sub(BUNDLE_MAGIC.len() as u64);
+    let mut buf = Vec::new();
+    r.take(max_len + 1)
+        .read_to_end(&mut buf)
+        .map_err(Culprit::from_err)?;
+    if buf.len() as u64 > max_len {
+        return Err(Culprit::new_with_note(
+            ClientErr::InvalidBundle("bundle is too large"),
+            format!("bundle exceeds {limit}"),
+        ));
+    }
+    Ok(Bundle::decode(buf.as_slice())?)
+}
+
+/// Export a volume's pending local commits into a bundle.
+/// Returns None if the volume has no pending local commits. The commits
+/// remain pending in storage.
+pub(crate) fn export_local(
+    storage: &Storage,
+    cid: &ClientId,
+    vid: &VolumeId,
+) -> Result<Option<Bundle>, ClientErr> {
+    let Some((remote_lsn, page_count, lsns, commits)) =
+        storage.prepare_export(vid).or_into_ctx()?
+    else {
+        return Ok(None);
+    };
+    let page_size = storage.page_size(vid).or_into_ctx()?;
+    let (_, pages, metadata) = load_commit_pages(storage, vid, page_count, page_size, commits)?;
+
+    Ok(Some(Bundle {
+        vid: vid.copy_to_bytes(),
+        commits: Some(Commits::Local(LocalCommits {
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn: remote_lsn.map(Into::into),
+            page_count: page_count.into(),
+            lsns: Some(LsnRange::from_range(lsns)),
+            pages,
+            page_size: encode_page_size(page_size),
+            metadata,
+        })),
+    }))
+}
+
+/// Export a range of remote commits into a bundle.
+/// Returns None if the range contains no commits.
+pub(crate) fn export_remote<R: RangeBounds<LSN>>(
+    clients: &ClientPair,
+    vid: &VolumeId,
+    lsns: R,
+) -> Result<Option<Bundle>, ClientErr> {
+    let Some((snapshot, lsns, graft)) = clients.metastore().pull_graft(vid, lsns)? else {
+        return Ok(None);
+    };
+    let snapshot_lsn = snapshot
+        .lsn()
+        .or_ctx(|_| ClientErr::InvalidBundle("invalid snapshot LSN"))?;
+    let graft = graft.into_inner();
+    let pages = clients
+        .pagestore()
+        .read_pages(vid, snapshot_lsn, graft.clone())?;
+
+    Ok(Some(Bundle {
+        vid: vid.copy_to_bytes(),
+        commits: Some(Commits::Remote(RemoteCommits {
+            snapshot: Some(snapshot),
+            lsns: Some(lsns),
+            graft,
+            pages,
+        })),
+    }))
+}
+
+/// Import a bundle, returning the remote snapshot which contains the bundled
+/// commits.
+///
+/// Local commits are written to the remote exactly like a push; the import is
+/// rejected if the remote has changed since the commits were exported.
+/// Remote commits are replicated into local storage exactly like a pull, and
+/// must continue from the volume's last known remote LSN.
+pub(crate) fn import(
+    storage: &Storage,
+    clients: &ClientPair,
+    bundle: Bundle,
+) -> Result<RemoteSnapshot, ClientErr> {
+    let vid = bundle
+        .vid()
+        .or_ctx(|_| ClientErr::InvalidBundle("invalid VolumeId"))?
+        .clone();
+    match bundle.commits {
+        Some(Commits::Local(commits)) => import_local(clients, &vid, commits),
+        Some(Commits::Remote(commits)) => import_remote(storage, &vid, commits),
+        None => Err(Culprit::new(ClientErr::InvalidBundle("missing commits"))),
+    }
+}
+
+fn import_local(
+    clients: &ClientPair,
+    vid: &VolumeId,
+    commits: LocalCommits,
+) -> Result<RemoteSnapshot, ClientErr> {
+    let cid =
+        ClientId::try_from(commits.cid).or_ctx(|_| ClientErr::InvalidBundle("invalid ClientId"))?;
+    let snapshot_lsn = commits
+        .snapshot_lsn
+        .map(LSN::try_from)
+        .transpose()
+        .or_ctx(|_| ClientErr::InvalidBundle("invalid snapshot LSN"))?;
+    let page_size = decode_page_size(commits.page_size)
+        .or_ctx(|_| ClientErr::InvalidBundle("invalid page size"))?;
+
+    // write the pages to the pagestore if there are any pages
+    let segments = if !commits.pages.is_empty() {
+        clients
+            .pagestore()
+            .write_pages(vid, page_size, commits.pages)?
+    } else {
+        Vec::new()
+    };
+
+    // commit the segments to the metastore
+    clients.metastore().commit(
+        vid,
+        &cid,
+        snapshot_lsn,
+        commits.page_count.into(),
+        page_size,
+        segments,
+        commits.metadata,
+    )
+}
+
+fn import_remote(
+    storage: &Storage,
+    vid: &VolumeId,
+    commits: RemoteCommits,
+) -> Result<RemoteSnapshot, ClientErr> {
+    let Some(remote_snapshot) = commits.snapshot else {
+        return Err(Culprit::new(ClientErr::InvalidBundle("missing snapshot")));
+    };
+    let remote_lsn = remote_snapshot
+        .lsn()
+        .or_ctx(|_| ClientErr::InvalidBundle("invalid snapshot LSN"))?;
+    let start_lsn = commits
+        .lsns
+        .map(|lsns| lsns.start())
+        .transpose()
+        .or_ctx(|_| ClientErr::InvalidBundle("invalid LSN range"))?
+        .ok_or_else(|| Culprit::new(ClientErr::InvalidBundle("missing LSN range")))?;
+    let graft = SplinterRef::from_bytes(commits.graft).or_into_ctx()?;
+
+    // the bundle must continue from the last known remote LSN
+    let next_lsn = storage
+        .snapshot(vid)
+        .or_into_ctx()?
+        .and_then(|s| s.remote())
+        .map_or(LSN::FIRST, |lsn| lsn.next().expect("lsn overflow"));
+    if remote_lsn < next_lsn {
+        // the volume already contains every commit in the bundle
+        return Ok(remote_snapshot);
+    }
+    if start_lsn > next_lsn {
+        return Err(Culprit::new_with_note(
+            ClientErr::InvalidBundle("bundle is missing earlier remote commits"),
+            format!("expected the bundle to start at or before {next_lsn}, got {start_lsn}"),
+        ));
+    }
+
+    let snapshot = storage
+        .receive_remote_commit(vid, remote_snapshot.clone(), graft.clone())
+        .or_into_ctx()?;
+
+    // every changed page is either contained in the bundle or empty
+    let local_lsn = snapshot.local();
+    let mut pages: HashMap<PageIdx, (LSN, PageValue)> = HashMap::new();
+    for pageidx in graft.iter() {
+        let pageidx = PageIdx::try_from(pageidx).or_into_ctx()?;
+        pages.insert(pageidx, (local_lsn, PageValue::Empty));
+    }
+    for page in commits.pages {
+        let pageidx = page.pageidx().or_into_ctx()?;
+        if let Some(entry) = pages.get_mut(&pageidx) {
+            entry.1 = page.page().or_into_ctx()?.into();
+        }
+    }
+    storage.receive_pages(vid, pages).or_into_ctx()?;
+
+    Ok(remote_snapshot)
+}
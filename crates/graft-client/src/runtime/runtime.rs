@@ -1,16 +1,19 @@
 use culprit::{Culprit, Result, ResultExt};
 use std::{
+    ops::RangeBounds,
     sync::Arc,
     time::{Duration, Instant},
 };
 
-use graft_core::{VolumeId, gid::ClientId};
+use graft_core::{VolumeId, gid::ClientId, lsn::LSN, volume_name::VolumeName};
+use graft_proto::common::v1::Snapshot as RemoteSnapshot;
 
 use crate::{ClientErr, ClientPair};
 
 use super::{
+    bundle::{self, Bundle},
     storage::{Storage, volume_state::VolumeConfig},
-    sync::{ShutdownErr, StartupErr, SyncTaskErr, SyncTaskHandle},
+    sync::{PushCoalescing, ShutdownErr, StartupErr, SyncTaskErr, SyncTaskHandle},
     volume_handle::VolumeHandle,
 };
 
@@ -70,6 +73,15 @@ impl Runtime {
         self.sync.rpc().set_autosync(autosync)
     }
 
+    pub fn get_push_coalescing(&self) -> PushCoalescing {
+        self.sync.rpc().get_push_coalescing()
+    }
+
+    /// Configure how autosync batches local commits into remote commits
+    pub fn set_push_coalescing(&self, policy: PushCoalescing) {
+        self.sync.rpc().set_push_coalescing(policy)
+    }
+
     pub fn drain_recent_sync_errors(&self) -> Vec<(Instant, Culprit<SyncTaskErr>)> {
         self.sync.rpc().drain_recent_errors()
     }
@@ -83,16 +95,48 @@ impl Runtime {
         vid: &VolumeId,
         config: VolumeConfig,
     ) -> Result<VolumeHandle, ClientErr> {
+        let page_size = config.page_size();
         self.storage.set_volume_config(vid, config).or_into_ctx()?;
 
         Ok(VolumeHandle::new(
             vid.clone(),
+            page_size,
             self.clients.clone(),
             self.storage.clone(),
             self.sync.rpc(),
         ))
     }
 
+    /// Retrieve a volume's config, returning None if the volume has never
+    /// been opened
+    pub fn volume_config(&self, vid: &VolumeId) -> Result<Option<VolumeConfig>, ClientErr> {
+        self.storage.get_volume_config(vid).or_into_ctx()
+    }
+
+    /// Resolve a Volume name into a `VolumeId` using the metastore's name
+    /// registry. If `create` is true, a missing name is registered to a new
+    /// `VolumeId`, otherwise None is returned.
+    pub fn resolve_volume_name(
+        &self,
+        name: &VolumeName,
+        create: bool,
+    ) -> Result<Option<VolumeId>, ClientErr> {
+        self.clients.metastore().resolve_name(name, create)
+    }
+
+    /// Open a Volume by name, registering the name if it doesn't exist yet.
+    /// Resolving the name requires access to the metastore.
+    pub fn open_volume_by_name(
+        &self,
+        name: &VolumeName,
+        config: VolumeConfig,
+    ) -> Result<VolumeHandle, ClientErr> {
+        let vid = self
+            .resolve_volume_name(name, true)?
+            .expect("volume name is created if missing");
+        self.open_volume(&vid, config)
+    }
+
     pub fn update_volume_config<U>(&self, vid: &VolumeId, f: U) -> Result<(), ClientErr>
     where
         U: FnMut(VolumeConfig) -> VolumeConfig,
@@ -100,19 +144,50 @@ impl Runtime {
         self.storage.update_volume_config(vid, f).or_into_ctx()?;
         Ok(())
     }
+
+    /// Export a volume's pending local commits into a bundle which can be
+    /// imported elsewhere to commit them to the remote. Returns None if the
+    /// volume has no pending local commits.
+    ///
+    /// The commits remain pending locally. Once the bundle has been imported,
+    /// reset the volume using [`VolumeHandle::reset_to_remote`] to discard
+    /// them and pull the imported commits. Until then the volume refuses
+    /// remote changes.
+    pub fn export_local_bundle(&self, vid: &VolumeId) -> Result<Option<Bundle>, ClientErr> {
+        bundle::export_local(&self.storage, &self.cid, vid)
+    }
+
+    /// Export a range of remote commits into a bundle which can be imported
+    /// by a client without network access. Returns None if the range is
+    /// empty.
+    pub fn export_remote_bundle<R: RangeBounds<LSN>>(
+        &self,
+        vid: &VolumeId,
+        lsns: R,
+    ) -> Result<Option<Bundle>, ClientErr> {
+        bundle::export_remote(&self.clients, vid, lsns)
+    }
+
+    /// Import a bundle created by `export_local_bundle` or
+    /// `export_remote_bundle`, returning the remote snapshot containing the
+    /// bundled commits.
+    pub fn import_bundle(&self, bundle: Bundle) -> Result<RemoteSnapshot, ClientErr> {
+        bundle::import(&self.storage, &self.clients, bundle)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use graft_core::{
-        page::{EMPTY_PAGE, Page},
+        page::{EMPTY_PAGE, PAGESIZE, Page},
+        page_count::PageCount,
         pageidx,
     };
 
     use crate::{
         oracle::NoopOracle,
         runtime::{
-            storage::{StorageErr, volume_state::SyncDirection},
+            storage::{StorageErr, page::PageStatus, volume_state::SyncDirection},
             volume_reader::VolumeRead,
             volume_writer::VolumeWrite,
         },
@@ -185,12 +260,114 @@ mod tests {
         assert_eq!(snapshot.pages(), 2);
     }
 
+    #[graft_test::test]
+    fn test_byte_range_read_write() {
+        let cid = ClientId::random();
+        let storage = Storage::open_memory().unwrap();
+        let runtime = Runtime::new(cid, ClientPair::test_empty(), storage);
+        let mut oracle = NoopOracle;
+
+        let vid = VolumeId::random();
+        let handle = runtime
+            .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap();
+
+        // seed the first page so we can verify partial writes preserve it
+        let mut writer = handle.writer().unwrap();
+        writer.write(pageidx!(1), Page::test_filled(0x42));
+        writer.commit().unwrap();
+
+        // write a range which starts in page 1, covers page 2, and ends in page 3
+        let pagesize = PAGESIZE.as_usize();
+        let data = vec![0x99; pagesize * 2];
+        let offset = (pagesize / 2) as u64;
+        let mut writer = handle.writer().unwrap();
+        writer.write_at(&mut oracle, offset, &data).unwrap();
+        assert_eq!(writer.pages(), 3);
+        let reader = writer.commit().unwrap();
+
+        // page 1 is half old, half new; page 2 is entirely new; page 3 is
+        // half new, half zeros
+        let page1 = reader.read(&mut oracle, pageidx!(1)).unwrap();
+        assert!(page1[..pagesize / 2].iter().all(|&b| b == 0x42));
+        assert!(page1[pagesize / 2..].iter().all(|&b| b == 0x99));
+        assert_eq!(
+            reader.read(&mut oracle, pageidx!(2)).unwrap(),
+            Page::test_filled(0x99)
+        );
+        let page3 = reader.read(&mut oracle, pageidx!(3)).unwrap();
+        assert!(page3[..pagesize / 2].iter().all(|&b| b == 0x99));
+        assert!(page3[pagesize / 2..].iter().all(|&b| b == 0));
+
+        // read back a range spanning all three pages and past the end
+        let mut buf = vec![0xff; pagesize * 3 + 8];
+        reader.read_at(&mut oracle, 8, &mut buf).unwrap();
+        assert!(buf[..offset as usize - 8].iter().all(|&b| b == 0x42));
+        assert_eq!(&buf[offset as usize - 8..][..data.len()], &data[..]);
+        assert!(
+            buf[offset as usize - 8 + data.len()..]
+                .iter()
+                .all(|&b| b == 0)
+        );
+    }
+
+    #[graft_test::test]
+    fn test_writer_savepoints() {
+        let cid = ClientId::random();
+        let storage = Storage::open_memory().unwrap();
+        let runtime = Runtime::new(cid, ClientPair::test_empty(), storage);
+        let mut oracle = NoopOracle;
+
+        let vid = VolumeId::random();
+        let page1 = Page::test_filled(0x42);
+        let page2 = Page::test_filled(0x99);
+
+        let handle = runtime
+            .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap();
+
+        let mut writer = handle.writer().unwrap();
+        writer.write(pageidx!(1), page1.clone());
+
+        // nested savepoints covering writes and truncation
+        let sp1 = writer.savepoint();
+        writer.write(pageidx!(1), page2.clone());
+        writer.write(pageidx!(3), page2.clone());
+        let sp2 = writer.savepoint();
+        writer.truncate(PageCount::new(1));
+        assert_eq!(writer.pages(), 1);
+
+        // rolling back to sp2 restores the truncated pages
+        writer.rollback_to(sp2).unwrap();
+        assert_eq!(writer.pages(), 3);
+        assert_eq!(writer.read(&mut oracle, pageidx!(3)).unwrap(), page2);
+
+        // sp2 remains active after a rollback; releasing it keeps its changes
+        writer.write(pageidx!(2), page1.clone());
+        writer.release(sp2).unwrap();
+        assert_eq!(writer.read(&mut oracle, pageidx!(2)).unwrap(), page1);
+        assert!(writer.rollback_to(sp2).is_err());
+
+        // rolling back to sp1 undoes everything after it
+        writer.rollback_to(sp1).unwrap();
+        assert_eq!(writer.pages(), 1);
+        assert_eq!(writer.read(&mut oracle, pageidx!(1)).unwrap(), page1);
+        assert!(matches!(
+            writer.status(pageidx!(2)).unwrap(),
+            PageStatus::Empty(_)
+        ));
+
+        let reader = writer.commit().unwrap();
+        assert_eq!(reader.snapshot().unwrap().pages(), 1);
+        assert_eq!(reader.read(&mut oracle, pageidx!(1)).unwrap(), page1);
+    }
+
     #[graft_test::test]
     fn test_concurrent_commit_err() {
         // open two writers, commit the first, then commit the second
 
         let cid = ClientId::random();
-        let storage = Storage::open_temporary().unwrap();
+        let storage = Storage::open_memory().unwrap();
         let runtime = Runtime::new(cid, ClientPair::test_empty(), storage);
         let mut oracle = NoopOracle;
 
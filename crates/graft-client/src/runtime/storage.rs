@@ -5,27 +5,31 @@ use std::{
     ops::RangeInclusive,
     path::Path,
     sync::Arc,
+    time::SystemTime,
 };
 
+use backend::{Batch, FjallBackend, MemBackend, Partition, StorageBackend};
 use bytes::Bytes;
 use changeset::ChangeSet;
-use commit::CommitKey;
+use commit::{CommitKey, decode_commit, encode_commit};
 use culprit::{Culprit, ResultExt};
-use fjall::{KvSeparationOptions, PartitionCreateOptions, Slice};
 use graft_core::{
     PageIdx, VolumeId,
     byte_unit::ByteUnit,
     lsn::{LSN, LSNRangeExt},
-    page::PageSizeErr,
+    page::{PageSize, PageSizeErr},
     page_count::PageCount,
     page_idx::ConvertToPageIdxErr,
     zerocopy_ext::ZerocopyErr,
 };
+use graft_proto::common::v1::CommitMetadata;
 use memtable::Memtable;
 use page::{PageKey, PageValue, PageValueConversionErr};
+use page_subscriptions::PageSubscriptions;
 use parking_lot::{Mutex, MutexGuard};
 use snapshot::{RemoteMapping, Snapshot};
 use splinter_rs::{DecodeErr, Splinter, SplinterRef};
+use stats::VolumeStats;
 use tracing::field;
 use tryiter::{TryIterator, TryIteratorExt};
 use volume_state::{
@@ -34,11 +38,14 @@ use volume_state::{
 };
 use zerocopy::IntoBytes;
 
+pub mod backend;
 pub mod changeset;
 pub(crate) mod commit;
 pub(crate) mod memtable;
 pub mod page;
+pub mod page_subscriptions;
 pub mod snapshot;
+pub mod stats;
 pub mod volume_state;
 
 type Result<T> = std::result::Result<T, Culprit<StorageErr>>;
@@ -69,6 +76,15 @@ pub enum StorageErr {
     #[error("Corrupt commit: {0}")]
     CorruptCommit(#[from] DecodeErr),
 
+    #[error("Corrupt commit header: {0}")]
+    CorruptCommitHeader(ZerocopyErr),
+
+    #[error("Corrupt commit metadata")]
+    CorruptCommitMetadata,
+
+    #[error("Invalid commit metadata")]
+    InvalidCommitMetadata,
+
     #[error("Illegal concurrent write to volume")]
     ConcurrentWrite,
 
@@ -82,6 +98,12 @@ pub enum StorageErr {
 
     #[error("invalid page index")]
     ConvertToPageIdxErr(#[from] ConvertToPageIdxErr),
+
+    #[error("Only unpushed local commits may be rolled back")]
+    InvalidRollback,
+
+    #[error("Page size does not match the Volume's page size")]
+    PageSizeMismatch,
 }
 
 impl From<io::Error> for StorageErr {
@@ -102,32 +124,51 @@ impl From<PageSizeErr> for StorageErr {
     }
 }
 
-pub struct Storage {
-    keyspace: fjall::Keyspace,
+/// Ensure that a remote snapshot has the same page size as the local Volume
+fn check_remote_page_size(
+    state: &VolumeState,
+    remote_snapshot: &graft_proto::Snapshot,
+) -> Result<()> {
+    let remote_page_size = remote_snapshot.page_size().or_into_ctx()?;
+    if remote_page_size != state.page_size() {
+        return Err(Culprit::new_with_note(
+            StorageErr::PageSizeMismatch,
+            format!(
+                "remote Volume {} has page size {remote_page_size}; local page size is {}",
+                state.vid(),
+                state.page_size()
+            ),
+        ));
+    }
+    Ok(())
+}
 
-    /// Used to store volume state broken out by tag.
-    /// Keyed by `VolumeStateKey`.
-    ///
-    /// ```text
-    /// {vid}/VolumeStateTag::Config -> VolumeConfig
-    /// {vid}/VolumeStateTag::Status -> VolumeStatus
-    /// {vid}/VolumeStateTag::Snapshot -> Snapshot
-    /// {vid}/VolumeStateTag::Watermarks -> Watermarks
-    /// ```
-    volumes: fjall::Partition,
-
-    /// Used to store page contents
-    /// maps from (`VolumeId`, `PageIdx`, LSN) to `PageValue`
-    pages: fjall::Partition,
-
-    /// Used to track changes made by local commits.
-    /// maps from (`VolumeId`, LSN) to Graft (Splinter of changed `PageIdxs`)
-    commits: fjall::Partition,
+/// `Storage` persists volumes into three partitions provided by a `StorageBackend`:
+///
+/// `Partition::Volumes` is used to store volume state broken out by tag.
+/// Keyed by `VolumeStateKey`.
+///
+/// ```text
+/// {vid}/VolumeStateTag::Config -> VolumeConfig
+/// {vid}/VolumeStateTag::Status -> VolumeStatus
+/// {vid}/VolumeStateTag::Snapshot -> Snapshot
+/// {vid}/VolumeStateTag::Watermarks -> Watermarks
+/// {vid}/VolumeStateTag::SyncTimes -> SyncTimes
+/// ```
+///
+/// `Partition::Pages` is used to store page contents.
+/// maps from (`VolumeId`, `PageIdx`, LSN) to `PageValue`
+///
+/// `Partition::Commits` is used to track changes made by local commits.
+/// maps from (`VolumeId`, LSN) to the commit's base page count and optional
+/// `CommitMetadata` followed by a Graft (Splinter of changed `PageIdxs`)
+pub struct Storage {
+    backend: Box<dyn StorageBackend>,
 
     /// Must be held while performing read+write transactions.
     /// Read-only and write-only transactions don't need to hold the lock as
     /// long as they are safe:
-    /// To make read-only txns safe, always use fjall snapshots
+    /// To make read-only txns safe, always use backend snapshots
     /// To make write-only txns safe, they must be monotonic
     commit_lock: Arc<Mutex<()>>,
 
@@ -136,39 +177,49 @@ pub struct Storage {
 
     /// Used to notify subscribers of new remote commits
     remote_changeset: ChangeSet<VolumeId>,
+
+    /// Used to notify subscribers of remote changes to specific pages
+    page_subscriptions: PageSubscriptions,
+
+    /// The time of the last pull which found no remote changes, per volume.
+    /// Kept in memory to avoid a durable write on every pull; pulls which
+    /// receive remote changes persist their time in the volume's sync times.
+    unchanged_pulls: Mutex<HashMap<VolumeId, SystemTime>>,
+
+    /// Caches the result of `pending_commit_stats` per volume. Local commits
+    /// update the cached stats while every other change to a volume's
+    /// snapshot removes them.
+    pending_stats: Mutex<HashMap<VolumeId, (u64, u64)>>,
 }
 
 impl Storage {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
         tracing::debug!("opening runtime storage at {}", path.as_ref().display());
-        Self::open_config(fjall::Config::new(path))
+        Self::open_backend(FjallBackend::open(path)?)
     }
 
     pub fn open_temporary() -> Result<Self> {
-        let path = tempfile::tempdir()?.keep();
-        tracing::debug!("opening temporary runtime storage at {}", path.display());
-        Self::open_config(fjall::Config::new(path).temporary(true))
-    }
-
-    fn open_config(config: fjall::Config) -> Result<Self> {
-        let keyspace = config.open()?;
-        let volumes = keyspace.open_partition("volumes", Default::default())?;
-        let pages = keyspace.open_partition(
-            "pages",
-            PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
-        )?;
-        let commits = keyspace.open_partition(
-            "commits",
-            PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
-        )?;
+        tracing::debug!("opening temporary runtime storage");
+        Self::open_backend(FjallBackend::open_temporary()?)
+    }
+
+    /// Open storage which is entirely held in memory.
+    /// All data is lost when the `Storage` is dropped.
+    pub fn open_memory() -> Result<Self> {
+        tracing::debug!("opening in-memory runtime storage");
+        Self::open_backend(MemBackend::default())
+    }
+
+    /// Open storage on top of the provided `StorageBackend`
+    pub fn open_backend<B: StorageBackend + 'static>(backend: B) -> Result<Self> {
         let storage = Storage {
-            keyspace,
-            volumes,
-            pages,
-            commits,
+            backend: Box::new(backend),
             commit_lock: Default::default(),
             local_changeset: Default::default(),
             remote_changeset: Default::default(),
+            page_subscriptions: Default::default(),
+            unchanged_pulls: Default::default(),
+            pending_stats: Default::default(),
         };
         storage.check_for_interrupted_push()?;
         Ok(storage)
@@ -176,10 +227,9 @@ impl Storage {
 
     fn check_for_interrupted_push(&self) -> Result<()> {
         let _permit = self.commit_lock.lock();
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
-        let iter = self.volumes.snapshot().iter().err_into();
+        let iter = self.backend.snapshot(Partition::Volumes).iter();
         let mut iter = VolumeQueryIter::new(iter);
         while let Some(state) = iter.try_next()? {
             if state.is_syncing() {
@@ -187,7 +237,7 @@ impl Storage {
                 self.set_volume_status(&mut batch, state.vid(), VolumeStatus::InterruptedPush);
             }
         }
-        Ok(batch.commit()?)
+        self.backend.write_batch(batch)
     }
 
     /// Access the local commit changeset. This `ChangeSet` is updated whenever a
@@ -196,42 +246,72 @@ impl Storage {
         &self.local_changeset
     }
 
+    /// Access the page subscriptions. Subscribers are notified whenever a
+    /// Volume receives a remote commit which changes pages they subscribed to.
+    pub fn page_subscriptions(&self) -> &PageSubscriptions {
+        &self.page_subscriptions
+    }
+
     /// Access the remote commit changeset. This `ChangeSet` is updated whenever a
     /// Volume receives a remote commit.
     pub fn remote_changeset(&self) -> &ChangeSet<VolumeId> {
         &self.remote_changeset
     }
 
-    /// Set the specified Volume's config
+    /// Set the specified Volume's config.
+    /// Fails if the config changes the page size of a Volume which contains data.
     pub fn set_volume_config(&self, vid: &VolumeId, config: VolumeConfig) -> Result<()> {
-        let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Config);
-        Ok(self.volumes.insert(key, config)?)
+        self.update_volume_config(vid, |_| config.clone())
     }
 
-    /// Update a Volume's config
+    /// Update a Volume's config.
+    /// Fails if the update changes the page size of a Volume which contains data.
     pub fn update_volume_config<F>(&self, vid: &VolumeId, mut f: F) -> Result<()>
     where
         F: FnMut(VolumeConfig) -> VolumeConfig,
     {
         let _permit = self.commit_lock.lock();
         let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Config);
-        let config = self
-            .volumes
-            .get(&key)?
+        let config = self.get_volume_config(vid)?.unwrap_or_default();
+        let new_config = f(config.clone());
+        if new_config.page_size() != config.page_size() && self.snapshot(vid)?.is_some() {
+            return Err(Culprit::new_with_note(
+                StorageErr::PageSizeMismatch,
+                format!(
+                    "Volume {vid} has page size {}; the page size can't be changed to {}",
+                    config.page_size(),
+                    new_config.page_size()
+                ),
+            ));
+        }
+        self.backend
+            .insert(Partition::Volumes, key.as_ref(), new_config.into())
+    }
+
+    /// Retrieve the specified Volume's config, or None if the Volume doesn't exist
+    pub fn get_volume_config(&self, vid: &VolumeId) -> Result<Option<VolumeConfig>> {
+        let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Config);
+        self.backend
+            .get(Partition::Volumes, key.as_ref())?
             .map(|c| VolumeConfig::from_bytes(&c))
-            .transpose()?
-            .unwrap_or_default();
-        Ok(self.volumes.insert(key, f(config))?)
+            .transpose()
     }
 
-    fn set_volume_status(&self, batch: &mut fjall::Batch, vid: &VolumeId, status: VolumeStatus) {
+    /// Retrieve the specified Volume's page size
+    pub fn page_size(&self, vid: &VolumeId) -> Result<PageSize> {
+        Ok(self
+            .get_volume_config(vid)?
+            .map_or(PageSize::DEFAULT, |c| c.page_size()))
+    }
+
+    fn set_volume_status(&self, batch: &mut Batch, vid: &VolumeId, status: VolumeStatus) {
         let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Status);
-        batch.insert(&self.volumes, key, status)
+        batch.insert(Partition::Volumes, key, status)
     }
 
     pub fn get_volume_status(&self, vid: &VolumeId) -> Result<VolumeStatus> {
         let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Status);
-        if let Some(value) = self.volumes.get(key)? {
+        if let Some(value) = self.backend.get(Partition::Volumes, key.as_ref())? {
             Ok(VolumeStatus::from_bytes(&value)?)
         } else {
             Ok(VolumeStatus::Ok)
@@ -240,7 +320,10 @@ impl Storage {
 
     pub fn volume_state(&self, vid: &VolumeId) -> Result<VolumeState> {
         let mut state = VolumeState::new(vid.clone());
-        let mut iter = self.volumes.snapshot().prefix(vid);
+        let mut iter = self
+            .backend
+            .snapshot(Partition::Volumes)
+            .prefix(vid.as_ref());
         while let Some((key, value)) = iter.try_next()? {
             let key = VolumeStateKey::ref_from_bytes(&key)?;
             debug_assert_eq!(key.vid(), vid, "vid mismatch");
@@ -251,7 +334,7 @@ impl Storage {
 
     pub fn snapshot(&self, vid: &VolumeId) -> Result<Option<Snapshot>> {
         let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Snapshot);
-        if let Some(snapshot) = self.volumes.get(key)? {
+        if let Some(snapshot) = self.backend.get(Partition::Volumes, key.as_ref())? {
             Ok(Some(Snapshot::try_from_bytes(&snapshot)?))
         } else {
             Ok(None)
@@ -259,13 +342,13 @@ impl Storage {
     }
 
     pub fn iter_volumes(&self) -> impl TryIterator<Ok = VolumeState, Err = Culprit<StorageErr>> {
-        let iter = self.volumes.snapshot().iter().err_into();
+        let iter = self.backend.snapshot(Partition::Volumes).iter();
         VolumeQueryIter::new(iter)
     }
 
     pub fn volume_exists(&self, vid: VolumeId) -> Result<bool> {
         let key = VolumeStateKey::new(vid, VolumeStateTag::Config);
-        Ok(self.volumes.contains_key(key)?)
+        self.backend.contains_key(Partition::Volumes, key.as_ref())
     }
 
     pub fn query_volumes(
@@ -273,7 +356,7 @@ impl Storage {
         sync: SyncDirection,
         vids: Option<HashSet<VolumeId>>,
     ) -> impl TryIterator<Ok = VolumeState, Err = Culprit<StorageErr>> {
-        let iter = self.volumes.snapshot().iter().err_into();
+        let iter = self.backend.snapshot(Partition::Volumes).iter();
         let iter = VolumeQueryIter::new(iter);
         iter.try_filter(move |state| {
             let matches_vid = vids.as_ref().is_none_or(|s| s.contains(state.vid()));
@@ -294,10 +377,10 @@ impl Storage {
     where
         I: TryIterator<Ok = PageIdx, Err = Culprit<StorageErr>> + 'a,
     {
-        let snapshot = self.pages.snapshot();
+        let snapshot = self.backend.snapshot(Partition::Pages);
         pages.map_ok(move |pageidx| {
             let key = PageKey::new(vid.clone(), pageidx, lsn);
-            if let Some(page) = snapshot.get(key)? {
+            if let Some(page) = snapshot.get(key.as_ref())? {
                 Ok((pageidx, Some(PageValue::try_from(page).or_into_ctx()?)))
             } else {
                 Ok((pageidx, None))
@@ -311,14 +394,14 @@ impl Storage {
     pub fn read(&self, vid: &VolumeId, lsn: LSN, pageidx: PageIdx) -> Result<(LSN, PageValue)> {
         let first_key = PageKey::new(vid.clone(), pageidx, LSN::FIRST);
         let key = PageKey::new(vid.clone(), pageidx, lsn);
-        let range = first_key..=key;
 
         // Search for the latest page between LSN(0) and the requested LSN,
         // returning PageValue::Pending if none found.
-        if let Some((key, page)) = self.pages.snapshot().range(range).next_back().transpose()? {
+        let snapshot = self.backend.snapshot(Partition::Pages);
+        let mut range = snapshot.range(first_key.as_ref(), key.as_ref());
+        if let Some((key, page)) = range.next_back().transpose()? {
             let lsn = PageKey::try_ref_from_bytes(&key)?.lsn();
-            let bytes: Bytes = page.into();
-            Ok((lsn, PageValue::try_from(bytes).or_into_ctx()?))
+            Ok((lsn, PageValue::try_from(page).or_into_ctx()?))
         } else {
             Ok((lsn, PageValue::Pending))
         }
@@ -330,8 +413,16 @@ impl Storage {
         snapshot: Option<Snapshot>,
         pages: impl Into<PageCount>,
         memtable: Memtable,
+        metadata: Option<CommitMetadata>,
     ) -> Result<Snapshot> {
         let pages = pages.into();
+        let metadata = metadata.filter(|m| !m.is_empty());
+        if let Some(metadata) = &metadata {
+            // the remote would otherwise reject the push
+            metadata
+                .validate()
+                .or_ctx(|_| StorageErr::InvalidCommitMetadata)?;
+        }
         let span = tracing::debug_span!(
             "volume_commit",
             ?vid,
@@ -341,8 +432,7 @@ impl Storage {
         )
         .entered();
 
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
         let read_lsn = snapshot.as_ref().map(|s| s.local());
         let commit_lsn = read_lsn.map_or(LSN::FIRST, |lsn| lsn.next().expect("lsn overflow"));
@@ -351,16 +441,31 @@ impl Storage {
         let mut graft = Splinter::default();
 
         // persist the memtable
+        let page_size = self.page_size(vid)?;
         let mut page_key = PageKey::new(vid.clone(), PageIdx::FIRST, commit_lsn);
         for (pageidx, page) in memtable {
+            if page.size() != page_size {
+                return Err(Culprit::new_with_note(
+                    StorageErr::PageSizeMismatch,
+                    format!(
+                        "page {pageidx} has size {}; Volume {vid} has page size {page_size}",
+                        page.size()
+                    ),
+                ));
+            }
             page_key = page_key.with_index(pageidx);
             graft.insert(pageidx.into());
-            batch.insert(&self.pages, page_key.as_bytes(), PageValue::from(page));
+            batch.insert(Partition::Pages, page_key.as_bytes(), PageValue::from(page));
         }
 
-        // persist the new commit
+        // persist the new commit along with the page count it was based on
+        let base_pages = snapshot.as_ref().map_or(PageCount::ZERO, |s| s.pages());
         let commit_key = CommitKey::new(vid.clone(), commit_lsn);
-        batch.insert(&self.commits, commit_key, graft.serialize_to_bytes());
+        batch.insert(
+            Partition::Commits,
+            commit_key,
+            encode_commit(base_pages, metadata.as_ref(), &graft, false),
+        );
 
         // acquire the commit lock
         let _permit = self.commit_lock.lock();
@@ -394,10 +499,17 @@ impl Storage {
                 .unwrap_or_default(),
             pages,
         );
-        batch.insert(&self.volumes, snapshot_key, snapshot.as_bytes());
-
-        // commit the changes
-        batch.commit()?;
+        batch.insert(Partition::Volumes, snapshot_key, snapshot.clone());
+
+        // commit the changes, holding the pending stats lock so that a
+        // concurrent scan can't count this commit twice
+        let mut pending_stats = self.pending_stats.lock();
+        self.backend.write_batch(batch)?;
+        if let Some((commits, pages)) = pending_stats.get_mut(vid) {
+            *commits += 1;
+            *pages += graft.cardinality() as u64;
+        }
+        drop(pending_stats);
 
         // notify listeners of the new local commit
         self.local_changeset.mark_changed(vid);
@@ -409,13 +521,14 @@ impl Storage {
         Ok(snapshot)
     }
 
-    /// Replicate a remote commit to local storage.
+    /// Replicate a remote commit to local storage, returning the new local
+    /// snapshot of the volume.
     pub fn receive_remote_commit(
         &self,
         vid: &VolumeId,
         remote_snapshot: graft_proto::Snapshot,
         changed: SplinterRef<Bytes>,
-    ) -> Result<()> {
+    ) -> Result<Snapshot> {
         self.receive_remote_commit_holding_lock(
             self.commit_lock.lock(),
             vid,
@@ -432,7 +545,7 @@ impl Storage {
         vid: &VolumeId,
         remote_snapshot: graft_proto::Snapshot,
         graft: SplinterRef<Bytes>,
-    ) -> Result<()> {
+    ) -> Result<Snapshot> {
         // resolve the remote lsn and page count
         let remote_lsn = remote_snapshot.lsn().expect("invalid remote LSN");
         let remote_pages = remote_snapshot.pages();
@@ -445,13 +558,13 @@ impl Storage {
         )
         .entered();
 
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
         // retrieve the current volume state
         let state = self.volume_state(vid)?;
         let snapshot = state.snapshot();
         let watermarks = state.watermarks();
+        check_remote_page_size(&state, &remote_snapshot)?;
 
         // ensure that we can accept this remote commit
         if state.is_syncing() {
@@ -482,38 +595,45 @@ impl Storage {
         // persist the new volume snapshot
         let new_snapshot = Snapshot::new(commit_lsn, remote_mapping, remote_pages);
         batch.insert(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Snapshot),
-            new_snapshot.as_bytes(),
+            new_snapshot.clone(),
         );
 
         // fast forward the pending sync watermark to ensure we don't roundtrip this
         // commit back to the server
         batch.insert(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Watermarks),
             watermarks
                 .clone()
                 .with_pending_sync(Watermark::new(commit_lsn, remote_pages)),
         );
+        batch.insert(
+            Partition::Volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::SyncTimes),
+            state.sync_times().with_last_pull(SystemTime::now()),
+        );
 
         // mark changed pages
         let mut key = PageKey::new(vid.clone(), PageIdx::FIRST, commit_lsn);
         let pending = Bytes::from(PageValue::Pending);
         for pageidx in graft.iter() {
             key = key.with_index(pageidx.try_into()?);
-            batch.insert(&self.pages, key.as_ref(), pending.clone());
+            batch.insert(Partition::Pages, key.as_ref(), pending.clone());
         }
 
-        batch.commit()?;
+        self.backend.write_batch(batch)?;
+        self.pending_stats.lock().remove(vid);
 
         // notify listeners of the new remote commit
         self.remote_changeset.mark_changed(vid);
+        self.page_subscriptions.notify(vid, &graft);
 
         // log the result
         span.record("result", new_snapshot.to_string());
 
-        Ok(())
+        Ok(new_snapshot)
     }
 
     /// Write a set of `PageValue`'s to storage.
@@ -522,45 +642,205 @@ impl Storage {
         vid: &VolumeId,
         pages: HashMap<PageIdx, (LSN, PageValue)>,
     ) -> Result<()> {
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
         for (pageidx, (lsn, pagevalue)) in pages {
             tracing::trace!("caching page {pageidx} into lsn {lsn} with value {pagevalue:?}");
             let key = PageKey::new(vid.clone(), pageidx, lsn);
-            batch.insert(&self.pages, key.as_ref(), pagevalue);
+            batch.insert(Partition::Pages, key, pagevalue);
+        }
+        self.backend.write_batch(batch)
+    }
+
+    /// Returns the number of local commits which have not been pushed to the
+    /// remote, along with the total number of pages they changed. Pages
+    /// changed by multiple commits are counted once per commit, and rolled
+    /// back commits are ignored.
+    pub fn pending_commit_stats(&self, vid: &VolumeId) -> Result<(u64, u64)> {
+        let mut pending_stats = self.pending_stats.lock();
+        if let Some(stats) = pending_stats.get(vid) {
+            return Ok(*stats);
+        }
+        let stats = self.scan_pending_commit_stats(vid)?;
+        pending_stats.insert(vid.clone(), stats);
+        Ok(stats)
+    }
+
+    fn scan_pending_commit_stats(&self, vid: &VolumeId) -> Result<(u64, u64)> {
+        let Some(snapshot) = self.snapshot(vid)? else {
+            return Ok((0, 0));
+        };
+        let start_lsn = snapshot
+            .remote_local()
+            .map_or(LSN::FIRST, |s| s.next().expect("LSN overflow"));
+        if start_lsn > snapshot.local() {
+            return Ok((0, 0));
+        }
+
+        let commit_start = CommitKey::new(vid.clone(), start_lsn);
+        let commit_end = CommitKey::new(vid.clone(), snapshot.local());
+        let mut commits = self
+            .backend
+            .snapshot(Partition::Commits)
+            .range(commit_start.as_ref(), commit_end.as_ref());
+        let (mut num_commits, mut num_pages) = (0, 0);
+        while let Some((_, value)) = commits.try_next()? {
+            let commit = decode_commit(value)?;
+            if commit.rolled_back {
+                continue;
+            }
+            num_commits += 1;
+            num_pages += commit.graft.cardinality() as u64;
+        }
+        Ok((num_commits, num_pages))
+    }
+
+    /// Compute statistics about a volume's local storage and sync state.
+    ///
+    /// Page counts reflect the latest snapshot, and are computed by scanning
+    /// every page version stored for the volume. Pages which have never been
+    /// stored locally count as pending if the volume has a remote LSN, and
+    /// empty otherwise.
+    pub fn volume_stats(&self, vid: &VolumeId) -> Result<VolumeStats> {
+        let state = self.volume_state(vid)?;
+        let snapshot = state.snapshot();
+        let pages = snapshot.map_or(PageCount::ZERO, |s| s.pages());
+        let has_remote = snapshot.is_some_and(|s| s.remote().is_some());
+
+        let mut stats = VolumeStats {
+            pages,
+            available_pages: 0,
+            pending_pages: 0,
+            empty_pages: 0,
+            local_bytes: ByteUnit::ZERO,
+            pending_commits: 0,
+            pending_bytes: ByteUnit::ZERO,
+            last_push: state.sync_times().last_push(),
+            last_pull: state
+                .sync_times()
+                .last_pull()
+                .max(self.unchanged_pulls.lock().get(vid).copied()),
+        };
+
+        // keys are ordered by page index and then LSN, so the last version of
+        // each page visible at the snapshot determines the page's state
+        let local_lsn = snapshot.map(|s| s.local());
+        let mut visible: Option<(PageIdx, Bytes)> = None;
+        let mut iter = self.backend.snapshot(Partition::Pages).prefix(vid.as_ref());
+        while let Some((key, value)) = iter.try_next()? {
+            stats.local_bytes = stats.local_bytes + value.len();
+            let key = PageKey::try_ref_from_bytes(&key)?;
+            let pageidx = key.index();
+            if let Some((_, value)) = visible.take_if(|(idx, _)| *idx != pageidx) {
+                stats.tally(PageValue::try_from(value).or_into_ctx()?, has_remote);
+            }
+            if Some(key.lsn()) <= local_lsn && pages.contains(pageidx) {
+                visible = Some((pageidx, value));
+            }
+        }
+        if let Some((_, value)) = visible {
+            stats.tally(PageValue::try_from(value).or_into_ctx()?, has_remote);
+        }
+
+        // pages which have never been stored locally
+        let stored = stats.available_pages + stats.pending_pages + stats.empty_pages;
+        for _ in stored..pages.to_u32() {
+            stats.tally(PageValue::Pending, has_remote);
         }
-        Ok(batch.commit()?)
+
+        let (pending_commits, pending_pages) = self.pending_commit_stats(vid)?;
+        stats.pending_commits = pending_commits;
+        stats.pending_bytes = state.page_size().as_byte_unit() * pending_pages;
+
+        Ok(stats)
+    }
+
+    /// Record that a pull from the remote found no remote changes
+    pub fn record_pull(&self, vid: &VolumeId) {
+        self.unchanged_pulls
+            .lock()
+            .insert(vid.clone(), SystemTime::now());
     }
 
     /// Prepare to sync a volume to the remote.
-    /// Returns:
+    /// Returns None if the volume has no local commits to sync, otherwise:
     /// - the last known remote LSN
     /// - the local page count we are syncing
     /// - the range of LSNs to sync
-    /// - an iterator of commits to sync
+    /// - an iterator of commits to sync along with their metadata
     #[allow(clippy::type_complexity)]
     pub fn prepare_sync_to_remote(
         &self,
         vid: &VolumeId,
-    ) -> Result<(
-        Option<LSN>,
-        PageCount,
-        RangeInclusive<LSN>,
-        impl TryIterator<Ok = (LSN, SplinterRef<Slice>), Err = Culprit<StorageErr>>,
-    )> {
+    ) -> Result<
+        Option<(
+            Option<LSN>,
+            PageCount,
+            RangeInclusive<LSN>,
+            impl TryIterator<
+                Ok = (LSN, SplinterRef<Bytes>, Option<CommitMetadata>),
+                Err = Culprit<StorageErr>,
+            >,
+        )>,
+    > {
+        self.prepare_sync(vid, false)
+    }
+
+    /// Prepare to export a volume's local commits without syncing them.
+    /// Returns the same values as `prepare_sync_to_remote`, however the
+    /// volume's sync state is left untouched. Fails if the volume is
+    /// currently syncing, as the commits may already be on the remote.
+    #[allow(clippy::type_complexity)]
+    pub fn prepare_export(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<
+        Option<(
+            Option<LSN>,
+            PageCount,
+            RangeInclusive<LSN>,
+            impl TryIterator<
+                Ok = (LSN, SplinterRef<Bytes>, Option<CommitMetadata>),
+                Err = Culprit<StorageErr>,
+            >,
+        )>,
+    > {
+        self.prepare_sync(vid, true)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn prepare_sync(
+        &self,
+        vid: &VolumeId,
+        export: bool,
+    ) -> Result<
+        Option<(
+            Option<LSN>,
+            PageCount,
+            RangeInclusive<LSN>,
+            impl TryIterator<
+                Ok = (LSN, SplinterRef<Bytes>, Option<CommitMetadata>),
+                Err = Culprit<StorageErr>,
+            >,
+        )>,
+    > {
         // acquire the commit lock
         let _permit = self.commit_lock.lock();
 
         // retrieve the current volume state
         let state = self.volume_state(vid)?;
 
-        // ensure that we only run this job when we actually have commits to sync
-        precept::expect_always_or_unreachable!(
-            state.has_pending_commits(),
-            "the sync push job only runs when we have local commits to push",
-            { "vid": vid, "state": state }
-        );
+        // the push job is only scheduled when we have local commits to push,
+        // however they may have been rolled back in the meantime
+        if !state.has_pending_commits() {
+            if !export {
+                precept::expect_reachable!(
+                    "local commits rolled back before push",
+                    { "vid": vid, "state": state }
+                );
+            }
+            return Ok(None);
+        }
 
         // resolve the snapshot; we can expect it to be available because this
         // function should only run when we have local commits to sync
@@ -568,7 +848,15 @@ impl Storage {
         let local_lsn = snapshot.local();
 
         // calculate the end of the sync range
-        let (end_lsn, page_count) = if state.is_syncing() {
+        let (end_lsn, page_count) = if export {
+            if state.is_syncing() {
+                return Err(Culprit::new_with_note(
+                    StorageErr::VolumeIsSyncing,
+                    format!("Volume {vid} is syncing, refusing to export local commits"),
+                ));
+            }
+            (local_lsn, snapshot.pages())
+        } else if state.is_syncing() {
             // if we are resuming a previously interrupted sync, use the
             // existing pending_sync watermark
             let pending_sync = state.watermarks().pending_sync();
@@ -582,12 +870,14 @@ impl Storage {
             pending_sync.splat().expect("pending sync must be mapped")
         } else {
             // update pending_sync to the local LSN
-            self.volumes.insert(
-                VolumeStateKey::new(vid.clone(), VolumeStateTag::Watermarks),
+            self.backend.insert(
+                Partition::Volumes,
+                VolumeStateKey::new(vid.clone(), VolumeStateTag::Watermarks).as_ref(),
                 state
                     .watermarks()
                     .clone()
-                    .with_pending_sync(Watermark::new(local_lsn, snapshot.pages())),
+                    .with_pending_sync(Watermark::new(local_lsn, snapshot.pages()))
+                    .into(),
             )?;
             (local_lsn, snapshot.pages())
         };
@@ -604,10 +894,9 @@ impl Storage {
         let commit_end = CommitKey::new(vid.clone(), *lsns.end());
         let mut cursor = commit_start.lsn();
         let commits = self
-            .commits
-            .snapshot()
-            .range(commit_start..=commit_end)
-            .err_into()
+            .backend
+            .snapshot(Partition::Commits)
+            .range(commit_start.as_ref(), commit_end.as_ref())
             .map_ok(move |(k, v)| {
                 let lsn = CommitKey::ref_from_bytes(&k)?.lsn();
 
@@ -615,35 +904,38 @@ impl Storage {
                 assert_eq!(lsn, cursor, "missing commit detected");
                 cursor = cursor.next().expect("lsn overflow");
 
-                let splinter = SplinterRef::from_bytes(v).or_into_ctx()?;
-                Ok((lsn, splinter))
+                let commit = decode_commit(v)?;
+                if commit.rolled_back {
+                    // rolled back commits don't change the volume
+                    return Ok((lsn, Splinter::default().serialize_to_splinter_ref(), None));
+                }
+                Ok((lsn, commit.graft, commit.metadata))
             });
 
-        Ok((snapshot.remote(), page_count, lsns, commits))
+        Ok(Some((snapshot.remote(), page_count, lsns, commits)))
     }
 
     /// Update storage after a rejected sync
     pub fn rejected_sync_to_remote(&self, vid: &VolumeId) -> Result<()> {
         // acquire the commit lock
         let _permit = self.commit_lock.lock();
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
         // clear the pending sync watermark
         let watermarks_key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Watermarks);
         let watermarks = self
-            .volumes
-            .get(&watermarks_key)?
+            .backend
+            .get(Partition::Volumes, watermarks_key.as_ref())?
             .map(|w| Watermarks::from_bytes(&w))
             .transpose()?
             .unwrap_or_default()
             .with_pending_sync(Watermark::default());
-        batch.insert(&self.volumes, watermarks_key, watermarks);
+        batch.insert(Partition::Volumes, watermarks_key, watermarks);
 
         // update the volume status
         self.set_volume_status(&mut batch, vid, VolumeStatus::RejectedCommit);
 
-        Ok(batch.commit()?)
+        self.backend.write_batch(batch)
     }
 
     /// Complete a push operation by updating the volume snapshot and removing
@@ -656,8 +948,7 @@ impl Storage {
     ) -> Result<()> {
         // acquire the commit lock and start a new batch
         let _permit = self.commit_lock.lock();
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
         let state = self.volume_state(vid)?;
 
@@ -685,25 +976,30 @@ impl Storage {
         let remote_mapping = RemoteMapping::new(remote_lsn, remote_local_lsn);
         let new_snapshot = Snapshot::new(local_lsn, remote_mapping, pages);
         batch.insert(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Snapshot),
-            new_snapshot.as_bytes(),
+            new_snapshot.clone(),
         );
 
         // clear the pending_sync watermark
         batch.insert(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Watermarks),
             state
                 .watermarks()
                 .clone()
                 .with_pending_sync(Watermark::default()),
         );
+        batch.insert(
+            Partition::Volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::SyncTimes),
+            state.sync_times().with_last_push(SystemTime::now()),
+        );
 
         // if the status is interrupted push, clear the status
         if state.status() == VolumeStatus::InterruptedPush {
             batch.remove(
-                &self.volumes,
+                Partition::Volumes,
                 VolumeStateKey::new(vid.clone(), VolumeStateTag::Status),
             );
         }
@@ -712,16 +1008,152 @@ impl Storage {
         let mut key = CommitKey::new(vid.clone(), LSN::FIRST);
         for lsn in synced_lsns.iter() {
             key = key.with_lsn(lsn);
-            batch.remove(&self.commits, key.as_ref());
+            batch.remove(Partition::Commits, key.as_ref());
         }
 
-        batch.commit()?;
+        self.backend.write_batch(batch)?;
+        self.pending_stats.lock().remove(vid);
 
         tracing::debug!(?synced_lsns, %remote_lsn, %new_snapshot, "completed sync to remote");
 
         Ok(())
     }
 
+    /// Roll back the newest local commits to the volume, such that its
+    /// contents match its snapshot at `to_lsn`, or an empty volume if `to_lsn`
+    /// is None. Only commits which have not started pushing to the remote may
+    /// be rolled back.
+    ///
+    /// LSNs are never reused. The rolled back commits are marked as rolled
+    /// back rather than removed from the commits and pages partitions, and the
+    /// restored contents are committed at a new LSN. Snapshots don't hold a
+    /// reference to the storage they read from, so removing the rolled back
+    /// pages would break readers of snapshots taken before the rollback.
+    /// Instead those snapshots remain readable, and writers based on them fail
+    /// with `StorageErr::ConcurrentWrite`. Rolled back commits are skipped when
+    /// the volume is pushed, and are removed along with the rest of the pushed
+    /// commits once the push completes. Returns the new snapshot of the volume.
+    pub fn rollback_local(&self, vid: &VolumeId, to_lsn: Option<LSN>) -> Result<Snapshot> {
+        // acquire the commit lock
+        let _permit = self.commit_lock.lock();
+
+        let span =
+            tracing::debug_span!("rollback_local", ?vid, ?to_lsn, result = field::Empty).entered();
+
+        // retrieve the current volume state
+        let state = self.volume_state(vid)?;
+        let Some(snapshot) = state.snapshot() else {
+            return Err(Culprit::new_with_note(
+                StorageErr::InvalidRollback,
+                format!("Volume {vid} has no local commits"),
+            ));
+        };
+        let local_lsn = snapshot.local();
+
+        if to_lsn == Some(local_lsn) {
+            // nothing to roll back
+            return Ok(snapshot.clone());
+        }
+
+        // ensure the volume is not in the middle of pushing its commits
+        if state.is_syncing() {
+            return Err(Culprit::new_with_note(
+                StorageErr::VolumeIsSyncing,
+                format!("Volume {vid} is syncing, refusing to roll back local commits"),
+            ));
+        }
+
+        // ensure we only roll back unpushed local commits
+        if to_lsn > Some(local_lsn) || to_lsn < snapshot.remote_local() {
+            return Err(Culprit::new_with_note(
+                StorageErr::InvalidRollback,
+                format!(
+                    "LSN {to_lsn:?} is outside of the unpushed commit range ({:?}, {local_lsn}]",
+                    snapshot.remote_local()
+                ),
+            ));
+        }
+
+        let mut batch = Batch::default();
+        let commit_lsn = local_lsn.next().expect("lsn overflow");
+
+        // mark all commits after to_lsn as rolled back, tracking the page count
+        // the first rolled back commit was based on along with every page the
+        // rolled back commits changed
+        let start_lsn = to_lsn.map_or(LSN::FIRST, |lsn| lsn.next().expect("lsn overflow"));
+        let commit_start = CommitKey::new(vid.clone(), start_lsn);
+        let commit_end = CommitKey::new(vid.clone(), local_lsn);
+        let mut commits = self
+            .backend
+            .snapshot(Partition::Commits)
+            .range(commit_start.as_ref(), commit_end.as_ref());
+        let mut cursor = start_lsn;
+        let mut pages = None;
+        let mut graft = Splinter::default();
+        while let Some((key, value)) = commits.try_next()? {
+            let lsn = CommitKey::ref_from_bytes(&key)?.lsn();
+            assert_eq!(lsn, cursor, "missing commit detected");
+            cursor = cursor.next().expect("lsn overflow");
+
+            let commit = decode_commit(value)?;
+            if pages.is_none() {
+                // commits written by earlier releases don't record the page
+                // count they were based on
+                let base_pages = commit.base_pages.ok_or_else(|| {
+                    Culprit::new_with_note(
+                        StorageErr::InvalidRollback,
+                        format!("commit {lsn} predates support for rollbacks"),
+                    )
+                })?;
+                pages = Some(base_pages);
+            }
+
+            for pageidx in commit.graft.iter() {
+                graft.insert(pageidx);
+            }
+            if !commit.rolled_back {
+                batch.insert(Partition::Commits, &key, commit.encode_rolled_back());
+            }
+        }
+        let pages = pages.expect("missing commit detected");
+
+        // restore each changed page to its contents at to_lsn in a new commit,
+        // which is itself rolled back as it doesn't change the volume relative
+        // to the commits before to_lsn
+        let mut page_key = PageKey::new(vid.clone(), PageIdx::FIRST, commit_lsn);
+        for pageidx in graft.iter() {
+            page_key = page_key.with_index(pageidx.try_into()?);
+            let value = match to_lsn {
+                Some(to_lsn) => self.read(vid, to_lsn, page_key.index())?.1,
+                None => PageValue::Pending,
+            };
+            batch.insert(Partition::Pages, page_key.as_bytes(), value);
+        }
+        batch.insert(
+            Partition::Commits,
+            CommitKey::new(vid.clone(), commit_lsn),
+            encode_commit(snapshot.pages(), None, &graft, true),
+        );
+
+        // persist the new volume snapshot
+        let new_snapshot = Snapshot::new(commit_lsn, snapshot.remote_mapping().clone(), pages);
+        batch.insert(
+            Partition::Volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::Snapshot),
+            new_snapshot.clone(),
+        );
+
+        self.backend.write_batch(batch)?;
+        self.pending_stats.lock().remove(vid);
+
+        // notify listeners of the local change
+        self.local_changeset.mark_changed(vid);
+
+        span.record("result", new_snapshot.to_string());
+
+        Ok(new_snapshot)
+    }
+
     /// Reset the volume to the provided remote snapshot.
     /// This will cause all pending commits to be rolled back and the volume
     /// status to be cleared.
@@ -748,6 +1180,7 @@ impl Storage {
         // retrieve the current volume state
         let state = self.volume_state(vid)?;
         let snapshot = state.snapshot();
+        check_remote_page_size(&state, &remote_snapshot)?;
 
         // the last local lsn
         let local_lsn = snapshot.map(|s| s.local());
@@ -772,12 +1205,9 @@ impl Storage {
             );
             span.record("result", format!("{snapshot:?}"));
             drop(span);
-            return self.receive_remote_commit_holding_lock(
-                permit,
-                vid,
-                remote_snapshot,
-                remote_graft,
-            );
+            return self
+                .receive_remote_commit_holding_lock(permit, vid, remote_snapshot, remote_graft)
+                .map(|_| ());
         }
 
         // ensure we never reset into the future
@@ -786,38 +1216,45 @@ impl Storage {
             "refusing to reset to a LSN larger than the current LSN; local={local_lsn:?}, target={reset_lsn:?}"
         );
 
-        let mut batch = self.keyspace.batch();
-        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        let mut batch = Batch::default();
 
         // persist the new volume snapshot
         let remote_mapping = RemoteMapping::new(remote_lsn, commit_lsn);
         let new_snapshot = Snapshot::new(commit_lsn, remote_mapping, remote_snapshot.pages());
         batch.insert(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Snapshot),
-            new_snapshot.as_bytes(),
+            new_snapshot.clone(),
         );
 
         // clear the volume status
         batch.remove(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Status),
         );
 
         // clear the pending_sync watermark
         batch.insert(
-            &self.volumes,
+            Partition::Volumes,
             VolumeStateKey::new(vid.clone(), VolumeStateTag::Watermarks),
             state
                 .watermarks()
                 .clone()
                 .with_pending_sync(Watermark::default()),
         );
+        batch.insert(
+            Partition::Volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::SyncTimes),
+            state.sync_times().with_last_pull(SystemTime::now()),
+        );
 
         // remove all pending commits
-        let mut commits = self.commits.snapshot().prefix(vid);
-        while let Some((key, graft)) = commits.try_next().or_into_ctx()? {
-            batch.remove(&self.commits, key.clone());
+        let mut commits = self
+            .backend
+            .snapshot(Partition::Commits)
+            .prefix(vid.as_ref());
+        while let Some((key, graft)) = commits.try_next()? {
+            batch.remove(Partition::Commits, &key);
 
             let key = CommitKey::ref_from_bytes(&key)?;
             assert_eq!(
@@ -831,12 +1268,12 @@ impl Storage {
             );
 
             // remove the commit's changed PageIdxs
-            let graft = SplinterRef::from_bytes(graft).or_into_ctx()?;
+            let graft = decode_commit(graft)?.graft;
 
             let mut key = PageKey::new(vid.clone(), PageIdx::FIRST, key.lsn());
             for pageidx in graft.iter() {
                 key = key.with_index(pageidx.try_into()?);
-                batch.remove(&self.pages, key.as_ref());
+                batch.remove(Partition::Pages, key.as_ref());
             }
         }
 
@@ -845,11 +1282,12 @@ impl Storage {
         let pending = Bytes::from(PageValue::Pending);
         for pageidx in remote_graft.iter() {
             key = key.with_index(pageidx.try_into()?);
-            batch.insert(&self.pages, key.as_ref(), pending.clone());
+            batch.insert(Partition::Pages, key.as_ref(), pending.clone());
         }
 
         // commit the changes
-        batch.commit()?;
+        self.backend.write_batch(batch)?;
+        self.pending_stats.lock().remove(vid);
 
         // post reset invariants
         // these are expensive so we only run them when precept is enabled
@@ -857,8 +1295,8 @@ impl Storage {
             // scan all of the pages in the volume to verify two invariants:
             // 1. all pages at commit_lsn must be pending
             // 2. no pages exist at an lsn > commit_lsn
-            let mut iter = self.pages.snapshot().prefix(vid);
-            while let Some((key, val)) = iter.try_next().or_into_ctx()? {
+            let mut iter = self.backend.snapshot(Partition::Pages).prefix(vid.as_ref());
+            while let Some((key, val)) = iter.try_next()? {
                 let key = PageKey::try_ref_from_bytes(&key)?;
                 if key.lsn() == commit_lsn {
                     // invariant 1: all pages at commit_lsn must be pending
@@ -878,6 +1316,7 @@ impl Storage {
 
         // notify listeners of the new remote commit
         self.remote_changeset.mark_changed(vid);
+        self.page_subscriptions.notify(vid, &remote_graft);
 
         // log the result
         span.record("result", new_snapshot.to_string());
@@ -889,21 +1328,32 @@ impl Storage {
 impl Debug for Storage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Storage")
-            .field("disk usage", &ByteUnit::new(self.keyspace.disk_space()))
+            .field("disk usage", &ByteUnit::new(self.backend.disk_space()))
             .finish()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use graft_core::{page::Page, pageidx};
+    use graft_core::{
+        gid::ClientId,
+        page::{PAGESIZE, Page},
+        pageidx,
+    };
 
     use super::*;
 
     #[graft_test::test]
     fn test_query_volumes() {
-        let storage = Storage::open_temporary().unwrap();
+        query_volumes_sanity(Storage::open_temporary().unwrap());
+    }
 
+    #[graft_test::test]
+    fn test_query_volumes_memory() {
+        query_volumes_sanity(Storage::open_memory().unwrap());
+    }
+
+    fn query_volumes_sanity(storage: Storage) {
         let mut memtable = Memtable::default();
         memtable.insert(pageidx!(1), Page::test_filled(0x42));
 
@@ -914,16 +1364,20 @@ mod tests {
         storage
             .set_volume_config(&vids[0], VolumeConfig::new(SyncDirection::Pull))
             .unwrap();
-        let snapshot = storage.commit(&vids[0], None, 1, memtable.clone()).unwrap();
+        let snapshot = storage
+            .commit(&vids[0], None, 1, memtable.clone(), None)
+            .unwrap();
         storage
-            .commit(&vids[0], Some(snapshot), 1, memtable.clone())
+            .commit(&vids[0], Some(snapshot), 1, memtable.clone(), None)
             .unwrap();
 
         // second volume has one commit, and is configured to push
         storage
             .set_volume_config(&vids[1], VolumeConfig::new(SyncDirection::Push))
             .unwrap();
-        storage.commit(&vids[1], None, 1, memtable.clone()).unwrap();
+        storage
+            .commit(&vids[1], None, 1, memtable.clone(), None)
+            .unwrap();
 
         // ensure that we can query back out the snapshots
         let sync = SyncDirection::Both;
@@ -975,4 +1429,255 @@ mod tests {
         // iter is empty
         assert!(iter.next().is_none());
     }
+
+    #[graft_test::test]
+    fn test_rollback_local() {
+        let storage = Storage::open_memory().unwrap();
+        let vid = VolumeId::random();
+        storage
+            .set_volume_config(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap();
+
+        // commit 1 writes page 1, commit 2 writes page 2, commit 3 truncates
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled(0x42));
+        let snapshot = storage.commit(&vid, None, 1, memtable, None).unwrap();
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(2), Page::test_filled(0x99));
+        let snapshot = storage
+            .commit(&vid, Some(snapshot), 2, memtable, None)
+            .unwrap();
+        let snapshot = storage
+            .commit(&vid, Some(snapshot), 1, Memtable::default(), None)
+            .unwrap();
+        assert_eq!(snapshot.local(), LSN::new(3));
+        let before_rollback = snapshot.clone();
+
+        // rolling back to the latest LSN is a noop
+        assert_eq!(
+            storage.rollback_local(&vid, Some(LSN::new(3))).unwrap(),
+            snapshot
+        );
+
+        // we can't roll forward
+        let err = storage.rollback_local(&vid, Some(LSN::new(4))).unwrap_err();
+        assert!(matches!(err.ctx(), StorageErr::InvalidRollback));
+
+        // roll back the truncate at a new LSN, restoring the page count
+        let snapshot = storage.rollback_local(&vid, Some(LSN::new(2))).unwrap();
+        assert_eq!(snapshot.local(), LSN::new(4));
+        assert_eq!(snapshot.pages(), 2);
+
+        // roll back the second commit, removing page 2
+        let snapshot = storage.rollback_local(&vid, Some(LSN::new(1))).unwrap();
+        assert_eq!(snapshot.local(), LSN::new(5));
+        assert_eq!(snapshot.pages(), 1);
+        assert_eq!(storage.snapshot(&vid).unwrap(), Some(snapshot.clone()));
+        let (_, page) = storage.read(&vid, LSN::new(5), pageidx!(2)).unwrap();
+        assert!(matches!(page, PageValue::Pending));
+        let (_, page) = storage.read(&vid, LSN::new(5), pageidx!(1)).unwrap();
+        assert_eq!(
+            page.try_into_page(PageSize::DEFAULT),
+            Some(Page::test_filled(0x42))
+        );
+
+        // snapshots from before the rollback remain readable
+        let (_, page) = storage.read(&vid, LSN::new(3), pageidx!(2)).unwrap();
+        assert_eq!(
+            page.try_into_page(PageSize::DEFAULT),
+            Some(Page::test_filled(0x99))
+        );
+
+        // but writers based on them conflict with the rollback
+        let err = storage
+            .commit(&vid, Some(before_rollback), 3, Memtable::default(), None)
+            .unwrap_err();
+        assert!(matches!(err.ctx(), StorageErr::ConcurrentWrite));
+
+        // rolled back commits are not counted as pending
+        assert_eq!(storage.pending_commit_stats(&vid).unwrap(), (1, 1));
+
+        // a new commit never reuses a rolled back LSN
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(3), Page::test_filled(0x11));
+        let snapshot = storage
+            .commit(&vid, Some(snapshot), 3, memtable, None)
+            .unwrap();
+        assert_eq!(snapshot.local(), LSN::new(6));
+        assert_eq!(storage.pending_commit_stats(&vid).unwrap(), (2, 2));
+
+        // rolled back commits are pushed without any changes
+        let (_, pages, lsns, commits) = storage.prepare_sync_to_remote(&vid).unwrap().unwrap();
+        assert_eq!(pages, 3);
+        assert_eq!(lsns, LSN::new(1)..=LSN::new(6));
+        let changed = commits
+            .map_ok(|(_, graft, _)| Ok(graft.cardinality()))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(changed, [1, 0, 0, 0, 0, 1]);
+
+        // once the commits start pushing they can no longer be rolled back
+        let err = storage.rollback_local(&vid, Some(LSN::new(1))).unwrap_err();
+        assert!(matches!(err.ctx(), StorageErr::VolumeIsSyncing));
+    }
+
+    #[graft_test::test]
+    fn test_rollback_local_unsynced() {
+        let storage = Storage::open_memory().unwrap();
+        let vid = VolumeId::random();
+        storage
+            .set_volume_config(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap();
+
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled(0x42));
+        storage.commit(&vid, None, 1, memtable, None).unwrap();
+
+        // a volume which has never been pushed may roll back every commit
+        let snapshot = storage.rollback_local(&vid, None).unwrap();
+        assert_eq!(snapshot.local(), LSN::new(2));
+        assert_eq!(snapshot.pages(), 0);
+        let (_, page) = storage.read(&vid, LSN::new(2), pageidx!(1)).unwrap();
+        assert!(matches!(page, PageValue::Pending));
+        assert_eq!(storage.pending_commit_stats(&vid).unwrap(), (0, 0));
+
+        // the next commit continues after the rollback
+        let snapshot = storage
+            .commit(&vid, Some(snapshot), 1, Memtable::default(), None)
+            .unwrap();
+        assert_eq!(snapshot.local(), LSN::new(3));
+        assert_eq!(snapshot.pages(), 1);
+    }
+
+    #[graft_test::test]
+    fn test_volume_stats() {
+        let storage = Storage::open_memory().unwrap();
+        let vid = VolumeId::random();
+        storage
+            .set_volume_config(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap();
+
+        // write pages 1 and 3, leaving page 2 empty
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled(0x42));
+        memtable.insert(pageidx!(3), Page::test_filled(0x43));
+        storage.commit(&vid, None, 3, memtable, None).unwrap();
+
+        let stats = storage.volume_stats(&vid).unwrap();
+        assert_eq!(stats.pages, 3);
+        assert_eq!(stats.available_pages, 2);
+        assert_eq!(stats.pending_pages, 0);
+        assert_eq!(stats.empty_pages, 1);
+        assert_eq!(stats.local_bytes, PAGESIZE * 2);
+        assert_eq!(stats.pending_commits, 1);
+        assert_eq!(stats.pending_bytes, PAGESIZE * 2);
+        assert_eq!(stats.last_push, None);
+        assert_eq!(stats.last_pull, None);
+
+        // pulls which find no remote changes are tracked in memory
+        storage.record_pull(&vid);
+        let last_pull = storage.volume_stats(&vid).unwrap().last_pull;
+        assert!(last_pull.is_some());
+        assert_eq!(
+            storage.volume_state(&vid).unwrap().sync_times().last_pull(),
+            None
+        );
+
+        // push the commit to the remote
+        let cid = ClientId::random();
+        let (_, _, lsns, _) = storage.prepare_sync_to_remote(&vid).unwrap().unwrap();
+        let remote = graft_proto::Snapshot::new(
+            &vid,
+            &cid,
+            LSN::FIRST,
+            LSN::FIRST,
+            3.into(),
+            SystemTime::now(),
+        );
+        storage.complete_sync_to_remote(&vid, remote, lsns).unwrap();
+
+        let stats = storage.volume_stats(&vid).unwrap();
+        assert_eq!(stats.pending_commits, 0);
+        assert_eq!(stats.pending_bytes, 0);
+        assert!(stats.last_push.is_some());
+
+        // receive a remote commit which changes page 2
+        let remote = graft_proto::Snapshot::new(
+            &vid,
+            &cid,
+            LSN::new(2),
+            LSN::FIRST,
+            3.into(),
+            SystemTime::now(),
+        );
+        let graft = Splinter::from_iter([2u32]).serialize_to_bytes();
+        storage
+            .receive_remote_commit(&vid, remote, SplinterRef::from_bytes(graft).unwrap())
+            .unwrap();
+
+        let stats = storage.volume_stats(&vid).unwrap();
+        assert_eq!(stats.available_pages, 2);
+        assert_eq!(stats.pending_pages, 1);
+        assert_eq!(stats.empty_pages, 0);
+        assert_eq!(stats.local_bytes, PAGESIZE * 2 + b"PENDING_".len());
+        assert!(stats.last_pull.is_some());
+    }
+
+    #[graft_test::test]
+    fn test_volume_page_size() {
+        let storage = Storage::open_memory().unwrap();
+        let vid = VolumeId::random();
+        let page_size = PageSize::try_from(16384usize).unwrap();
+        let config = VolumeConfig::new(SyncDirection::Both).with_page_size(page_size);
+        storage.set_volume_config(&vid, config.clone()).unwrap();
+        assert_eq!(storage.page_size(&vid).unwrap(), page_size);
+
+        // pages must match the volume's page size
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled(0x42));
+        let err = storage.commit(&vid, None, 1, memtable, None).unwrap_err();
+        assert!(matches!(err.ctx(), StorageErr::PageSizeMismatch));
+
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled_sized(0x42, page_size));
+        storage.commit(&vid, None, 1, memtable, None).unwrap();
+
+        // the page size can't change once the volume contains data
+        let err = storage
+            .set_volume_config(&vid, VolumeConfig::new(SyncDirection::Both))
+            .unwrap_err();
+        assert!(matches!(err.ctx(), StorageErr::PageSizeMismatch));
+        storage
+            .set_volume_config(&vid, config.clone().with_sync(SyncDirection::Pull))
+            .unwrap();
+
+        // remote commits must have the same page size
+        let vid = VolumeId::random();
+        storage.set_volume_config(&vid, config).unwrap();
+        let cid = ClientId::random();
+        let graft = Splinter::from_iter([1u32]).serialize_to_bytes();
+        let remote = graft_proto::Snapshot::new(
+            &vid,
+            &cid,
+            LSN::FIRST,
+            LSN::FIRST,
+            1.into(),
+            SystemTime::now(),
+        );
+        let err = storage
+            .receive_remote_commit(
+                &vid,
+                remote.clone(),
+                SplinterRef::from_bytes(graft.clone()).unwrap(),
+            )
+            .unwrap_err();
+        assert!(matches!(err.ctx(), StorageErr::PageSizeMismatch));
+        storage
+            .receive_remote_commit(
+                &vid,
+                remote.with_page_size(page_size),
+                SplinterRef::from_bytes(graft).unwrap(),
+            )
+            .unwrap();
+    }
 }
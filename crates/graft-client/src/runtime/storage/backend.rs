@@ -0,0 +1,129 @@
+//! A `StorageBackend` persists the partitions backing `Storage`.
+//! `Storage` owns all of the logic for interpreting keys and values, while a
+//! backend only needs to provide ordered key-value partitions, consistent
+//! snapshots, and atomic batches.
+
+use bytes::Bytes;
+use culprit::Culprit;
+
+use super::StorageErr;
+
+pub mod disk;
+pub mod mem;
+
+pub use disk::FjallBackend;
+pub use mem::MemBackend;
+
+pub type Result<T> = std::result::Result<T, Culprit<StorageErr>>;
+
+/// A key-value pair returned by backend iterators
+pub type KvPair = (Bytes, Bytes);
+
+/// An ordered iterator of key-value pairs. Backend iterators own whatever
+/// state they need, so they can outlive the snapshot that created them.
+pub type KvIter = Box<dyn DoubleEndedIterator<Item = Result<KvPair>>>;
+
+/// The partitions used by `Storage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Partition {
+    /// Volume state broken out by tag, keyed by `VolumeStateKey`
+    Volumes,
+
+    /// Page contents, keyed by `PageKey`
+    Pages,
+
+    /// Local commits, keyed by `CommitKey`
+    Commits,
+}
+
+impl Partition {
+    pub const ALL: [Partition; 3] = [Partition::Volumes, Partition::Pages, Partition::Commits];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Partition::Volumes => "volumes",
+            Partition::Pages => "pages",
+            Partition::Commits => "commits",
+        }
+    }
+}
+
+pub trait StorageBackend: Send + Sync {
+    /// Retrieve a value from a partition
+    fn get(&self, partition: Partition, key: &[u8]) -> Result<Option<Bytes>>;
+
+    /// Returns true if the partition contains the key
+    fn contains_key(&self, partition: Partition, key: &[u8]) -> Result<bool> {
+        Ok(self.get(partition, key)?.is_some())
+    }
+
+    /// Insert a single value into a partition.
+    /// Unlike `write_batch`, this write is not required to be durable once
+    /// this function returns.
+    fn insert(&self, partition: Partition, key: &[u8], value: Bytes) -> Result<()>;
+
+    /// Take a consistent read-only snapshot of a partition
+    fn snapshot(&self, partition: Partition) -> Box<dyn BackendSnapshot>;
+
+    /// Atomically and durably apply a batch of writes
+    fn write_batch(&self, batch: Batch) -> Result<()>;
+
+    /// The number of bytes this backend is using on disk
+    fn disk_space(&self) -> u64;
+}
+
+pub trait BackendSnapshot {
+    /// Retrieve a value from the snapshot
+    fn get(&self, key: &[u8]) -> Result<Option<Bytes>>;
+
+    /// Iterate over all keys in the snapshot
+    fn iter(&self) -> KvIter;
+
+    /// Iterate over all keys between start and end (inclusive)
+    fn range(&self, start: &[u8], end: &[u8]) -> KvIter;
+
+    /// Iterate over all keys which start with prefix
+    fn prefix(&self, prefix: &[u8]) -> KvIter;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOp {
+    Insert(Bytes),
+    Remove,
+}
+
+/// A set of writes which are applied atomically by `StorageBackend::write_batch`
+#[derive(Debug, Default)]
+pub struct Batch {
+    ops: Vec<(Partition, Bytes, BatchOp)>,
+}
+
+impl Batch {
+    pub fn insert(&mut self, partition: Partition, key: impl AsRef<[u8]>, value: impl Into<Bytes>) {
+        let key = Bytes::copy_from_slice(key.as_ref());
+        self.ops
+            .push((partition, key, BatchOp::Insert(value.into())));
+    }
+
+    pub fn remove(&mut self, partition: Partition, key: impl AsRef<[u8]>) {
+        let key = Bytes::copy_from_slice(key.as_ref());
+        self.ops.push((partition, key, BatchOp::Remove));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+}
+
+impl IntoIterator for Batch {
+    type Item = (Partition, Bytes, BatchOp);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ops.into_iter()
+    }
+}
@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use bytes::Bytes;
+use culprit::ResultExt;
+use fjall::{KvSeparationOptions, PartitionCreateOptions};
+
+use super::{BackendSnapshot, Batch, BatchOp, KvIter, Partition, Result, StorageBackend};
+
+/// The default `StorageBackend`, which persists each partition into a fjall
+/// keyspace on disk.
+pub struct FjallBackend {
+    keyspace: fjall::Keyspace,
+    volumes: fjall::Partition,
+    pages: fjall::Partition,
+    commits: fjall::Partition,
+}
+
+impl FjallBackend {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_config(fjall::Config::new(path))
+    }
+
+    pub fn open_temporary() -> Result<Self> {
+        let path = tempfile::tempdir()?.keep();
+        Self::open_config(fjall::Config::new(path).temporary(true))
+    }
+
+    pub fn open_config(config: fjall::Config) -> Result<Self> {
+        let keyspace = config.open()?;
+        let volumes = keyspace.open_partition(Partition::Volumes.name(), Default::default())?;
+        let pages = keyspace.open_partition(
+            Partition::Pages.name(),
+            PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
+        )?;
+        let commits = keyspace.open_partition(
+            Partition::Commits.name(),
+            PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
+        )?;
+        Ok(Self { keyspace, volumes, pages, commits })
+    }
+
+    fn partition(&self, partition: Partition) -> &fjall::Partition {
+        match partition {
+            Partition::Volumes => &self.volumes,
+            Partition::Pages => &self.pages,
+            Partition::Commits => &self.commits,
+        }
+    }
+}
+
+impl StorageBackend for FjallBackend {
+    fn get(&self, partition: Partition, key: &[u8]) -> Result<Option<Bytes>> {
+        Ok(self.partition(partition).get(key)?.map(Bytes::from))
+    }
+
+    fn contains_key(&self, partition: Partition, key: &[u8]) -> Result<bool> {
+        Ok(self.partition(partition).contains_key(key)?)
+    }
+
+    fn insert(&self, partition: Partition, key: &[u8], value: Bytes) -> Result<()> {
+        Ok(self.partition(partition).insert(key, value)?)
+    }
+
+    fn snapshot(&self, partition: Partition) -> Box<dyn BackendSnapshot> {
+        Box::new(FjallSnapshot(self.partition(partition).snapshot()))
+    }
+
+    fn write_batch(&self, batch: Batch) -> Result<()> {
+        let mut fbatch = self.keyspace.batch();
+        fbatch = fbatch.durability(Some(fjall::PersistMode::SyncAll));
+        for (partition, key, op) in batch {
+            let partition = self.partition(partition);
+            match op {
+                BatchOp::Insert(value) => fbatch.insert(partition, key, value),
+                BatchOp::Remove => fbatch.remove(partition, key),
+            }
+        }
+        Ok(fbatch.commit()?)
+    }
+
+    fn disk_space(&self) -> u64 {
+        self.keyspace.disk_space()
+    }
+}
+
+struct FjallSnapshot(fjall::Snapshot);
+
+fn convert_iter(
+    iter: impl DoubleEndedIterator<Item = lsm_tree::Result<fjall::KvPair>> + 'static,
+) -> KvIter {
+    Box::new(iter.map(|kv| {
+        let (k, v) = kv.or_into_ctx()?;
+        Ok((k.into(), v.into()))
+    }))
+}
+
+impl BackendSnapshot for FjallSnapshot {
+    fn get(&self, key: &[u8]) -> Result<Option<Bytes>> {
+        Ok(self.0.get(key)?.map(Bytes::from))
+    }
+
+    fn iter(&self) -> KvIter {
+        convert_iter(self.0.iter())
+    }
+
+    fn range(&self, start: &[u8], end: &[u8]) -> KvIter {
+        convert_iter(self.0.range(start..=end))
+    }
+
+    fn prefix(&self, prefix: &[u8]) -> KvIter {
+        convert_iter(self.0.prefix(prefix))
+    }
+}
@@ -0,0 +1,227 @@
+use std::{collections::BTreeMap, ops::Bound, sync::Arc};
+
+use bytes::Bytes;
+use parking_lot::RwLock;
+
+use super::{BackendSnapshot, Batch, BatchOp, KvIter, KvPair, Partition, Result, StorageBackend};
+
+type Tree = Arc<BTreeMap<Bytes, Bytes>>;
+type KeyBounds<'a> = (Bound<&'a [u8]>, Bound<&'a [u8]>);
+
+/// A `StorageBackend` which keeps every partition in memory.
+/// Useful for tests and ephemeral clients which don't need to persist state
+/// between restarts.
+///
+/// Snapshots are cheap: each partition is a copy-on-write tree, so taking a
+/// snapshot only clones an `Arc`. Writes only copy the tree if a snapshot is
+/// still alive.
+#[derive(Default)]
+pub struct MemBackend {
+    volumes: RwLock<Tree>,
+    pages: RwLock<Tree>,
+    commits: RwLock<Tree>,
+}
+
+impl MemBackend {
+    fn partition(&self, partition: Partition) -> &RwLock<Tree> {
+        match partition {
+            Partition::Volumes => &self.volumes,
+            Partition::Pages => &self.pages,
+            Partition::Commits => &self.commits,
+        }
+    }
+}
+
+impl StorageBackend for MemBackend {
+    fn get(&self, partition: Partition, key: &[u8]) -> Result<Option<Bytes>> {
+        Ok(self.partition(partition).read().get(key).cloned())
+    }
+
+    fn insert(&self, partition: Partition, key: &[u8], value: Bytes) -> Result<()> {
+        let mut tree = self.partition(partition).write();
+        Arc::make_mut(&mut tree).insert(Bytes::copy_from_slice(key), value);
+        Ok(())
+    }
+
+    fn snapshot(&self, partition: Partition) -> Box<dyn BackendSnapshot> {
+        Box::new(MemSnapshot(self.partition(partition).read().clone()))
+    }
+
+    fn write_batch(&self, batch: Batch) -> Result<()> {
+        // acquire every partition lock in a fixed order to make the batch atomic
+        let mut volumes = self.volumes.write();
+        let mut pages = self.pages.write();
+        let mut commits = self.commits.write();
+
+        for (partition, key, op) in batch {
+            let tree = match partition {
+                Partition::Volumes => &mut *volumes,
+                Partition::Pages => &mut *pages,
+                Partition::Commits => &mut *commits,
+            };
+            let tree = Arc::make_mut(tree);
+            match op {
+                BatchOp::Insert(value) => tree.insert(key, value),
+                BatchOp::Remove => tree.remove(&key),
+            };
+        }
+        Ok(())
+    }
+
+    fn disk_space(&self) -> u64 {
+        0
+    }
+}
+
+struct MemSnapshot(Tree);
+
+impl BackendSnapshot for MemSnapshot {
+    fn get(&self, key: &[u8]) -> Result<Option<Bytes>> {
+        Ok(self.0.get(key).cloned())
+    }
+
+    fn iter(&self) -> KvIter {
+        MemIter::boxed(self.0.clone(), Bound::Unbounded, Bound::Unbounded)
+    }
+
+    fn range(&self, start: &[u8], end: &[u8]) -> KvIter {
+        MemIter::boxed(
+            self.0.clone(),
+            Bound::Included(Bytes::copy_from_slice(start)),
+            Bound::Included(Bytes::copy_from_slice(end)),
+        )
+    }
+
+    fn prefix(&self, prefix: &[u8]) -> KvIter {
+        let back = match prefix_successor(prefix) {
+            Some(succ) => Bound::Excluded(succ),
+            None => Bound::Unbounded,
+        };
+        MemIter::boxed(
+            self.0.clone(),
+            Bound::Included(Bytes::copy_from_slice(prefix)),
+            back,
+        )
+    }
+}
+
+/// Returns the smallest key which is larger than every key starting with
+/// prefix, or None if no such key exists.
+fn prefix_successor(prefix: &[u8]) -> Option<Bytes> {
+    let mut succ = prefix.to_vec();
+    while let Some(last) = succ.pop() {
+        if last < u8::MAX {
+            succ.push(last + 1);
+            return Some(succ.into());
+        }
+    }
+    None
+}
+
+/// A cursor over a snapshot of a tree. Each step seeks into the tree using the
+/// remaining bounds, which allows the iterator to own the tree.
+struct MemIter {
+    tree: Tree,
+    front: Bound<Bytes>,
+    back: Bound<Bytes>,
+}
+
+impl MemIter {
+    fn boxed(tree: Tree, front: Bound<Bytes>, back: Bound<Bytes>) -> KvIter {
+        Box::new(Self { tree, front, back })
+    }
+
+    fn bounds(&self) -> Option<KeyBounds<'_>> {
+        let front = self.front.as_ref().map(|b| b.as_ref());
+        let back = self.back.as_ref().map(|b| b.as_ref());
+
+        // BTreeMap::range panics if the bounds cross
+        let crossed = match (front, back) {
+            (Bound::Included(f), Bound::Included(b)) => f > b,
+            (Bound::Included(f), Bound::Excluded(b))
+            | (Bound::Excluded(f), Bound::Included(b))
+            | (Bound::Excluded(f), Bound::Excluded(b)) => f >= b,
+            _ => false,
+        };
+        (!crossed).then_some((front, back))
+    }
+}
+
+impl Iterator for MemIter {
+    type Item = Result<KvPair>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (k, v) = self.tree.range::<[u8], _>(self.bounds()?).next()?;
+        let (k, v) = (k.clone(), v.clone());
+        self.front = Bound::Excluded(k.clone());
+        Some(Ok((k, v)))
+    }
+}
+
+impl DoubleEndedIterator for MemIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (k, v) = self.tree.range::<[u8], _>(self.bounds()?).next_back()?;
+        let (k, v) = (k.clone(), v.clone());
+        self.back = Bound::Excluded(k.clone());
+        Some(Ok((k, v)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_keys(iter: impl Iterator<Item = Result<KvPair>>) -> Vec<Bytes> {
+        iter.map(|kv| kv.unwrap().0).collect()
+    }
+
+    #[graft_test::test]
+    fn test_mem_backend_iter() {
+        let backend = MemBackend::default();
+        let mut batch = Batch::default();
+        for key in [&b"a"[..], b"b\x00", b"b\x01", b"b\xff", b"c"] {
+            batch.insert(Partition::Pages, key, Bytes::from_static(b"value"));
+        }
+        backend.write_batch(batch).unwrap();
+
+        let snapshot = backend.snapshot(Partition::Pages);
+
+        // writes after the snapshot is taken are not visible to the snapshot
+        backend
+            .insert(Partition::Pages, b"b\x02", Bytes::from_static(b"value"))
+            .unwrap();
+        assert!(snapshot.get(b"b\x02").unwrap().is_none());
+        assert!(backend.get(Partition::Pages, b"b\x02").unwrap().is_some());
+
+        assert_eq!(collect_keys(snapshot.iter()).len(), 5);
+        assert_eq!(
+            collect_keys(snapshot.prefix(b"b")),
+            vec![&b"b\x00"[..], b"b\x01", b"b\xff"]
+        );
+        assert_eq!(
+            collect_keys(snapshot.range(b"b\x01", b"c").rev()),
+            vec![&b"c"[..], b"b\xff", b"b\x01"]
+        );
+
+        // iterating from both ends should never yield the same key twice
+        let mut iter = snapshot.range(b"a", b"b\x01");
+        assert_eq!(iter.next().unwrap().unwrap().0, &b"a"[..]);
+        assert_eq!(iter.next_back().unwrap().unwrap().0, &b"b\x01"[..]);
+        assert_eq!(iter.next().unwrap().unwrap().0, &b"b\x00"[..]);
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+
+        // removes are visible to new snapshots
+        let mut batch = Batch::default();
+        batch.remove(Partition::Pages, b"a");
+        backend.write_batch(batch).unwrap();
+        assert!(snapshot.get(b"a").unwrap().is_some());
+        assert!(
+            backend
+                .snapshot(Partition::Pages)
+                .get(b"a")
+                .unwrap()
+                .is_none()
+        );
+    }
+}
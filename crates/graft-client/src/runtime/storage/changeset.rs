@@ -12,9 +12,38 @@ use parking_lot::{Mutex, RwLock};
 
 type InnerSet<K> = Arc<RwLock<HashMap<K, AtomicU64>>>;
 
+enum Subscriber {
+    Channel(Sender<()>),
+
+    #[cfg(feature = "tokio")]
+    Notify(std::sync::Weak<tokio::sync::Notify>),
+}
+
+impl Subscriber {
+    /// notifies the subscriber, returning false if the subscriber has gone away
+    fn notify(&self) -> bool {
+        match self {
+            Subscriber::Channel(tx) => match tx.try_send(()) {
+                Ok(()) => true,
+                Err(TrySendError::Full(())) => true,
+                Err(TrySendError::Disconnected(())) => false,
+            },
+
+            #[cfg(feature = "tokio")]
+            Subscriber::Notify(notify) => match notify.upgrade() {
+                Some(notify) => {
+                    notify.notify_one();
+                    true
+                }
+                None => false,
+            },
+        }
+    }
+}
+
 pub struct ChangeSet<K> {
     next_version: AtomicU64,
-    subscribers: Mutex<Vec<(Option<K>, Sender<()>)>>,
+    subscribers: Mutex<Vec<(Option<K>, Subscriber)>>,
     set: InnerSet<K>,
 }
 
@@ -41,11 +70,7 @@ impl<K: Eq + Hash + Clone> ChangeSet<K> {
         let mut subscribers = self.subscribers.lock();
         subscribers.retain(|(k, s)| {
             if k.as_ref().is_none_or(|k| k == key) {
-                match s.try_send(()) {
-                    Ok(()) => true,
-                    Err(TrySendError::Full(())) => true,
-                    Err(TrySendError::Disconnected(())) => false,
-                }
+                s.notify()
             } else {
                 true
             }
@@ -91,19 +116,67 @@ impl<K: Eq + Hash + Clone> ChangeSet<K> {
 
     pub fn subscribe(&self, key: K) -> Receiver<()> {
         let (tx, rx) = bounded(1);
-        self.subscribers.lock().push((Some(key), tx));
+        self.subscribers
+            .lock()
+            .push((Some(key), Subscriber::Channel(tx)));
         rx
     }
 
     pub fn subscribe_all(&self) -> SetSubscriber<K> {
         let (tx, rx) = bounded(1);
-        self.subscribers.lock().push((None, tx));
+        self.subscribers
+            .lock()
+            .push((None, Subscriber::Channel(tx)));
         SetSubscriber {
             rx,
             version: self.version(),
             set: self.set.clone(),
         }
     }
+
+    /// Subscribe to changes to a key from async code. The subscription is
+    /// dropped once every clone of the returned `Notify` is dropped.
+    #[cfg(feature = "tokio")]
+    pub fn subscribe_notify(&self, key: K) -> Arc<tokio::sync::Notify> {
+        let notify = Arc::new(tokio::sync::Notify::new());
+        self.subscribers
+            .lock()
+            .push((Some(key), Subscriber::Notify(Arc::downgrade(&notify))));
+        notify
+    }
+
+    #[cfg(feature = "tokio")]
+    pub fn subscribe_all_notify(&self) -> NotifySetSubscriber<K> {
+        let notify = Arc::new(tokio::sync::Notify::new());
+        self.subscribers
+            .lock()
+            .push((None, Subscriber::Notify(Arc::downgrade(&notify))));
+        NotifySetSubscriber {
+            notify,
+            version: self.version(),
+            set: self.set.clone(),
+        }
+    }
+}
+
+/// returns the set of keys changed since version, advancing version if the
+/// set is non-empty
+fn changed_since<K: Clone + Eq + Hash>(set: &InnerSet<K>, version: &mut u64) -> HashSet<K> {
+    let set = set.read();
+    let mut max_version = *version;
+    let changed: HashSet<K> = set
+        .iter()
+        .filter_map(|(k, v)| {
+            let v = v.load(Ordering::SeqCst);
+            max_version = max_version.max(v);
+            (v >= *version).then_some(k.clone())
+        })
+        .collect();
+
+    if !changed.is_empty() {
+        *version = max_version;
+    }
+    changed
 }
 
 pub struct SetSubscriber<K> {
@@ -121,20 +194,28 @@ impl<K: Clone + Eq + Hash> SetSubscriber<K> {
     /// returns a set of changed keys since the last time this function returned
     /// a non-empty set
     pub fn changed(&mut self) -> HashSet<K> {
-        let set = self.set.read();
-        let mut max_version = self.version;
-        let set: HashSet<K> = set
-            .iter()
-            .filter_map(|(k, v)| {
-                let version = v.load(Ordering::SeqCst);
-                max_version = max_version.max(version);
-                (version >= self.version).then_some(k.clone())
-            })
-            .collect();
-
-        if !set.is_empty() {
-            self.version = max_version;
-        }
-        set
+        changed_since(&self.set, &mut self.version)
+    }
+}
+
+/// The async equivalent of `SetSubscriber`
+#[cfg(feature = "tokio")]
+pub struct NotifySetSubscriber<K> {
+    version: u64,
+    notify: Arc<tokio::sync::Notify>,
+    set: InnerSet<K>,
+}
+
+#[cfg(feature = "tokio")]
+impl<K: Clone + Eq + Hash> NotifySetSubscriber<K> {
+    /// resolves once the set changes
+    pub async fn ready(&self) {
+        self.notify.notified().await
+    }
+
+    /// returns a set of changed keys since the last time this function returned
+    /// a non-empty set
+    pub fn changed(&mut self) -> HashSet<K> {
+        changed_since(&self.set, &mut self.version)
     }
 }
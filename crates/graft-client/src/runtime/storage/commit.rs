@@ -1,7 +1,12 @@
+use bytes::{BufMut, Bytes, BytesMut};
 use culprit::{Culprit, ResultExt};
-use fjall::Slice;
-use graft_core::{VolumeId, lsn::LSN, zerocopy_ext::TryFromBytesExt};
-use zerocopy::{BigEndian, Immutable, IntoBytes, KnownLayout, TryFromBytes, U64, Unaligned};
+use graft_core::{VolumeId, lsn::LSN, page_count::PageCount, zerocopy_ext::TryFromBytesExt};
+use graft_proto::common::v1::CommitMetadata;
+use prost::Message;
+use splinter_rs::{Splinter, SplinterRef};
+use zerocopy::{
+    BigEndian, FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes, U32, U64, Unaligned,
+};
 
 use super::StorageErr;
 
@@ -45,8 +50,151 @@ impl AsRef<[u8]> for CommitKey {
     }
 }
 
-impl From<CommitKey> for Slice {
-    fn from(key: CommitKey) -> Slice {
-        key.as_bytes().into()
+impl From<CommitKey> for Bytes {
+    fn from(key: CommitKey) -> Bytes {
+        Bytes::copy_from_slice(key.as_bytes())
+    }
+}
+
+/// The version of the commit value layout written by [`encode_commit`]. Each
+/// value starts with this byte followed by a [`CommitHeader`], the encoded
+/// `CommitMetadata`, and the Graft of `PageIdxs` the commit changed.
+///
+/// Values written by earlier releases are the Graft alone. Serialized Grafts
+/// start with Splinter's magic bytes, so they never start with this byte.
+const COMMIT_VERSION: u8 = 1;
+
+/// Set in [`CommitHeader::flags`] once a commit has been rolled back. Rolled
+/// back commits are kept until they are pushed so that LSNs are never reused,
+/// but they no longer change any pages.
+const COMMIT_ROLLED_BACK: u8 = 1;
+
+/// `CommitHeader` records the page count of the snapshot the commit was based
+/// on, which allows local commits to be rolled back without consulting the
+/// remote, along with the length of the encoded `CommitMetadata` which follows
+/// the header.
+#[derive(Debug, KnownLayout, Immutable, FromBytes, IntoBytes, Unaligned)]
+#[repr(C)]
+struct CommitHeader {
+    base_pages: U32<BigEndian>,
+    metadata_len: U32<BigEndian>,
+    flags: u8,
+}
+
+/// Encode a commit value from the page count of the snapshot the commit was
+/// based on, the commit's optional metadata, and the Graft of `PageIdxs` the
+/// commit changed
+pub(crate) fn encode_commit(
+    base_pages: PageCount,
+    metadata: Option<&CommitMetadata>,
+    graft: &Splinter,
+    rolled_back: bool,
+) -> Bytes {
+    let metadata = metadata.map(|m| m.encode_to_vec()).unwrap_or_default();
+    let header = CommitHeader {
+        base_pages: base_pages.to_u32().into(),
+        metadata_len: (metadata.len() as u32).into(),
+        flags: if rolled_back { COMMIT_ROLLED_BACK } else { 0 },
+    };
+    let graft = graft.serialize_to_bytes();
+    let mut buf =
+        BytesMut::with_capacity(1 + size_of::<CommitHeader>() + metadata.len() + graft.len());
+    buf.put_u8(COMMIT_VERSION);
+    buf.put_slice(header.as_bytes());
+    buf.put_slice(&metadata);
+    buf.put_slice(&graft);
+    buf.freeze()
+}
+
+/// A decoded commit value. `base_pages` is None for commits written before
+/// values recorded the page count the commit was based on.
+#[derive(Debug)]
+pub(crate) struct DecodedCommit {
+    pub base_pages: Option<PageCount>,
+    pub metadata: Option<CommitMetadata>,
+    pub graft: SplinterRef<Bytes>,
+    pub rolled_back: bool,
+}
+
+impl DecodedCommit {
+    /// Re-encode this commit, marking it as rolled back
+    pub(crate) fn encode_rolled_back(&self) -> Bytes {
+        let base_pages = self.base_pages.unwrap_or(PageCount::ZERO);
+        let graft = Splinter::from_iter(self.graft.iter());
+        encode_commit(base_pages, self.metadata.as_ref(), &graft, true)
+    }
+}
+
+/// Decode a commit value written by [`encode_commit`] or an earlier release
+pub(crate) fn decode_commit(mut value: Bytes) -> Result<DecodedCommit, Culprit<StorageErr>> {
+    if value.first() != Some(&COMMIT_VERSION) {
+        // commits written by earlier releases are the Graft alone
+        let graft = SplinterRef::from_bytes(value).or_into_ctx()?;
+        return Ok(DecodedCommit {
+            base_pages: None,
+            metadata: None,
+            graft,
+            rolled_back: false,
+        });
+    }
+
+    let (header, _) = CommitHeader::read_from_prefix(&value[1..])
+        .or_ctx(|e| StorageErr::CorruptCommitHeader(e.into()))?;
+    let mut graft = value.split_off(1 + size_of::<CommitHeader>());
+    let metadata_len = header.metadata_len.get() as usize;
+    if graft.len() < metadata_len {
+        return Err(Culprit::new(StorageErr::CorruptCommitMetadata));
+    }
+    let metadata = graft.split_to(metadata_len);
+    let metadata = if metadata.is_empty() {
+        None
+    } else {
+        Some(CommitMetadata::decode(metadata).or_ctx(|_| StorageErr::CorruptCommitMetadata)?)
+    };
+    let graft = SplinterRef::from_bytes(graft).or_into_ctx()?;
+    Ok(DecodedCommit {
+        base_pages: Some(PageCount::new(header.base_pages.get())),
+        metadata,
+        graft,
+        rolled_back: header.flags & COMMIT_ROLLED_BACK != 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_decode_commits() {
+        let graft = Splinter::from_iter([1u32, 2, 5]);
+        let metadata = CommitMetadata {
+            message: "hello".into(),
+            ..CommitMetadata::default()
+        };
+        let pages = |commit: &DecodedCommit| commit.graft.iter().collect::<Vec<_>>();
+
+        // the current layout round trips
+        for base_pages in [0, 3, 0x0100_0000] {
+            let value = encode_commit(PageCount::new(base_pages), Some(&metadata), &graft, false);
+            let commit = decode_commit(value).unwrap();
+            assert_eq!(commit.base_pages, Some(PageCount::new(base_pages)));
+            assert_eq!(commit.metadata.as_ref(), Some(&metadata));
+            assert_eq!(pages(&commit), [1, 2, 5]);
+            assert!(!commit.rolled_back);
+
+            // rolling back a commit preserves everything else
+            let commit = decode_commit(commit.encode_rolled_back()).unwrap();
+            assert_eq!(commit.base_pages, Some(PageCount::new(base_pages)));
+            assert_eq!(commit.metadata.as_ref(), Some(&metadata));
+            assert_eq!(pages(&commit), [1, 2, 5]);
+            assert!(commit.rolled_back);
+        }
+
+        // commits written by earlier releases are just the Graft
+        let commit = decode_commit(graft.serialize_to_bytes()).unwrap();
+        assert_eq!(commit.base_pages, None);
+        assert_eq!(commit.metadata, None);
+        assert_eq!(pages(&commit), [1, 2, 5]);
+        assert!(!commit.rolled_back);
     }
 }
@@ -1,10 +1,9 @@
 use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
-use fjall::Slice;
 use graft_core::{
     PageIdx, VolumeId,
     lsn::LSN,
-    page::{EMPTY_PAGE, Page, PageSizeErr},
+    page::{Page, PageSize, PageSizeErr},
     zerocopy_ext::TryFromBytesExt,
 };
 use std::fmt::{Debug, Display};
@@ -41,6 +40,10 @@ impl PageKey {
         Self { index: index.into(), ..self }
     }
 
+    pub fn index(&self) -> PageIdx {
+        self.index.get().try_into().expect("invalid PageIdx")
+    }
+
     pub fn lsn(&self) -> LSN {
         self.lsn.try_into().expect("invalid LSN")
     }
@@ -101,11 +104,12 @@ pub enum PageValue {
 }
 
 impl PageValue {
-    /// resolves the `PageValue` to a Page if it's not pending
-    pub fn try_into_page(self) -> Option<Page> {
+    /// resolves the `PageValue` to a Page if it's not pending. Empty pages
+    /// resolve to a page of the provided size.
+    pub fn try_into_page(self, page_size: PageSize) -> Option<Page> {
         match self {
             PageValue::Pending => None,
-            PageValue::Empty => Some(EMPTY_PAGE),
+            PageValue::Empty => Some(Page::empty(page_size)),
             PageValue::Available(page) => Some(page),
         }
     }
@@ -115,15 +119,6 @@ impl PageValue {
     }
 }
 
-impl TryFrom<Slice> for PageValue {
-    type Error = Culprit<PageValueConversionErr>;
-
-    #[inline]
-    fn try_from(value: Slice) -> Result<Self, Self::Error> {
-        Bytes::from(value).try_into()
-    }
-}
-
 impl TryFrom<Bytes> for PageValue {
     type Error = Culprit<PageValueConversionErr>;
 
@@ -153,12 +148,6 @@ impl From<PageValue> for Bytes {
     }
 }
 
-impl From<PageValue> for Slice {
-    fn from(value: PageValue) -> Self {
-        Bytes::from(value).into()
-    }
-}
-
 impl From<Page> for PageValue {
     fn from(page: Page) -> Self {
         if page.is_empty() {
@@ -0,0 +1,155 @@
+use std::{
+    collections::HashMap,
+    ops::Deref,
+    sync::{Arc, Weak},
+};
+
+use crossbeam::channel::{Receiver, Sender, unbounded};
+use graft_core::VolumeId;
+use parking_lot::Mutex;
+use splinter_rs::{Splinter, SplinterRef, ops::Intersection};
+
+enum PageSubscriber {
+    /// crossbeam senders can't tell whether the receiver is still around, so
+    /// the `PageSubscription` holding the receiver also holds the other end of
+    /// the `Weak`
+    Channel(Sender<Splinter>, Weak<()>),
+
+    #[cfg(feature = "tokio")]
+    Tokio(tokio::sync::mpsc::UnboundedSender<Splinter>),
+}
+
+impl PageSubscriber {
+    /// sends the changed pages to the subscriber, returning false if the
+    /// subscriber has gone away
+    fn send(&self, changed: Splinter) -> bool {
+        match self {
+            PageSubscriber::Channel(tx, _) => tx.send(changed).is_ok(),
+
+            #[cfg(feature = "tokio")]
+            PageSubscriber::Tokio(tx) => tx.send(changed).is_ok(),
+        }
+    }
+
+    /// returns true if the subscriber has gone away
+    fn is_closed(&self) -> bool {
+        match self {
+            PageSubscriber::Channel(_, alive) => alive.strong_count() == 0,
+
+            #[cfg(feature = "tokio")]
+            PageSubscriber::Tokio(tx) => tx.is_closed(),
+        }
+    }
+}
+
+/// Receives remote changes to a subset of a Volume's pages. The subscription
+/// is removed once this is dropped.
+pub struct PageSubscription {
+    rx: Receiver<Splinter>,
+    _alive: Arc<()>,
+}
+
+impl Deref for PageSubscription {
+    type Target = Receiver<Splinter>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rx
+    }
+}
+
+/// `PageSubscriptions` tracks subscribers interested in remote changes to a
+/// subset of a Volume's pages.
+#[derive(Default)]
+pub struct PageSubscriptions {
+    subscribers: Mutex<HashMap<VolumeId, Vec<(Splinter, PageSubscriber)>>>,
+}
+
+impl PageSubscriptions {
+    /// Subscribe to remote changes to the provided pages of a Volume. Each
+    /// message contains the subset of `pages` changed by a remote commit.
+    pub fn subscribe(&self, vid: VolumeId, pages: Splinter) -> PageSubscription {
+        let (tx, rx) = unbounded();
+        let alive = Arc::new(());
+        self.insert(
+            vid,
+            pages,
+            PageSubscriber::Channel(tx, Arc::downgrade(&alive)),
+        );
+        PageSubscription { rx, _alive: alive }
+    }
+
+    /// Like `subscribe` but returns a tokio channel
+    #[cfg(feature = "tokio")]
+    pub fn subscribe_tokio(
+        &self,
+        vid: VolumeId,
+        pages: Splinter,
+    ) -> tokio::sync::mpsc::UnboundedReceiver<Splinter> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.insert(vid, pages, PageSubscriber::Tokio(tx));
+        rx
+    }
+
+    fn insert(&self, vid: VolumeId, pages: Splinter, subscriber: PageSubscriber) {
+        self.subscribers
+            .lock()
+            .entry(vid)
+            .or_default()
+            .push((pages, subscriber));
+    }
+
+    /// Notify every subscriber whose pages intersect the graft of pages
+    /// changed by a remote commit
+    pub fn notify<T: AsRef<[u8]>>(&self, vid: &VolumeId, graft: &SplinterRef<T>) {
+        let mut subscribers = self.subscribers.lock();
+
+        // remove every subscriber which has gone away, including those which
+        // wouldn't be notified by this commit
+        subscribers.retain(|_, subs| {
+            subs.retain(|(_, subscriber)| !subscriber.is_closed());
+            !subs.is_empty()
+        });
+
+        let Some(subs) = subscribers.get_mut(vid) else {
+            return;
+        };
+        subs.retain(|(pages, subscriber)| {
+            let changed = pages.intersection(graft);
+            // intersections may contain empty blocks, so check the cardinality
+            changed.cardinality() == 0 || subscriber.send(changed)
+        });
+        if subs.is_empty() {
+            subscribers.remove(vid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_page_subscriptions() {
+        let subs = PageSubscriptions::default();
+        let vid = VolumeId::random();
+        let other = VolumeId::random();
+
+        let rx = subs.subscribe(vid.clone(), Splinter::from_slice(&[1, 2, 3]));
+        let graft = |pages: &[u32]| Splinter::from_slice(pages).serialize_to_splinter_ref();
+
+        // unrelated volumes and pages don't trigger the subscription
+        subs.notify(&other, &graft(&[1]));
+        subs.notify(&vid, &graft(&[4, 5]));
+        assert!(rx.try_recv().is_err());
+
+        // intersecting grafts deliver the changed pages
+        subs.notify(&vid, &graft(&[2, 3, 4]));
+        assert_eq!(rx.try_recv().unwrap(), Splinter::from_slice(&[2, 3]));
+
+        // dropped subscribers are removed on the next notification, even if
+        // it's for another volume
+        drop(rx);
+        subs.notify(&other, &graft(&[1]));
+        assert!(subs.subscribers.lock().is_empty());
+    }
+}
@@ -5,8 +5,8 @@
 
 use std::fmt::{Debug, Display};
 
+use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
-use fjall::Slice;
 use graft_core::{lsn::LSN, page_count::PageCount};
 use serde::Serialize;
 use zerocopy::{ByteHash, Immutable, IntoBytes, KnownLayout, TryFromBytes};
@@ -150,8 +150,8 @@ impl Display for Snapshot {
     }
 }
 
-impl From<Snapshot> for Slice {
-    fn from(snapshot: Snapshot) -> Slice {
-        snapshot.as_bytes().into()
+impl From<Snapshot> for Bytes {
+    fn from(snapshot: Snapshot) -> Bytes {
+        Bytes::copy_from_slice(snapshot.as_bytes())
     }
 }
@@ -0,0 +1,52 @@
+use std::time::SystemTime;
+
+use graft_core::{byte_unit::ByteUnit, page_count::PageCount};
+use serde::Serialize;
+
+use super::page::PageValue;
+
+/// A summary of a volume's local storage and sync state.
+/// See `Storage::volume_stats` for how each field is computed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VolumeStats {
+    /// the number of pages in the latest snapshot
+    pub pages: PageCount,
+
+    /// the number of pages stored locally
+    pub available_pages: u32,
+
+    /// the number of pages which must be fetched from the remote
+    pub pending_pages: u32,
+
+    /// the number of pages which are known to be empty
+    pub empty_pages: u32,
+
+    /// the size of every page version stored locally, including versions
+    /// shadowed by later commits
+    pub local_bytes: ByteUnit,
+
+    /// the number of local commits which have not been pushed to the remote
+    pub pending_commits: u64,
+
+    /// the size of the pages changed by pending commits
+    pub pending_bytes: ByteUnit,
+
+    /// when the volume last pushed commits to the remote
+    pub last_push: Option<SystemTime>,
+
+    /// when the volume last pulled from the remote
+    pub last_pull: Option<SystemTime>,
+}
+
+impl VolumeStats {
+    /// Count a page towards the page totals. Pending pages are empty unless
+    /// the volume has a remote to fetch them from.
+    pub(super) fn tally(&mut self, page: PageValue, has_remote: bool) {
+        match page {
+            PageValue::Available(_) => self.available_pages += 1,
+            PageValue::Empty => self.empty_pages += 1,
+            PageValue::Pending if has_remote => self.pending_pages += 1,
+            PageValue::Pending => self.empty_pages += 1,
+        }
+    }
+}
@@ -3,18 +3,19 @@
 // generates for enum struct variants
 #![allow(non_camel_case_types)]
 
+use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
-use fjall::{KvPair, Slice};
-use graft_core::{PageCount, VolumeId, lsn::LSN, zerocopy_ext::TryFromBytesExt};
+use graft_core::{PageCount, VolumeId, lsn::LSN, page::PageSize, zerocopy_ext::TryFromBytesExt};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
     iter::FusedIterator,
+    time::{Duration, SystemTime},
 };
 use tryiter::TryIteratorExt;
-use zerocopy::{Immutable, IntoBytes, KnownLayout, TryFromBytes, Unaligned};
+use zerocopy::{BE, FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes, U64, Unaligned};
 
-use super::{StorageErr, snapshot::Snapshot};
+use super::{StorageErr, backend::KvPair, snapshot::Snapshot};
 
 #[derive(
     Debug, KnownLayout, Immutable, TryFromBytes, IntoBytes, Unaligned, Clone, Copy, PartialEq, Eq,
@@ -25,6 +26,7 @@ pub enum VolumeStateTag {
     Status = 2,
     Snapshot = 3,
     Watermarks = 4,
+    SyncTimes = 5,
 }
 
 #[derive(
@@ -36,9 +38,9 @@ pub struct VolumeStateKey {
     tag: VolumeStateTag,
 }
 
-impl From<VolumeStateKey> for Slice {
-    fn from(key: VolumeStateKey) -> Slice {
-        key.as_bytes().into()
+impl From<VolumeStateKey> for Bytes {
+    fn from(key: VolumeStateKey) -> Bytes {
+        Bytes::copy_from_slice(key.as_bytes())
     }
 }
 
@@ -114,16 +116,30 @@ impl SyncDirection {
 #[repr(C)]
 pub struct VolumeConfig {
     sync: SyncDirection,
+
+    /// The size of every page in the Volume. The page size can't be changed
+    /// once the Volume contains data.
+    page_size: PageSize,
 }
 
 impl VolumeConfig {
-    pub const DEFAULT: Self = Self { sync: SyncDirection::Disabled };
+    pub const DEFAULT: Self = Self {
+        sync: SyncDirection::Disabled,
+        page_size: PageSize::DEFAULT,
+    };
 
     pub fn new(sync: SyncDirection) -> Self {
-        Self { sync }
+        Self { sync, page_size: PageSize::DEFAULT }
     }
 
     pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Culprit<StorageErr>> {
+        // configs written before volumes had a configurable page size only
+        // contain the sync direction
+        if bytes.len() == size_of::<SyncDirection>() {
+            let sync = SyncDirection::try_read_from_bytes(bytes)
+                .or_ctx(|e| StorageErr::CorruptVolumeState(VolumeStateTag::Config, e.into()))?;
+            return Ok(Self::new(sync));
+        }
         Self::try_read_from_bytes(bytes)
             .or_ctx(|e| StorageErr::CorruptVolumeState(VolumeStateTag::Config, e.into()))
     }
@@ -133,7 +149,15 @@ impl VolumeConfig {
     }
 
     pub fn with_sync(self, sync: SyncDirection) -> Self {
-        Self { sync }
+        Self { sync, ..self }
+    }
+
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    pub fn with_page_size(self, page_size: PageSize) -> Self {
+        Self { page_size, ..self }
     }
 }
 
@@ -143,9 +167,9 @@ impl AsRef<[u8]> for VolumeConfig {
     }
 }
 
-impl From<VolumeConfig> for Slice {
-    fn from(config: VolumeConfig) -> Slice {
-        config.as_bytes().into()
+impl From<VolumeConfig> for Bytes {
+    fn from(config: VolumeConfig) -> Bytes {
+        Bytes::copy_from_slice(config.as_bytes())
     }
 }
 
@@ -190,9 +214,9 @@ impl AsRef<[u8]> for VolumeStatus {
     }
 }
 
-impl From<VolumeStatus> for Slice {
-    fn from(status: VolumeStatus) -> Slice {
-        status.as_bytes().into()
+impl From<VolumeStatus> for Bytes {
+    fn from(status: VolumeStatus) -> Bytes {
+        Bytes::copy_from_slice(status.as_bytes())
     }
 }
 
@@ -334,9 +358,73 @@ impl Watermarks {
     }
 }
 
-impl From<Watermarks> for Slice {
-    fn from(watermarks: Watermarks) -> Slice {
-        watermarks.as_bytes().into()
+impl From<Watermarks> for Bytes {
+    fn from(watermarks: Watermarks) -> Bytes {
+        Bytes::copy_from_slice(watermarks.as_bytes())
+    }
+}
+
+/// `SyncTimes` records when a volume last completed a push or pull. Times are
+/// stored as milliseconds since the Unix epoch, where zero means never.
+#[derive(
+    Debug, KnownLayout, Immutable, FromBytes, IntoBytes, Unaligned, Clone, Copy, PartialEq, Eq,
+)]
+#[repr(C)]
+pub struct SyncTimes {
+    last_push: U64<BE>,
+    last_pull: U64<BE>,
+}
+
+impl SyncTimes {
+    pub const DEFAULT: Self = Self {
+        last_push: U64::ZERO,
+        last_pull: U64::ZERO,
+    };
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Culprit<StorageErr>> {
+        Self::try_read_from_bytes(bytes)
+            .or_ctx(|e| StorageErr::CorruptVolumeState(VolumeStateTag::SyncTimes, e.into()))
+    }
+
+    fn to_time(millis: U64<BE>) -> Option<SystemTime> {
+        match millis.get() {
+            0 => None,
+            millis => Some(SystemTime::UNIX_EPOCH + Duration::from_millis(millis)),
+        }
+    }
+
+    fn from_time(time: SystemTime) -> U64<BE> {
+        let millis = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+        // zero is reserved for never
+        U64::new(millis.max(1))
+    }
+
+    #[inline]
+    pub fn last_push(&self) -> Option<SystemTime> {
+        Self::to_time(self.last_push)
+    }
+
+    #[inline]
+    pub fn with_last_push(self, time: SystemTime) -> Self {
+        Self { last_push: Self::from_time(time), ..self }
+    }
+
+    #[inline]
+    pub fn last_pull(&self) -> Option<SystemTime> {
+        Self::to_time(self.last_pull)
+    }
+
+    #[inline]
+    pub fn with_last_pull(self, time: SystemTime) -> Self {
+        Self { last_pull: Self::from_time(time), ..self }
+    }
+}
+
+impl From<SyncTimes> for Bytes {
+    fn from(times: SyncTimes) -> Bytes {
+        Bytes::copy_from_slice(times.as_bytes())
     }
 }
 
@@ -347,6 +435,8 @@ pub struct VolumeState {
     status: Option<VolumeStatus>,
     snapshot: Option<Snapshot>,
     watermarks: Option<Watermarks>,
+    #[serde(skip)]
+    sync_times: Option<SyncTimes>,
 }
 
 impl VolumeState {
@@ -357,6 +447,7 @@ impl VolumeState {
             status: None,
             snapshot: None,
             watermarks: None,
+            sync_times: None,
         }
     }
 
@@ -379,6 +470,14 @@ impl VolumeState {
         self.config.as_ref().unwrap_or(&VolumeConfig::DEFAULT)
     }
 
+    /// The size of every page in the Volume
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.config
+            .as_ref()
+            .map_or(PageSize::DEFAULT, |c| c.page_size())
+    }
+
     #[inline]
     pub fn status(&self) -> VolumeStatus {
         self.status.unwrap_or(VolumeStatus::Ok)
@@ -394,6 +493,11 @@ impl VolumeState {
         self.watermarks.as_ref().unwrap_or(&Watermarks::DEFAULT)
     }
 
+    #[inline]
+    pub fn sync_times(&self) -> SyncTimes {
+        self.sync_times.unwrap_or(SyncTimes::DEFAULT)
+    }
+
     pub fn is_syncing(&self) -> bool {
         if let Some(pending_sync) = self.watermarks().pending_sync().lsn() {
             let last_sync = self.snapshot().and_then(|s| s.remote_local());
@@ -420,7 +524,7 @@ impl VolumeState {
     pub(crate) fn accumulate(
         &mut self,
         tag: VolumeStateTag,
-        value: Slice,
+        value: Bytes,
     ) -> Result<(), Culprit<StorageErr>> {
         match tag {
             VolumeStateTag::Config => {
@@ -435,6 +539,9 @@ impl VolumeState {
             VolumeStateTag::Watermarks => {
                 self.watermarks = Some(Watermarks::from_bytes(&value)?);
             }
+            VolumeStateTag::SyncTimes => {
+                self.sync_times = Some(SyncTimes::from_bytes(&value)?);
+            }
         }
         Ok(())
     }
@@ -453,11 +560,11 @@ impl<I> VolumeQueryIter<I> {
 
 impl<I> VolumeQueryIter<I>
 where
-    I: Iterator<Item = lsm_tree::Result<KvPair>>,
+    I: Iterator<Item = Result<KvPair, Culprit<StorageErr>>>,
 {
     fn next_inner(&mut self) -> Result<Option<VolumeState>, Culprit<StorageErr>> {
         // pull from our inner iterator until we see the next vid, then emit
-        while let Some((key, value)) = self.inner.try_next().or_into_ctx()? {
+        while let Some((key, value)) = self.inner.try_next()? {
             let key = VolumeStateKey::ref_from_bytes(&key)?;
 
             let current = self
@@ -491,7 +598,7 @@ where
 
 impl<I> Iterator for VolumeQueryIter<I>
 where
-    I: Iterator<Item = lsm_tree::Result<KvPair>>,
+    I: Iterator<Item = Result<KvPair, Culprit<StorageErr>>>,
 {
     type Item = Result<VolumeState, Culprit<StorageErr>>;
 
@@ -501,4 +608,7 @@ where
 }
 
 // VolumeQueryIter fuses
-impl<I> FusedIterator for VolumeQueryIter<I> where I: Iterator<Item = lsm_tree::Result<KvPair>> {}
+impl<I> FusedIterator for VolumeQueryIter<I> where
+    I: Iterator<Item = Result<KvPair, Culprit<StorageErr>>>
+{
+}
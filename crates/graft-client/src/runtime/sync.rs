@@ -1,4 +1,5 @@
 use std::{
+    any::Any,
     collections::HashSet,
     fmt::Debug,
     sync::Arc,
@@ -24,9 +25,14 @@ use super::storage::{
 };
 
 const MAX_RECENT_ERRORS: usize = 16;
+pub(crate) const ERROR_BACKOFF: Duration = Duration::from_millis(100);
 
+mod coalesce;
 pub mod control;
-mod job;
+pub(crate) mod job;
+
+pub(crate) use coalesce::PendingPushes;
+pub use coalesce::PushCoalescing;
 
 #[derive(Debug, Error)]
 pub enum StartupErr {
@@ -93,6 +99,7 @@ impl SyncTaskHandle {
             commits,
             control: control_rx,
             autosync,
+            pending_pushes: Default::default(),
             recent_errors: Default::default(),
         };
 
@@ -136,16 +143,9 @@ impl SyncTaskHandle {
                 }
                 Ok(Err(err)) => {
                     tracing::error!(?err, "sync task shutdown error");
-                    let msg = match err.downcast_ref::<&'static str>() {
-                        Some(s) => *s,
-                        None => match err.downcast_ref::<String>() {
-                            Some(s) => &s[..],
-                            None => "unknown panic",
-                        },
-                    };
                     Err(Culprit::new_with_note(
                         ShutdownErr::JoinError,
-                        format!("sync task panic: {msg}"),
+                        format!("sync task panic: {}", panic_message(&err)),
                     ))
                 }
                 Err(_) => {
@@ -159,6 +159,17 @@ impl SyncTaskHandle {
     }
 }
 
+/// Extract the message from a panic payload
+pub(crate) fn panic_message(err: &Box<dyn Any + Send>) -> &str {
+    match err.downcast_ref::<&'static str>() {
+        Some(s) => s,
+        None => match err.downcast_ref::<String>() {
+            Some(s) => &s[..],
+            None => "unknown panic",
+        },
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum SyncTaskErr {
     #[error("client error: {0}")]
@@ -188,6 +199,9 @@ pub struct SyncTask {
     /// to the server when they change or every `refresh_interval`.
     autosync: bool,
 
+    /// volumes with local commits waiting to be pushed
+    pending_pushes: PendingPushes,
+
     recent_errors: Vec<(Instant, Culprit<SyncTaskErr>)>,
 }
 
@@ -200,28 +214,23 @@ impl SyncTask {
                     break;
                 }
                 Err(err) => {
-                    match err.ctx() {
-                        SyncTaskErr::Client(err) if err.is_network_err() || err.is_auth_err() => {
-                            tracing::debug!("sync task: network error: {:?}", err)
-                        }
-                        _ => tracing::error!("sync task error: {:?}", err),
-                    }
-
-                    self.recent_errors.push((Instant::now(), err));
-                    if self.recent_errors.len() > MAX_RECENT_ERRORS {
-                        self.recent_errors.remove(0);
-                    }
-
-                    // we want to explore system states that include sync task errors
-                    precept::expect_reachable!("error occurred in sync task");
-                    sleep(Duration::from_millis(100));
+                    record_error(&mut self.recent_errors, err);
+                    sleep(ERROR_BACKOFF);
                 }
             }
         }
     }
 
     fn run_inner(&mut self) -> Result<(), SyncTaskErr> {
+        let mut next_tick = Instant::now() + self.refresh_interval;
         loop {
+            // wake up early if a coalesced push is due before the next refresh
+            let deadline = self
+                .pending_pushes
+                .next_deadline()
+                .map_or(next_tick, |deadline| deadline.min(next_tick));
+            let timeout = deadline.saturating_duration_since(Instant::now());
+
             select_biased! {
                 recv(self.control) -> control => {
                     match control.ok() {
@@ -242,7 +251,15 @@ impl SyncTask {
                     }
                 }
 
-                default(self.refresh_interval) => self.handle_tick()?,
+                default(timeout) => {
+                    let now = Instant::now();
+                    let due = self.pending_pushes.take_due(now);
+                    self.handle_push(due)?;
+                    if now >= next_tick {
+                        next_tick = now + self.refresh_interval;
+                        self.handle_tick()?;
+                    }
+                }
             }
         }
         Ok(())
@@ -270,8 +287,17 @@ impl SyncTask {
                 self.autosync = autosync;
                 reply!(complete, ())
             }
+            SyncControl::GetPushCoalescing { complete } => {
+                reply!(complete, self.pending_pushes.policy())
+            }
+            SyncControl::SetPushCoalescing { policy, complete } => {
+                self.pending_pushes.set_policy(policy);
+                reply!(complete, ())
+            }
             SyncControl::Sync { vid, direction, complete } => {
-                reply!(complete, self.sync_volume(vid, direction))
+                // the caller may have stopped waiting for the sync to complete
+                let _ = complete.try_send(self.sync_volume(vid, direction));
+                Ok(())
             }
             SyncControl::ResetToRemote { vid, complete } => {
                 reply!(complete, self.reset_volume_to_remote(vid))
@@ -289,6 +315,7 @@ impl SyncTask {
     /// If dir is `SyncDirection::Both`, this function will push before it pulls
     fn sync_volume(&mut self, vid: VolumeId, dir: SyncDirection) -> Result<(), ClientErr> {
         if dir.matches(SyncDirection::Push) {
+            self.pending_pushes.remove(&vid);
             let state = self.storage.volume_state(&vid).or_into_ctx()?;
             if state.has_pending_commits() {
                 Job::push(vid.clone(), self.cid.clone())
@@ -319,7 +346,15 @@ impl SyncTask {
             return Ok(());
         }
 
-        let mut jobs = self.jobs(SyncDirection::Both, None);
+        // volumes waiting on the coalescing policy are pulled but left for
+        // `handle_push` to push
+        let mut jobs = sync_jobs(
+            &self.storage,
+            &self.cid,
+            SyncDirection::Both,
+            None,
+            Some(&self.pending_pushes),
+        );
         while let Some(job) = jobs.try_next()? {
             job.run(&self.storage, &self.clients).or_into_ctx()?;
         }
@@ -331,6 +366,18 @@ impl SyncTask {
             return Ok(());
         }
 
+        let ready = self
+            .pending_pushes
+            .record(&self.storage, vids, Instant::now())
+            .or_into_ctx()?;
+        self.handle_push(ready)
+    }
+
+    fn handle_push(&mut self, vids: HashSet<VolumeId>) -> Result<(), SyncTaskErr> {
+        if !self.autosync || vids.is_empty() {
+            return Ok(());
+        }
+
         let mut jobs = self.jobs(SyncDirection::Push, Some(vids));
         while let Some(job) = jobs.try_next()? {
             job.run(&self.storage, &self.clients).or_into_ctx()?;
@@ -343,26 +390,61 @@ impl SyncTask {
         sync: SyncDirection,
         vids: Option<HashSet<VolumeId>>,
     ) -> impl TryIterator<Ok = Job, Err = Culprit<SyncTaskErr>> + '_ {
-        self.storage
-            .query_volumes(sync, vids)
-            .map_err(|err| err.map_ctx(SyncTaskErr::from))
-            .try_filter_map(move |state| {
-                if state.status() != VolumeStatus::Ok {
-                    // volume must be healthy
-                    return Ok(None);
-                }
+        sync_jobs(&self.storage, &self.cid, sync, vids, None)
+    }
+}
 
-                let config = state.config();
-                let can_push = config.sync().matches(SyncDirection::Push);
-                let can_pull = config.sync().matches(SyncDirection::Pull);
-                let has_pending_commits = state.has_pending_commits();
-                if can_push && has_pending_commits && sync.matches(SyncDirection::Push) {
-                    Ok(Some(Job::push(state.vid().clone(), self.cid.clone())))
-                } else if can_pull && sync.matches(SyncDirection::Pull) && !state.is_syncing() {
-                    Ok(Some(Job::pull(state.vid().clone())))
-                } else {
-                    Ok(None)
-                }
-            })
+/// Log a sync task error and remember it in the list of recent errors
+pub(crate) fn record_error(
+    recent_errors: &mut Vec<(Instant, Culprit<SyncTaskErr>)>,
+    err: Culprit<SyncTaskErr>,
+) {
+    match err.ctx() {
+        SyncTaskErr::Client(err) if err.is_network_err() || err.is_auth_err() => {
+            tracing::debug!("sync task: network error: {:?}", err)
+        }
+        _ => tracing::error!("sync task error: {:?}", err),
     }
+
+    recent_errors.push((Instant::now(), err));
+    if recent_errors.len() > MAX_RECENT_ERRORS {
+        recent_errors.remove(0);
+    }
+
+    // we want to explore system states that include sync task errors
+    precept::expect_reachable!("error occurred in sync task");
+}
+
+/// Determine which sync jobs to run for volumes matching the sync direction
+/// and optional set of volume ids. Volumes in `deferred` are not pushed, but
+/// may still be pulled.
+pub(crate) fn sync_jobs<'a>(
+    storage: &'a Storage,
+    cid: &'a ClientId,
+    sync: SyncDirection,
+    vids: Option<HashSet<VolumeId>>,
+    deferred: Option<&'a PendingPushes>,
+) -> impl TryIterator<Ok = Job, Err = Culprit<SyncTaskErr>> + 'a {
+    storage
+        .query_volumes(sync, vids)
+        .map_err(|err| err.map_ctx(SyncTaskErr::from))
+        .try_filter_map(move |state| {
+            if state.status() != VolumeStatus::Ok {
+                // volume must be healthy
+                return Ok(None);
+            }
+
+            let config = state.config();
+            let can_push = config.sync().matches(SyncDirection::Push);
+            let can_pull = config.sync().matches(SyncDirection::Pull);
+            let has_pending_commits = state.has_pending_commits()
+                && !deferred.is_some_and(|deferred| deferred.contains(state.vid()));
+            if can_push && has_pending_commits && sync.matches(SyncDirection::Push) {
+                Ok(Some(Job::push(state.vid().clone(), cid.clone())))
+            } else if can_pull && sync.matches(SyncDirection::Pull) && !state.is_syncing() {
+                Ok(Some(Job::pull(state.vid().clone())))
+            } else {
+                Ok(None)
+            }
+        })
 }
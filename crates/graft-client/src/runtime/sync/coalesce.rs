@@ -0,0 +1,329 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use culprit::Result;
+use graft_core::{VolumeId, byte_unit::ByteUnit};
+use graft_proto::{MAX_COMMIT_METADATA_SIZE, common::v1::CommitMetadata};
+use prost::Message;
+
+use crate::runtime::storage::{Storage, StorageErr};
+
+/// `PushCoalescing` controls how the sync task batches local commits into
+/// remote commits when autosync is enabled.
+///
+/// By default every local commit is pushed as soon as it happens. Once a quiet
+/// period or max delay is configured, the sync task waits until the volume has
+/// stopped changing for the quiet period, or until the first pending commit is
+/// older than the max delay, and then pushes all pending commits as a single
+/// remote commit. The commit and byte limits trigger an early push regardless
+/// of timing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PushCoalescing {
+    quiet_period: Duration,
+    max_delay: Option<Duration>,
+    max_commits: Option<u64>,
+    max_bytes: Option<ByteUnit>,
+}
+
+impl PushCoalescing {
+    /// Wait until a volume has had no local commits for `quiet_period`
+    /// before pushing
+    pub fn with_quiet_period(self, quiet_period: Duration) -> Self {
+        Self { quiet_period, ..self }
+    }
+
+    /// Push no later than `max_delay` after the first pending local commit,
+    /// even if the volume is still changing
+    pub fn with_max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay: Some(max_delay), ..self }
+    }
+
+    /// Push as soon as at least `max_commits` local commits are pending
+    pub fn with_max_commits(self, max_commits: u64) -> Self {
+        Self { max_commits: Some(max_commits), ..self }
+    }
+
+    /// Push as soon as the pending local commits have changed at least
+    /// `max_bytes` worth of pages
+    pub fn with_max_bytes(self, max_bytes: ByteUnit) -> Self {
+        Self { max_bytes: Some(max_bytes), ..self }
+    }
+
+    pub fn quiet_period(&self) -> Duration {
+        self.quiet_period
+    }
+
+    pub fn max_delay(&self) -> Option<Duration> {
+        self.max_delay
+    }
+
+    pub fn max_commits(&self) -> Option<u64> {
+        self.max_commits
+    }
+
+    pub fn max_bytes(&self) -> Option<ByteUnit> {
+        self.max_bytes
+    }
+
+    /// Returns true if local commits may be delayed before being pushed
+    pub fn is_enabled(&self) -> bool {
+        !self.quiet_period.is_zero() || self.max_delay.is_some_and(|d| !d.is_zero())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PendingPush {
+    /// when the first pending commit was observed
+    first: Instant,
+    /// when the most recent pending commit was observed
+    last: Instant,
+}
+
+/// Tracks volumes with local commits that are waiting to be pushed according
+/// to a `PushCoalescing` policy
+#[derive(Debug, Default)]
+pub(crate) struct PendingPushes {
+    policy: PushCoalescing,
+    pending: HashMap<VolumeId, PendingPush>,
+}
+
+impl PendingPushes {
+    pub fn policy(&self) -> PushCoalescing {
+        self.policy
+    }
+
+    /// Update the policy; volumes which are already pending are re-evaluated
+    /// against the new policy by the next call to `take_due`
+    pub fn set_policy(&mut self, policy: PushCoalescing) {
+        self.policy = policy;
+    }
+
+    /// Record new local commits to the provided volumes, returning the subset
+    /// of volumes which should be pushed immediately.
+    pub fn record(
+        &mut self,
+        storage: &Storage,
+        vids: HashSet<VolumeId>,
+        now: Instant,
+    ) -> Result<HashSet<VolumeId>, StorageErr> {
+        if !self.policy.is_enabled() {
+            self.pending.clear();
+            return Ok(vids);
+        }
+
+        let check_limits = self.policy.max_commits.is_some() || self.policy.max_bytes.is_some();
+        let mut ready = HashSet::new();
+        for vid in vids {
+            self.pending
+                .entry(vid.clone())
+                .and_modify(|p| p.last = now)
+                .or_insert(PendingPush { first: now, last: now });
+
+            if check_limits {
+                let (commits, pages) = storage.pending_commit_stats(&vid)?;
+                let bytes = storage.page_size(&vid)?.as_byte_unit() * pages;
+                if self.policy.max_commits.is_some_and(|max| commits >= max)
+                    || self.policy.max_bytes.is_some_and(|max| bytes >= max)
+                {
+                    self.pending.remove(&vid);
+                    ready.insert(vid);
+                }
+            }
+        }
+        Ok(ready)
+    }
+
+    /// Remove and return every pending volume whose push is due
+    pub fn take_due(&mut self, now: Instant) -> HashSet<VolumeId> {
+        let policy = self.policy;
+        let mut due = HashSet::new();
+        self.pending.retain(|vid, pending| {
+            if now >= Self::deadline(policy, pending) {
+                due.insert(vid.clone());
+                false
+            } else {
+                true
+            }
+        });
+        due
+    }
+
+    /// The earliest instant at which a pending volume should be pushed
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending
+            .values()
+            .map(|pending| Self::deadline(self.policy, pending))
+            .min()
+    }
+
+    /// Returns true if the volume has commits waiting on the coalescing policy
+    pub fn contains(&self, vid: &VolumeId) -> bool {
+        self.pending.contains_key(vid)
+    }
+
+    /// Forget about a volume, usually because it has just been pushed
+    pub fn remove(&mut self, vid: &VolumeId) {
+        self.pending.remove(vid);
+    }
+
+    fn deadline(policy: PushCoalescing, pending: &PendingPush) -> Instant {
+        let quiet = pending.last + policy.quiet_period;
+        match policy.max_delay {
+            Some(max_delay) => quiet.min(pending.first + max_delay),
+            None => quiet,
+        }
+    }
+}
+
+/// Combine the metadata of local commits which are pushed as a single remote
+/// commit. The metadata must be ordered from the oldest commit to the newest.
+///
+/// Messages are joined with newlines, distinct authors are joined with commas,
+/// and multiple JSON documents are combined into a JSON array. If the result
+/// exceeds `MAX_COMMIT_METADATA_SIZE`, the metadata of the oldest commits is
+/// dropped until it fits.
+pub(crate) fn combine_metadata(metadata: Vec<CommitMetadata>) -> Option<CommitMetadata> {
+    let metadata: Vec<_> = metadata.into_iter().filter(|m| !m.is_empty()).collect();
+    (0..metadata.len())
+        .map(|start| combine_metadata_slice(&metadata[start..]))
+        .find(|combined| combined.encoded_len() <= MAX_COMMIT_METADATA_SIZE)
+}
+
+fn combine_metadata_slice(metadata: &[CommitMetadata]) -> CommitMetadata {
+    if let [single] = metadata {
+        return single.clone();
+    }
+
+    let non_empty = |field: fn(&CommitMetadata) -> &str| {
+        metadata
+            .iter()
+            .map(field)
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>()
+    };
+
+    let mut authors = non_empty(|m| &m.author);
+    let mut seen = HashSet::new();
+    authors.retain(|author| seen.insert(*author));
+
+    let json = match non_empty(|m| &m.json).as_slice() {
+        [] => String::new(),
+        [doc] => doc.to_string(),
+        docs => format!("[{}]", docs.join(",")),
+    };
+
+    CommitMetadata {
+        message: non_empty(|m| &m.message).join("\n"),
+        author: authors.join(", "),
+        json,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graft_core::{page::Page, pageidx};
+
+    use crate::runtime::storage::memtable::Memtable;
+
+    use super::*;
+
+    #[graft_test::test]
+    fn test_pending_pushes() {
+        let storage = Storage::open_memory().unwrap();
+        let vid = VolumeId::random();
+        let vids = || HashSet::from_iter([vid.clone()]);
+        let start = Instant::now();
+        let secs = |n| start + Duration::from_secs(n);
+
+        // when disabled, every commit is pushed immediately
+        let mut pending = PendingPushes::default();
+        assert_eq!(pending.record(&storage, vids(), start).unwrap(), vids());
+        assert_eq!(pending.next_deadline(), None);
+
+        // commits are delayed until the volume has been quiet
+        pending.set_policy(
+            PushCoalescing::default()
+                .with_quiet_period(Duration::from_secs(2))
+                .with_max_delay(Duration::from_secs(5)),
+        );
+        assert!(pending.record(&storage, vids(), start).unwrap().is_empty());
+        assert!(
+            pending
+                .record(&storage, vids(), secs(1))
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(pending.next_deadline(), Some(secs(3)));
+        assert!(pending.take_due(secs(2)).is_empty());
+        assert_eq!(pending.take_due(secs(3)), vids());
+        assert_eq!(pending.next_deadline(), None);
+
+        // a chatty volume is pushed once the max delay elapses
+        for n in 0..5 {
+            pending.record(&storage, vids(), secs(10 + n)).unwrap();
+        }
+        assert_eq!(pending.next_deadline(), Some(secs(15)));
+        assert_eq!(pending.take_due(secs(15)), vids());
+
+        // the commit limit triggers an immediate push
+        pending.set_policy(pending.policy().with_max_commits(2));
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled(0x42));
+        let snapshot = storage
+            .commit(&vid, None, 1, memtable.clone(), None)
+            .unwrap();
+        assert!(
+            pending
+                .record(&storage, vids(), secs(20))
+                .unwrap()
+                .is_empty()
+        );
+        storage
+            .commit(&vid, Some(snapshot), 1, memtable, None)
+            .unwrap();
+        assert_eq!(pending.record(&storage, vids(), secs(21)).unwrap(), vids());
+        assert_eq!(pending.next_deadline(), None);
+    }
+
+    #[graft_test::test]
+    fn test_combine_metadata() {
+        let metadata = |message: &str, author: &str, json: &str| CommitMetadata {
+            message: message.into(),
+            author: author.into(),
+            json: json.into(),
+        };
+
+        assert_eq!(combine_metadata(vec![]), None);
+        assert_eq!(combine_metadata(vec![CommitMetadata::default()]), None);
+
+        // a single commit's metadata is preserved
+        let single = metadata("one", "alice", r#"{"n":1}"#);
+        assert_eq!(
+            combine_metadata(vec![CommitMetadata::default(), single.clone()]),
+            Some(single.clone())
+        );
+
+        let combined = combine_metadata(vec![
+            single,
+            metadata("two", "bob", ""),
+            metadata("", "alice", r#"{"n":3}"#),
+        ])
+        .unwrap();
+        assert_eq!(
+            combined,
+            metadata("one\ntwo", "alice, bob", r#"[{"n":1},{"n":3}]"#)
+        );
+
+        // the oldest metadata is dropped if the combination is too large
+        let large = "x".repeat(MAX_COMMIT_METADATA_SIZE / 2);
+        let combined = combine_metadata(vec![
+            metadata(&large, "", ""),
+            metadata(&large, "", ""),
+            metadata("newest", "", ""),
+        ])
+        .unwrap();
+        assert_eq!(combined.message, format!("{large}\nnewest"));
+    }
+}
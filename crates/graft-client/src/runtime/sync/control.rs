@@ -1,12 +1,12 @@
 use std::time::Instant;
 
-use crossbeam::channel::{self, Receiver, Sender};
+use crossbeam::channel::{self, Receiver, RecvTimeoutError, Sender};
 use graft_core::VolumeId;
 
 use crate::{ClientErr, runtime::storage::volume_state::SyncDirection};
 use culprit::{Culprit, Result};
 
-use super::SyncTaskErr;
+use super::{PushCoalescing, SyncTaskErr};
 
 #[derive(Debug)]
 pub enum SyncControl {
@@ -19,6 +19,15 @@ pub enum SyncControl {
         complete: Sender<()>,
     },
 
+    GetPushCoalescing {
+        complete: Sender<PushCoalescing>,
+    },
+
+    SetPushCoalescing {
+        policy: PushCoalescing,
+        complete: Sender<()>,
+    },
+
     Sync {
         vid: VolumeId,
         direction: SyncDirection,
@@ -47,12 +56,16 @@ impl SyncRpc {
         Self { control }
     }
 
-    fn must_call<T>(&self, msg: SyncControl, recv: Receiver<T>) -> T {
+    fn must_send(&self, msg: SyncControl) {
         self.control
             .as_ref()
             .expect("SyncRpc: control channel missing")
             .send(msg)
             .expect("SyncRpc: control channel closed");
+    }
+
+    fn must_call<T>(&self, msg: SyncControl, recv: Receiver<T>) -> T {
+        self.must_send(msg);
         recv.recv().expect("SyncRpc: response channel closed")
     }
 
@@ -66,11 +79,39 @@ impl SyncRpc {
         self.must_call(SyncControl::SetAutosync { autosync, complete }, recv)
     }
 
+    pub fn get_push_coalescing(&self) -> PushCoalescing {
+        let (complete, recv) = channel::bounded(1);
+        self.must_call(SyncControl::GetPushCoalescing { complete }, recv)
+    }
+
+    pub fn set_push_coalescing(&self, policy: PushCoalescing) {
+        let (complete, recv) = channel::bounded(1);
+        self.must_call(SyncControl::SetPushCoalescing { policy, complete }, recv)
+    }
+
     pub fn sync(&self, vid: VolumeId, direction: SyncDirection) -> Result<(), ClientErr> {
         let (complete, recv) = channel::bounded(1);
         self.must_call(SyncControl::Sync { vid, direction, complete }, recv)
     }
 
+    /// Like `sync`, but stops waiting at `deadline`. Returns None if the
+    /// deadline passes first, in which case the sync completes in the
+    /// background.
+    pub fn sync_until(
+        &self,
+        vid: VolumeId,
+        direction: SyncDirection,
+        deadline: Instant,
+    ) -> Option<Result<(), ClientErr>> {
+        let (complete, recv) = channel::bounded(1);
+        self.must_send(SyncControl::Sync { vid, direction, complete });
+        match recv.recv_deadline(deadline) {
+            Ok(result) => Some(result),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => panic!("SyncRpc: response channel closed"),
+        }
+    }
+
     pub fn reset_to_remote(&self, vid: VolumeId) -> Result<(), ClientErr> {
         let (complete, recv) = channel::bounded(1);
         self.must_call(SyncControl::ResetToRemote { vid, complete }, recv)
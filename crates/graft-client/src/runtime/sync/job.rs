@@ -1,9 +1,30 @@
-use culprit::{Result, ResultExt};
-use graft_core::{PageIdx, VolumeId, gid::ClientId, lsn::LSN, page::Page};
-use graft_proto::pagestore::v1::PageAtIdx;
-use tryiter::TryIteratorExt;
+use std::ops::RangeInclusive;
 
-use crate::{ClientErr, ClientPair, runtime::storage::Storage};
+use bytes::Bytes;
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{
+    PageIdx, VolumeId,
+    gid::ClientId,
+    lsn::LSN,
+    page::{Page, PageSize},
+    page_count::PageCount,
+};
+use graft_proto::{
+    common::v1::{CommitMetadata, LsnRange, Snapshot as RemoteSnapshot},
+    pagestore::v1::PageAtIdx,
+};
+use splinter_rs::SplinterRef;
+use tryiter::{TryIterator, TryIteratorExt};
+
+#[cfg(feature = "tokio")]
+use crate::nonblocking::spawn_blocking;
+use crate::{
+    ClientErr, ClientPair,
+    runtime::{
+        storage::{Storage, StorageErr, volume_state::VolumeState},
+        sync::coalesce::combine_metadata,
+    },
+};
 
 #[derive(Debug)]
 pub enum Job {
@@ -30,6 +51,18 @@ impl Job {
             Job::Push(job) => job.run(storage, clients),
         }
     }
+
+    #[cfg(feature = "tokio")]
+    pub async fn run_async(
+        self,
+        storage: &std::sync::Arc<Storage>,
+        clients: &crate::nonblocking::ClientPair,
+    ) -> Result<(), ClientErr> {
+        match self {
+            Job::Pull(job) => job.run_async(storage, clients).await,
+            Job::Push(job) => job.run_async(storage, clients).await,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -45,22 +78,63 @@ pub struct PullJob {
 impl PullJob {
     fn run(self, storage: &Storage, clients: &ClientPair) -> Result<(), ClientErr> {
         let state = storage.volume_state(&self.vid).or_into_ctx()?;
-
-        // pull starting at the next LSN after the last remote LSN
-        let start_lsn = state
-            .snapshot()
-            .and_then(|s| s.remote())
-            .map_or(LSN::FIRST, |lsn| lsn.next().expect("lsn overflow"));
+        let start_lsn = self.start_lsn(&state);
         let lsns = start_lsn..;
 
         let _span =
             tracing::debug_span!("PullJob", vid = ?self.vid, ?lsns, reset=self.reset).entered();
 
-        if let Some((snapshot, _, changed)) = clients
+        let result = clients
             .metastore()
             .pull_graft(&self.vid, lsns)
-            .or_into_ctx()?
-        {
+            .or_into_ctx()?;
+        self.complete(storage, &state, start_lsn, result)
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn run_async(
+        self,
+        storage: &std::sync::Arc<Storage>,
+        clients: &crate::nonblocking::ClientPair,
+    ) -> Result<(), ClientErr> {
+        use tracing::Instrument;
+
+        let state = storage.volume_state(&self.vid).or_into_ctx()?;
+        let start_lsn = self.start_lsn(&state);
+        let lsns = start_lsn..;
+
+        let span = tracing::debug_span!("PullJob", vid = ?self.vid, ?lsns, reset=self.reset);
+        async {
+            let result = clients
+                .metastore()
+                .pull_graft(&self.vid, lsns)
+                .await
+                .or_into_ctx()?;
+
+            // applying the pull waits on a durable write to storage
+            let storage = storage.clone();
+            spawn_blocking(move || self.complete(&storage, &state, start_lsn, result)).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// pull starting at the next LSN after the last remote LSN
+    fn start_lsn(&self, state: &VolumeState) -> LSN {
+        state
+            .snapshot()
+            .and_then(|s| s.remote())
+            .map_or(LSN::FIRST, |lsn| lsn.next().expect("lsn overflow"))
+    }
+
+    fn complete(
+        &self,
+        storage: &Storage,
+        state: &VolumeState,
+        start_lsn: LSN,
+        result: Option<(RemoteSnapshot, LsnRange, SplinterRef<Bytes>)>,
+    ) -> Result<(), ClientErr> {
+        if let Some((snapshot, _, changed)) = result {
             let snapshot_lsn = snapshot.lsn().expect("invalid LSN");
 
             assert!(
@@ -80,6 +154,9 @@ impl PullJob {
                     .receive_remote_commit(&self.vid, snapshot, changed)
                     .or_into_ctx()?;
             }
+        } else {
+            // the volume is already up to date
+            storage.record_pull(&self.vid);
         }
 
         Ok(())
@@ -92,66 +169,40 @@ pub struct PushJob {
     cid: ClientId,
 }
 
+/// The pages and metadata collected from local storage for a push
+struct PreparedPush {
+    remote_lsn: Option<LSN>,
+    page_count: PageCount,
+    page_size: PageSize,
+    lsns: RangeInclusive<LSN>,
+    pages: Vec<PageAtIdx>,
+    metadata: Option<CommitMetadata>,
+}
+
 impl PushJob {
     fn run(self, storage: &Storage, clients: &ClientPair) -> Result<(), ClientErr> {
-        // prepare the sync
-        let (remote_lsn, page_count, lsns, mut commits) =
-            storage.prepare_sync_to_remote(&self.vid).or_into_ctx()?;
+        let Some(PreparedPush {
+            remote_lsn,
+            page_count,
+            page_size,
+            lsns,
+            pages,
+            metadata,
+        }) = self.prepare(storage)?
+        else {
+            // there is nothing to push
+            return Ok(());
+        };
 
         let _span =
             tracing::debug_span!("PushJob", vid=?self.vid, ?remote_lsn, ?lsns, ?page_count,)
                 .entered();
 
-        // setup temporary storage for pages
-        // TODO: we will eventually stream pages directly to the remote
-        let mut pages = Vec::new();
-        let mut upsert_page = |pageidx: PageIdx, page: Page| {
-            // binary search upsert the page into pages
-            match pages.binary_search_by_key(&pageidx.to_u32(), |p: &PageAtIdx| p.pageidx) {
-                Ok(i) => {
-                    // replace the page in the list with this page
-                    pages[i].data = page.into();
-                }
-                Err(i) => {
-                    // insert the page into the list
-                    pages.insert(i, PageAtIdx::new(pageidx, page));
-                }
-            }
-        };
-
-        #[allow(unused)]
-        let mut num_commits = 0;
-
-        // load all of the pages into memory
-        // TODO: stream pages directly to the remote
-        while let Some((lsn, graft)) = commits.try_next().or_into_ctx()? {
-            num_commits += 1;
-            let pageidxs = graft.iter().map(PageIdx::try_from).err_into();
-            let mut commit_pages = storage.query_pages(&self.vid, lsn, pageidxs);
-            while let Some((pageidx, page)) = commit_pages.try_next().or_into_ctx()? {
-                // it's a fatal error if the page is None or Pending
-                let page = page
-                    .and_then(|p| p.try_into_page())
-                    .expect("page missing from storage");
-
-                // if the page is still contained within the page_count, include it
-                if page_count.contains(pageidx) {
-                    upsert_page(pageidx, page);
-                }
-            }
-        }
-
-        precept::expect_always_or_unreachable!(
-            num_commits == graft_core::lsn::LSNRangeExt::try_len(&lsns).expect("lsns is RangeInclusive"),
-            "push job always pushes all expected commits",
-            { "vid": self.vid, "cid": self.cid, "lsns": format!("{lsns:?}") }
-        );
-
         // write the pages to the pagestore if there are any pages
         let segments = if !pages.is_empty() {
             clients
                 .pagestore()
-                .write_pages(&self.vid, pages)
+                .write_pages(&self.vid, page_size, pages)
                 .or_into_ctx()?
         } else {
             Vec::new()
@@ -160,10 +211,104 @@ impl PushJob {
         precept::maybe_fault!(0.1, "PushJob: before metastore commit", std::process::exit(0), { "cid": self.cid });
 
         // commit the segments to the metastore
-        let remote_snapshot = match clients
-            .metastore()
-            .commit(&self.vid, &self.cid, remote_lsn, page_count, segments)
-        {
+        let result = clients.metastore().commit(
+            &self.vid, &self.cid, remote_lsn, page_count, page_size, segments, metadata,
+        );
+        self.complete(storage, lsns, result)
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn run_async(
+        self,
+        storage: &std::sync::Arc<Storage>,
+        clients: &crate::nonblocking::ClientPair,
+    ) -> Result<(), ClientErr> {
+        use tracing::Instrument;
+
+        let Some(PreparedPush {
+            remote_lsn,
+            page_count,
+            page_size,
+            lsns,
+            pages,
+            metadata,
+        }) = self.prepare(storage)?
+        else {
+            // there is nothing to push
+            return Ok(());
+        };
+
+        let span = tracing::debug_span!("PushJob", vid=?self.vid, ?remote_lsn, ?lsns, ?page_count,);
+        async {
+            // write the pages to the pagestore if there are any pages
+            let segments = if !pages.is_empty() {
+                clients
+                    .pagestore()
+                    .write_pages(&self.vid, page_size, pages)
+                    .await
+                    .or_into_ctx()?
+            } else {
+                Vec::new()
+            };
+
+            precept::maybe_fault!(0.1, "PushJob: before metastore commit", std::process::exit(0), { "cid": self.cid });
+
+            // commit the segments to the metastore
+            let result = clients
+                .metastore()
+                .commit(
+                &self.vid, &self.cid, remote_lsn, page_count, page_size, segments, metadata,
+            )
+                .await;
+
+            // completing the push waits on a durable write to storage
+            let storage = storage.clone();
+            spawn_blocking(move || self.complete(&storage, lsns, result)).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Load every page which needs to be pushed into memory.
+    /// Returns None if there is nothing to push.
+    fn prepare(&self, storage: &Storage) -> Result<Option<PreparedPush>, ClientErr> {
+        // prepare the sync
+        let Some((remote_lsn, page_count, lsns, commits)) =
+            storage.prepare_sync_to_remote(&self.vid).or_into_ctx()?
+        else {
+            return Ok(None);
+        };
+
+        let page_size = storage.page_size(&self.vid).or_into_ctx()?;
+
+        #[allow(unused)]
+        let (num_commits, pages, metadata) =
+            load_commit_pages(storage, &self.vid, page_count, page_size, commits)?;
+
+        precept::expect_always_or_unreachable!(
+            num_commits == graft_core::lsn::LSNRangeExt::try_len(&lsns).expect("lsns is RangeInclusive"),
+            "push job always pushes all expected commits",
+            { "vid": self.vid, "cid": self.cid, "lsns": format!("{lsns:?}") }
+        );
+
+        Ok(Some(PreparedPush {
+            remote_lsn,
+            page_count,
+            page_size,
+            lsns,
+            pages,
+            metadata,
+        }))
+    }
+
+    /// Complete the push by applying the result of the metastore commit
+    fn complete(
+        &self,
+        storage: &Storage,
+        lsns: RangeInclusive<LSN>,
+        result: Result<RemoteSnapshot, ClientErr>,
+    ) -> Result<(), ClientErr> {
+        let remote_snapshot = match result {
             Ok(remote_snapshot) => remote_snapshot,
             Err(err) => {
                 tracing::debug!("metastore commit failed: {:?}", err);
@@ -190,3 +335,61 @@ impl PushJob {
         Ok(())
     }
 }
+
+/// Load the latest version of every page changed by a sequence of local
+/// commits into memory, skipping pages outside of `page_count`.
+/// Returns the number of commits loaded, the pages sorted by page index, and
+/// the combined metadata of the commits.
+#[allow(clippy::type_complexity)]
+pub(crate) fn load_commit_pages(
+    storage: &Storage,
+    vid: &VolumeId,
+    page_count: PageCount,
+    page_size: PageSize,
+    mut commits: impl TryIterator<
+        Ok = (LSN, SplinterRef<Bytes>, Option<CommitMetadata>),
+        Err = Culprit<StorageErr>,
+    >,
+) -> Result<(usize, Vec<PageAtIdx>, Option<CommitMetadata>), ClientErr> {
+    // setup temporary storage for pages
+    // TODO: we will eventually stream pages directly to the remote
+    let mut pages = Vec::new();
+    let mut upsert_page = |pageidx: PageIdx, page: Page| {
+        // binary search upsert the page into pages
+        match pages.binary_search_by_key(&pageidx.to_u32(), |p: &PageAtIdx| p.pageidx) {
+            Ok(i) => {
+                // replace the page in the list with this page
+                pages[i].data = page.into();
+            }
+            Err(i) => {
+                // insert the page into the list
+                pages.insert(i, PageAtIdx::new(pageidx, page));
+            }
+        }
+    };
+
+    let mut num_commits = 0;
+    let mut metadata = Vec::new();
+
+    // load all of the pages into memory
+    // TODO: stream pages directly to the remote
+    while let Some((lsn, graft, commit_metadata)) = commits.try_next().or_into_ctx()? {
+        num_commits += 1;
+        metadata.extend(commit_metadata);
+        let pageidxs = graft.iter().map(PageIdx::try_from).err_into();
+        let mut commit_pages = storage.query_pages(vid, lsn, pageidxs);
+        while let Some((pageidx, page)) = commit_pages.try_next().or_into_ctx()? {
+            // it's a fatal error if the page is None or Pending
+            let page = page
+                .and_then(|p| p.try_into_page(page_size))
+                .expect("page missing from storage");
+
+            // if the page is still contained within the page_count, include it
+            if page_count.contains(pageidx) {
+                upsert_page(pageidx, page);
+            }
+        }
+    }
+
+    Ok((num_commits, pages, combine_metadata(metadata)))
+}
@@ -1,14 +1,21 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{VolumeId, lsn::LSN, page::PageSize};
 
-use culprit::{Result, ResultExt};
-use graft_core::VolumeId;
+use splinter_rs::Splinter;
 
 use crate::{ClientErr, ClientPair};
 
 use super::{
     storage::{
         Storage,
+        page_subscriptions::PageSubscription,
         snapshot::Snapshot,
+        stats::VolumeStats,
         volume_state::{SyncDirection, VolumeStatus},
     },
     sync::control::SyncRpc,
@@ -16,9 +23,13 @@ use super::{
     volume_writer::VolumeWriter,
 };
 
+/// How often `wait_for_remote_lsn` pulls from the remote while waiting
+pub(crate) const REMOTE_LSN_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
 #[derive(Clone, Debug)]
 pub struct VolumeHandle {
     vid: VolumeId,
+    page_size: PageSize,
     clients: Arc<ClientPair>,
     storage: Arc<Storage>,
     sync_rpc: SyncRpc,
@@ -27,11 +38,18 @@ pub struct VolumeHandle {
 impl VolumeHandle {
     pub(crate) fn new(
         vid: VolumeId,
+        page_size: PageSize,
         clients: Arc<ClientPair>,
         storage: Arc<Storage>,
         sync_rpc: SyncRpc,
     ) -> Self {
-        Self { vid, clients, storage, sync_rpc }
+        Self {
+            vid,
+            page_size,
+            clients,
+            storage,
+            sync_rpc,
+        }
     }
 
     #[inline]
@@ -39,6 +57,12 @@ impl VolumeHandle {
         &self.vid
     }
 
+    /// The size of every page in the volume
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
     /// Retrieve the current volume status
     pub fn status(&self) -> Result<VolumeStatus, ClientErr> {
         self.storage.get_volume_status(&self.vid).or_into_ctx()
@@ -49,10 +73,17 @@ impl VolumeHandle {
         self.storage.snapshot(&self.vid).or_into_ctx()
     }
 
+    /// Retrieve statistics about the volume's local storage and sync state.
+    /// This scans every page stored locally for the volume.
+    pub fn stats(&self) -> Result<VolumeStats, ClientErr> {
+        self.storage.volume_stats(&self.vid).or_into_ctx()
+    }
+
     /// Open a `VolumeReader` at the latest snapshot
     pub fn reader(&self) -> Result<VolumeReader, ClientErr> {
         Ok(VolumeReader::new(
             self.vid.clone(),
+            self.page_size,
             self.snapshot()?,
             self.clients.clone(),
             self.storage.clone(),
@@ -63,6 +94,7 @@ impl VolumeHandle {
     pub fn reader_at(&self, snapshot: Option<Snapshot>) -> VolumeReader {
         VolumeReader::new(
             self.vid.clone(),
+            self.page_size,
             snapshot,
             self.clients.clone(),
             self.storage.clone(),
@@ -84,6 +116,15 @@ impl VolumeHandle {
         self.storage.remote_changeset().subscribe(self.vid.clone())
     }
 
+    /// Subscribe to remote commits to this Volume which change any of the
+    /// provided pages. Each message contains the subset of `pages` changed by
+    /// a remote commit.
+    pub fn subscribe_pages(&self, pages: Splinter) -> PageSubscription {
+        self.storage
+            .page_subscriptions()
+            .subscribe(self.vid.clone(), pages)
+    }
+
     /// Subscribe to local commits to this Volume
     pub fn subscribe_to_local_changes(&self) -> crossbeam::channel::Receiver<()> {
         self.storage.local_changeset().subscribe(self.vid.clone())
@@ -97,6 +138,57 @@ impl VolumeHandle {
             .or_into_ctx()
     }
 
+    /// Block until this volume has received a remote LSN greater than or equal
+    /// to `lsn`, pulling from the remote as needed. Returns the first snapshot
+    /// which includes the remote LSN, or `ClientErr::RemoteLsnTimeout` if
+    /// `timeout` elapses first.
+    pub fn wait_for_remote_lsn(&self, lsn: LSN, timeout: Duration) -> Result<Snapshot, ClientErr> {
+        let deadline = Instant::now() + timeout;
+
+        // subscribe before checking the snapshot to ensure we don't miss a
+        // remote commit
+        let remote_changes = self.subscribe_to_remote_changes();
+
+        let reached = || -> Result<Option<Snapshot>, ClientErr> {
+            Ok(self.snapshot()?.filter(|s| s.remote() >= Some(lsn)))
+        };
+
+        loop {
+            if let Some(snapshot) = reached()? {
+                return Ok(snapshot);
+            }
+            if Instant::now() >= deadline {
+                return Err(Culprit::new_with_note(
+                    ClientErr::RemoteLsnTimeout,
+                    format!("timed out waiting for Volume {} to reach {lsn}", self.vid),
+                ));
+            }
+
+            // bound the pull by the remaining time, checking the deadline
+            // again if it runs out
+            let pull = self
+                .sync_rpc
+                .sync_until(self.vid.clone(), SyncDirection::Pull, deadline);
+            let Some(pull) = pull else { continue };
+            pull.or_into_ctx()?;
+            if let Some(snapshot) = reached()? {
+                return Ok(snapshot);
+            }
+
+            // wait for the next remote commit or poll interval before pulling again
+            let wait_until = deadline.min(Instant::now() + REMOTE_LSN_POLL_INTERVAL);
+            let _ = remote_changes.recv_deadline(wait_until);
+        }
+    }
+
+    /// Roll back the newest local commits to this volume such that it matches
+    /// its snapshot at `to_lsn`, or is empty if `to_lsn` is None. Only commits
+    /// which have not yet started pushing to the remote may be rolled back.
+    /// The rollback is recorded at a new LSN, so LSNs are never reused.
+    pub fn rollback_local(&self, to_lsn: Option<LSN>) -> Result<Snapshot, ClientErr> {
+        self.storage.rollback_local(&self.vid, to_lsn).or_into_ctx()
+    }
+
     /// Reset this volume to the remote. This will cause all pending commits to
     /// be rolled back and the volume status to be cleared.
     pub fn reset_to_remote(&self) -> Result<(), ClientErr> {
@@ -1,12 +1,14 @@
-use std::{borrow::Cow, collections::HashMap, iter::once, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, iter::once, ops::Range, sync::Arc};
 
-use culprit::{Result, ResultExt};
+use culprit::{Culprit, Result, ResultExt};
 
+use bytes::Bytes;
 use graft_core::{
     PageIdx, VolumeId,
     lsn::LSN,
-    page::{EMPTY_PAGE, Page},
+    page::{Page, PageSize},
 };
+use graft_proto::pagestore::v1::PageAtIdx;
 use splinter_rs::Splinter;
 use tracing::field;
 
@@ -14,7 +16,7 @@ use crate::{ClientErr, ClientPair, oracle::Oracle};
 
 use super::{
     storage::{
-        Storage,
+        Storage, StorageErr,
         page::{PageStatus, PageValue},
         snapshot::Snapshot,
     },
@@ -27,16 +29,85 @@ pub trait VolumeRead {
     /// Retrieve the Volume snapshot backing this reader
     fn snapshot(&self) -> Option<&Snapshot>;
 
+    /// The size of every page in the Volume
+    fn page_size(&self) -> PageSize;
+
     /// Read a page from the snapshot
     fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr>;
 
     /// Retrieve a page's status
     fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr>;
+
+    /// Read `buf.len()` bytes starting at the byte `offset` into the volume.
+    /// The range may span multiple pages, each of which is read (and fetched
+    /// if needed) via [`VolumeRead::read`]. Bytes past the end of the volume
+    /// read as zeros.
+    fn read_at<O: Oracle>(
+        &self,
+        oracle: &mut O,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<(), ClientErr> {
+        for span in PageSpan::iter(self.page_size(), offset, buf.len()) {
+            let span = span?;
+            let page = self.read(oracle, span.pageidx)?;
+            buf[span.buf].copy_from_slice(&page[span.page]);
+        }
+        Ok(())
+    }
+}
+
+/// The portion of a single page covered by a byte range within a volume
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PageSpan {
+    pub pageidx: PageIdx,
+    /// the byte range within the page
+    pub page: Range<usize>,
+    /// the corresponding byte range within the caller's buffer
+    pub buf: Range<usize>,
+}
+
+impl PageSpan {
+    /// Split the byte range `offset..offset+len` into per-page spans
+    pub fn iter(
+        page_size: PageSize,
+        offset: u64,
+        len: usize,
+    ) -> impl Iterator<Item = Result<PageSpan, ClientErr>> {
+        let mut pos = 0;
+        std::iter::from_fn(move || {
+            if pos >= len {
+                return None;
+            }
+            // saturating here is fine as PageIdx conversion will fail long
+            // before we reach u64::MAX
+            let abs = offset.saturating_add(pos as u64);
+            let page_start = (abs % page_size.as_u64()) as usize;
+            let n = (page_size.as_usize() - page_start).min(len - pos);
+            let idx = usize::try_from(abs / page_size.as_u64() + 1).unwrap_or(usize::MAX);
+            let span = PageIdx::try_from(idx)
+                .map_err(Culprit::from_err)
+                .map(|pageidx| PageSpan {
+                    pageidx,
+                    page: page_start..page_start + n,
+                    buf: pos..pos + n,
+                });
+            pos += n;
+            Some(span)
+        })
+    }
+
+    /// Returns true if this span covers an entire page of the provided size
+    #[inline]
+    pub fn is_full_page(&self, page_size: PageSize) -> bool {
+        self.page.len() == page_size.as_usize()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct VolumeReader {
     vid: VolumeId,
+    page_size: PageSize,
     snapshot: Option<Snapshot>,
     clients: Arc<ClientPair>,
     storage: Arc<Storage>,
@@ -45,11 +116,18 @@ pub struct VolumeReader {
 impl VolumeReader {
     pub(crate) fn new(
         vid: VolumeId,
+        page_size: PageSize,
         snapshot: Option<Snapshot>,
         clients: Arc<ClientPair>,
         storage: Arc<Storage>,
     ) -> Self {
-        Self { vid, snapshot, clients, storage }
+        Self {
+            vid,
+            page_size,
+            snapshot,
+            clients,
+            storage,
+        }
     }
 
     /// Upgrade this reader into a writer
@@ -58,8 +136,23 @@ impl VolumeReader {
     }
 
     /// decompose this reader into snapshot and storage
-    pub(crate) fn into_parts(self) -> (VolumeId, Option<Snapshot>, Arc<ClientPair>, Arc<Storage>) {
-        (self.vid, self.snapshot, self.clients, self.storage)
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        VolumeId,
+        PageSize,
+        Option<Snapshot>,
+        Arc<ClientPair>,
+        Arc<Storage>,
+    ) {
+        (
+            self.vid,
+            self.page_size,
+            self.snapshot,
+            self.clients,
+            self.storage,
+        )
     }
 }
 
@@ -74,65 +167,107 @@ impl VolumeRead for VolumeReader {
         self.snapshot.as_ref()
     }
 
+    #[inline]
+    fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
     fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
-        if let Some(snapshot) = self.snapshot() {
-            match self
-                .storage
-                .read(self.vid(), snapshot.local(), pageidx)
-                .or_into_ctx()?
-            {
-                (_, PageValue::Available(page)) => {
-                    oracle.observe_cache_hit(pageidx);
-                    Ok(page)
-                }
-                (_, PageValue::Empty) => {
-                    oracle.observe_cache_hit(pageidx);
-                    Ok(EMPTY_PAGE)
-                }
-                (_, PageValue::Pending) => {
-                    if let Some((remote_lsn, local_lsn)) = snapshot.remote_mapping().splat() {
-                        fetch_page(
-                            &self.clients,
-                            &self.storage,
-                            oracle,
-                            self.vid(),
-                            remote_lsn,
-                            local_lsn,
-                            pageidx,
-                        )
-                        .or_into_ctx()
-                    } else {
-                        Ok(EMPTY_PAGE)
-                    }
-                }
-            }
-        } else {
-            Ok(EMPTY_PAGE)
+        match read_local(
+            &self.storage,
+            oracle,
+            self.vid(),
+            self.snapshot(),
+            self.page_size,
+            pageidx,
+        )? {
+            LocalRead::Page(page) => Ok(page),
+            LocalRead::Fetch { remote_lsn, local_lsn } => fetch_page(
+                &self.clients,
+                &self.storage,
+                oracle,
+                self.vid(),
+                self.page_size,
+                remote_lsn,
+                local_lsn,
+                pageidx,
+            )
+            .or_into_ctx(),
         }
     }
 
     fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
-        if let Some(snapshot) = self.snapshot() {
-            match self
-                .storage
-                .read(self.vid(), snapshot.local(), pageidx)
-                .or_into_ctx()?
-            {
-                (lsn, PageValue::Available(_)) => Ok(PageStatus::Available(lsn)),
-                (lsn, PageValue::Empty) => Ok(PageStatus::Empty(Some(lsn))),
-                (_, PageValue::Pending) => Ok(PageStatus::Pending),
+        page_status(&self.storage, self.vid(), self.snapshot(), pageidx)
+    }
+}
+
+/// Retrieve a page's status from local storage at the provided snapshot
+pub(crate) fn page_status(
+    storage: &Storage,
+    vid: &VolumeId,
+    snapshot: Option<&Snapshot>,
+    pageidx: PageIdx,
+) -> Result<PageStatus, ClientErr> {
+    if let Some(snapshot) = snapshot {
+        match storage.read(vid, snapshot.local(), pageidx).or_into_ctx()? {
+            (lsn, PageValue::Available(_)) => Ok(PageStatus::Available(lsn)),
+            (lsn, PageValue::Empty) => Ok(PageStatus::Empty(Some(lsn))),
+            (_, PageValue::Pending) => Ok(PageStatus::Pending),
+        }
+    } else {
+        Ok(PageStatus::Empty(None))
+    }
+}
+
+/// The outcome of reading a page from local storage
+pub(crate) enum LocalRead {
+    Page(Page),
+
+    /// The page is pending and must be fetched from the remote
+    Fetch {
+        remote_lsn: LSN,
+        local_lsn: LSN,
+    },
+}
+
+/// Read a page from local storage at the provided snapshot
+pub(crate) fn read_local<O: Oracle>(
+    storage: &Storage,
+    oracle: &mut O,
+    vid: &VolumeId,
+    snapshot: Option<&Snapshot>,
+    page_size: PageSize,
+    pageidx: PageIdx,
+) -> Result<LocalRead, ClientErr> {
+    let Some(snapshot) = snapshot else {
+        return Ok(LocalRead::Page(Page::empty(page_size)));
+    };
+    match storage.read(vid, snapshot.local(), pageidx).or_into_ctx()? {
+        (_, PageValue::Available(page)) => {
+            oracle.observe_cache_hit(pageidx);
+            Ok(LocalRead::Page(page))
+        }
+        (_, PageValue::Empty) => {
+            oracle.observe_cache_hit(pageidx);
+            Ok(LocalRead::Page(Page::empty(page_size)))
+        }
+        (_, PageValue::Pending) => {
+            if let Some((remote_lsn, local_lsn)) = snapshot.remote_mapping().splat() {
+                Ok(LocalRead::Fetch { remote_lsn, local_lsn })
+            } else {
+                Ok(LocalRead::Page(Page::empty(page_size)))
             }
-        } else {
-            Ok(PageStatus::Empty(None))
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn fetch_page<O: Oracle>(
     clients: &ClientPair,
     storage: &Storage,
     oracle: &mut O,
     vid: &VolumeId,
+    page_size: PageSize,
     remote_lsn: LSN,
     local_lsn: LSN,
     pageidx: PageIdx,
@@ -147,51 +282,106 @@ fn fetch_page<O: Oracle>(
     )
     .entered();
 
-    // predict future page fetches using the oracle, then eliminate pages we
-    // have already fetched while building our update hashmap.
-    let mut graft = Splinter::default();
-    let mut pages = HashMap::new();
-    for idx in once(pageidx).chain(oracle.predict_next(pageidx)) {
-        let (lsn, page) = storage.read(vid, local_lsn, idx).or_into_ctx()?;
-        if matches!(page, PageValue::Pending) {
-            graft.insert(idx.to_u32());
-            pages.insert(idx, (lsn, PageValue::Empty));
+    let fetch = PageFetch::prepare(storage, oracle, vid, page_size, local_lsn, pageidx)?;
+    span.record("num_pages", fetch.len());
+
+    let response = clients
+        .pagestore()
+        .read_pages(vid, remote_lsn, fetch.graft())?;
+    fetch.complete(storage, vid, remote_lsn, response)
+}
+
+/// A batch of pages to fetch from the pagestore in order to read a page.
+pub(crate) struct PageFetch {
+    pageidx: PageIdx,
+    page_size: PageSize,
+    graft: Splinter,
+    pages: HashMap<PageIdx, (LSN, PageValue)>,
+}
+
+impl PageFetch {
+    /// predict future page fetches using the oracle, then eliminate pages we
+    /// have already fetched while building our update hashmap.
+    pub(crate) fn prepare<O: Oracle>(
+        storage: &Storage,
+        oracle: &mut O,
+        vid: &VolumeId,
+        page_size: PageSize,
+        local_lsn: LSN,
+        pageidx: PageIdx,
+    ) -> Result<Self, ClientErr> {
+        let mut graft = Splinter::default();
+        let mut pages = HashMap::new();
+        for idx in once(pageidx).chain(oracle.predict_next(pageidx)) {
+            let (lsn, page) = storage.read(vid, local_lsn, idx).or_into_ctx()?;
+            if matches!(page, PageValue::Pending) {
+                graft.insert(idx.to_u32());
+                pages.insert(idx, (lsn, PageValue::Empty));
+            }
         }
+        Ok(Self { pageidx, page_size, graft, pages })
+    }
+
+    /// the number of pages to fetch
+    pub(crate) fn len(&self) -> usize {
+        self.pages.len()
     }
 
-    span.record("num_pages", pages.len());
+    /// the serialized set of pages to fetch
+    pub(crate) fn graft(&self) -> Bytes {
+        self.graft.serialize_to_bytes()
+    }
 
-    // process client results and update the hashmap
-    let response = clients
-        .pagestore()
-        .read_pages(vid, remote_lsn, graft.serialize_to_bytes())?;
-    for page in response {
-        if let Some(entry) = pages.get_mut(&page.pageidx().or_into_ctx()?) {
-            entry.1 = page.page().or_into_ctx()?.into();
-        } else {
-            tracing::warn!(?vid, %remote_lsn, pageidx=page.pageidx, "unexpected page");
-            precept::expect_unreachable!(
-                "received unexpected page from pagestore",
-                {
-                    "vid": vid,
-                    "remote_lsn": remote_lsn,
-                    "pageidx": page.pageidx,
+    /// apply the pagestore response to local storage, returning the
+    /// requested page
+    pub(crate) fn complete(
+        mut self,
+        storage: &Storage,
+        vid: &VolumeId,
+        remote_lsn: LSN,
+        response: Vec<PageAtIdx>,
+    ) -> Result<Page, ClientErr> {
+        // process client results and update the hashmap
+        for page in response {
+            if let Some(entry) = self.pages.get_mut(&page.pageidx().or_into_ctx()?) {
+                let data = page.page().or_into_ctx()?;
+                if data.size() != self.page_size {
+                    return Err(Culprit::new_with_note(
+                        StorageErr::PageSizeMismatch.into(),
+                        format!(
+                            "received page with size {}; Volume {vid} has page size {}",
+                            data.size(),
+                            self.page_size
+                        ),
+                    ));
                 }
-            );
+                entry.1 = data.into();
+            } else {
+                tracing::warn!(?vid, %remote_lsn, pageidx=page.pageidx, "unexpected page");
+                precept::expect_unreachable!(
+                    "received unexpected page from pagestore",
+                    {
+                        "vid": vid,
+                        "remote_lsn": remote_lsn,
+                        "pageidx": page.pageidx,
+                    }
+                );
+            }
         }
-    }
 
-    let requested_page = pages
-        .get(&pageidx)
-        .cloned()
-        .and_then(|(_, p)| p.try_into_page())
-        .expect("requested page not found");
+        let requested_page = self
+            .pages
+            .get(&self.pageidx)
+            .cloned()
+            .and_then(|(_, p)| p.try_into_page(self.page_size))
+            .expect("requested page not found");
 
-    // update local storage with fetched pages
-    storage.receive_pages(vid, pages).or_into_ctx()?;
+        // update local storage with fetched pages
+        storage.receive_pages(vid, self.pages).or_into_ctx()?;
 
-    // return the requested page
-    Ok(requested_page)
+        // return the requested page
+        Ok(requested_page)
+    }
 }
 
 pub enum VolumeReadRef<'a> {
@@ -214,6 +404,13 @@ impl VolumeRead for VolumeReadRef<'_> {
         }
     }
 
+    fn page_size(&self) -> PageSize {
+        match self {
+            VolumeReadRef::Reader(reader) => reader.page_size(),
+            VolumeReadRef::Writer(writer) => writer.page_size(),
+        }
+    }
+
     fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
         match self {
             VolumeReadRef::Reader(reader) => reader.read(oracle, pageidx),
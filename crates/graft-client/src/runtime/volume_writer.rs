@@ -1,11 +1,17 @@
-use culprit::{Result, ResultExt};
-use graft_core::{PageIdx, VolumeId, page::Page, page_count::PageCount};
+use bytes::BytesMut;
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{
+    PageIdx, VolumeId,
+    page::{Page, PageSize},
+    page_count::PageCount,
+};
+use graft_proto::common::v1::CommitMetadata;
 
 use crate::{ClientErr, oracle::Oracle};
 
 use super::{
     storage::{memtable::Memtable, page::PageStatus, snapshot::Snapshot},
-    volume_reader::{VolumeRead, VolumeReader},
+    volume_reader::{PageSpan, VolumeRead, VolumeReader},
 };
 
 pub trait VolumeWrite {
@@ -18,21 +24,130 @@ pub trait VolumeWrite {
     /// This can be used to increase or decrease the Volume's size.
     fn truncate(&mut self, pages: PageCount);
 
+    /// Write `data` starting at the byte `offset` into the volume.
+    /// The range may span multiple pages. Pages which are only partially
+    /// covered are first read (and fetched if needed) so that the bytes
+    /// outside of the range are preserved. The volume grows to include every
+    /// page touched by the write.
+    fn write_at<O: Oracle>(
+        &mut self,
+        oracle: &mut O,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<(), ClientErr>
+    where
+        Self: VolumeRead,
+    {
+        let page_size = self.page_size();
+        for span in PageSpan::iter(page_size, offset, data.len()) {
+            let span = span?;
+            let page = if span.is_full_page(page_size) {
+                Page::try_from(&data[span.buf]).or_into_ctx()?
+            } else {
+                let mut page: BytesMut = self.read(oracle, span.pageidx)?.into();
+                page[span.page].copy_from_slice(&data[span.buf]);
+                Page::try_from(page).or_into_ctx()?
+            };
+            self.write(span.pageidx, page);
+        }
+        Ok(())
+    }
+
     /// Commit the transaction
     fn commit(self) -> Result<Self::CommitOutput, ClientErr>;
 }
 
+/// A handle to a savepoint created by [`VolumeWriter::savepoint`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Savepoint {
+    id: u64,
+}
+
+/// A stack of nested savepoints, each of which records the state of a writer
+/// at the time the savepoint was created
+#[derive(Debug, Default)]
+pub(crate) struct Savepoints {
+    next_id: u64,
+    stack: Vec<(Savepoint, PageCount, Memtable)>,
+}
+
+impl Savepoints {
+    pub fn push(&mut self, pages: PageCount, memtable: &Memtable) -> Savepoint {
+        let sp = Savepoint { id: self.next_id };
+        self.next_id += 1;
+        self.stack.push((sp, pages, memtable.clone()));
+        sp
+    }
+
+    fn position(&self, sp: Savepoint) -> Result<usize, ClientErr> {
+        self.stack
+            .iter()
+            .rposition(|(s, _, _)| *s == sp)
+            .ok_or_else(|| Culprit::new(ClientErr::InvalidSavepoint))
+    }
+
+    /// Restore the state recorded by `sp`, discarding all newer savepoints.
+    /// `sp` remains active and may be rolled back to again.
+    pub fn rollback_to(
+        &mut self,
+        sp: Savepoint,
+        pages: &mut PageCount,
+        memtable: &mut Memtable,
+    ) -> Result<(), ClientErr> {
+        let pos = self.position(sp)?;
+        self.stack.truncate(pos + 1);
+        let (_, saved_pages, saved_memtable) = &self.stack[pos];
+        *pages = *saved_pages;
+        *memtable = saved_memtable.clone();
+        Ok(())
+    }
+
+    /// Forget `sp` and all newer savepoints while keeping their changes
+    pub fn release(&mut self, sp: Savepoint) -> Result<(), ClientErr> {
+        let pos = self.position(sp)?;
+        self.stack.truncate(pos);
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct VolumeWriter {
     pages: PageCount,
     reader: VolumeReader,
     memtable: Memtable,
+    savepoints: Savepoints,
+    metadata: Option<CommitMetadata>,
 }
 
 impl VolumeWriter {
     pub fn pages(&self) -> PageCount {
         self.pages
     }
+
+    /// Create a savepoint capturing all writes and truncations made so far.
+    /// Savepoints may be nested.
+    pub fn savepoint(&mut self) -> Savepoint {
+        self.savepoints.push(self.pages, &self.memtable)
+    }
+
+    /// Undo all writes and truncations made since `sp` was created. Any
+    /// savepoints created after `sp` are released, while `sp` itself remains
+    /// active.
+    pub fn rollback_to(&mut self, sp: Savepoint) -> Result<(), ClientErr> {
+        self.savepoints
+            .rollback_to(sp, &mut self.pages, &mut self.memtable)
+    }
+
+    /// Release `sp` and all savepoints created after it, keeping their changes
+    pub fn release(&mut self, sp: Savepoint) -> Result<(), ClientErr> {
+        self.savepoints.release(sp)
+    }
+
+    /// Attach metadata to the commit. The metadata is pushed to the remote
+    /// along with the commit, and is ignored if the commit has no changes.
+    pub fn set_commit_metadata(&mut self, metadata: CommitMetadata) {
+        self.metadata = Some(metadata);
+    }
 }
 
 impl From<VolumeReader> for VolumeWriter {
@@ -42,6 +157,8 @@ impl From<VolumeReader> for VolumeWriter {
             pages,
             reader,
             memtable: Default::default(),
+            savepoints: Default::default(),
+            metadata: None,
         }
     }
 }
@@ -58,6 +175,11 @@ impl VolumeRead for VolumeWriter {
         self.reader.snapshot()
     }
 
+    #[inline]
+    fn page_size(&self) -> PageSize {
+        self.reader.page_size()
+    }
+
     /// Read a page; supports read your own writes (RYOW)
     fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
         if let Some(page) = self.memtable.get(pageidx) {
@@ -90,19 +212,27 @@ impl VolumeWrite for VolumeWriter {
     }
 
     fn commit(self) -> Result<VolumeReader, ClientErr> {
-        let (vid, snapshot, clients, storage) = self.reader.into_parts();
+        let (vid, page_size, snapshot, clients, storage) = self.reader.into_parts();
 
         // we have nothing to commit if the page count is equal to the snapshot
         // pagecount *and* the memtable is empty
         let snapshot_pagecount = snapshot.as_ref().map_or(PageCount::ZERO, |s| s.pages());
         let memtable_empty = self.memtable.is_empty();
         if self.pages == snapshot_pagecount && memtable_empty {
-            return Ok(VolumeReader::new(vid, snapshot, clients, storage));
+            return Ok(VolumeReader::new(
+                vid, page_size, snapshot, clients, storage,
+            ));
         }
 
         let snapshot = storage
-            .commit(&vid, snapshot, self.pages, self.memtable)
+            .commit(&vid, snapshot, self.pages, self.memtable, self.metadata)
             .or_into_ctx()?;
-        Ok(VolumeReader::new(vid, Some(snapshot), clients, storage))
+        Ok(VolumeReader::new(
+            vid,
+            page_size,
+            Some(snapshot),
+            clients,
+            storage,
+        ))
     }
 }
@@ -82,6 +82,21 @@ impl<P: Prefix> Gid<P> {
     }
 }
 
+impl SegmentId {
+    /// The empty segment is a virtual segment containing an empty version of
+    /// every page. Commits reference it to clear pages without writing empty
+    /// pages to a real segment. It's never stored.
+    pub const EMPTY: SegmentId = Gid {
+        prefix: prefix::Segment::Value,
+        ts: GidTimestamp::ZERO,
+        random: [0; 9],
+    };
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::EMPTY
+    }
+}
+
 impl ClientId {
     /// derive a `ClientId` from source bytes deterministically
     pub fn derive(source: &[u8]) -> ClientId {
@@ -6,6 +6,8 @@ pub mod lsn;
 pub mod page;
 pub mod page_count;
 pub mod page_idx;
+pub mod tag_name;
+pub mod volume_name;
 pub mod zerocopy_ext;
 
 pub use gid::{ClientId, SegmentId, VolumeId};
@@ -1,24 +1,166 @@
-use std::{fmt::Debug, ops::Deref};
+use std::{
+    fmt::{Debug, Display},
+    ops::Deref,
+};
 
 use bytes::{Bytes, BytesMut};
 use culprit::Culprit;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use zerocopy::{Immutable, IntoBytes, KnownLayout, TryFromBytes, Unaligned};
 
 use crate::byte_unit::ByteUnit;
 
+/// The default page size, used by every Volume which doesn't pick a different
+/// page size when it's created.
 pub const PAGESIZE: ByteUnit = ByteUnit::from_kb(4);
 static_assertions::const_assert!(PAGESIZE.is_power_of_two());
 
-static STATIC_EMPTY_PAGE: [u8; PAGESIZE.as_usize()] = [0; PAGESIZE.as_usize()];
-pub const EMPTY_PAGE: Page = Page(Bytes::from_static(&STATIC_EMPTY_PAGE));
+/// The largest supported page size
+const MAX_PAGESIZE: ByteUnit = ByteUnit::from_kb(64);
+
+static STATIC_EMPTY_PAGE: [u8; MAX_PAGESIZE.as_usize()] = [0; MAX_PAGESIZE.as_usize()];
+pub const EMPTY_PAGE: Page = Page(Bytes::from_static(
+    STATIC_EMPTY_PAGE.split_at(PAGESIZE.as_usize()).0,
+));
+
+/// The size of every page in a Volume. A Volume's page size is chosen when the
+/// Volume is created and never changes. Each variant stores log2 of the size.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    KnownLayout,
+    Immutable,
+    TryFromBytes,
+    IntoBytes,
+    Unaligned,
+    Serialize,
+    Deserialize,
+)]
+#[repr(u8)]
+#[serde(try_from = "u32", into = "u32")]
+pub enum PageSize {
+    #[default]
+    Kb4 = 12,
+    Kb8 = 13,
+    Kb16 = 14,
+    Kb32 = 15,
+    Kb64 = 16,
+}
+
+static_assertions::const_assert_eq!(1 << (PageSize::Kb4 as u8), PAGESIZE.as_usize());
+static_assertions::const_assert_eq!(1 << (PageSize::Kb64 as u8), MAX_PAGESIZE.as_usize());
+
+impl PageSize {
+    pub const DEFAULT: Self = PageSize::Kb4;
+
+    #[inline]
+    pub const fn as_byte_unit(self) -> ByteUnit {
+        ByteUnit::new(1 << (self as u8))
+    }
+
+    #[inline]
+    pub const fn as_usize(self) -> usize {
+        1 << (self as u8)
+    }
+
+    #[inline]
+    pub const fn as_u32(self) -> u32 {
+        1 << (self as u8)
+    }
+
+    #[inline]
+    pub const fn as_u64(self) -> u64 {
+        1 << (self as u8)
+    }
+
+    #[inline]
+    pub fn is_default(self) -> bool {
+        self == Self::DEFAULT
+    }
+}
+
+impl Display for PageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.as_byte_unit(), f)
+    }
+}
+
+impl TryFrom<usize> for PageSize {
+    type Error = Culprit<PageSizeErr>;
+
+    fn try_from(size: usize) -> Result<Self, Self::Error> {
+        match size {
+            0x1000 => Ok(PageSize::Kb4),
+            0x2000 => Ok(PageSize::Kb8),
+            0x4000 => Ok(PageSize::Kb16),
+            0x8000 => Ok(PageSize::Kb32),
+            0x10000 => Ok(PageSize::Kb64),
+            _ => {
+                let size = ByteUnit::new(size as u64);
+                Err(Culprit::new_with_note(
+                    PageSizeErr,
+                    format!("invalid page size {size}"),
+                ))
+            }
+        }
+    }
+}
+
+impl TryFrom<u32> for PageSize {
+    type Error = Culprit<PageSizeErr>;
+
+    #[inline]
+    fn try_from(size: u32) -> Result<Self, Self::Error> {
+        PageSize::try_from(size as usize)
+    }
+}
+
+impl TryFrom<ByteUnit> for PageSize {
+    type Error = Culprit<PageSizeErr>;
+
+    #[inline]
+    fn try_from(size: ByteUnit) -> Result<Self, Self::Error> {
+        PageSize::try_from(size.as_usize())
+    }
+}
+
+impl From<PageSize> for u32 {
+    #[inline]
+    fn from(size: PageSize) -> Self {
+        size.as_u32()
+    }
+}
+
+impl From<PageSize> for ByteUnit {
+    #[inline]
+    fn from(size: PageSize) -> Self {
+        size.as_byte_unit()
+    }
+}
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Page(Bytes);
 
 impl Page {
+    /// Returns an empty page of the provided size
+    pub fn empty(size: PageSize) -> Self {
+        Page(Bytes::from_static(&STATIC_EMPTY_PAGE[..size.as_usize()]))
+    }
+
     /// Returns true if all of the page's bytes are 0.
     pub fn is_empty(&self) -> bool {
-        self.0.as_ref() == STATIC_EMPTY_PAGE
+        self.0.as_ref() == &STATIC_EMPTY_PAGE[..self.0.len()]
+    }
+
+    /// Returns the size of this page
+    pub fn size(&self) -> PageSize {
+        PageSize::try_from(self.0.len()).expect("page has a valid size")
     }
 }
 
@@ -37,7 +179,7 @@ impl AsRef<[u8]> for Page {
 }
 
 #[derive(Debug, Error)]
-#[error("Pages must have size {PAGESIZE}")]
+#[error("Pages must be a power of two between {PAGESIZE} and {MAX_PAGESIZE}")]
 pub struct PageSizeErr;
 
 impl From<&[u8; PAGESIZE.as_usize()]> for Page {
@@ -70,14 +212,7 @@ impl TryFrom<Bytes> for Page {
     type Error = Culprit<PageSizeErr>;
 
     fn try_from(value: Bytes) -> Result<Self, Self::Error> {
-        if value.len() != PAGESIZE.as_usize() {
-            let size = ByteUnit::new(value.len() as u64);
-            return Err(Culprit::new_with_note(
-                PageSizeErr,
-                format!("invalid page size {size}"),
-            ));
-        }
-
+        PageSize::try_from(value.len())?;
         Ok(Page(value))
     }
 }
@@ -86,20 +221,46 @@ impl TryFrom<&[u8]> for Page {
     type Error = Culprit<PageSizeErr>;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        if value.len() != PAGESIZE.as_usize() {
-            let size = ByteUnit::new(value.len() as u64);
-            return Err(Culprit::new_with_note(
-                PageSizeErr,
-                format!("invalid page size {size}"),
-            ));
-        }
-
+        PageSize::try_from(value.len())?;
         Ok(Page(Bytes::copy_from_slice(value)))
     }
 }
 
 impl Debug for Page {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Page({PAGESIZE})")
+        write!(f, "Page({})", self.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_page_sizes() {
+        for size in [
+            PageSize::Kb4,
+            PageSize::Kb8,
+            PageSize::Kb16,
+            PageSize::Kb32,
+            PageSize::Kb64,
+        ] {
+            assert_eq!(PageSize::try_from(size.as_u32()).unwrap(), size);
+
+            let page = Page::empty(size);
+            assert_eq!(page.len(), size.as_usize());
+            assert_eq!(page.size(), size);
+            assert!(page.is_empty());
+
+            let page = Page::try_from(vec![1; size.as_usize()].as_slice()).unwrap();
+            assert_eq!(page.size(), size);
+            assert!(!page.is_empty());
+        }
+        assert_eq!(Page::empty(PageSize::DEFAULT), EMPTY_PAGE);
+
+        for size in [0, 512, 4095, 4097, 12288, 128 * 1024] {
+            assert!(PageSize::try_from(size as u32).is_err());
+            assert!(Page::try_from(Bytes::from(vec![0; size])).is_err());
+        }
     }
 }
@@ -92,6 +92,7 @@ impl PageCount {
         pageidx.to_u32() <= self.0
     }
 
+    /// The size of this many pages, assuming the default page size
     #[inline]
     pub fn size(self) -> ByteUnit {
         PAGESIZE * self.0
@@ -286,7 +286,7 @@ impl PageIdxRangeExt for std::ops::RangeInclusive<PageIdx> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{PageCount, PageIdx, page_idx::PageIdxRangeExt, pageidx};
+    use crate::{PageCount, PageIdx, page_idx::PageIdxRangeExt};
 
     #[test]
     fn test_page_idx_iter() {
@@ -0,0 +1,101 @@
+//! Named tags for Volume LSNs.
+//!
+//! A [`TagName`] such as `release-2026-10` points at a single LSN within a
+//! Volume, similar to a git tag. Tags are stored by the metastore and may be
+//! used in place of an LSN when requesting a snapshot or reading pages.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+/// The maximum length of a tag name in bytes
+pub const MAX_TAG_LEN: usize = 128;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TagNameErr {
+    #[error("tag names must not be empty")]
+    Empty,
+
+    #[error("tag names must be at most {MAX_TAG_LEN} bytes")]
+    TooLong,
+
+    #[error("tag names must not start with a '.'")]
+    LeadingDot,
+
+    #[error("invalid character {0:?}; only ASCII letters, digits, '.', '_', and '-' are allowed")]
+    InvalidChar(char),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TagName(String);
+
+impl TagName {
+    pub fn new(name: impl Into<String>) -> Result<Self, TagNameErr> {
+        let name = name.into();
+        if name.is_empty() {
+            return Err(TagNameErr::Empty);
+        }
+        if name.len() > MAX_TAG_LEN {
+            return Err(TagNameErr::TooLong);
+        }
+        // tags are used as object store path segments, which disallow "." and ".."
+        if name.starts_with('.') {
+            return Err(TagNameErr::LeadingDot);
+        }
+        if let Some(c) = name
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+        {
+            return Err(TagNameErr::InvalidChar(c));
+        }
+        Ok(Self(name))
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for TagName {
+    type Err = TagNameErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for TagName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_tag_name() {
+        let tag: TagName = "release-2026-10".parse().unwrap();
+        assert_eq!(tag.as_str(), "release-2026-10");
+        assert_eq!(tag.to_string().parse::<TagName>().unwrap(), tag);
+
+        for (input, err) in [
+            ("", TagNameErr::Empty),
+            (".", TagNameErr::LeadingDot),
+            ("..", TagNameErr::LeadingDot),
+            ("v1/v2", TagNameErr::InvalidChar('/')),
+            ("my tag", TagNameErr::InvalidChar(' ')),
+        ] {
+            assert_eq!(input.parse::<TagName>().unwrap_err(), err, "{input}");
+        }
+        assert_eq!(
+            TagName::new("x".repeat(MAX_TAG_LEN + 1)).unwrap_err(),
+            TagNameErr::TooLong
+        );
+    }
+}
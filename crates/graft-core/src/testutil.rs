@@ -9,7 +9,7 @@ use rand::{
 };
 
 use crate::{
-    page::{PAGESIZE, Page},
+    page::{PAGESIZE, Page, PageSize},
     page_idx::PageIdx,
 };
 
@@ -17,6 +17,10 @@ impl Page {
     pub fn test_filled(value: u8) -> Self {
         Page::from(&[value; PAGESIZE.as_usize()])
     }
+
+    pub fn test_filled_sized(value: u8, size: PageSize) -> Self {
+        Page::try_from(vec![value; size.as_usize()].as_slice()).unwrap()
+    }
 }
 
 impl Distribution<Page> for StandardUniform {
@@ -0,0 +1,142 @@
+//! Human-readable names for Volumes.
+//!
+//! A [`VolumeName`] maps to exactly one [`VolumeId`](crate::VolumeId) via the
+//! metastore's name registry. Names are scoped to a namespace, which allows
+//! unrelated applications to share a metastore without colliding.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+/// The namespace used when a name is parsed without one
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+/// The maximum length of a namespace or name in bytes
+pub const MAX_NAME_LEN: usize = 128;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum VolumeNameErr {
+    #[error("volume names and namespaces must not be empty")]
+    Empty,
+
+    #[error("volume names and namespaces must be at most {MAX_NAME_LEN} bytes")]
+    TooLong,
+
+    #[error("volume names and namespaces must not start with a '.'")]
+    LeadingDot,
+
+    #[error("invalid character {0:?}; only ASCII letters, digits, '.', '_', and '-' are allowed")]
+    InvalidChar(char),
+}
+
+/// A Volume name along with the namespace it belongs to.
+///
+/// The string form is `namespace/name`; when parsing, the namespace may be
+/// omitted in which case [`DEFAULT_NAMESPACE`] is used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VolumeName {
+    namespace: String,
+    name: String,
+}
+
+impl VolumeName {
+    pub fn new(
+        namespace: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Result<Self, VolumeNameErr> {
+        let namespace = namespace.into();
+        let name = name.into();
+        validate(&namespace)?;
+        validate(&name)?;
+        Ok(Self { namespace, name })
+    }
+
+    /// Create a name in the default namespace
+    pub fn new_default(name: impl Into<String>) -> Result<Self, VolumeNameErr> {
+        Self::new(DEFAULT_NAMESPACE, name)
+    }
+
+    #[inline]
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn validate(part: &str) -> Result<(), VolumeNameErr> {
+    if part.is_empty() {
+        return Err(VolumeNameErr::Empty);
+    }
+    if part.len() > MAX_NAME_LEN {
+        return Err(VolumeNameErr::TooLong);
+    }
+    // names are used as object store path segments, which disallow "." and ".."
+    if part.starts_with('.') {
+        return Err(VolumeNameErr::LeadingDot);
+    }
+    if let Some(c) = part
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+    {
+        return Err(VolumeNameErr::InvalidChar(c));
+    }
+    Ok(())
+}
+
+impl FromStr for VolumeName {
+    type Err = VolumeNameErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((namespace, name)) => Self::new(namespace, name),
+            None => Self::new_default(s),
+        }
+    }
+}
+
+impl Display for VolumeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.namespace, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_volume_name() {
+        let name: VolumeName = "customers.db".parse().unwrap();
+        assert_eq!(name.namespace(), DEFAULT_NAMESPACE);
+        assert_eq!(name.name(), "customers.db");
+        assert_eq!(name.to_string(), "default/customers.db");
+
+        let name: VolumeName = "acme/orders_2024-01".parse().unwrap();
+        assert_eq!(name.namespace(), "acme");
+        assert_eq!(name.name(), "orders_2024-01");
+        assert_eq!(name.to_string().parse::<VolumeName>().unwrap(), name);
+
+        for (input, err) in [
+            ("", VolumeNameErr::Empty),
+            ("acme/", VolumeNameErr::Empty),
+            ("/orders", VolumeNameErr::Empty),
+            ("..", VolumeNameErr::LeadingDot),
+            ("acme/.hidden", VolumeNameErr::LeadingDot),
+            ("a/b/c", VolumeNameErr::InvalidChar('/')),
+            ("hello world", VolumeNameErr::InvalidChar(' ')),
+        ] {
+            assert_eq!(input.parse::<VolumeName>().unwrap_err(), err, "{input}");
+        }
+        assert_eq!(
+            VolumeName::new_default("x".repeat(MAX_NAME_LEN + 1)).unwrap_err(),
+            VolumeNameErr::TooLong
+        );
+    }
+}
@@ -0,0 +1,609 @@
+// @generated
+// This file is @generated by prost-build.
+/// A snapshot of a volume in the agent's local storage.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LocalSnapshot {
+    #[prost(uint64, tag="1")]
+    pub local: u64,
+    /// the last known remote LSN along with the local LSN it maps to
+    #[prost(uint64, optional, tag="2")]
+    pub remote: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="3")]
+    pub remote_local: ::core::option::Option<u64>,
+    #[prost(uint32, tag="4")]
+    pub pages: u32,
+}
+/// Open a volume, creating it if it doesn't exist locally. The sync direction
+/// and page size are only used to create the volume; volumes which already
+/// exist keep their config.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenVolumeRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(enumeration="SyncDirection", tag="2")]
+    pub sync: i32,
+    /// the size of each page in bytes, 0 means the default of 4 KiB
+    #[prost(uint32, tag="3")]
+    pub page_size: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OpenVolumeResponse {
+    /// the size of each page in the volume, 0 means the default of 4 KiB
+    #[prost(uint32, tag="1")]
+    pub page_size: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SnapshotResponse {
+    /// missing if the volume has no commits
+    #[prost(message, optional, tag="1")]
+    pub snapshot: ::core::option::Option<LocalSnapshot>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadPageRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(message, optional, tag="2")]
+    pub snapshot: ::core::option::Option<LocalSnapshot>,
+    #[prost(uint32, tag="3")]
+    pub pageidx: u32,
+    /// fetch the page from the pagestore if it's not available locally
+    #[prost(bool, tag="4")]
+    pub fetch: bool,
+    /// additional pages to fetch along with the requested page
+    #[prost(uint32, repeated, tag="5")]
+    pub prefetch: ::prost::alloc::vec::Vec<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadPageResponse {
+    /// missing if the page is not available locally and fetch was not requested
+    #[prost(bytes="bytes", optional, tag="1")]
+    pub page: ::core::option::Option<::prost::bytes::Bytes>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PageStatusRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(message, optional, tag="2")]
+    pub snapshot: ::core::option::Option<LocalSnapshot>,
+    #[prost(uint32, tag="3")]
+    pub pageidx: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PageStatusResponse {
+    /// the page is not available locally
+    #[prost(bool, tag="1")]
+    pub pending: bool,
+    /// the page is empty
+    #[prost(bool, tag="2")]
+    pub empty: bool,
+    /// the LSN at which the page last changed, if known
+    #[prost(uint64, optional, tag="3")]
+    pub lsn: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommitRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    /// the snapshot the commit is based on, missing if the volume is empty
+    #[prost(message, optional, tag="2")]
+    pub snapshot: ::core::option::Option<LocalSnapshot>,
+    #[prost(uint32, tag="3")]
+    pub page_count: u32,
+    #[prost(message, repeated, tag="4")]
+    pub pages: ::prost::alloc::vec::Vec<super::super::pagestore::v1::PageAtIdx>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CommitResponse {
+    #[prost(message, optional, tag="1")]
+    pub snapshot: ::core::option::Option<LocalSnapshot>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncWithRemoteRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(enumeration="SyncDirection", tag="2")]
+    pub direction: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SyncWithRemoteResponse {
+}
+/// Requests and responses are sent over the agent socket as protobuf messages
+/// prefixed with their length as a big endian u32.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AgentRequest {
+    #[prost(oneof="agent_request::Request", tags="1, 2, 3, 4, 5, 6")]
+    pub request: ::core::option::Option<agent_request::Request>,
+}
+/// Nested message and enum types in `AgentRequest`.
+pub mod agent_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Request {
+        #[prost(message, tag="1")]
+        OpenVolume(super::OpenVolumeRequest),
+        #[prost(message, tag="2")]
+        Snapshot(super::SnapshotRequest),
+        #[prost(message, tag="3")]
+        ReadPage(super::ReadPageRequest),
+        #[prost(message, tag="4")]
+        PageStatus(super::PageStatusRequest),
+        #[prost(message, tag="5")]
+        Commit(super::CommitRequest),
+        #[prost(message, tag="6")]
+        SyncWithRemote(super::SyncWithRemoteRequest),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AgentResponse {
+    #[prost(oneof="agent_response::Response", tags="1, 2, 3, 4, 5, 6, 7")]
+    pub response: ::core::option::Option<agent_response::Response>,
+}
+/// Nested message and enum types in `AgentResponse`.
+pub mod agent_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag="1")]
+        Err(super::super::super::common::v1::GraftErr),
+        #[prost(message, tag="2")]
+        OpenVolume(super::OpenVolumeResponse),
+        #[prost(message, tag="3")]
+        Snapshot(super::SnapshotResponse),
+        #[prost(message, tag="4")]
+        ReadPage(super::ReadPageResponse),
+        #[prost(message, tag="5")]
+        PageStatus(super::PageStatusResponse),
+        #[prost(message, tag="6")]
+        Commit(super::CommitResponse),
+        #[prost(message, tag="7")]
+        SyncWithRemote(super::SyncWithRemoteResponse),
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SyncDirection {
+    Unspecified = 0,
+    Disabled = 1,
+    Push = 2,
+    Pull = 3,
+    Both = 4,
+}
+impl SyncDirection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SyncDirection::Unspecified => "SYNC_DIRECTION_UNSPECIFIED",
+            SyncDirection::Disabled => "SYNC_DIRECTION_DISABLED",
+            SyncDirection::Push => "SYNC_DIRECTION_PUSH",
+            SyncDirection::Pull => "SYNC_DIRECTION_PULL",
+            SyncDirection::Both => "SYNC_DIRECTION_BOTH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SYNC_DIRECTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "SYNC_DIRECTION_DISABLED" => Some(Self::Disabled),
+            "SYNC_DIRECTION_PUSH" => Some(Self::Push),
+            "SYNC_DIRECTION_PULL" => Some(Self::Pull),
+            "SYNC_DIRECTION_BOTH" => Some(Self::Both),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `graft.agent.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xcc, 0x30, 0x0a, 0x1a, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2f, 0x61, 0x67, 0x65, 0x6e, 0x74,
+    0x2f, 0x76, 0x31, 0x2f, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12,
+    0x0e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x1a,
+    0x1c, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2f, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2f, 0x76, 0x31,
+    0x2f, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x22, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2f, 0x70, 0x61, 0x67, 0x65, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2f, 0x76,
+    0x31, 0x2f, 0x70, 0x61, 0x67, 0x65, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x22, 0x9c, 0x01, 0x0a, 0x0d, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x53, 0x6e, 0x61, 0x70, 0x73,
+    0x68, 0x6f, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x05, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x12, 0x1b, 0x0a, 0x06, 0x72, 0x65, 0x6d,
+    0x6f, 0x74, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x06, 0x72, 0x65, 0x6d,
+    0x6f, 0x74, 0x65, 0x88, 0x01, 0x01, 0x12, 0x26, 0x0a, 0x0c, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65,
+    0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x01, 0x52, 0x0b,
+    0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x88, 0x01, 0x01, 0x12, 0x14,
+    0x0a, 0x05, 0x70, 0x61, 0x67, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x70,
+    0x61, 0x67, 0x65, 0x73, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x42,
+    0x0f, 0x0a, 0x0d, 0x5f, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x6c,
+    0x22, 0x75, 0x0a, 0x11, 0x4f, 0x70, 0x65, 0x6e, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x31, 0x0a, 0x04, 0x73, 0x79, 0x6e, 0x63, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67,
+    0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x79, 0x6e, 0x63, 0x44, 0x69, 0x72, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x52, 0x04, 0x73, 0x79, 0x6e, 0x63, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61,
+    0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70,
+    0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x22, 0x14, 0x0a, 0x12, 0x4f, 0x70, 0x65, 0x6e, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x23, 0x0a,
+    0x0f, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76,
+    0x69, 0x64, 0x22, 0x4d, 0x0a, 0x10, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x39, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74,
+    0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x53,
+    0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
+    0x74, 0x22, 0xaa, 0x01, 0x0a, 0x0f, 0x52, 0x65, 0x61, 0x64, 0x50, 0x61, 0x67, 0x65, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x39, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73,
+    0x68, 0x6f, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x67, 0x72, 0x61, 0x66,
+    0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x63, 0x61, 0x6c,
+    0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x61, 0x67, 0x65, 0x69, 0x64, 0x78, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x07, 0x70, 0x61, 0x67, 0x65, 0x69, 0x64, 0x78, 0x12, 0x14, 0x0a, 0x05,
+    0x66, 0x65, 0x74, 0x63, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x65, 0x74,
+    0x63, 0x68, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x72, 0x65, 0x66, 0x65, 0x74, 0x63, 0x68, 0x18, 0x05,
+    0x20, 0x03, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x72, 0x65, 0x66, 0x65, 0x74, 0x63, 0x68, 0x22, 0x34,
+    0x0a, 0x10, 0x52, 0x65, 0x61, 0x64, 0x50, 0x61, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x17, 0x0a, 0x04, 0x70, 0x61, 0x67, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+    0x48, 0x00, 0x52, 0x04, 0x70, 0x61, 0x67, 0x65, 0x88, 0x01, 0x01, 0x42, 0x07, 0x0a, 0x05, 0x5f,
+    0x70, 0x61, 0x67, 0x65, 0x22, 0x7a, 0x0a, 0x11, 0x50, 0x61, 0x67, 0x65, 0x53, 0x74, 0x61, 0x74,
+    0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x39, 0x0a, 0x08, 0x73,
+    0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e,
+    0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c,
+    0x6f, 0x63, 0x61, 0x6c, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x61, 0x67, 0x65, 0x69, 0x64,
+    0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07, 0x70, 0x61, 0x67, 0x65, 0x69, 0x64, 0x78,
+    0x22, 0x63, 0x0a, 0x12, 0x50, 0x61, 0x67, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e,
+    0x67, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x12, 0x14, 0x0a, 0x05, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52,
+    0x05, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x12, 0x15, 0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x03, 0x6c, 0x73, 0x6e, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a,
+    0x04, 0x5f, 0x6c, 0x73, 0x6e, 0x22, 0xb0, 0x01, 0x0a, 0x0d, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x39, 0x0a, 0x08, 0x73, 0x6e, 0x61,
+    0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x67, 0x72,
+    0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x63,
+    0x61, 0x6c, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70,
+    0x73, 0x68, 0x6f, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x43, 0x6f,
+    0x75, 0x6e, 0x74, 0x12, 0x33, 0x0a, 0x05, 0x70, 0x61, 0x67, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x70, 0x61, 0x67, 0x65, 0x73,
+    0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x61, 0x67, 0x65, 0x41, 0x74, 0x49, 0x64,
+    0x78, 0x52, 0x05, 0x70, 0x61, 0x67, 0x65, 0x73, 0x22, 0x4b, 0x0a, 0x0e, 0x43, 0x6f, 0x6d, 0x6d,
+    0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x39, 0x0a, 0x08, 0x73, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f,
+    0x63, 0x61, 0x6c, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61,
+    0x70, 0x73, 0x68, 0x6f, 0x74, 0x22, 0x66, 0x0a, 0x15, 0x53, 0x79, 0x6e, 0x63, 0x57, 0x69, 0x74,
+    0x68, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10,
+    0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64,
+    0x12, 0x3b, 0x0a, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e,
+    0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x79, 0x6e, 0x63, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x52, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x18, 0x0a,
+    0x16, 0x53, 0x79, 0x6e, 0x63, 0x57, 0x69, 0x74, 0x68, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0xb0, 0x03, 0x0a, 0x0c, 0x41, 0x67, 0x65, 0x6e,
+    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x44, 0x0a, 0x0b, 0x6f, 0x70, 0x65, 0x6e,
+    0x5f, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
+    0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x4f,
+    0x70, 0x65, 0x6e, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x48, 0x00, 0x52, 0x0a, 0x6f, 0x70, 0x65, 0x6e, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x12, 0x3d,
+    0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x1f, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76,
+    0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x48, 0x00, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x12, 0x3e, 0x0a,
+    0x09, 0x72, 0x65, 0x61, 0x64, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x1f, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76,
+    0x31, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x61, 0x67, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x48, 0x00, 0x52, 0x08, 0x72, 0x65, 0x61, 0x64, 0x50, 0x61, 0x67, 0x65, 0x12, 0x44, 0x0a,
+    0x0b, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x21, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74,
+    0x2e, 0x76, 0x31, 0x2e, 0x50, 0x61, 0x67, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x53, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x12, 0x37, 0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x05, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e,
+    0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x48, 0x00, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x51, 0x0a, 0x10,
+    0x73, 0x79, 0x6e, 0x63, 0x5f, 0x77, 0x69, 0x74, 0x68, 0x5f, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61,
+    0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x79, 0x6e, 0x63, 0x57, 0x69, 0x74, 0x68,
+    0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52,
+    0x0e, 0x73, 0x79, 0x6e, 0x63, 0x57, 0x69, 0x74, 0x68, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x42,
+    0x09, 0x0a, 0x07, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0xe7, 0x03, 0x0a, 0x0d, 0x41,
+    0x67, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2d, 0x0a, 0x03,
+    0x65, 0x72, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66,
+    0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x72, 0x61, 0x66,
+    0x74, 0x45, 0x72, 0x72, 0x48, 0x00, 0x52, 0x03, 0x65, 0x72, 0x72, 0x12, 0x45, 0x0a, 0x0b, 0x6f,
+    0x70, 0x65, 0x6e, 0x5f, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x22, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76,
+    0x31, 0x2e, 0x4f, 0x70, 0x65, 0x6e, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x0a, 0x6f, 0x70, 0x65, 0x6e, 0x56, 0x6f, 0x6c, 0x75,
+    0x6d, 0x65, 0x12, 0x3e, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65,
+    0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x12, 0x3f, 0x0a, 0x09, 0x72, 0x65, 0x61, 0x64, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67,
+    0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x61, 0x64, 0x50, 0x61, 0x67, 0x65, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x08, 0x72, 0x65, 0x61, 0x64, 0x50,
+    0x61, 0x67, 0x65, 0x12, 0x45, 0x0a, 0x0b, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74,
+    0x75, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74,
+    0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x61, 0x67, 0x65, 0x53, 0x74,
+    0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x0a,
+    0x70, 0x61, 0x67, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x38, 0x0a, 0x06, 0x63, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x67, 0x72, 0x61,
+    0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d,
+    0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x06, 0x63, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x12, 0x52, 0x0a, 0x10, 0x73, 0x79, 0x6e, 0x63, 0x5f, 0x77, 0x69, 0x74,
+    0x68, 0x5f, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26,
+    0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x2e, 0x76, 0x31, 0x2e,
+    0x53, 0x79, 0x6e, 0x63, 0x57, 0x69, 0x74, 0x68, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x48, 0x00, 0x52, 0x0e, 0x73, 0x79, 0x6e, 0x63, 0x57, 0x69,
+    0x74, 0x68, 0x52, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x42, 0x0a, 0x0a, 0x08, 0x72, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x2a, 0x97, 0x01, 0x0a, 0x0d, 0x53, 0x79, 0x6e, 0x63, 0x44, 0x69, 0x72,
+    0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e, 0x0a, 0x1a, 0x53, 0x59, 0x4e, 0x43, 0x5f, 0x44,
+    0x49, 0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1b, 0x0a, 0x17, 0x53, 0x59, 0x4e, 0x43, 0x5f, 0x44,
+    0x49, 0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x44, 0x49, 0x53, 0x41, 0x42, 0x4c, 0x45,
+    0x44, 0x10, 0x01, 0x12, 0x17, 0x0a, 0x13, 0x53, 0x59, 0x4e, 0x43, 0x5f, 0x44, 0x49, 0x52, 0x45,
+    0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x50, 0x55, 0x53, 0x48, 0x10, 0x02, 0x12, 0x17, 0x0a, 0x13,
+    0x53, 0x59, 0x4e, 0x43, 0x5f, 0x44, 0x49, 0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x50,
+    0x55, 0x4c, 0x4c, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x53, 0x59, 0x4e, 0x43, 0x5f, 0x44, 0x49,
+    0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x42, 0x4f, 0x54, 0x48, 0x10, 0x04, 0x4a, 0xb6,
+    0x1d, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x6d, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
+    0x01, 0x00, 0x17, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x03, 0x00, 0x26, 0x0a, 0x09,
+    0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x04, 0x00, 0x2c, 0x0a, 0x42, 0x0a, 0x02, 0x04, 0x00, 0x12,
+    0x04, 0x0f, 0x00, 0x15, 0x01, 0x1a, 0x36, 0x20, 0x41, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x69,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x65, 0x6e, 0x74, 0x27, 0x73, 0x20, 0x6c, 0x6f,
+    0x63, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x03, 0x10, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x10, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10,
+    0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x02, 0x08,
+    0x0a, 0x4c, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x1d, 0x1a, 0x3f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x72,
+    0x65, 0x6d, 0x6f, 0x74, 0x65, 0x20, 0x4c, 0x53, 0x4e, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x4c,
+    0x53, 0x4e, 0x20, 0x69, 0x74, 0x20, 0x6d, 0x61, 0x70, 0x73, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x13, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x13, 0x12,
+    0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13, 0x21, 0x22, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x13, 0x0b, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x03, 0x12, 0x03, 0x14, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x14, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x14, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x14,
+    0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x17, 0x00, 0x1c, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x17, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x00, 0x12, 0x03, 0x18, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x18, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x18, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x18, 0x02,
+    0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x19, 0x02, 0x0f, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02,
+    0x12, 0x03, 0x1b, 0x02, 0x17, 0x1a, 0x3e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x7a, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x69, 0x6e,
+    0x20, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2c, 0x20, 0x30, 0x20, 0x6d, 0x65, 0x61, 0x6e, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x34,
+    0x20, 0x4b, 0x69, 0x42, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x1b, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x15,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1b, 0x02, 0x08, 0x0a,
+    0x09, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1e, 0x00, 0x1d, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02,
+    0x01, 0x12, 0x03, 0x1e, 0x08, 0x1a, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x20, 0x00,
+    0x2a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x20, 0x08, 0x17, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x20, 0x1a, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x20, 0x20, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x20, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x20, 0x1a, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x22, 0x00, 0x25, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x22, 0x08, 0x18, 0x0a, 0x33, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x1d, 0x1a, 0x26, 0x20, 0x6d, 0x69, 0x73, 0x73,
+    0x69, 0x6e, 0x67, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d,
+    0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x10, 0x18, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x24, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x24, 0x02, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0x27, 0x00, 0x2f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03,
+    0x27, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x08, 0x0b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x29, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x29, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x06, 0x12, 0x03, 0x29, 0x02,
+    0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x2a, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2a, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x08, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03,
+    0x12, 0x03, 0x2c, 0x02, 0x11, 0x1a, 0x41, 0x20, 0x66, 0x65, 0x74, 0x63, 0x68, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x61, 0x67, 0x65, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x27,
+    0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x6c, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x2c, 0x07, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x2c, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x2c,
+    0x02, 0x06, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x1f, 0x1a,
+    0x39, 0x20, 0x61, 0x64, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x70, 0x61, 0x67,
+    0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x66, 0x65, 0x74, 0x63, 0x68, 0x20, 0x61, 0x6c, 0x6f, 0x6e,
+    0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x65, 0x64, 0x20, 0x70, 0x61, 0x67, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x2e, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12,
+    0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x05, 0x12, 0x03, 0x2e,
+    0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x31, 0x00, 0x34, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x31, 0x08, 0x18, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x00, 0x12, 0x03, 0x33, 0x02, 0x1a, 0x1a, 0x4a, 0x20, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6e,
+    0x67, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x6c,
+    0x6f, 0x63, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x66, 0x65, 0x74, 0x63, 0x68,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x33, 0x11,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x33, 0x18, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x33, 0x0b, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x07, 0x12, 0x04, 0x36, 0x00, 0x3a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03,
+    0x36, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x37, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x37, 0x08, 0x0b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x37, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x01, 0x12, 0x03, 0x38, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x38, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x38, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x06, 0x12, 0x03, 0x38, 0x02,
+    0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x39, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x39, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x39, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x39, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04,
+    0x3c, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x3c, 0x08, 0x1a,
+    0x0a, 0x30, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02, 0x13, 0x1a, 0x23, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x6c,
+    0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x07, 0x0e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3e, 0x11, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x06, 0x0a, 0x20, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x40, 0x02, 0x11, 0x1a, 0x13, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x40, 0x07, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x40, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x40, 0x02, 0x06, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02,
+    0x12, 0x03, 0x42, 0x02, 0x1a, 0x1a, 0x32, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4c, 0x53, 0x4e, 0x20,
+    0x61, 0x74, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x67,
+    0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x2c, 0x20,
+    0x69, 0x66, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x42, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x42, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x42, 0x0b,
+    0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x45, 0x00, 0x4b, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x45, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02,
+    0x00, 0x12, 0x03, 0x46, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x46, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46,
+    0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x02, 0x07,
+    0x0a, 0x52, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x48, 0x02, 0x1d, 0x1a, 0x45, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x62, 0x61, 0x73, 0x65, 0x64,
+    0x20, 0x6f, 0x6e, 0x2c, 0x20, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x48,
+    0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x48, 0x1b, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12, 0x03, 0x48, 0x02, 0x0f, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x49, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x49, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x49, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x49, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x4a,
+    0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4a, 0x28, 0x2d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4a, 0x30, 0x31, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x04, 0x12, 0x03, 0x4a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4a, 0x0b, 0x27, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0a,
+    0x12, 0x03, 0x4d, 0x00, 0x36, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x4d, 0x08,
+    0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x4d, 0x19, 0x34, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4d, 0x27, 0x2f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4d, 0x32, 0x33, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x4d, 0x19, 0x26, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04,
+    0x4f, 0x00, 0x52, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x1d,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x50, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x50, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x50, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x50, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12,
+    0x03, 0x51, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x51,
+    0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x51, 0x1c, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x06, 0x12, 0x03, 0x51, 0x02, 0x0f, 0x0a, 0x09,
+    0x0a, 0x02, 0x04, 0x0c, 0x12, 0x03, 0x54, 0x00, 0x21, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01,
+    0x12, 0x03, 0x54, 0x08, 0x1e, 0x0a, 0x89, 0x01, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x58, 0x00,
+    0x61, 0x01, 0x1a, 0x7d, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x73, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67,
+    0x65, 0x6e, 0x74, 0x20, 0x73, 0x6f, 0x63, 0x6b, 0x65, 0x74, 0x20, 0x61, 0x73, 0x20, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x0a,
+    0x20, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74,
+    0x68, 0x65, 0x69, 0x72, 0x20, 0x6c, 0x65, 0x6e, 0x67, 0x74, 0x68, 0x20, 0x61, 0x73, 0x20, 0x61,
+    0x20, 0x62, 0x69, 0x67, 0x20, 0x65, 0x6e, 0x64, 0x69, 0x61, 0x6e, 0x20, 0x75, 0x33, 0x32, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x58, 0x08, 0x14, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x5a, 0x04, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x5a, 0x16, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x5a, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x5a, 0x04, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x03, 0x5b, 0x04,
+    0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5b, 0x14, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5b, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x01, 0x06, 0x12, 0x03, 0x5b, 0x04, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x02, 0x12, 0x03, 0x5c, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x5c, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x5c, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x06, 0x12, 0x03, 0x5c,
+    0x04, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x03, 0x5d, 0x04, 0x26, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12, 0x03, 0x5d, 0x16, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x03, 0x5d, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x03, 0x06, 0x12, 0x03, 0x5d, 0x04, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x04, 0x12, 0x03, 0x5e, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x5e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x03, 0x12, 0x03, 0x5e,
+    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x06, 0x12, 0x03, 0x5e, 0x04, 0x11,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x05, 0x12, 0x03, 0x5f, 0x04, 0x2f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5f, 0x1a, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x05, 0x03, 0x12, 0x03, 0x5f, 0x2d, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x05, 0x06, 0x12, 0x03, 0x5f, 0x04, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x08, 0x00, 0x12,
+    0x04, 0x59, 0x02, 0x60, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x08, 0x00, 0x01, 0x12, 0x03,
+    0x59, 0x08, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x63, 0x00, 0x6d, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x63, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0e, 0x02, 0x00, 0x12, 0x03, 0x65, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x65, 0x1d, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x65, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x06, 0x12, 0x03, 0x65,
+    0x04, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x03, 0x66, 0x04, 0x27, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x03, 0x66, 0x17, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x03, 0x66, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x01, 0x06, 0x12, 0x03, 0x66, 0x04, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02,
+    0x02, 0x12, 0x03, 0x67, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x67, 0x15, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x03, 0x67,
+    0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x06, 0x12, 0x03, 0x67, 0x04, 0x14,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12, 0x03, 0x68, 0x04, 0x23, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x03, 0x68, 0x15, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x03, 0x03, 0x12, 0x03, 0x68, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x03, 0x06, 0x12, 0x03, 0x68, 0x04, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12,
+    0x03, 0x69, 0x04, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x01, 0x12, 0x03, 0x69,
+    0x17, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x03, 0x12, 0x03, 0x69, 0x25, 0x26,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x06, 0x12, 0x03, 0x69, 0x04, 0x16, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0e, 0x02, 0x05, 0x12, 0x03, 0x6a, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x05, 0x01, 0x12, 0x03, 0x6a, 0x13, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x05, 0x03, 0x12, 0x03, 0x6a, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x06,
+    0x12, 0x03, 0x6a, 0x04, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x03, 0x6b,
+    0x04, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x03, 0x6b, 0x1b, 0x2b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x03, 0x6b, 0x2e, 0x2f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x06, 0x12, 0x03, 0x6b, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0e, 0x08, 0x00, 0x12, 0x04, 0x64, 0x02, 0x6c, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
+    0x08, 0x00, 0x01, 0x12, 0x03, 0x64, 0x08, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
+    0x06, 0x00, 0x0c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x06, 0x05, 0x12,
+    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x07, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x01, 0x12, 0x03, 0x08, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x08, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x08,
+    0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x09, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x09, 0x02, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x09, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x03, 0x12, 0x03, 0x0a, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x0a, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12,
+    0x03, 0x0a, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x0b, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x0b, 0x18, 0x19, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
@@ -9,12 +9,50 @@ pub struct SnapshotRequest {
     pub vid: ::prost::bytes::Bytes,
     #[prost(uint64, optional, tag="2")]
     pub lsn: ::core::option::Option<u64>,
+    /// retrieve the snapshot at the tagged LSN; lsn must be omitted if set
+    #[prost(string, tag="3")]
+    pub tag: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotResponse {
     #[prost(message, optional, tag="1")]
     pub snapshot: ::core::option::Option<super::super::common::v1::Snapshot>,
+    /// the labels cached by the metastore, which may lag behind updates made
+    /// through other metastores
+    #[prost(message, optional, tag="2")]
+    pub labels: ::core::option::Option<VolumeLabels>,
+}
+/// User defined key/value labels attached to a Volume
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeLabels {
+    #[prost(map="string, string", tag="1")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// incremented by each update; zero if the Volume has never been labeled
+    #[prost(uint64, tag="2")]
+    pub version: u64,
+}
+/// Records the Volume a forked Volume was created from along with the segments
+/// it inherited, which contain pages belonging to another Volume
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeFork {
+    #[prost(bytes="bytes", tag="1")]
+    pub source_vid: ::prost::bytes::Bytes,
+    #[prost(uint64, tag="2")]
+    pub source_lsn: u64,
+    #[prost(message, repeated, tag="3")]
+    pub segments: ::prost::alloc::vec::Vec<InheritedSegment>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InheritedSegment {
+    #[prost(bytes="bytes", tag="1")]
+    pub sid: ::prost::bytes::Bytes,
+    /// the Volume whose pages the segment contains
+    #[prost(bytes="bytes", tag="2")]
+    pub vid: ::prost::bytes::Bytes,
 }
 /// Retrieve the snapshot at the end of the given LSN range along with a Splinter
 /// containing all changed indices. If the start of the range is Unbounded, it
@@ -27,6 +65,10 @@ pub struct PullGraftRequest {
     pub vid: ::prost::bytes::Bytes,
     #[prost(message, optional, tag="2")]
     pub range: ::core::option::Option<super::super::common::v1::LsnRange>,
+    /// end the range at the tagged LSN; the range must be unbounded at the end
+    /// if set
+    #[prost(string, tag="3")]
+    pub tag: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -71,6 +113,13 @@ pub struct CommitRequest {
     pub page_count: u32,
     #[prost(message, repeated, tag="5")]
     pub segments: ::prost::alloc::vec::Vec<super::super::common::v1::SegmentInfo>,
+    /// The size of each page in the volume in bytes. Zero means the default page
+    /// size of 4 KiB. Must match the page size of every previous commit.
+    #[prost(uint32, tag="6")]
+    pub page_size: u32,
+    /// Optional user metadata to store with the commit. Returned by PullCommits.
+    #[prost(message, optional, tag="7")]
+    pub metadata: ::core::option::Option<super::super::common::v1::CommitMetadata>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -78,187 +127,1308 @@ pub struct CommitResponse {
     #[prost(message, optional, tag="1")]
     pub snapshot: ::core::option::Option<super::super::common::v1::Snapshot>,
 }
+/// Resolve a human-readable Volume name to its VolumeId. If the name doesn't
+/// exist and create is set, the name is atomically registered to a new random
+/// VolumeId. Concurrent creates of the same name all resolve to the same
+/// VolumeId.
+/// Returns: graft.metastore.v1.ResolveNameResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResolveNameRequest {
+    #[prost(string, tag="1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bool, tag="3")]
+    pub create: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResolveNameResponse {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    /// true if this request registered the name
+    #[prost(bool, tag="2")]
+    pub created: bool,
+}
+/// Write a checkpoint for the Volume at its latest LSN. A checkpoint maps every
+/// page in the Volume to the segment containing its most recent version. Once a
+/// checkpoint exists, subsequent commits reference it via their checkpoint LSN
+/// which bounds the number of commits readers need to scan.
+/// Returns: graft.metastore.v1.CheckpointResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CheckpointResponse {
+    /// the LSN of the Volume's latest checkpoint
+    #[prost(uint64, tag="1")]
+    pub checkpoint_lsn: u64,
+}
+/// Retrieve the checkpoint written at the given LSN. The returned Commit
+/// contains the checkpoint's snapshot along with the consolidated segments.
+/// Returns: graft.metastore.v1.PullCheckpointResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PullCheckpointRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(uint64, tag="2")]
+    pub lsn: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PullCheckpointResponse {
+    #[prost(message, optional, tag="1")]
+    pub checkpoint: ::core::option::Option<super::super::common::v1::Commit>,
+    /// An opaque version of the stored checkpoint which changes whenever the
+    /// checkpoint is rewritten by compaction. Empty if the checkpoint is implicit.
+    #[prost(string, tag="2")]
+    pub version: ::prost::alloc::string::String,
+}
+/// Replace the Volume's checkpoint at the given LSN with one that reads the
+/// pages in the provided segments from those segments. Each page in the
+/// provided segments must contain the version of the page visible at the LSN.
+/// This allows a compactor to rewrite many small segments into fewer, denser
+/// segments. The LSN must refer to a written checkpoint, which every snapshot
+/// based on it reads from. Returns the rewritten checkpoint.
+/// Returns: graft.metastore.v1.CompactResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompactRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(uint64, tag="2")]
+    pub lsn: u64,
+    #[prost(message, repeated, tag="3")]
+    pub segments: ::prost::alloc::vec::Vec<super::super::common::v1::SegmentInfo>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompactResponse {
+    #[prost(message, optional, tag="1")]
+    pub checkpoint: ::core::option::Option<super::super::common::v1::Commit>,
+    /// The version of the rewritten checkpoint, see PullCheckpointResponse.
+    #[prost(string, tag="2")]
+    pub version: ::prost::alloc::string::String,
+}
+/// Delete a Volume by writing a tombstone for it. Once deleted, commits to the
+/// Volume are rejected and reads fail with GRAFT_ERR_CODE_VOLUME_DELETED. The
+/// Volume may be restored with UndeleteVolume until purge_at, after which the
+/// garbage collector asynchronously purges its commits and segments. Deleting
+/// an already deleted Volume returns the original tombstone.
+/// Returns: graft.metastore.v1.DeleteVolumeResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteVolumeRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteVolumeResponse {
+    #[prost(message, optional, tag="1")]
+    pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// the Volume can no longer be undeleted after this time
+    #[prost(message, optional, tag="2")]
+    pub purge_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Restore a deleted Volume within its undelete window. Undeleting a Volume
+/// which isn't deleted has no effect. Returns the latest snapshot of the Volume.
+/// Returns: graft.metastore.v1.UndeleteVolumeResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UndeleteVolumeRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UndeleteVolumeResponse {
+    #[prost(message, optional, tag="1")]
+    pub snapshot: ::core::option::Option<super::super::common::v1::Snapshot>,
+}
+/// List Volumes in order by VolumeId, which roughly corresponds to the order in
+/// which they were created. Each Volume is returned along with the latest
+/// snapshot and labels cached by the metastore, which may lag behind the
+/// Volume's latest commit and label update. Deleted Volumes are not listed.
+/// Returns: graft.metastore.v1.ListVolumesResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListVolumesRequest {
+    /// only list Volumes whose VolumeId starts with this prefix
+    #[prost(string, tag="1")]
+    pub prefix: ::prost::alloc::string::String,
+    /// the maximum number of Volumes to return. Zero uses the server's default
+    /// limit, and larger limits are capped by the server.
+    #[prost(uint32, tag="2")]
+    pub limit: u32,
+    /// resume a previous listing from its next_page_token
+    #[prost(bytes="bytes", tag="3")]
+    pub page_token: ::prost::bytes::Bytes,
+    /// only list Volumes which have all of these labels
+    #[prost(map="string, string", tag="4")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListedVolume {
+    #[prost(message, optional, tag="1")]
+    pub snapshot: ::core::option::Option<super::super::common::v1::Snapshot>,
+    #[prost(message, optional, tag="2")]
+    pub labels: ::core::option::Option<VolumeLabels>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListVolumesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub volumes: ::prost::alloc::vec::Vec<ListedVolume>,
+    /// empty once every Volume has been listed
+    #[prost(bytes="bytes", tag="2")]
+    pub next_page_token: ::prost::bytes::Bytes,
+}
+/// Replace the labels of a Volume if the version of its current labels matches
+/// expected_version. Otherwise the update fails with
+/// GRAFT_ERR_CODE_VERSION_CONFLICT, in which case the caller should reload the
+/// labels and try again.
+/// Returns: graft.metastore.v1.UpdateLabelsResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateLabelsRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(map="string, string", tag="2")]
+    pub labels: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(uint64, tag="3")]
+    pub expected_version: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateLabelsResponse {
+    #[prost(message, optional, tag="1")]
+    pub labels: ::core::option::Option<VolumeLabels>,
+}
+/// Create a new Volume whose first commit contains the state of the source
+/// Volume at the given LSN (or latest). The new Volume references the source's
+/// segments rather than copying its pages, and is independent of the source
+/// from then on. Returns the snapshot of the new Volume.
+/// Returns: graft.metastore.v1.ForkVolumeResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForkVolumeRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub source_vid: ::prost::bytes::Bytes,
+    #[prost(uint64, optional, tag="2")]
+    pub lsn: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForkVolumeResponse {
+    #[prost(message, optional, tag="1")]
+    pub snapshot: ::core::option::Option<super::super::common::v1::Snapshot>,
+}
+/// Revert a Volume to the state it had at target_lsn by committing a new
+/// snapshot on top of the latest one. The revert commit references the segments
+/// which contained each page at target_lsn rather than copying them, and takes
+/// the page count the Volume had at target_lsn. Clients receive the revert like
+/// any other commit. Fails with GRAFT_ERR_CODE_COMMIT_REJECTED if another commit
+/// lands concurrently. Returns the snapshot of the revert commit.
+/// Returns: graft.metastore.v1.RevertVolumeResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevertVolumeRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    /// the client performing the revert
+    #[prost(bytes="bytes", tag="2")]
+    pub cid: ::prost::bytes::Bytes,
+    #[prost(uint64, tag="3")]
+    pub target_lsn: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevertVolumeResponse {
+    #[prost(message, optional, tag="1")]
+    pub snapshot: ::core::option::Option<super::super::common::v1::Snapshot>,
+}
+/// A named pointer to an LSN of a Volume, such as `release-2026-10`
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Tag {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub lsn: u64,
+}
+/// Tag the Volume's snapshot at lsn. Tags may be used in place of an LSN when
+/// requesting a snapshot, pulling a graft, or reading pages, and the tagged
+/// snapshot is retained by the garbage collector until the tag is deleted.
+/// Creating a tag which already points at lsn has no effect, while creating a
+/// tag which points at another LSN fails with GRAFT_ERR_CODE_TAG_EXISTS.
+/// Returns: graft.metastore.v1.CreateTagResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTagRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub lsn: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTagResponse {
+    #[prost(message, optional, tag="1")]
+    pub tag: ::core::option::Option<Tag>,
+}
+/// List the Volume's tags in order by name.
+/// Returns: graft.metastore.v1.ListTagsResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagsRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagsResponse {
+    #[prost(message, repeated, tag="1")]
+    pub tags: ::prost::alloc::vec::Vec<Tag>,
+}
+/// Delete a tag, allowing the garbage collector to collect the tagged snapshot
+/// once it falls outside the retention period. Fails with
+/// GRAFT_ERR_CODE_TAG_NOT_FOUND if the tag doesn't exist.
+/// Returns: graft.metastore.v1.DeleteTagResponse
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteTagRequest {
+    #[prost(bytes="bytes", tag="1")]
+    pub vid: ::prost::bytes::Bytes,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteTagResponse {
+    /// the deleted tag
+    #[prost(message, optional, tag="1")]
+    pub tag: ::core::option::Option<Tag>,
+}
 /// Encoded file descriptor set for the `graft.metastore.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xb8, 0x16, 0x0a, 0x22, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2f, 0x6d, 0x65, 0x74, 0x61, 0x73,
+    0x0a, 0x89, 0x7e, 0x0a, 0x22, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2f, 0x6d, 0x65, 0x74, 0x61, 0x73,
     0x74, 0x6f, 0x72, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72,
     0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x12, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d,
-    0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x1a, 0x1c, 0x67, 0x72, 0x61,
-    0x66, 0x74, 0x2f, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6f, 0x6d,
-    0x6d, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x42, 0x0a, 0x0f, 0x53, 0x6e, 0x61,
-    0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03,
-    0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x15,
-    0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x03, 0x6c,
-    0x73, 0x6e, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x6c, 0x73, 0x6e, 0x22, 0x49, 0x0a,
-    0x10, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-    0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d,
-    0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08,
-    0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x22, 0x55, 0x0a, 0x10, 0x50, 0x75, 0x6c, 0x6c,
-    0x47, 0x72, 0x61, 0x66, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03,
+    0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f,
+    0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1c, 0x67, 0x72,
+    0x61, 0x66, 0x74, 0x2f, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6f,
+    0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x54, 0x0a, 0x0f, 0x53, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a,
+    0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12,
+    0x15, 0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x03,
+    0x6c, 0x73, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x10, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61, 0x67, 0x42, 0x06, 0x0a, 0x04, 0x5f, 0x6c, 0x73, 0x6e,
+    0x22, 0x83, 0x01, 0x0a, 0x10, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
+    0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e,
+    0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x12, 0x38, 0x0a, 0x06,
+    0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x52, 0x06,
+    0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x22, 0xa9, 0x01, 0x0a, 0x0c, 0x56, 0x6f, 0x6c, 0x75, 0x6d,
+    0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x12, 0x44, 0x0a, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x2c, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e,
+    0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x56, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x2e, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73,
+    0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x12, 0x18, 0x0a,
+    0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x1a, 0x39, 0x0a, 0x0b, 0x4c, 0x61, 0x62, 0x65, 0x6c,
+    0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02,
+    0x38, 0x01, 0x22, 0x8c, 0x01, 0x0a, 0x0a, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x46, 0x6f, 0x72,
+    0x6b, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x76, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x56, 0x69, 0x64,
+    0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x73, 0x6e, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x73, 0x6e, 0x12,
+    0x40, 0x0a, 0x08, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x24, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74,
+    0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x6e, 0x68, 0x65, 0x72, 0x69, 0x74, 0x65, 0x64,
+    0x53, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x52, 0x08, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74,
+    0x73, 0x22, 0x36, 0x0a, 0x10, 0x49, 0x6e, 0x68, 0x65, 0x72, 0x69, 0x74, 0x65, 0x64, 0x53, 0x65,
+    0x67, 0x6d, 0x65, 0x6e, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x03, 0x73, 0x69, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x22, 0x67, 0x0a, 0x10, 0x50, 0x75, 0x6c,
+    0x6c, 0x47, 0x72, 0x61, 0x66, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a,
+    0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12,
+    0x2f, 0x0a, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19,
+    0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31,
+    0x2e, 0x4c, 0x73, 0x6e, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x52, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65,
+    0x12, 0x10, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x74,
+    0x61, 0x67, 0x22, 0x91, 0x01, 0x0a, 0x11, 0x50, 0x75, 0x6c, 0x6c, 0x47, 0x72, 0x61, 0x66, 0x74,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70,
+    0x73, 0x68, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61,
+    0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61,
+    0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x12,
+    0x2f, 0x0a, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19,
+    0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31,
+    0x2e, 0x4c, 0x73, 0x6e, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x52, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65,
+    0x12, 0x14, 0x0a, 0x05, 0x67, 0x72, 0x61, 0x66, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x05, 0x67, 0x72, 0x61, 0x66, 0x74, 0x22, 0x57, 0x0a, 0x12, 0x50, 0x75, 0x6c, 0x6c, 0x43, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03,
     0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x2f,
     0x0a, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e,
     0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e,
     0x4c, 0x73, 0x6e, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x52, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x22,
-    0x91, 0x01, 0x0a, 0x11, 0x50, 0x75, 0x6c, 0x6c, 0x47, 0x72, 0x61, 0x66, 0x74, 0x52, 0x65, 0x73,
-    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
-    0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e,
-    0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68,
-    0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x12, 0x2f, 0x0a, 0x05,
-    0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72,
-    0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x73,
-    0x6e, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x52, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x12, 0x14, 0x0a,
-    0x05, 0x67, 0x72, 0x61, 0x66, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x67, 0x72,
-    0x61, 0x66, 0x74, 0x22, 0x57, 0x0a, 0x12, 0x50, 0x75, 0x6c, 0x6c, 0x43, 0x6f, 0x6d, 0x6d, 0x69,
-    0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x2f, 0x0a, 0x05, 0x72,
-    0x61, 0x6e, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61,
-    0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x73, 0x6e,
-    0x52, 0x61, 0x6e, 0x67, 0x65, 0x52, 0x05, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x22, 0x48, 0x0a, 0x13,
-    0x50, 0x75, 0x6c, 0x6c, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x18, 0x01,
-    0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d,
-    0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x07, 0x63,
-    0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x22, 0xc5, 0x01, 0x0a, 0x0d, 0x43, 0x6f, 0x6d, 0x6d, 0x69,
-    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18,
+    0x48, 0x0a, 0x13, 0x50, 0x75, 0x6c, 0x6c, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e,
+    0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
+    0x52, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x22, 0x9f, 0x02, 0x0a, 0x0d, 0x43, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x10, 0x0a,
+    0x03, 0x63, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x63, 0x69, 0x64, 0x12,
+    0x26, 0x0a, 0x0c, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
+    0x74, 0x4c, 0x73, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x70, 0x61, 0x67,
+    0x65, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x38, 0x0a, 0x08, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e,
+    0x74, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74,
+    0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x67, 0x6d, 0x65,
+    0x6e, 0x74, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x08, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73,
+    0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x3b, 0x0a,
+    0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x1f, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76,
+    0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
+    0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x73,
+    0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x22, 0x47, 0x0a, 0x0e, 0x43,
+    0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a,
+    0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76,
+    0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70,
+    0x73, 0x68, 0x6f, 0x74, 0x22, 0x5e, 0x0a, 0x12, 0x52, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x4e,
+    0x61, 0x6d, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x6e, 0x61,
+    0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6e,
+    0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x16, 0x0a, 0x06,
+    0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x63, 0x72,
+    0x65, 0x61, 0x74, 0x65, 0x22, 0x41, 0x0a, 0x13, 0x52, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x4e,
+    0x61, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x76,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x18, 0x0a,
+    0x07, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07,
+    0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x22, 0x25, 0x0a, 0x11, 0x43, 0x68, 0x65, 0x63, 0x6b,
+    0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03,
+    0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x22, 0x3b,
+    0x0a, 0x12, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x25, 0x0a, 0x0e, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69,
+    0x6e, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x63, 0x68,
+    0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x4c, 0x73, 0x6e, 0x22, 0x3b, 0x0a, 0x15, 0x50,
+    0x75, 0x6c, 0x6c, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x03, 0x6c, 0x73, 0x6e, 0x22, 0x6b, 0x0a, 0x16, 0x50, 0x75, 0x6c, 0x6c,
+    0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x37, 0x0a, 0x0a, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63,
+    0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52,
+    0x0a, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x6e, 0x0a, 0x0e, 0x43, 0x6f, 0x6d, 0x70, 0x61, 0x63, 0x74,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6c, 0x73, 0x6e,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03, 0x6c, 0x73, 0x6e, 0x12, 0x38, 0x0a, 0x08, 0x73,
+    0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e,
+    0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e,
+    0x53, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x08, 0x73, 0x65, 0x67,
+    0x6d, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x64, 0x0a, 0x0f, 0x43, 0x6f, 0x6d, 0x70, 0x61, 0x63, 0x74,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x37, 0x0a, 0x0a, 0x63, 0x68, 0x65, 0x63,
+    0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x43,
+    0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x0a, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e,
+    0x74, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x27, 0x0a, 0x13, 0x44,
+    0x65, 0x6c, 0x65, 0x74, 0x65, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x03, 0x76, 0x69, 0x64, 0x22, 0x88, 0x01, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x39, 0x0a,
+    0x0a, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x64,
+    0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x35, 0x0a, 0x08, 0x70, 0x75, 0x72, 0x67,
+    0x65, 0x5f, 0x61, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f,
+    0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x07, 0x70, 0x75, 0x72, 0x67, 0x65, 0x41, 0x74, 0x22,
+    0x29, 0x0a, 0x15, 0x55, 0x6e, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x56, 0x6f, 0x6c, 0x75, 0x6d,
+    0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x22, 0x4f, 0x0a, 0x16, 0x55, 0x6e,
+    0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63,
+    0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
+    0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x22, 0xe8, 0x01, 0x0a, 0x12,
+    0x4c, 0x69, 0x73, 0x74, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x06, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69,
+    0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
+    0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12,
+    0x4a, 0x0a, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x32, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72,
+    0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x45, 0x6e,
+    0x74, 0x72, 0x79, 0x52, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x1a, 0x39, 0x0a, 0x0b, 0x4c,
+    0x61, 0x62, 0x65, 0x6c, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65,
+    0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c,
+    0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x7f, 0x0a, 0x0c, 0x4c, 0x69, 0x73, 0x74, 0x65, 0x64,
+    0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74,
+    0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73,
+    0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x12, 0x38, 0x0a,
+    0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e,
+    0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x52,
+    0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x22, 0x79, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a,
+    0x0a, 0x07, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x20, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72,
+    0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x65, 0x64, 0x56, 0x6f, 0x6c, 0x75, 0x6d,
+    0x65, 0x52, 0x07, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e, 0x65,
+    0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x22, 0xda, 0x01, 0x0a, 0x13, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x61, 0x62,
+    0x65, 0x6c, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x4b, 0x0a, 0x06,
+    0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x45, 0x6e, 0x74, 0x72,
+    0x79, 0x52, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x12, 0x29, 0x0a, 0x10, 0x65, 0x78, 0x70,
+    0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x0f, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x56, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x1a, 0x39, 0x0a, 0x0b, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x45, 0x6e,
+    0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22,
+    0x50, 0x0a, 0x14, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x38, 0x0a, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c,
+    0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e,
+    0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x56, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x4c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x52, 0x06, 0x6c, 0x61, 0x62, 0x65, 0x6c,
+    0x73, 0x22, 0x51, 0x0a, 0x11, 0x46, 0x6f, 0x72, 0x6b, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
+    0x5f, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x6f, 0x75, 0x72,
+    0x63, 0x65, 0x56, 0x69, 0x64, 0x12, 0x15, 0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x04, 0x48, 0x00, 0x52, 0x03, 0x6c, 0x73, 0x6e, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a, 0x04,
+    0x5f, 0x6c, 0x73, 0x6e, 0x22, 0x4b, 0x0a, 0x12, 0x46, 0x6f, 0x72, 0x6b, 0x56, 0x6f, 0x6c, 0x75,
+    0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53,
+    0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f,
+    0x74, 0x22, 0x58, 0x0a, 0x13, 0x52, 0x65, 0x76, 0x65, 0x72, 0x74, 0x56, 0x6f, 0x6c, 0x75, 0x6d,
+    0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18,
     0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x63, 0x69,
-    0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x63, 0x69, 0x64, 0x12, 0x26, 0x0a, 0x0c,
-    0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x18, 0x03, 0x20, 0x01,
-    0x28, 0x04, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x4c, 0x73,
-    0x6e, 0x88, 0x01, 0x01, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x75,
-    0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x43, 0x6f,
-    0x75, 0x6e, 0x74, 0x12, 0x38, 0x0a, 0x08, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x18,
-    0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f,
-    0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x49,
-    0x6e, 0x66, 0x6f, 0x52, 0x08, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x42, 0x0f, 0x0a,
-    0x0d, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x22, 0x47,
-    0x0a, 0x0e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f, 0x6d, 0x6d, 0x6f,
-    0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x52, 0x08, 0x73,
-    0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x4a, 0xa9, 0x0f, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00,
-    0x32, 0x41, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01,
-    0x02, 0x12, 0x03, 0x01, 0x00, 0x1b, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x03, 0x00,
-    0x26, 0x0a, 0x7a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x07, 0x00, 0x0a, 0x01, 0x1a, 0x6e, 0x20,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x61, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
-    0x6f, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65,
-    0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x4c, 0x53,
-    0x4e, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x29, 0x2e, 0x0a, 0x20,
-    0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d,
-    0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70,
-    0x73, 0x68, 0x6f, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x08, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x08, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x08,
-    0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x08, 0x0e, 0x0f,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x09, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x09, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x09, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x09, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x09, 0x18, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0c, 0x00, 0x43,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x1b, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x0c, 0x1b, 0x33, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x0c, 0x34, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x0c, 0x3f, 0x40, 0x0a, 0xfc, 0x01, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x12, 0x00, 0x15, 0x01,
-    0x1a, 0xef, 0x01, 0x20, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x65, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65,
-    0x6e, 0x20, 0x4c, 0x53, 0x4e, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x61, 0x6c, 0x6f, 0x6e,
-    0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x53, 0x70, 0x6c, 0x69, 0x6e, 0x74, 0x65,
-    0x72, 0x0a, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6c,
-    0x6c, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x65,
-    0x73, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20,
-    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20,
-    0x55, 0x6e, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x74, 0x0a, 0x20, 0x77,
-    0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e,
-    0x74, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61,
+    0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x63, 0x69, 0x64, 0x12, 0x1d, 0x0a, 0x0a,
+    0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x09, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x4c, 0x73, 0x6e, 0x22, 0x4d, 0x0a, 0x14, 0x52,
+    0x65, 0x76, 0x65, 0x72, 0x74, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x63, 0x6f,
+    0x6d, 0x6d, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74,
+    0x52, 0x08, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x22, 0x2b, 0x0a, 0x03, 0x54, 0x61,
+    0x67, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x03, 0x6c, 0x73, 0x6e, 0x22, 0x4a, 0x0a, 0x10, 0x43, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x54, 0x61, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12, 0x12, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x12, 0x10, 0x0a, 0x03, 0x6c, 0x73, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03,
+    0x6c, 0x73, 0x6e, 0x22, 0x3e, 0x0a, 0x11, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x61, 0x67,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x29, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65,
+    0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x61, 0x67, 0x52, 0x03,
+    0x74, 0x61, 0x67, 0x22, 0x23, 0x0a, 0x0f, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x67, 0x73, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x22, 0x3f, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74,
+    0x54, 0x61, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x04,
+    0x74, 0x61, 0x67, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x72, 0x61,
     0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e,
-    0x50, 0x75, 0x6c, 0x6c, 0x47, 0x72, 0x61, 0x66, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-    0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x12, 0x08, 0x18, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x13, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x13, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x13, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x13, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x14,
-    0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x14, 0x02, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x14, 0x1b, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x14, 0x23, 0x24, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x03, 0x12, 0x04, 0x17, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12,
-    0x03, 0x17, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x18, 0x02,
-    0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x18, 0x02, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x1b, 0x23, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18, 0x26, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x03, 0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
-    0x06, 0x12, 0x03, 0x19, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x19, 0x1b, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19,
-    0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x12, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1a, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1a, 0x10, 0x11, 0x0a, 0xd5, 0x01, 0x0a, 0x02, 0x04, 0x04,
-    0x12, 0x04, 0x20, 0x00, 0x23, 0x01, 0x1a, 0xc8, 0x01, 0x20, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65,
-    0x76, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
-    0x6d, 0x6d, 0x69, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c,
-    0x75, 0x6d, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69,
-    0x64, 0x65, 0x64, 0x20, 0x4c, 0x53, 0x4e, 0x20, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x2e, 0x20, 0x49,
-    0x66, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x6f, 0x66, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x55, 0x6e, 0x62,
-    0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x74, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20,
+    0x54, 0x61, 0x67, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x22, 0x38, 0x0a, 0x10, 0x44, 0x65, 0x6c,
+    0x65, 0x74, 0x65, 0x54, 0x61, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a,
+    0x03, 0x76, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x76, 0x69, 0x64, 0x12,
+    0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x22, 0x3e, 0x0a, 0x11, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x61, 0x67,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x29, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65,
+    0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x61, 0x67, 0x52, 0x03,
+    0x74, 0x61, 0x67, 0x4a, 0xfe, 0x5e, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x9f, 0x02, 0x01, 0x0a,
+    0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x01, 0x00, 0x1b, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12,
+    0x03, 0x03, 0x00, 0x29, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x04, 0x00, 0x26, 0x0a,
+    0x7a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x08, 0x00, 0x0d, 0x01, 0x1a, 0x6e, 0x20, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x61, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x61,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x4c, 0x53, 0x4e, 0x20,
+    0x28, 0x6f, 0x72, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x29, 0x2e, 0x0a, 0x20, 0x52, 0x65,
+    0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74,
+    0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x08, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12,
+    0x03, 0x09, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09,
+    0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x0e, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x09, 0x02, 0x07, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x0a, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x0a, 0x0b, 0x11, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0c, 0x02, 0x11,
+    0x1a, 0x45, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x61, 0x67, 0x67, 0x65, 0x64, 0x20, 0x4c, 0x53, 0x4e, 0x3b, 0x20, 0x6c, 0x73, 0x6e, 0x20,
+    0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x6f, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20,
+    0x69, 0x66, 0x20, 0x73, 0x65, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x0c, 0x09, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x0c, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0c, 0x02,
+    0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0f, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0f, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x00, 0x12, 0x03, 0x10, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x10, 0x1b, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10,
+    0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x10, 0x02, 0x1a,
+    0x0a, 0x6e, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x13, 0x02, 0x1a, 0x1a, 0x61, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65,
+    0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f,
+    0x72, 0x65, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x6c, 0x61,
+    0x67, 0x20, 0x62, 0x65, 0x68, 0x69, 0x6e, 0x64, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73,
+    0x20, 0x6d, 0x61, 0x64, 0x65, 0x0a, 0x20, 0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x6f,
+    0x74, 0x68, 0x65, 0x72, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x73, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13, 0x0f, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x13, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x13, 0x02, 0x0e, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x02,
+    0x12, 0x04, 0x17, 0x00, 0x1b, 0x01, 0x1a, 0x34, 0x20, 0x55, 0x73, 0x65, 0x72, 0x20, 0x64, 0x65,
+    0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x6b, 0x65, 0x79, 0x2f, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20,
+    0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x20, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x65, 0x64, 0x20,
+    0x74, 0x6f, 0x20, 0x61, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x02, 0x01, 0x12, 0x03, 0x17, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00,
+    0x12, 0x03, 0x18, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x18, 0x16, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18, 0x1f,
+    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x18, 0x02, 0x15, 0x0a,
+    0x54, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x15, 0x1a, 0x47, 0x20, 0x69,
+    0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x65, 0x61,
+    0x63, 0x68, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x3b, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20,
+    0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x68, 0x61,
+    0x73, 0x20, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x62,
+    0x65, 0x6c, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x1a, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1a, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1a, 0x02, 0x08, 0x0a,
+    0x99, 0x01, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x1f, 0x00, 0x23, 0x01, 0x1a, 0x8c, 0x01, 0x20,
+    0x52, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75,
+    0x6d, 0x65, 0x20, 0x61, 0x20, 0x66, 0x6f, 0x72, 0x6b, 0x65, 0x64, 0x20, 0x56, 0x6f, 0x6c, 0x75,
+    0x6d, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66,
+    0x72, 0x6f, 0x6d, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x0a, 0x20, 0x69, 0x74, 0x20,
+    0x69, 0x6e, 0x68, 0x65, 0x72, 0x69, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x61, 0x67, 0x65, 0x73, 0x20, 0x62,
+    0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x03, 0x01, 0x12, 0x03, 0x1f, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12,
+    0x03, 0x20, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x20,
+    0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x20, 0x15, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x20, 0x02, 0x07, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x21, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x21, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x21, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x22,
+    0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x1c, 0x24,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x22, 0x27, 0x28, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x04, 0x12, 0x03, 0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x02, 0x06, 0x12, 0x03, 0x22, 0x0b, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04,
+    0x12, 0x04, 0x25, 0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x25,
+    0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x26, 0x02, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x08, 0x0b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x26, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x26, 0x02, 0x07, 0x0a, 0x3a, 0x0a, 0x04, 0x04, 0x04, 0x02,
+    0x01, 0x12, 0x03, 0x28, 0x02, 0x10, 0x1a, 0x2d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x70, 0x61, 0x67, 0x65, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x61, 0x69, 0x6e, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x28, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x28, 0x0e,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x28, 0x02, 0x07, 0x0a,
+    0xfc, 0x01, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x2f, 0x00, 0x35, 0x01, 0x1a, 0xef, 0x01, 0x20,
+    0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6e, 0x61,
+    0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x4c, 0x53,
+    0x4e, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x61, 0x20, 0x53, 0x70, 0x6c, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x0a, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x63, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x65, 0x73, 0x2e, 0x20, 0x49,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x55, 0x6e, 0x62, 0x6f,
+    0x75, 0x6e, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x74, 0x0a, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20,
     0x62, 0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61,
     0x73, 0x74, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x2e, 0x0a, 0x20,
     0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d,
     0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x6c, 0x6c,
-    0x53, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x20, 0x08, 0x1a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x21, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x21, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x21, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x22, 0x02,
-    0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x22, 0x02, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x1b, 0x20, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x22, 0x23, 0x24, 0x0a, 0x09, 0x0a, 0x02, 0x04,
-    0x05, 0x12, 0x03, 0x25, 0x00, 0x4c, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x25,
-    0x08, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x25, 0x1e, 0x4a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x25, 0x1e, 0x26, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x25, 0x27, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x3e, 0x45, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x25, 0x48, 0x49, 0x0a, 0xc0, 0x01, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
-    0x2a, 0x00, 0x30, 0x01, 0x1a, 0xb3, 0x01, 0x20, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x20, 0x63,
-    0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x56, 0x6f, 0x6c, 0x75,
-    0x6d, 0x65, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x73, 0x61, 0x66, 0x65,
-    0x20, 0x74, 0x6f, 0x20, 0x64, 0x6f, 0x20, 0x73, 0x6f, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70,
-    0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x20, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74,
-    0x20, 0x4c, 0x53, 0x4e, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6e, 0x61,
-    0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
-    0x20, 0x77, 0x61, 0x73, 0x20, 0x62, 0x61, 0x73, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x2e, 0x20, 0x52,
-    0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x6c, 0x79,
-    0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x53, 0x6e, 0x61, 0x70, 0x73,
-    0x68, 0x6f, 0x74, 0x0a, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x6f, 0x6e,
-    0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06,
-    0x01, 0x12, 0x03, 0x2a, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03,
-    0x2b, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2b, 0x02,
-    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2b, 0x08, 0x0b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2b, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x2c, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x01, 0x05, 0x12, 0x03, 0x2c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x2c, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12,
-    0x03, 0x2c, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x2d, 0x02,
-    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2d, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
-    0x03, 0x12, 0x03, 0x2e, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12,
-    0x03, 0x2e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2e,
-    0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2e, 0x16, 0x17,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x34, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x04, 0x06, 0x12, 0x03, 0x2f, 0x0b, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x2f, 0x27, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x2f, 0x32, 0x33, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x03, 0x32, 0x00, 0x41,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x32, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x32, 0x19, 0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x32, 0x19, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x32, 0x32, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x32, 0x3d, 0x3e, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x47, 0x72, 0x61, 0x66, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x2f, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x00, 0x12, 0x03, 0x30, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x30, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x30, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x30, 0x02,
+    0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x31, 0x02, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x31, 0x1b, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x31, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x31, 0x02, 0x1a, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02,
+    0x12, 0x03, 0x34, 0x02, 0x11, 0x1a, 0x51, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x67,
+    0x67, 0x65, 0x64, 0x20, 0x4c, 0x53, 0x4e, 0x3b, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e,
+    0x67, 0x65, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x75, 0x6e, 0x62, 0x6f, 0x75,
+    0x6e, 0x64, 0x65, 0x64, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x0a,
+    0x20, 0x69, 0x66, 0x20, 0x73, 0x65, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x34, 0x09, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x34, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x34,
+    0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x37, 0x00, 0x3b, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x37, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x00, 0x12, 0x03, 0x38, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x38, 0x1b, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x38, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03, 0x38, 0x02,
+    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x39, 0x02, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x39, 0x1b, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x39, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x39, 0x02, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02,
+    0x12, 0x03, 0x3a, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x3a, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3a, 0x10,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3a, 0x02, 0x07, 0x0a,
+    0xd5, 0x01, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x40, 0x00, 0x43, 0x01, 0x1a, 0xc8, 0x01, 0x20,
+    0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x20, 0x4c, 0x53, 0x4e, 0x20, 0x52, 0x61,
+    0x6e, 0x67, 0x65, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20,
+    0x69, 0x73, 0x20, 0x55, 0x6e, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x74,
+    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x73, 0x65, 0x74, 0x20, 0x74, 0x6f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f,
+    0x69, 0x6e, 0x74, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x50, 0x75, 0x6c, 0x6c, 0x53, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03,
+    0x40, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x41, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x08, 0x0b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x41, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x01, 0x12, 0x03, 0x42, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x42, 0x1b, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x42, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x06, 0x12, 0x03, 0x42, 0x02,
+    0x1a, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x03, 0x45, 0x00, 0x4c, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x08, 0x01, 0x12, 0x03, 0x45, 0x08, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00,
+    0x12, 0x03, 0x45, 0x1e, 0x4a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x45, 0x3e, 0x45, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x45, 0x48,
+    0x49, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x45, 0x1e, 0x26, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x06, 0x12, 0x03, 0x45, 0x27, 0x3d, 0x0a, 0xc0, 0x01,
+    0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x4a, 0x00, 0x57, 0x01, 0x1a, 0xb3, 0x01, 0x20, 0x43, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20,
+    0x61, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x20, 0x69,
+    0x73, 0x20, 0x73, 0x61, 0x66, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x6f, 0x20, 0x73, 0x6f, 0x2e,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x20, 0x53, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x4c, 0x53, 0x4e, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x62, 0x61, 0x73, 0x65, 0x64,
+    0x20, 0x6f, 0x6e, 0x2e, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6e, 0x65, 0x77, 0x6c, 0x79, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64,
+    0x20, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x0a, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64,
+    0x61, 0x74, 0x61, 0x20, 0x6f, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x4b, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x4b, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x4b, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x4b, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x4c, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x0b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4c, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4c, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09,
+    0x02, 0x02, 0x12, 0x03, 0x4d, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x4d, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x4d, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12, 0x03, 0x4d, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x4d, 0x0b, 0x11, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x4e, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4e, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x4e, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x4e, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x04, 0x12, 0x03,
+    0x4f, 0x02, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4f, 0x27,
+    0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x4f, 0x32, 0x33, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x04, 0x12, 0x03, 0x4f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12, 0x03, 0x4f, 0x0b, 0x26, 0x0a, 0x9c, 0x01, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x05, 0x12, 0x03, 0x53, 0x02, 0x17, 0x1a, 0x8e, 0x01, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x73, 0x69, 0x7a, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x70, 0x61,
+    0x67, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x6f, 0x6c, 0x75, 0x6d, 0x65,
+    0x20, 0x69, 0x6e, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2e, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x20,
+    0x6d, 0x65, 0x61, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c,
+    0x74, 0x20, 0x70, 0x61, 0x67, 0x65, 0x0a, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x34, 0x20, 0x4b, 0x69, 0x42, 0x2e, 0x20, 0x4d, 0x75, 0x73, 0x74, 0x20, 0x6d, 0x61, 0x74, 0x63,
+    0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75,
+    0x73, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x53, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x53, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x05, 0x12,
+    0x03, 0x53, 0x02, 0x08, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x06, 0x12, 0x03, 0x56, 0x02,
+    0x2e, 0x1a, 0x4b, 0x20, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x75, 0x73, 0x65,
+    0x72, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x74,
+    0x6f, 0x72, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d,
+    0x6d, 0x69, 0x74, 0x2e, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79,
+    0x20, 0x50, 0x75, 0x6c, 0x6c, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x01, 0x12, 0x03, 0x56, 0x21, 0x29, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x06, 0x03, 0x12, 0x03, 0x56, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x06, 0x06, 0x12, 0x03, 0x56, 0x02, 0x20, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x03,
+    0x59, 0x00, 0x41, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x59, 0x08, 0x16, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x59, 0x19, 0x3f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x59, 0x32, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x59, 0x3d, 0x3e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
+    0x06, 0x12, 0x03, 0x59, 0x19, 0x31, 0x0a, 0xa7, 0x02, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x60,
+    0x00, 0x64, 0x01, 0x1a, 0x9a, 0x02, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x20, 0x61,
+    0x20, 0x68, 0x75, 0x6d, 0x61, 0x6e, 0x2d, 0x72, 0x65, 0x61, 0x64, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x69,
+    0x74, 0x73, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x49, 0x64, 0x2e, 0x20, 0x49, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x6e, 0x27, 0x74,
+    0x0a, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x63, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e,
+    0x61, 0x6d, 0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x74, 0x6f, 0x6d, 0x69, 0x63, 0x61, 0x6c, 0x6c,
+    0x79, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x61, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x0a, 0x20, 0x56, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x49, 0x64, 0x2e, 0x20, 0x43, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65,
+    0x6e, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x20,
+    0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x61, 0x6d, 0x65, 0x0a, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x49, 0x64, 0x2e, 0x0a, 0x20,
+    0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d,
+    0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x73, 0x6f,
+    0x6c, 0x76, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x60, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x61, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x61, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x61, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x61, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x62, 0x02, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x62, 0x09, 0x0d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x62, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x62, 0x02, 0x08, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b,
+    0x02, 0x02, 0x12, 0x03, 0x63, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x63, 0x07, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x63, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x63, 0x02,
+    0x06, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x66, 0x00, 0x6a, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x66, 0x08, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02,
+    0x00, 0x12, 0x03, 0x67, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x67, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x67,
+    0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x67, 0x02, 0x07,
+    0x0a, 0x37, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x69, 0x02, 0x13, 0x1a, 0x2a, 0x20,
+    0x74, 0x72, 0x75, 0x65, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x69, 0x07, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x69, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x69, 0x02, 0x06, 0x0a, 0xdf, 0x02, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x03, 0x71, 0x00, 0x2c, 0x1a,
+    0xd3, 0x02, 0x20, 0x57, 0x72, 0x69, 0x74, 0x65, 0x20, 0x61, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b,
+    0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x20, 0x61, 0x74, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6c, 0x61, 0x74, 0x65,
+    0x73, 0x74, 0x20, 0x4c, 0x53, 0x4e, 0x2e, 0x20, 0x41, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70,
+    0x6f, 0x69, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x70, 0x73, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x0a,
+    0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65,
+    0x6e, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x6e, 0x74, 0x20, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x20, 0x4f, 0x6e, 0x63, 0x65, 0x20, 0x61, 0x0a, 0x20, 0x63,
+    0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74, 0x73,
+    0x2c, 0x20, 0x73, 0x75, 0x62, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x74, 0x20, 0x63, 0x6f, 0x6d,
+    0x6d, 0x69, 0x74, 0x73, 0x20, 0x72, 0x65, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x69,
+    0x74, 0x20, 0x76, 0x69, 0x61, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x63, 0x68, 0x65, 0x63,
+    0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x4c, 0x53, 0x4e, 0x0a, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x20, 0x72,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73,
+    0x63, 0x61, 0x6e, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x71, 0x08,
+    0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x71, 0x1c, 0x2a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x71, 0x22, 0x25, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x71, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x71, 0x1c, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04,
+    0x73, 0x00, 0x76, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x73, 0x08, 0x1a,
+    0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03, 0x75, 0x02, 0x1c, 0x1a, 0x2b, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x4c, 0x53, 0x4e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x27, 0x73, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x63,
+    0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x75, 0x09, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x75, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x75, 0x02, 0x08, 0x0a, 0xd2, 0x01, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x7b, 0x00, 0x7e,
+    0x01, 0x1a, 0xc5, 0x01, 0x20, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x77, 0x72, 0x69,
+    0x74, 0x74, 0x65, 0x6e, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65,
+    0x6e, 0x20, 0x4c, 0x53, 0x4e, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72,
+    0x6e, 0x65, 0x64, 0x20, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x0a, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x61, 0x69, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f,
+    0x69, 0x6e, 0x74, 0x27, 0x73, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x61,
+    0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
+    0x6e, 0x73, 0x6f, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x64, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65,
+    0x6e, 0x74, 0x73, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67,
+    0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x50, 0x75, 0x6c, 0x6c, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01,
+    0x12, 0x03, 0x7b, 0x08, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x7c,
+    0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x0b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7c, 0x0e, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7c, 0x02, 0x07, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0f, 0x02, 0x01, 0x12, 0x03, 0x7d, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x7d, 0x09, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x7d, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x7d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0x80, 0x01, 0x00, 0x86, 0x01,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x81, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0x81, 0x01, 0x19, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0x81, 0x01, 0x26, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0x81, 0x01, 0x02, 0x18, 0x0a, 0xa3, 0x01, 0x0a, 0x04, 0x04, 0x10,
+    0x02, 0x01, 0x12, 0x04, 0x85, 0x01, 0x02, 0x15, 0x1a, 0x94, 0x01, 0x20, 0x41, 0x6e, 0x20, 0x6f,
+    0x70, 0x61, 0x71, 0x75, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x63, 0x68, 0x65, 0x63,
+    0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x68, 0x61,
+    0x6e, 0x67, 0x65, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x69, 0x73,
+    0x20, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x74, 0x65, 0x6e, 0x20, 0x62, 0x79, 0x20, 0x63, 0x6f,
+    0x6d, 0x70, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x20,
+    0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e,
+    0x74, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x69, 0x63, 0x69, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0x85, 0x01, 0x09, 0x10, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0x85, 0x01, 0x13, 0x14, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0x85, 0x01, 0x02, 0x08, 0x0a, 0x90, 0x03, 0x0a,
+    0x02, 0x04, 0x11, 0x12, 0x06, 0x8e, 0x01, 0x00, 0x92, 0x01, 0x01, 0x1a, 0x81, 0x03, 0x20, 0x52,
+    0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d,
+    0x65, 0x27, 0x73, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x61,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x4c, 0x53, 0x4e, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65,
+    0x61, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x70, 0x61, 0x67, 0x65, 0x73, 0x20, 0x69,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x20, 0x73,
+    0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x6f,
+    0x73, 0x65, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20, 0x45, 0x61, 0x63,
+    0x68, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x70,
+    0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73,
+    0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x61, 0x74,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x4c, 0x53, 0x4e, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20,
+    0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20, 0x61, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x61, 0x63, 0x74,
+    0x6f, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x65, 0x20, 0x6d, 0x61,
+    0x6e, 0x79, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74,
+    0x73, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x66, 0x65, 0x77, 0x65, 0x72, 0x2c, 0x20, 0x64, 0x65,
+    0x6e, 0x73, 0x65, 0x72, 0x0a, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20,
+    0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x77, 0x72,
+    0x69, 0x74, 0x74, 0x65, 0x6e, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74,
+    0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66,
+    0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x43,
+    0x6f, 0x6d, 0x70, 0x61, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12,
+    0x04, 0x90, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x90, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0x90,
+    0x01, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0x90, 0x01,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12, 0x04, 0x91, 0x01, 0x02, 0x34,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0x91, 0x01, 0x27, 0x2f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01, 0x32, 0x33, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x02, 0x06, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x26, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x12, 0x12, 0x06, 0x94, 0x01, 0x00, 0x99, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12,
+    0x01, 0x12, 0x04, 0x94, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12,
+    0x04, 0x95, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x95, 0x01, 0x19, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0x95,
+    0x01, 0x26, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x06, 0x12, 0x04, 0x95, 0x01,
+    0x02, 0x18, 0x0a, 0x54, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0x98, 0x01, 0x02, 0x15,
+    0x1a, 0x46, 0x20, 0x54, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x74, 0x65, 0x6e, 0x20,
+    0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20,
+    0x50, 0x75, 0x6c, 0x6c, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0x98, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0x98, 0x01, 0x13, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0x98, 0x01, 0x02, 0x08, 0x0a, 0xab, 0x03, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x04, 0xa1, 0x01,
+    0x00, 0x2e, 0x1a, 0x9e, 0x03, 0x20, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x61, 0x20, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x62, 0x79, 0x20, 0x77, 0x72, 0x69, 0x74, 0x69, 0x6e, 0x67,
+    0x20, 0x61, 0x20, 0x74, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x69, 0x74, 0x2e, 0x20, 0x4f, 0x6e, 0x63, 0x65, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65,
+    0x64, 0x2c, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65,
+    0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x61, 0x64, 0x73,
+    0x20, 0x66, 0x61, 0x69, 0x6c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x47, 0x52, 0x41, 0x46, 0x54,
+    0x5f, 0x45, 0x52, 0x52, 0x5f, 0x43, 0x4f, 0x44, 0x45, 0x5f, 0x56, 0x4f, 0x4c, 0x55, 0x4d, 0x45,
+    0x5f, 0x44, 0x45, 0x4c, 0x45, 0x54, 0x45, 0x44, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x0a, 0x20, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x62, 0x65, 0x20, 0x72, 0x65, 0x73,
+    0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x55, 0x6e, 0x64, 0x65, 0x6c,
+    0x65, 0x74, 0x65, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20,
+    0x70, 0x75, 0x72, 0x67, 0x65, 0x5f, 0x61, 0x74, 0x2c, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20,
+    0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x67, 0x61, 0x72, 0x62, 0x61,
+    0x67, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x73, 0x79,
+    0x6e, 0x63, 0x68, 0x72, 0x6f, 0x6e, 0x6f, 0x75, 0x73, 0x6c, 0x79, 0x20, 0x70, 0x75, 0x72, 0x67,
+    0x65, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x73, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20, 0x44, 0x65, 0x6c,
+    0x65, 0x74, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x6c, 0x72, 0x65, 0x61, 0x64,
+    0x79, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65,
+    0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x74, 0x6f, 0x6d, 0x62, 0x73, 0x74, 0x6f, 0x6e, 0x65, 0x2e,
+    0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74,
+    0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65,
+    0x6c, 0x65, 0x74, 0x65, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x08, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0xa1, 0x01, 0x1e, 0x2c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x24, 0x27, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa1, 0x01, 0x2a, 0x2b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa1, 0x01, 0x1e, 0x23, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x14, 0x12, 0x06, 0xa3, 0x01, 0x00, 0xa7, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01,
+    0x12, 0x04, 0xa3, 0x01, 0x08, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04,
+    0xa4, 0x01, 0x02, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa4,
+    0x01, 0x1c, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa4, 0x01,
+    0x29, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa4, 0x01, 0x02,
+    0x1b, 0x0a, 0x45, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x02, 0x29, 0x1a,
+    0x37, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x63, 0x61, 0x6e,
+    0x20, 0x6e, 0x6f, 0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x65, 0x72, 0x20, 0x62, 0x65, 0x20, 0x75, 0x6e,
+    0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68,
+    0x69, 0x73, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xa6, 0x01, 0x1c, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0xa6, 0x01, 0x27, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0xa6, 0x01, 0x02, 0x1b, 0x0a, 0xda, 0x01, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x04, 0xac, 0x01,
+    0x00, 0x30, 0x1a, 0xcd, 0x01, 0x20, 0x52, 0x65, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x20, 0x61, 0x20,
+    0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x75, 0x6e, 0x64, 0x65, 0x6c, 0x65,
+    0x74, 0x65, 0x20, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x2e, 0x20, 0x55, 0x6e, 0x64, 0x65, 0x6c,
+    0x65, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x0a, 0x20,
+    0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x69, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x64, 0x65, 0x6c, 0x65,
+    0x74, 0x65, 0x64, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63,
+    0x74, 0x2e, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+    0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x2e, 0x0a, 0x20, 0x52,
+    0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65,
+    0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x55, 0x6e, 0x64, 0x65, 0x6c,
+    0x65, 0x74, 0x65, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0xac, 0x01, 0x08, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xac, 0x01, 0x20, 0x2e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xac, 0x01, 0x26, 0x29, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xac, 0x01, 0x2c, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x00, 0x05, 0x12, 0x04, 0xac, 0x01, 0x20, 0x25, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x16,
+    0x12, 0x04, 0xae, 0x01, 0x00, 0x49, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xae,
+    0x01, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xae, 0x01, 0x21,
+    0x47, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xae, 0x01, 0x3a, 0x42,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xae, 0x01, 0x45, 0x46, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x06, 0x12, 0x04, 0xae, 0x01, 0x21, 0x39, 0x0a, 0xe7,
+    0x02, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xb5, 0x01, 0x00, 0xc2, 0x01, 0x01, 0x1a, 0xd8, 0x02,
+    0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x20, 0x69, 0x6e,
+    0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x62, 0x79, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65,
+    0x49, 0x64, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x6c,
+    0x79, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x6f,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x0a, 0x20, 0x77,
+    0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x20, 0x45, 0x61, 0x63, 0x68, 0x20, 0x56, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20,
+    0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+    0x61, 0x74, 0x65, 0x73, 0x74, 0x0a, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65,
+    0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f,
+    0x72, 0x65, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x6c, 0x61,
+    0x67, 0x20, 0x62, 0x65, 0x68, 0x69, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x56, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x27, 0x73, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x63, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x20, 0x75,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x2e, 0x20, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x20, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6c,
+    0x69, 0x73, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a,
+    0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65,
+    0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12,
+    0x04, 0xb5, 0x01, 0x08, 0x1a, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xb7,
+    0x01, 0x02, 0x14, 0x1a, 0x3a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20,
+    0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x56, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x49, 0x64, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x73, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb7, 0x01, 0x09, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb7, 0x01, 0x12, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb7, 0x01, 0x02, 0x08, 0x0a, 0x8b, 0x01, 0x0a,
+    0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x13, 0x1a, 0x7d, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72,
+    0x65, 0x74, 0x75, 0x72, 0x6e, 0x2e, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x20, 0x75, 0x73, 0x65, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x64, 0x65,
+    0x66, 0x61, 0x75, 0x6c, 0x74, 0x0a, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x2c, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x6c, 0x61, 0x72, 0x67, 0x65, 0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x73, 0x20,
+    0x61, 0x72, 0x65, 0x20, 0x63, 0x61, 0x70, 0x70, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x11, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x08, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x02, 0x12,
+    0x04, 0xbe, 0x01, 0x02, 0x17, 0x1a, 0x34, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6d, 0x65, 0x20, 0x61,
+    0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x69, 0x6e,
+    0x67, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x5f,
+    0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x02, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xbe, 0x01, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xbe, 0x01, 0x02, 0x07, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x03,
+    0x12, 0x04, 0xc1, 0x01, 0x02, 0x21, 0x1a, 0x32, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x6c, 0x69,
+    0x73, 0x74, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x73, 0x65, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0xc1, 0x01, 0x16, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x1f, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x03,
+    0x06, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xc4,
+    0x01, 0x00, 0xc7, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0xc4, 0x01,
+    0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x28,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x1b, 0x23, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc5, 0x01, 0x26, 0x27, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0xc6, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc6, 0x01, 0x0f, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xc6, 0x01, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x01, 0x06, 0x12, 0x04, 0xc6, 0x01, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06,
+    0xc9, 0x01, 0x00, 0xce, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0xc9,
+    0x01, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xca, 0x01, 0x02,
+    0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0xca, 0x01, 0x18, 0x1f,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04, 0xca, 0x01, 0x22, 0x23, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x04, 0x12, 0x04, 0xca, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x06, 0x12, 0x04, 0xca, 0x01, 0x0b, 0x17, 0x0a, 0x37, 0x0a,
+    0x04, 0x04, 0x19, 0x02, 0x01, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x1c, 0x1a, 0x29, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x56,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x6c,
+    0x69, 0x73, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xcd, 0x01, 0x08, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xcd, 0x01, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x05, 0x12, 0x04, 0xcd,
+    0x01, 0x02, 0x07, 0x0a, 0xa5, 0x02, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xd5, 0x01, 0x00, 0xd9,
+    0x01, 0x01, 0x1a, 0x96, 0x02, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x56, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65,
+    0x6e, 0x74, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x65,
+    0x73, 0x0a, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x5f, 0x76, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x2e, 0x20, 0x4f, 0x74, 0x68, 0x65, 0x72, 0x77, 0x69, 0x73, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x73, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x0a, 0x20, 0x47, 0x52, 0x41, 0x46, 0x54, 0x5f, 0x45, 0x52, 0x52, 0x5f,
+    0x43, 0x4f, 0x44, 0x45, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x43, 0x4f, 0x4e,
+    0x46, 0x4c, 0x49, 0x43, 0x54, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20,
+    0x63, 0x61, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x20,
+    0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x72, 0x65, 0x6c, 0x6f, 0x61, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x72,
+    0x79, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e,
+    0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f,
+    0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x61, 0x62, 0x65,
+    0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x1a, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00,
+    0x12, 0x04, 0xd6, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xd6, 0x01, 0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xd6, 0x01, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd6,
+    0x01, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x02,
+    0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x16, 0x1c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd7, 0x01, 0x1f, 0x20, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x06, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd8, 0x01, 0x09, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x02, 0x03, 0x12, 0x04, 0xd8, 0x01, 0x1c, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x1b, 0x12,
+    0x04, 0xdb, 0x01, 0x00, 0x39, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0xdb, 0x01,
+    0x08, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0xdb, 0x01, 0x1f, 0x37,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x2c, 0x32, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x35, 0x36, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x06, 0x12, 0x04, 0xdb, 0x01, 0x1f, 0x2b, 0x0a, 0xd6, 0x02,
+    0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0xe2, 0x01, 0x00, 0xe5, 0x01, 0x01, 0x1a, 0xc7, 0x02, 0x20,
+    0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x56, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20,
+    0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x0a, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20,
+    0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x4c, 0x53, 0x4e,
+    0x20, 0x28, 0x6f, 0x72, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x29, 0x2e, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x72, 0x65, 0x66,
+    0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6f, 0x75, 0x72,
+    0x63, 0x65, 0x27, 0x73, 0x0a, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x72,
+    0x61, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x63, 0x6f, 0x70, 0x79, 0x69,
+    0x6e, 0x67, 0x20, 0x69, 0x74, 0x73, 0x20, 0x70, 0x61, 0x67, 0x65, 0x73, 0x2c, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x6e, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x0a, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x6f, 0x6e, 0x2e, 0x20, 0x52, 0x65,
+    0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68,
+    0x6f, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x56, 0x6f,
+    0x6c, 0x75, 0x6d, 0x65, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20,
+    0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x46, 0x6f, 0x72, 0x6b, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0xe2,
+    0x01, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0xe3, 0x01, 0x02,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x08, 0x12,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x15, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe3, 0x01, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1c, 0x02, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe4, 0x01, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x02, 0x01, 0x04, 0x12, 0x04, 0xe4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x01, 0x05, 0x12, 0x04, 0xe4, 0x01, 0x0b, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x04,
+    0xe7, 0x01, 0x00, 0x45, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x08,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xe7, 0x01, 0x1d, 0x43, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x36, 0x3e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x41, 0x42, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1d, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe7, 0x01, 0x1d, 0x35, 0x0a, 0x80, 0x04, 0x0a,
+    0x02, 0x04, 0x1e, 0x12, 0x06, 0xf0, 0x01, 0x00, 0xf5, 0x01, 0x01, 0x1a, 0xf1, 0x03, 0x20, 0x52,
+    0x65, 0x76, 0x65, 0x72, 0x74, 0x20, 0x61, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x74,
+    0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x69, 0x74, 0x20, 0x68,
+    0x61, 0x64, 0x20, 0x61, 0x74, 0x20, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x5f, 0x6c, 0x73, 0x6e,
+    0x20, 0x62, 0x79, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61,
+    0x20, 0x6e, 0x65, 0x77, 0x0a, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x6f,
+    0x6e, 0x20, 0x74, 0x6f, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74,
+    0x65, 0x73, 0x74, 0x20, 0x6f, 0x6e, 0x65, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x76,
+    0x65, 0x72, 0x74, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x20, 0x72, 0x65, 0x66, 0x65, 0x72,
+    0x65, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x67, 0x6d, 0x65, 0x6e,
+    0x74, 0x73, 0x0a, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69,
+    0x6e, 0x65, 0x64, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x61, 0x74,
+    0x20, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x20, 0x72, 0x61, 0x74, 0x68,
+    0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x63, 0x6f, 0x70, 0x79, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x68, 0x65, 0x6d, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x73, 0x0a,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x20, 0x68, 0x61, 0x64, 0x20, 0x61,
+    0x74, 0x20, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x5f, 0x6c, 0x73, 0x6e, 0x2e, 0x20, 0x43, 0x6c,
+    0x69, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x0a, 0x20, 0x61,
+    0x6e, 0x79, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x2e,
+    0x20, 0x46, 0x61, 0x69, 0x6c, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x47, 0x52, 0x41, 0x46,
+    0x54, 0x5f, 0x45, 0x52, 0x52, 0x5f, 0x43, 0x4f, 0x44, 0x45, 0x5f, 0x43, 0x4f, 0x4d, 0x4d, 0x49,
+    0x54, 0x5f, 0x52, 0x45, 0x4a, 0x45, 0x43, 0x54, 0x45, 0x44, 0x20, 0x69, 0x66, 0x20, 0x61, 0x6e,
+    0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x0a, 0x20, 0x6c, 0x61,
+    0x6e, 0x64, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79,
+    0x2e, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
+    0x76, 0x65, 0x72, 0x74, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x2e, 0x0a, 0x20, 0x52, 0x65,
+    0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74,
+    0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x76, 0x65, 0x72, 0x74,
+    0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xf1, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xf1, 0x01, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xf1, 0x01, 0x02, 0x07, 0x0a, 0x30, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x01, 0x12,
+    0x04, 0xf3, 0x01, 0x02, 0x10, 0x1a, 0x22, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65,
+    0x6e, 0x74, 0x20, 0x70, 0x65, 0x72, 0x66, 0x6f, 0x72, 0x6d, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xf3, 0x01, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xf3, 0x01, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x02, 0x12, 0x04,
+    0xf4, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf4,
+    0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf4, 0x01,
+    0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf4, 0x01, 0x02,
+    0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x1f, 0x12, 0x04, 0xf7, 0x01, 0x00, 0x47, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xf7, 0x01, 0x08, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f,
+    0x02, 0x00, 0x12, 0x04, 0xf7, 0x01, 0x1f, 0x45, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xf7, 0x01, 0x38, 0x40, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xf7, 0x01, 0x43, 0x44, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0xf7, 0x01, 0x1f, 0x37, 0x0a, 0x50, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06, 0xfa, 0x01, 0x00,
+    0xfd, 0x01, 0x01, 0x1a, 0x42, 0x20, 0x41, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x70, 0x6f,
+    0x69, 0x6e, 0x74, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x4c, 0x53, 0x4e, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x2c, 0x20, 0x73, 0x75, 0x63,
+    0x68, 0x20, 0x61, 0x73, 0x20, 0x60, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x2d, 0x32, 0x30,
+    0x32, 0x36, 0x2d, 0x31, 0x30, 0x60, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04,
+    0xfa, 0x01, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xfb, 0x01,
+    0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfb, 0x01, 0x09,
+    0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfb, 0x01, 0x10, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x05, 0x12, 0x04, 0xfb, 0x01, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x01, 0x12, 0x04, 0xfc, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x20, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfc, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x20, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfc, 0x01, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x20, 0x02, 0x01, 0x05, 0x12, 0x04, 0xfc, 0x01, 0x02, 0x08, 0x0a, 0xb0, 0x03, 0x0a, 0x02, 0x04,
+    0x21, 0x12, 0x06, 0x85, 0x02, 0x00, 0x89, 0x02, 0x01, 0x1a, 0xa1, 0x03, 0x20, 0x54, 0x61, 0x67,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x27, 0x73, 0x20, 0x73, 0x6e,
+    0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x61, 0x74, 0x20, 0x6c, 0x73, 0x6e, 0x2e, 0x20, 0x54,
+    0x61, 0x67, 0x73, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20,
+    0x69, 0x6e, 0x20, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x4c,
+    0x53, 0x4e, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x0a, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x2c, 0x20,
+    0x70, 0x75, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2c,
+    0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x61, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x61, 0x67, 0x65,
+    0x73, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x67, 0x67, 0x65,
+    0x64, 0x0a, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x20, 0x69, 0x73, 0x20, 0x72,
+    0x65, 0x74, 0x61, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67,
+    0x61, 0x72, 0x62, 0x61, 0x67, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72,
+    0x20, 0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x67, 0x20, 0x69,
+    0x73, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x20, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x74, 0x61, 0x67, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x61, 0x6c, 0x72, 0x65, 0x61, 0x64, 0x79, 0x20, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x20,
+    0x61, 0x74, 0x20, 0x6c, 0x73, 0x6e, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x65, 0x66,
+    0x66, 0x65, 0x63, 0x74, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61,
+    0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x0a, 0x20, 0x74, 0x61, 0x67, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x74, 0x20, 0x61, 0x6e, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x20, 0x4c, 0x53, 0x4e, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x73, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x47, 0x52, 0x41, 0x46, 0x54, 0x5f, 0x45, 0x52, 0x52, 0x5f, 0x43, 0x4f, 0x44,
+    0x45, 0x5f, 0x54, 0x41, 0x47, 0x5f, 0x45, 0x58, 0x49, 0x53, 0x54, 0x53, 0x2e, 0x0a, 0x20, 0x52,
+    0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65,
+    0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x54, 0x61, 0x67, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x21, 0x01, 0x12, 0x04, 0x85, 0x02, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21,
+    0x02, 0x00, 0x12, 0x04, 0x86, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x86, 0x02, 0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x86, 0x02, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x86, 0x02, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x01, 0x12, 0x04, 0x87,
+    0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x01, 0x12, 0x04, 0x87, 0x02,
+    0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x03, 0x12, 0x04, 0x87, 0x02, 0x10,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x05, 0x12, 0x04, 0x87, 0x02, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x02, 0x12, 0x04, 0x88, 0x02, 0x02, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x21, 0x02, 0x02, 0x01, 0x12, 0x04, 0x88, 0x02, 0x09, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x21, 0x02, 0x02, 0x03, 0x12, 0x04, 0x88, 0x02, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x21, 0x02, 0x02, 0x05, 0x12, 0x04, 0x88, 0x02, 0x02, 0x08, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x22, 0x12, 0x04, 0x8b, 0x02, 0x00, 0x2a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x22, 0x01, 0x12, 0x04,
+    0x8b, 0x02, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x00, 0x12, 0x04, 0x8b, 0x02,
+    0x1c, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8b, 0x02, 0x20,
+    0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8b, 0x02, 0x26, 0x27,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x06, 0x12, 0x04, 0x8b, 0x02, 0x1c, 0x1f, 0x0a,
+    0x64, 0x0a, 0x02, 0x04, 0x23, 0x12, 0x04, 0x8f, 0x02, 0x00, 0x2a, 0x1a, 0x58, 0x20, 0x4c, 0x69,
+    0x73, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x56, 0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x27, 0x73, 0x20,
+    0x74, 0x61, 0x67, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x62, 0x79,
+    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a,
+    0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d, 0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65,
+    0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0x8f, 0x02,
+    0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02, 0x00, 0x12, 0x04, 0x8f, 0x02, 0x1a, 0x28,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8f, 0x02, 0x20, 0x23, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8f, 0x02, 0x26, 0x27, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8f, 0x02, 0x1a, 0x1f, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x24, 0x12, 0x04, 0x91, 0x02, 0x00, 0x33, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x24, 0x01,
+    0x12, 0x04, 0x91, 0x02, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x00, 0x12, 0x04,
+    0x91, 0x02, 0x1b, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91,
+    0x02, 0x28, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x02,
+    0x2f, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x04, 0x12, 0x04, 0x91, 0x02, 0x1b,
+    0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x06, 0x12, 0x04, 0x91, 0x02, 0x24, 0x27,
+    0x0a, 0xfb, 0x01, 0x0a, 0x02, 0x04, 0x25, 0x12, 0x06, 0x97, 0x02, 0x00, 0x9a, 0x02, 0x01, 0x1a,
+    0xec, 0x01, 0x20, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x61, 0x20, 0x74, 0x61, 0x67, 0x2c,
+    0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x61,
+    0x72, 0x62, 0x61, 0x67, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x20,
+    0x74, 0x6f, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x61, 0x67, 0x67, 0x65, 0x64, 0x20, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x0a, 0x20,
+    0x6f, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x74, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x6f, 0x75,
+    0x74, 0x73, 0x69, 0x64, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x74, 0x65, 0x6e, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x2e, 0x20, 0x46, 0x61, 0x69, 0x6c,
+    0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x0a, 0x20, 0x47, 0x52, 0x41, 0x46, 0x54, 0x5f, 0x45, 0x52,
+    0x52, 0x5f, 0x43, 0x4f, 0x44, 0x45, 0x5f, 0x54, 0x41, 0x47, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46,
+    0x4f, 0x55, 0x4e, 0x44, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x61, 0x67, 0x20,
+    0x64, 0x6f, 0x65, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74, 0x2e, 0x0a, 0x20,
+    0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x73, 0x3a, 0x20, 0x67, 0x72, 0x61, 0x66, 0x74, 0x2e, 0x6d,
+    0x65, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x6c, 0x65,
+    0x74, 0x65, 0x54, 0x61, 0x67, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x25, 0x01, 0x12, 0x04, 0x97, 0x02, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x25, 0x02, 0x00, 0x12, 0x04, 0x98, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x98, 0x02, 0x08, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x98, 0x02, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0x98, 0x02, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x01, 0x12, 0x04,
+    0x99, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x01, 0x12, 0x04, 0x99,
+    0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x03, 0x12, 0x04, 0x99, 0x02,
+    0x10, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x05, 0x12, 0x04, 0x99, 0x02, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x26, 0x12, 0x06, 0x9c, 0x02, 0x00, 0x9f, 0x02, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x26, 0x01, 0x12, 0x04, 0x9c, 0x02, 0x08, 0x19, 0x0a, 0x1f, 0x0a, 0x04,
+    0x04, 0x26, 0x02, 0x00, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x0e, 0x1a, 0x11, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x20, 0x74, 0x61, 0x67, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x26, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9e, 0x02, 0x06, 0x09, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x26, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9e, 0x02, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x26, 0x02, 0x00, 0x06, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x05, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12,
+    0x03, 0x00, 0x00, 0x12, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
-// @@protoc_insertion_point(module)
\ No newline at end of file
+// @@protoc_insertion_point(module)
@@ -3,22 +3,25 @@ include!("mod.rs");
 
 use std::{error::Error, fmt::Display, ops::RangeBounds, time::SystemTime};
 
+use bundle::v1::Bundle;
 use bytes::Bytes;
-use common::v1::{Commit, GraftErr, LsnRange, SegmentInfo};
+use common::v1::{Commit, CommitMetadata, GraftErr, LsnRange, SegmentInfo};
 use culprit::{Culprit, ResultExt};
 use graft_core::{
     PageCount, PageIdx, SegmentId, VolumeId,
     gid::{ClientId, GidParseErr},
     lsn::{InvalidLSN, LSN, LSNRangeExt},
-    page::{Page, PageSizeErr},
+    page::{Page, PageSize, PageSizeErr},
     page_idx::ConvertToPageIdxErr,
 };
 use pagestore::v1::PageAtIdx;
+use prost::Message;
 use prost_types::TimestampError;
 
 pub use graft::common::v1::{GraftErrCode, Snapshot};
 pub use graft::*;
 use splinter_rs::{DecodeErr, SplinterRef};
+use thiserror::Error;
 
 impl Error for GraftErr {}
 impl Display for GraftErr {
@@ -40,9 +43,62 @@ impl Commit {
     }
 }
 
+/// The maximum encoded size of a commit's `CommitMetadata` in bytes
+pub const MAX_COMMIT_METADATA_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Error)]
+pub enum CommitMetadataErr {
+    #[error("commit metadata is {0} bytes; the limit is {MAX_COMMIT_METADATA_SIZE}")]
+    TooLarge(usize),
+
+    #[error("commit metadata json is invalid: {0}")]
+    InvalidJson(String),
+}
+
+impl CommitMetadata {
+    /// Returns true if every field is empty, in which case the metadata
+    /// doesn't need to be stored
+    pub fn is_empty(&self) -> bool {
+        self.message.is_empty() && self.author.is_empty() && self.json.is_empty()
+    }
+
+    /// Ensure that the metadata fits within `MAX_COMMIT_METADATA_SIZE` and
+    /// that its json field is empty or a valid JSON document
+    pub fn validate(&self) -> Result<(), Culprit<CommitMetadataErr>> {
+        let size = self.encoded_len();
+        if size > MAX_COMMIT_METADATA_SIZE {
+            return Err(Culprit::new(CommitMetadataErr::TooLarge(size)));
+        }
+        if !self.json.is_empty() {
+            if let Err(err) = serde_json::from_str::<serde::de::IgnoredAny>(&self.json) {
+                return Err(Culprit::new(CommitMetadataErr::InvalidJson(
+                    err.to_string(),
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Bundle {
+    pub fn vid(&self) -> Result<&VolumeId, Culprit<GidParseErr>> {
+        Ok(self.vid.as_ref().try_into()?)
+    }
+}
+
 impl SegmentInfo {
     pub fn new(sid: &SegmentId, graft: Bytes) -> Self {
-        Self { sid: sid.copy_to_bytes(), graft }
+        Self {
+            sid: sid.copy_to_bytes(),
+            graft,
+            vid: Bytes::new(),
+        }
+    }
+
+    /// Record that the segment contains pages belonging to `vid` rather than
+    /// the Volume it was committed to
+    pub fn with_vid(self, vid: &VolumeId) -> Self {
+        Self { vid: vid.copy_to_bytes(), ..self }
     }
 
     pub fn sid(&self) -> Result<&SegmentId, Culprit<GidParseErr>> {
@@ -52,6 +108,35 @@ impl SegmentInfo {
     pub fn graft(&self) -> Result<SplinterRef<Bytes>, Culprit<DecodeErr>> {
         SplinterRef::from_bytes(self.graft.clone())
     }
+
+    /// Returns the Volume whose pages the segment contains, or None if they
+    /// belong to the Volume the segment was committed to
+    pub fn vid(&self) -> Result<Option<&VolumeId>, Culprit<GidParseErr>> {
+        if self.vid.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(self.vid.as_ref().try_into()?))
+        }
+    }
+}
+
+/// Encode a page size for a proto message. The default page size is encoded as
+/// zero, which allows it to be omitted from the message.
+pub fn encode_page_size(page_size: PageSize) -> u32 {
+    if page_size.is_default() {
+        0
+    } else {
+        page_size.as_u32()
+    }
+}
+
+/// Decode a page size from a proto message, where zero means the default page size
+pub fn decode_page_size(page_size: u32) -> Result<PageSize, Culprit<PageSizeErr>> {
+    if page_size == 0 {
+        Ok(PageSize::DEFAULT)
+    } else {
+        PageSize::try_from(page_size)
+    }
 }
 
 impl Snapshot {
@@ -70,6 +155,14 @@ impl Snapshot {
             checkpoint_lsn: checkpoint_lsn.into(),
             page_count: page_count.into(),
             timestamp: Some(timestamp.into()),
+            page_size: 0,
+        }
+    }
+
+    pub fn with_page_size(self, page_size: PageSize) -> Self {
+        Self {
+            page_size: encode_page_size(page_size),
+            ..self
         }
     }
 
@@ -93,6 +186,10 @@ impl Snapshot {
         self.page_count.into()
     }
 
+    pub fn page_size(&self) -> Result<PageSize, Culprit<PageSizeErr>> {
+        decode_page_size(self.page_size)
+    }
+
     pub fn system_time(&self) -> Result<Option<SystemTime>, TimestampError> {
         self.timestamp.map(|ts| ts.try_into()).transpose()
     }
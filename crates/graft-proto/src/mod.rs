@@ -1,5 +1,15 @@
 #[allow(clippy::doc_markdown)]
 pub mod graft {
+    pub mod agent {
+        pub mod v1 {
+            include!("graft.agent.v1.rs");
+        }
+    }
+    pub mod bundle {
+        pub mod v1 {
+            include!("graft.bundle.v1.rs");
+        }
+    }
     pub mod common {
         pub mod v1 {
             include!("graft.common.v1.rs");
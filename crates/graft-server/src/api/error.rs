@@ -7,6 +7,7 @@ use axum::{
 use culprit::Culprit;
 use graft_core::{
     gid::GidParseErr, lsn::InvalidLSN, page::PageSizeErr, page_idx::ConvertToPageIdxErr,
+    tag_name::TagNameErr, volume_name::VolumeNameErr,
 };
 use graft_proto::common::v1::{GraftErr, GraftErrCode};
 use splinter_rs::DecodeErr;
@@ -83,6 +84,9 @@ pub enum ApiErrCtx {
     #[error("duplicate page index")]
     DuplicatePageIdx,
 
+    #[error("page size does not match the volume's page size")]
+    PageSizeMismatch,
+
     #[error("failed to parse graft")]
     GraftDecodeErr(#[from] DecodeErr),
 
@@ -98,6 +102,42 @@ pub enum ApiErrCtx {
     #[error("invalid LSN")]
     InvalidLSN,
 
+    #[error("invalid volume name: {0}")]
+    InvalidVolumeName(#[from] VolumeNameErr),
+
+    #[error("volume name not found")]
+    NameNotFound,
+
+    #[error("volume has been deleted")]
+    VolumeDeleted,
+
+    #[error("volume can no longer be undeleted")]
+    UndeleteExpired,
+
+    #[error("invalid volume labels")]
+    InvalidLabels,
+
+    #[error("invalid commit metadata")]
+    InvalidCommitMetadata,
+
+    #[error("volume labels have been concurrently updated")]
+    LabelsVersionConflict,
+
+    #[error("compacted segments don't match the volume")]
+    InvalidCompaction,
+
+    #[error("invalid tag name: {0}")]
+    InvalidTagName(#[from] TagNameErr),
+
+    #[error("a tag and an LSN may not both be specified")]
+    TagWithLsn,
+
+    #[error("volume tag not found")]
+    TagNotFound,
+
+    #[error("volume tag already exists")]
+    TagExists,
+
     #[error("unauthorized")]
     Unauthorized,
 }
@@ -123,6 +163,8 @@ impl From<UpdateErr> for ApiErrCtx {
             UpdateErr::CatalogErr(err) => Self::CatalogErr(err),
             UpdateErr::StoreErr(err) => Self::VolumeStoreErr(err),
             UpdateErr::ClientErr(err) => Self::ClientErr(err),
+            UpdateErr::CheckpointMissing => Self::SnapshotMissing,
+            UpdateErr::InvalidCompaction => Self::InvalidCompaction,
         }
     }
 }
@@ -141,17 +183,29 @@ impl IntoResponse for ApiErr {
             SnapshotMissing => GraftErrCode::SnapshotMissing,
             RejectedCommit => GraftErrCode::CommitRejected,
             Unauthorized => GraftErrCode::Unauthorized,
+            NameNotFound => GraftErrCode::NameNotFound,
+            VolumeDeleted | UndeleteExpired => GraftErrCode::VolumeDeleted,
+            LabelsVersionConflict => GraftErrCode::VersionConflict,
+            TagNotFound => GraftErrCode::TagNotFound,
+            TagExists => GraftErrCode::TagExists,
 
             InvalidIdempotentCommit
             | InvalidRequestBody
             | GidParseErr(_)
             | PageSizeErr(_)
             | DuplicatePageIdx
+            | PageSizeMismatch
             | GraftDecodeErr(_)
             | ConvertToPageIdxErr(_)
             | ZeroPageIdx
             | GraftTooLarge
-            | InvalidLSN => GraftErrCode::Client,
+            | InvalidLSN
+            | InvalidVolumeName(_)
+            | InvalidLabels
+            | InvalidCommitMetadata
+            | InvalidCompaction
+            | InvalidTagName(_)
+            | TagWithLsn => GraftErrCode::Client,
 
             SegmentDownloadErr
             | SegmentUploadErr
@@ -167,7 +221,12 @@ impl IntoResponse for ApiErr {
         match code {
             GraftErrCode::SnapshotMissing
             | GraftErrCode::CommitRejected
-            | GraftErrCode::Unauthorized => {
+            | GraftErrCode::Unauthorized
+            | GraftErrCode::NameNotFound
+            | GraftErrCode::VolumeDeleted
+            | GraftErrCode::VersionConflict
+            | GraftErrCode::TagNotFound
+            | GraftErrCode::TagExists => {
                 tracing::trace!(culprit = ?self.0, "client error")
             }
             GraftErrCode::Client => {
@@ -216,7 +275,12 @@ fn graft_err_code_to_status(code: GraftErrCode) -> StatusCode {
         GraftErrCode::Unspecified => unreachable!("graft error code unspecified"),
         GraftErrCode::Client => StatusCode::BAD_REQUEST,
         GraftErrCode::SnapshotMissing => StatusCode::NOT_FOUND,
+        GraftErrCode::NameNotFound => StatusCode::NOT_FOUND,
+        GraftErrCode::VolumeDeleted => StatusCode::GONE,
         GraftErrCode::CommitRejected => StatusCode::CONFLICT,
+        GraftErrCode::VersionConflict => StatusCode::CONFLICT,
+        GraftErrCode::TagNotFound => StatusCode::NOT_FOUND,
+        GraftErrCode::TagExists => StatusCode::CONFLICT,
         GraftErrCode::Unauthorized => StatusCode::UNAUTHORIZED,
         GraftErrCode::Server => StatusCode::INTERNAL_SERVER_ERROR,
         GraftErrCode::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
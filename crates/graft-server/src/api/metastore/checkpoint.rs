@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{CheckpointRequest, CheckpointResponse};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::{MetastoreApiState, check_tombstone};
+
+/// Writes a checkpoint of the Volume at its latest LSN if the latest snapshot
+/// isn't already checkpointed. The next commit to the Volume will reference the
+/// checkpoint.
+#[tracing::instrument(name = "metastore/v1/checkpoint", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<CheckpointRequest>,
+) -> Result<ProtoResponse<CheckpointResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+
+    tracing::info!(?vid);
+
+    state.check_deleted(&vid)?;
+
+    let Some(snapshot) = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &vid, None)
+        .await
+        .or_into_ctx()?
+    else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} has no snapshots to checkpoint"),
+        )
+        .into());
+    };
+    check_tombstone(&vid, &snapshot)?;
+
+    // the latest snapshot may already be checkpointed
+    let written = state.catalog.latest_checkpoint(&vid).or_into_ctx()?;
+    let checkpoint_lsn = written.map_or(snapshot.checkpoint(), |c| {
+        c.lsn().max(snapshot.checkpoint())
+    });
+
+    let checkpoint_lsn = if checkpoint_lsn == snapshot.lsn() {
+        checkpoint_lsn
+    } else {
+        state
+            .updater
+            .write_checkpoint(&state.store, &state.catalog, &snapshot)
+            .await
+            .or_into_ctx()?
+            .lsn()
+    };
+
+    Ok(ProtoResponse::new(CheckpointResponse {
+        checkpoint_lsn: checkpoint_lsn.into(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_core::{SegmentId, gid::ClientId, lsn::LSN};
+    use graft_proto::{
+        common::v1::{LsnRange, SegmentInfo},
+        metastore::v1::{
+            CommitRequest, CommitResponse, PullCheckpointRequest, PullCheckpointResponse,
+            PullGraftRequest, PullGraftResponse,
+        },
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+    use splinter_rs::Splinter;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF,
+            metastore::{commit, pull_checkpoint, pull_graft},
+        },
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_checkpoint() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let state = Arc::new(
+            MetastoreApiState::new(store, catalog.clone(), VolumeCatalogUpdater::new(8))
+                .with_checkpoint_interval(4),
+        );
+
+        let router = Router::new()
+            .route("/commit", post(commit::handler))
+            .route("/checkpoint", post(handler))
+            .route("/pull_checkpoint", post(pull_checkpoint::handler))
+            .route("/pull_graft", post(pull_graft::handler))
+            .with_state(state);
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // checkpointing a missing volume fails
+        let req = CheckpointRequest { vid: vid.copy_to_bytes() };
+        let resp = server
+            .post("/checkpoint")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        // each commit writes page 1 and page lsn to a new segment
+        let segments: Vec<SegmentId> = (0..8).map(|_| SegmentId::random()).collect();
+        let commit = async |lsn: u64| {
+            let sid = &segments[lsn as usize - 1];
+            let graft = Splinter::from_iter([1, lsn as u32]).serialize_to_bytes();
+            let req = CommitRequest {
+                vid: vid.copy_to_bytes(),
+                cid: cid.copy_to_bytes(),
+                snapshot_lsn: (lsn > 1).then(|| lsn - 1),
+                page_count: lsn as u32,
+                segments: vec![SegmentInfo::new(sid, graft)],
+                page_size: 0,
+                metadata: None,
+            };
+            let resp = server
+                .post("/commit")
+                .bytes(req.encode_to_vec().into())
+                .await;
+            let resp = CommitResponse::decode(resp.into_bytes()).unwrap();
+            resp.snapshot.unwrap().checkpoint().unwrap()
+        };
+
+        // the checkpoint advances once the interval elapses
+        for lsn in 1..=5 {
+            assert_eq!(commit(lsn).await, LSN::FIRST);
+        }
+        assert_eq!(commit(6).await, LSN::new(5));
+        assert_eq!(commit(7).await, LSN::new(5));
+
+        // the checkpoint maps each page to the last segment that wrote it
+        let req = PullCheckpointRequest { vid: vid.copy_to_bytes(), lsn: 5 };
+        let resp = server
+            .post("/pull_checkpoint")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let checkpoint = PullCheckpointResponse::decode(resp.into_bytes())
+            .unwrap()
+            .checkpoint
+            .unwrap();
+        assert_eq!(checkpoint.snapshot.unwrap().lsn().unwrap(), 5);
+        assert_eq!(checkpoint.segments.len(), 4);
+        for info in checkpoint.segments {
+            let sid = SegmentId::try_from(info.sid).unwrap();
+            let lsn = segments.iter().position(|s| s == &sid).unwrap() as u32 + 1;
+            let graft = Splinter::from_bytes(info.graft).unwrap();
+            let expected = if lsn == 5 { vec![1, 5] } else { vec![lsn] };
+            assert_eq!(graft.iter().collect::<Vec<_>>(), expected);
+        }
+
+        // explicitly checkpoint the latest snapshot
+        let resp = server
+            .post("/checkpoint")
+            .bytes(
+                CheckpointRequest { vid: vid.copy_to_bytes() }
+                    .encode_to_vec()
+                    .into(),
+            )
+            .await;
+        let resp = CheckpointResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.checkpoint_lsn, 7);
+        assert_eq!(commit(8).await, LSN::new(7));
+
+        // a full pull covers every page, while ranges only cover changes
+        let pull = async |range: Option<LsnRange>| {
+            let req = PullGraftRequest {
+                vid: vid.copy_to_bytes(),
+                range,
+                tag: String::new(),
+            };
+            let resp = server
+                .post("/pull_graft")
+                .bytes(req.encode_to_vec().into())
+                .await;
+            let resp = PullGraftResponse::decode(resp.into_bytes()).unwrap();
+            Splinter::from_bytes(resp.graft).unwrap().cardinality()
+        };
+        assert_eq!(pull(None).await, 8);
+        assert_eq!(
+            pull(Some(LsnRange::from_range(LSN::new(8)..=LSN::new(8)))).await,
+            2
+        );
+        assert_eq!(
+            pull(Some(LsnRange::from_range(LSN::new(2)..=LSN::new(3)))).await,
+            3
+        );
+
+        // checkpoints which don't exist are reported missing
+        let req = PullCheckpointRequest { vid: vid.copy_to_bytes(), lsn: 6 };
+        let resp = server
+            .post("/pull_checkpoint")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+    }
+}
@@ -3,7 +3,10 @@ use std::{sync::Arc, time::SystemTime};
 use axum::extract::State;
 use culprit::{Culprit, ResultExt};
 use graft_core::{VolumeId, gid::ClientId, lsn::LSN, page_count::PageCount};
-use graft_proto::metastore::v1::{CommitRequest, CommitResponse};
+use graft_proto::{
+    decode_page_size,
+    metastore::v1::{CommitRequest, CommitResponse},
+};
 use splinter_rs::{
     Splinter,
     ops::{Cut, Union},
@@ -15,10 +18,13 @@ use crate::{
         extractors::Protobuf,
         response::ProtoResponse,
     },
-    volume::commit::{CommitBuilder, CommitMeta},
+    volume::{
+        commit::{CommitBuilder, CommitMeta},
+        store::VolumeStoreErr,
+    },
 };
 
-use super::MetastoreApiState;
+use super::{MetastoreApiState, check_tombstone};
 
 #[tracing::instrument(name = "metastore/v1/commit", skip(state, req))]
 pub async fn handler(
@@ -33,12 +39,19 @@ pub async fn handler(
         .transpose()
         .or_into_ctx()?;
     let page_count: PageCount = req.page_count.into();
+    let page_size = decode_page_size(req.page_size).or_into_ctx()?;
+    if let Some(metadata) = &req.metadata {
+        metadata
+            .validate()
+            .or_ctx(|_| ApiErrCtx::InvalidCommitMetadata)?;
+    }
 
     tracing::info!(
         ?vid,
         ?cid,
         ?snapshot_lsn,
         ?page_count,
+        %page_size,
         num_segments = req.segments.len(),
     );
 
@@ -52,6 +65,11 @@ pub async fn handler(
         .await
         .or_into_ctx()?;
 
+    // deleted Volumes end with a tombstone commit
+    if let Some(latest) = &latest_snapshot {
+        check_tombstone(&vid, latest)?;
+    }
+
     let latest_lsn = latest_snapshot.as_ref().map(|s| s.lsn());
 
     // if the client's snapshot is out of sync with the latest snapshot we can't
@@ -81,6 +99,13 @@ pub async fn handler(
                         )
                         .into());
                     }
+                    if commit_snapshot.page_size() != page_size {
+                        return Err(Culprit::new_with_note(
+                            ApiErrCtx::InvalidIdempotentCommit,
+                            "page size mismatch",
+                        )
+                        .into());
+                    }
 
                     // check that the segments being committed contain the same
                     // set of pages as the segments in the catalog
@@ -138,8 +163,41 @@ pub async fn handler(
         return Err(Culprit::new_with_note(ApiErrCtx::RejectedCommit, note).into());
     }
 
-    // checkpoint doesn't change
-    let checkpoint = latest_snapshot.map_or(LSN::FIRST, |s| s.checkpoint());
+    // a Volume's page size is chosen by its first commit and never changes
+    if let Some(latest) = latest_snapshot
+        .as_ref()
+        .filter(|latest| latest.page_size() != page_size)
+    {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::PageSizeMismatch,
+            format!(
+                "volume {vid} has page size {}; commit has page size {page_size}",
+                latest.page_size()
+            ),
+        )
+        .into());
+    }
+
+    // advance the checkpoint to the latest checkpoint written for this volume,
+    // checkpointing the latest snapshot if too many commits have accumulated
+    let mut checkpoint = LSN::FIRST;
+    if let Some(latest) = &latest_snapshot {
+        checkpoint = latest.checkpoint();
+        if let Some(written) = state.catalog.latest_checkpoint(&vid).or_into_ctx()? {
+            checkpoint = checkpoint.max(written.lsn());
+        }
+
+        let interval = state.checkpoint_interval();
+        let since = latest.lsn().since(&checkpoint).unwrap_or_default();
+        if interval > 0 && since >= interval {
+            checkpoint = state
+                .updater
+                .write_checkpoint(&state.store, &state.catalog, latest)
+                .await
+                .or_into_ctx()?
+                .lsn();
+        }
+    }
 
     let mut commit = CommitBuilder::new_with_capacity(
         CommitMeta::new(
@@ -149,9 +207,11 @@ pub async fn handler(
             checkpoint,
             page_count,
             SystemTime::now(),
-        ),
+        )
+        .with_page_size(page_size),
         req.segments.len(),
-    );
+    )
+    .with_metadata(req.metadata);
     for segment in req.segments {
         let sid = segment.sid().or_into_ctx()?;
         let graft = segment.graft().or_into_ctx()?;
@@ -163,8 +223,22 @@ pub async fn handler(
 
     let commit = commit.build();
 
-    // commit the new snapshot to the store
-    state.store.commit(commit.clone()).await.or_into_ctx()?;
+    // commit the new snapshot to the store. Deletes write a tombstone commit to
+    // the same LSN, so if the commit conflicts we recheck whether the Volume
+    // was deleted. A successful commit is always ordered before any delete.
+    if let Err(err) = state.store.commit(commit.clone()).await {
+        if matches!(err.ctx(), VolumeStoreErr::CommitAlreadyExists) {
+            let latest = state
+                .updater
+                .snapshot(&state.store, &state.catalog, &vid, None)
+                .await
+                .or_into_ctx()?;
+            if let Some(latest) = &latest {
+                check_tombstone(&vid, latest)?;
+            }
+        }
+        return Err(err.map_ctx(ApiErrCtx::from).into());
+    }
 
     // update the catalog
     let mut batch = state.catalog.batch_insert();
@@ -186,8 +260,8 @@ pub async fn handler(
 mod tests {
     use axum::handler::Handler;
     use axum_test::TestServer;
-    use graft_core::SegmentId;
-    use graft_proto::common::v1::SegmentInfo;
+    use graft_core::{SegmentId, page::PageSize};
+    use graft_proto::common::v1::{CommitMetadata, SegmentInfo};
     use object_store::memory::InMemory;
     use prost::Message;
     use splinter_rs::Splinter;
@@ -237,6 +311,8 @@ mod tests {
                 snapshot_lsn,
                 page_count: 1,
                 segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+                page_size: 0,
+                metadata: None,
             };
             commits.push(commit.clone());
 
@@ -294,6 +370,16 @@ mod tests {
                 c.segments[0].graft = Splinter::from_iter([2u32]).serialize_to_bytes();
                 c
             }),
+            ("different page size", {
+                let mut c = commits[5].clone();
+                c.page_size = PageSize::Kb16.as_u32();
+                c
+            }),
+            ("invalid metadata json", {
+                let mut c = commits[5].clone();
+                c.metadata = Some(CommitMetadata { json: "{".into(), ..Default::default() });
+                c
+            }),
             ("extra segment", {
                 let mut c = commits[5].clone();
                 c.segments
@@ -319,6 +405,8 @@ mod tests {
             snapshot_lsn: Some(5),
             page_count: 1,
             segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+            page_size: 0,
+            metadata: None,
         };
         server
             .post("/")
@@ -326,4 +414,72 @@ mod tests {
             .bytes(commit.encode_to_vec().into())
             .await;
     }
+
+    #[graft_test::test]
+    async fn test_commit_page_size() {
+        let store = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(store));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let graft = Splinter::from_iter([1u32]).serialize_to_bytes();
+        let mkcommit = |snapshot_lsn, page_size: PageSize| CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn,
+            page_count: 1,
+            segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+            page_size: page_size.as_u32(),
+            metadata: None,
+        };
+
+        // the first commit picks the volume's page size
+        let resp = server
+            .post("/")
+            .bytes(mkcommit(None, PageSize::Kb16).encode_to_vec().into())
+            .await;
+        let snapshot = CommitResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.page_size().unwrap(), PageSize::Kb16);
+
+        // the page size is stored in the catalog and the store
+        let meta = catalog.latest_snapshot(&vid).unwrap().unwrap();
+        assert_eq!(meta.page_size(), PageSize::Kb16);
+        let commit = store.get_commit(&vid, LSN::FIRST).await.unwrap().unwrap();
+        assert_eq!(commit.meta().page_size(), PageSize::Kb16);
+
+        // commits with a different page size are rejected
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(mkcommit(Some(1), PageSize::Kb4).encode_to_vec().into())
+            .await;
+
+        // commits with the same page size succeed
+        let resp = server
+            .post("/")
+            .bytes(mkcommit(Some(1), PageSize::Kb16).encode_to_vec().into())
+            .await;
+        let snapshot = CommitResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), 2);
+        assert_eq!(snapshot.page_size().unwrap(), PageSize::Kb16);
+    }
 }
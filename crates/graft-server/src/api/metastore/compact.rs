@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, lsn::LSN};
+use graft_proto::{
+    common::v1::Commit,
+    metastore::v1::{CompactRequest, CompactResponse},
+};
+use splinter_rs::{Splinter, ops::Merge};
+use tryiter::TryIteratorExt;
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::{MetastoreApiState, segment_info};
+
+/// Rewrites the checkpoint at the requested LSN such that the pages contained
+/// by the compacted segments are read from them. Returns the new checkpoint.
+#[tracing::instrument(name = "metastore/v1/compact", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<CompactRequest>,
+) -> Result<ProtoResponse<CompactResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+
+    tracing::info!(?vid, ?lsn, num_segments = req.segments.len());
+
+    state.check_deleted(&vid)?;
+
+    // readers find compacted pages through the checkpoint their snapshot is
+    // based on, so only written checkpoints may be compacted
+    if lsn == LSN::FIRST {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidCompaction,
+            format!("volume {vid} has no written checkpoint at {lsn}"),
+        )
+        .into());
+    }
+    state
+        .updater
+        .update_checkpoint_from_store(&state.store, &state.catalog, &vid, lsn)
+        .await
+        .or_into_ctx()?;
+    let checkpoint = state
+        .catalog
+        .checkpoint(vid.clone(), lsn)
+        .or_into_ctx()?
+        .expect("missing checkpoint after update");
+
+    let mut compacted = Vec::with_capacity(req.segments.len());
+    for segment in req.segments {
+        let sid = segment.sid().or_into_ctx()?;
+        let mut pages = Splinter::default();
+        pages.merge(&segment.graft().or_into_ctx()?);
+        compacted.push((sid.clone(), pages));
+    }
+
+    let checkpoint = state
+        .updater
+        .write_compacted_checkpoint(&state.store, &state.catalog, &checkpoint, compacted)
+        .await
+        .or_into_ctx()?;
+
+    let version = state
+        .catalog
+        .checkpoint_version(vid.clone(), lsn)
+        .or_into_ctx()?;
+
+    let inherited = state.inherited_segments(&vid).await?;
+    let mut segments = Vec::default();
+    let mut scan = state.catalog.scan_checkpoint(&vid, lsn);
+    while let Some((key, graft)) = scan.try_next().or_into_ctx()? {
+        segments.push(segment_info(&inherited, key.sid(), graft.into_inner()));
+    }
+
+    Ok(ProtoResponse::new(CompactResponse {
+        checkpoint: Some(Commit {
+            snapshot: Some(checkpoint.into_snapshot()),
+            segments,
+            metadata: None,
+        }),
+        version: version.map(|v| v.as_str().to_owned()).unwrap_or_default(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_core::{SegmentId, gid::ClientId};
+    use graft_proto::{
+        common::v1::SegmentInfo,
+        metastore::v1::{CommitRequest, PullCheckpointRequest, PullCheckpointResponse},
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF,
+            metastore::{commit, pull_checkpoint},
+        },
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_compact() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let state = Arc::new(
+            MetastoreApiState::new(store, catalog.clone(), VolumeCatalogUpdater::new(8))
+                .with_checkpoint_interval(1),
+        );
+
+        let router = Router::new()
+            .route("/commit", post(commit::handler))
+            .route("/compact", post(handler))
+            .route("/pull_checkpoint", post(pull_checkpoint::handler))
+            .with_state(state);
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // each commit writes page 1 and page lsn to a new segment
+        for lsn in 1..=3u64 {
+            let graft = Splinter::from_iter([1, lsn as u32]).serialize_to_bytes();
+            let req = CommitRequest {
+                vid: vid.copy_to_bytes(),
+                cid: cid.copy_to_bytes(),
+                snapshot_lsn: (lsn > 1).then(|| lsn - 1),
+                page_count: lsn as u32,
+                segments: vec![SegmentInfo::new(&SegmentId::random(), graft)],
+                page_size: 0,
+                metadata: None,
+            };
+            server
+                .post("/commit")
+                .bytes(req.encode_to_vec().into())
+                .await;
+        }
+
+        // commit 3 checkpoints the volume at LSN 2
+        let snapshot = catalog.snapshot(vid.clone(), LSN::new(3)).unwrap().unwrap();
+        assert_eq!(snapshot.checkpoint(), LSN::new(2));
+
+        let compact = |lsn: u64, pages: &[u32]| CompactRequest {
+            vid: vid.copy_to_bytes(),
+            lsn,
+            segments: vec![SegmentInfo::new(
+                &SegmentId::random(),
+                Splinter::from_iter(pages.iter().copied()).serialize_to_bytes(),
+            )],
+        };
+
+        // compacting pages which aren't visible at the checkpoint fails
+        let resp = server
+            .post("/compact")
+            .bytes(compact(2, &[2, 3]).encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+
+        // compacting an LSN without a written checkpoint fails
+        let resp = server
+            .post("/compact")
+            .bytes(compact(1, &[1]).encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+        for lsn in [3, 4] {
+            let resp = server
+                .post("/compact")
+                .bytes(compact(lsn, &[1]).encode_to_vec().into())
+                .expect_failure()
+                .await;
+            assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+        }
+
+        // compacting every page in the checkpoint results in a single segment
+        let req = compact(2, &[1, 2]);
+        let sid = req.segments[0].sid.clone();
+        let resp = server
+            .post("/compact")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let checkpoint = CompactResponse::decode(resp.into_bytes())
+            .unwrap()
+            .checkpoint
+            .unwrap();
+        assert_eq!(checkpoint.snapshot.unwrap().lsn().unwrap(), 2);
+        assert_eq!(checkpoint.segments.len(), 1);
+        assert_eq!(checkpoint.segments[0].sid, sid);
+
+        // the compacted checkpoint replaces the stored checkpoint, which
+        // snapshots based on it read from
+        let req = PullCheckpointRequest { vid: vid.copy_to_bytes(), lsn: 2 };
+        let resp = server
+            .post("/pull_checkpoint")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let checkpoint = PullCheckpointResponse::decode(resp.into_bytes())
+            .unwrap()
+            .checkpoint
+            .unwrap();
+        assert_eq!(checkpoint.segments.len(), 1);
+        let graft = Splinter::from_bytes(checkpoint.segments[0].graft.clone()).unwrap();
+        assert_eq!(graft.iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+}
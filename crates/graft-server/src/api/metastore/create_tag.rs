@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, lsn::LSN, tag_name::TagName};
+use graft_proto::metastore::v1::{CreateTagRequest, CreateTagResponse, Tag};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+/// Points a tag at one of the Volume's snapshots. Tags are never moved once
+/// created, so creating a tag which already points at the requested LSN
+/// succeeds while creating one which points elsewhere fails.
+#[tracing::instrument(name = "metastore/v1/create_tag", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<CreateTagRequest>,
+) -> Result<ProtoResponse<CreateTagResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let tag = TagName::new(req.name)?;
+    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+
+    tracing::info!(?vid, %tag, ?lsn);
+
+    state.check_deleted(&vid)?;
+
+    if state
+        .updater
+        .snapshot(&state.store, &state.catalog, &vid, Some(lsn))
+        .await
+        .or_into_ctx()?
+        .is_none()
+    {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} is missing snapshot at {lsn}"),
+        )
+        .into());
+    }
+
+    // the catalog may still contain snapshots whose commits have been garbage
+    // collected, and tagging one of them wouldn't make it readable again
+    let oldest = state.store.oldest_commit(&vid).await.or_into_ctx()?;
+    if oldest.is_none_or(|oldest| lsn < oldest) {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} no longer retains the commit at {lsn}"),
+        )
+        .into());
+    }
+
+    let existing = state.store.put_tag(&vid, &tag, lsn).await.or_into_ctx()?;
+    if existing != lsn {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::TagExists,
+            format!("tag {tag} of volume {vid} already points at {existing}"),
+        )
+        .into());
+    }
+
+    Ok(ProtoResponse::new(CreateTagResponse {
+        tag: Some(Tag { name: tag.to_string(), lsn: lsn.into() }),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_core::{gid::ClientId, page_count::PageCount};
+    use graft_proto::{
+        common::v1::{GraftErr, GraftErrCode},
+        metastore::v1::{
+            DeleteTagRequest, DeleteTagResponse, ListTagsRequest, ListTagsResponse,
+            SnapshotRequest, SnapshotResponse,
+        },
+    };
+    use object_store::{ObjectStore, memory::InMemory};
+    use prost::Message;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF,
+            metastore::{delete_tag, list_tags, snapshot},
+        },
+        volume::{
+            catalog::VolumeCatalog,
+            commit::{CommitBuilder, CommitMeta, commit_key_path},
+            store::VolumeStore,
+            updater::VolumeCatalogUpdater,
+        },
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_tags() {
+        let objstore = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(objstore.clone()));
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+        let router = Router::new()
+            .route("/create_tag", post(handler))
+            .route("/list_tags", post(list_tags::handler))
+            .route("/delete_tag", post(delete_tag::handler))
+            .route("/snapshot", post(snapshot::handler))
+            .with_state(state);
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        for lsn in 1..=3 {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                LSN::new(lsn),
+                LSN::FIRST,
+                PageCount::new(lsn as u32),
+                SystemTime::now(),
+            );
+            store
+                .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+                .await
+                .unwrap();
+        }
+
+        let create = |name: &str, lsn: u64| {
+            CreateTagRequest {
+                vid: vid.copy_to_bytes(),
+                name: name.into(),
+                lsn,
+            }
+            .encode_to_vec()
+        };
+        let graft_err =
+            |resp: axum_test::TestResponse| GraftErr::decode(resp.into_bytes()).unwrap();
+
+        // tags must point at an existing snapshot and have a valid name
+        let resp = server
+            .post("/create_tag")
+            .bytes(create("v1", 4).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+        let resp = server
+            .post("/create_tag")
+            .bytes(create("v1/2", 1).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+
+        for (name, lsn) in [("v1", 1), ("release-2026-10", 2)] {
+            let resp = server
+                .post("/create_tag")
+                .bytes(create(name, lsn).into())
+                .await;
+            let tag = CreateTagResponse::decode(resp.into_bytes())
+                .unwrap()
+                .tag
+                .unwrap();
+            assert_eq!(tag, Tag { name: name.into(), lsn });
+        }
+
+        // recreating a tag is idempotent, but tags can't be moved
+        server
+            .post("/create_tag")
+            .bytes(create("v1", 1).into())
+            .await;
+        let resp = server
+            .post("/create_tag")
+            .bytes(create("v1", 3).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::CONFLICT);
+        assert_eq!(graft_err(resp).code(), GraftErrCode::TagExists);
+
+        // tags are listed in order by name
+        let list = ListTagsRequest { vid: vid.copy_to_bytes() }.encode_to_vec();
+        let resp = server.post("/list_tags").bytes(list.clone().into()).await;
+        let tags = ListTagsResponse::decode(resp.into_bytes()).unwrap().tags;
+        assert_eq!(
+            tags,
+            vec![
+                Tag { name: "release-2026-10".into(), lsn: 2 },
+                Tag { name: "v1".into(), lsn: 1 },
+            ]
+        );
+
+        // snapshots can be requested by tag
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: "release-2026-10".into(),
+        };
+        let resp = server
+            .post("/snapshot")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let snapshot = SnapshotResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), 2);
+        assert_eq!(snapshot.pages(), 2);
+
+        // but not by both a tag and an LSN
+        let req = SnapshotRequest { lsn: Some(2), ..req };
+        let resp = server
+            .post("/snapshot")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+
+        // deleting a tag returns it
+        let delete = DeleteTagRequest {
+            vid: vid.copy_to_bytes(),
+            name: "v1".into(),
+        }
+        .encode_to_vec();
+        let resp = server
+            .post("/delete_tag")
+            .bytes(delete.clone().into())
+            .await;
+        let tag = DeleteTagResponse::decode(resp.into_bytes())
+            .unwrap()
+            .tag
+            .unwrap();
+        assert_eq!(tag, Tag { name: "v1".into(), lsn: 1 });
+
+        // after which it's gone
+        let resp = server
+            .post("/delete_tag")
+            .bytes(delete.into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(graft_err(resp).code(), GraftErrCode::TagNotFound);
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: "v1".into(),
+        };
+        let resp = server
+            .post("/snapshot")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(graft_err(resp).code(), GraftErrCode::TagNotFound);
+
+        // and the tag may be recreated elsewhere
+        server
+            .post("/create_tag")
+            .bytes(create("v1", 3).into())
+            .await;
+        let resp = server.post("/list_tags").bytes(list.into()).await;
+        let tags = ListTagsResponse::decode(resp.into_bytes()).unwrap().tags;
+        assert_eq!(tags[1], Tag { name: "v1".into(), lsn: 3 });
+
+        // snapshots which are still cached in the catalog can't be tagged once
+        // their commit has been garbage collected
+        objstore
+            .delete(&commit_key_path(&vid, LSN::FIRST))
+            .await
+            .unwrap();
+        let resp = server
+            .post("/create_tag")
+            .bytes(create("v0", 1).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(graft_err(resp).code(), GraftErrCode::SnapshotMissing);
+    }
+}
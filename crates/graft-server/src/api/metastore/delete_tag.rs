@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, tag_name::TagName};
+use graft_proto::metastore::v1::{DeleteTagRequest, DeleteTagResponse, Tag};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+/// Deletes a tag. The previously tagged snapshot remains readable until the
+/// garbage collector's retention policy allows it to be collected.
+#[tracing::instrument(name = "metastore/v1/delete_tag", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<DeleteTagRequest>,
+) -> Result<ProtoResponse<DeleteTagResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let tag = TagName::new(req.name)?;
+
+    tracing::info!(?vid, %tag);
+
+    state.check_deleted(&vid)?;
+
+    let Some(lsn) = state.store.delete_tag(&vid, &tag).await.or_into_ctx()? else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::TagNotFound,
+            format!("volume {vid} has no tag {tag}"),
+        )
+        .into());
+    };
+
+    Ok(ProtoResponse::new(DeleteTagResponse {
+        tag: Some(Tag { name: tag.to_string(), lsn: lsn.into() }),
+    }))
+}
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::Culprit;
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{DeleteVolumeRequest, DeleteVolumeResponse};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+/// Deletes a Volume by writing a tombstone commit to its commit log, which
+/// serializes the delete with concurrent commits. The Volume's commits and
+/// segments are purged by the garbage collector once the undelete window
+/// expires.
+#[tracing::instrument(name = "metastore/v1/delete_volume", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<DeleteVolumeRequest>,
+) -> Result<ProtoResponse<DeleteVolumeResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+
+    tracing::info!(?vid);
+
+    // deleting a Volume more than once returns the original tombstone commit
+    let Some(tombstone) = state.commit_tombstone(&vid, true).await? else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} does not exist"),
+        )
+        .into());
+    };
+    let deleted_at = tombstone.system_time();
+
+    Ok(ProtoResponse::new(DeleteVolumeResponse {
+        deleted_at: Some(deleted_at.into()),
+        purge_at: Some(state.purge_at(deleted_at).into()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_core::{gid::ClientId, lsn::LSN};
+    use graft_proto::{
+        common::v1::{GraftErr, GraftErrCode},
+        metastore::v1::{
+            CommitRequest, SnapshotRequest, SnapshotResponse, UndeleteVolumeRequest,
+            UndeleteVolumeResponse,
+        },
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF,
+            metastore::{commit, snapshot, undelete_volume},
+        },
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    fn test_server(state: Arc<MetastoreApiState>) -> TestServer {
+        let router = Router::new()
+            .route("/commit", post(commit::handler))
+            .route("/snapshot", post(snapshot::handler))
+            .route("/delete_volume", post(handler))
+            .route("/undelete_volume", post(undelete_volume::handler))
+            .with_state(state);
+        TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap()
+    }
+
+    #[graft_test::test]
+    async fn test_delete_volume() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+        let server = test_server(state.clone());
+
+        // a second metastore sharing the store which never allows undeletes
+        let other = test_server(Arc::new(
+            MetastoreApiState::new(
+                store,
+                VolumeCatalog::open_temporary().unwrap(),
+                VolumeCatalogUpdater::new(8),
+            )
+            .with_undelete_window(Duration::ZERO),
+        ));
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        let delete = DeleteVolumeRequest { vid: vid.copy_to_bytes() }.encode_to_vec();
+        let undelete = UndeleteVolumeRequest { vid: vid.copy_to_bytes() }.encode_to_vec();
+        let snapshot = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: String::new(),
+        }
+        .encode_to_vec();
+        let commit = |lsn: u64| {
+            CommitRequest {
+                vid: vid.copy_to_bytes(),
+                cid: cid.copy_to_bytes(),
+                snapshot_lsn: (lsn > 1).then(|| lsn - 1),
+                page_count: 1,
+                segments: vec![],
+                page_size: 0,
+                metadata: None,
+            }
+            .encode_to_vec()
+        };
+
+        // deleting a missing volume fails
+        let resp = server
+            .post("/delete_volume")
+            .bytes(delete.clone().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        server.post("/commit").bytes(commit(1).into()).await;
+
+        // both metastores have served the volume before it's deleted
+        for server in [&server, &other] {
+            server
+                .post("/snapshot")
+                .bytes(snapshot.clone().into())
+                .await;
+        }
+
+        // deleting is idempotent
+        let resp = server
+            .post("/delete_volume")
+            .bytes(delete.clone().into())
+            .await;
+        let deleted = DeleteVolumeResponse::decode(resp.into_bytes()).unwrap();
+        let resp = server
+            .post("/delete_volume")
+            .bytes(delete.clone().into())
+            .await;
+        assert_eq!(
+            DeleteVolumeResponse::decode(resp.into_bytes()).unwrap(),
+            deleted
+        );
+        assert!(deleted.purge_at.unwrap().seconds > deleted.deleted_at.unwrap().seconds);
+
+        // both metastores reject reads and commits with a distinct error
+        for server in [&server, &other] {
+            for (path, req) in [("/snapshot", snapshot.clone()), ("/commit", commit(2))] {
+                let resp = server.post(path).bytes(req.into()).expect_failure().await;
+                assert_eq!(resp.status_code(), StatusCode::GONE);
+                let err = GraftErr::decode(resp.into_bytes()).unwrap();
+                assert_eq!(err.code(), GraftErrCode::VolumeDeleted);
+            }
+        }
+
+        // the volume can't be undeleted once the undelete window expires
+        let resp = other
+            .post("/undelete_volume")
+            .bytes(undelete.clone().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::GONE);
+
+        // undeleting restores the volume on every metastore by committing on
+        // top of the tombstone commit at lsn 2
+        let resp = server
+            .post("/undelete_volume")
+            .bytes(undelete.clone().into())
+            .await;
+        let resp = UndeleteVolumeResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.snapshot.unwrap().lsn().unwrap(), 3);
+        other.post("/commit").bytes(commit(4).into()).await;
+        let resp = server
+            .post("/snapshot")
+            .bytes(snapshot.clone().into())
+            .await;
+        let resp = SnapshotResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.snapshot.unwrap().lsn().unwrap(), 4);
+
+        // a delete made through one metastore conflicts with commits on every
+        // metastore, including those which haven't seen the delete
+        let resp = other.post("/delete_volume").bytes(delete.into()).await;
+        let deleted = DeleteVolumeResponse::decode(resp.into_bytes()).unwrap();
+        let tombstone = state.commit_tombstone(&vid, true).await.unwrap().unwrap();
+        assert_eq!(tombstone.lsn(), LSN::new(5));
+        assert_eq!(
+            SystemTime::try_from(deleted.deleted_at.unwrap()).unwrap(),
+            tombstone.system_time()
+        );
+        for server in [&server, &other] {
+            for lsn in [5, 6] {
+                let resp = server
+                    .post("/commit")
+                    .bytes(commit(lsn).into())
+                    .expect_failure()
+                    .await;
+                assert_eq!(resp.status_code(), StatusCode::GONE);
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use std::{sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, gid::ClientId, lsn::LSN};
+use graft_proto::metastore::v1::{
+    ForkVolumeRequest, ForkVolumeResponse, InheritedSegment, VolumeFork,
+};
+
+use crate::{
+    api::{
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    volume::commit::{CommitBuilder, CommitMeta},
+};
+
+use super::{MetastoreApiState, check_tombstone};
+
+/// Creates a new Volume whose first commit contains the source Volume's pages
+/// at the requested LSN. The first commit references the segments containing
+/// those pages rather than copying them, and the new Volume's fork record
+/// tracks which Volume each inherited segment stores pages for.
+#[tracing::instrument(name = "metastore/v1/fork_volume", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<ForkVolumeRequest>,
+) -> Result<ProtoResponse<ForkVolumeResponse>, ApiErr> {
+    let source: VolumeId = req.source_vid.try_into()?;
+    let lsn: Option<LSN> = req.lsn.map(LSN::try_from).transpose().or_into_ctx()?;
+
+    tracing::info!(?source, ?lsn);
+
+    state.check_deleted(&source)?;
+
+    let Some(snapshot) = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &source, lsn)
+        .await
+        .or_into_ctx()?
+    else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {source} is missing snapshot at {lsn:?}"),
+        )
+        .into());
+    };
+    check_tombstone(&source, &snapshot)?;
+
+    let live = state
+        .updater
+        .live_segments(&state.store, &state.catalog, &snapshot)
+        .await
+        .or_into_ctx()?;
+
+    // segments which the source inherited from its own fork source continue
+    // to refer to the Volume which stores their pages
+    let inherited = state.inherited_segments(&source).await?;
+    let fork = VolumeFork {
+        source_vid: source.copy_to_bytes(),
+        source_lsn: snapshot.lsn().into(),
+        segments: live
+            .keys()
+            .filter(|sid| !sid.is_empty())
+            .map(|sid| InheritedSegment {
+                sid: sid.copy_to_bytes(),
+                vid: inherited.get(sid).unwrap_or(&source).copy_to_bytes(),
+            })
+            .collect(),
+    };
+
+    // the fork record must be written before the first commit so that any
+    // metastore which sees the commit can find it
+    let vid = VolumeId::random();
+    state.store.put_fork(&vid, &fork).await.or_into_ctx()?;
+    state.catalog.insert_fork(&vid, &fork).or_into_ctx()?;
+
+    let meta = CommitMeta::new(
+        vid.clone(),
+        ClientId::random(),
+        LSN::FIRST,
+        LSN::FIRST,
+        snapshot.page_count(),
+        SystemTime::now(),
+    )
+    .with_page_size(snapshot.page_size());
+    let mut commit = CommitBuilder::new_with_capacity(meta, live.len());
+    for (sid, pages) in live {
+        commit.write_graft(sid, pages.serialize_to_bytes());
+    }
+    let commit = commit.build();
+
+    state.store.commit(commit.clone()).await.or_into_ctx()?;
+
+    let mut batch = state.catalog.batch_insert();
+    batch.insert_commit(&commit).or_into_ctx()?;
+    batch.commit().or_into_ctx()?;
+
+    tracing::debug!(?source, source_lsn = ?snapshot.lsn(), ?vid, "forked volume");
+
+    Ok(ProtoResponse::new(ForkVolumeResponse {
+        snapshot: Some(commit.into_snapshot()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_core::SegmentId;
+    use graft_proto::{
+        common::v1::{GraftErr, GraftErrCode, SegmentInfo},
+        metastore::v1::{
+            CommitRequest, DeleteVolumeRequest, PullCommitsRequest, PullCommitsResponse,
+        },
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+    use splinter_rs::Splinter;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF,
+            metastore::{commit, delete_volume, pull_commits},
+        },
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    fn test_server(state: MetastoreApiState) -> TestServer {
+        let router = Router::new()
+            .route("/commit", post(commit::handler))
+            .route("/pull_commits", post(pull_commits::handler))
+            .route("/delete_volume", post(delete_volume::handler))
+            .route("/fork_volume", post(handler))
+            .with_state(Arc::new(state));
+        TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap()
+    }
+
+    async fn fork(server: &TestServer, source: &VolumeId, lsn: Option<u64>) -> VolumeId {
+        let req = ForkVolumeRequest { source_vid: source.copy_to_bytes(), lsn };
+        let resp = server
+            .post("/fork_volume")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let snapshot = ForkVolumeResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), LSN::FIRST);
+        snapshot.vid().unwrap().clone()
+    }
+
+    /// pull the commits since the Volume's last checkpoint, returning the
+    /// pages and owning Volume of each segment in the latest commit
+    async fn latest_segments(
+        server: &TestServer,
+        vid: &VolumeId,
+    ) -> Vec<(SegmentId, Vec<u32>, Option<VolumeId>)> {
+        let req = PullCommitsRequest { vid: vid.copy_to_bytes(), range: None };
+        let resp = server
+            .post("/pull_commits")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let mut resp = PullCommitsResponse::decode(resp.into_bytes()).unwrap();
+        let commit = resp.commits.pop().unwrap();
+        let mut segments: Vec<_> = commit
+            .segments
+            .iter()
+            .map(|info| {
+                (
+                    info.sid().unwrap().clone(),
+                    info.graft().unwrap().iter().collect(),
+                    info.vid().unwrap().cloned(),
+                )
+            })
+            .collect();
+        segments.sort();
+        segments
+    }
+
+    #[graft_test::test]
+    async fn test_fork_volume() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let server = test_server(MetastoreApiState::new(
+            store.clone(),
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+        // a second metastore sharing the store
+        let other = test_server(MetastoreApiState::new(
+            store,
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let source = VolumeId::random();
+        let cid = ClientId::random();
+        let commit = |vid: &VolumeId, lsn: u64, sid: &SegmentId, pages: &[u32]| {
+            CommitRequest {
+                vid: vid.copy_to_bytes(),
+                cid: cid.copy_to_bytes(),
+                snapshot_lsn: (lsn > 1).then(|| lsn - 1),
+                page_count: 3,
+                segments: vec![SegmentInfo::new(
+                    sid,
+                    Splinter::from_iter(pages.iter().copied()).serialize_to_bytes(),
+                )],
+                page_size: 0,
+                metadata: None,
+            }
+            .encode_to_vec()
+        };
+
+        // forking a missing volume fails
+        let req = ForkVolumeRequest {
+            source_vid: source.copy_to_bytes(),
+            lsn: None,
+        };
+        let resp = server
+            .post("/fork_volume")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        let (sid1, sid2, sid3) = (
+            SegmentId::random(),
+            SegmentId::random(),
+            SegmentId::random(),
+        );
+        server
+            .post("/commit")
+            .bytes(commit(&source, 1, &sid1, &[1, 2]).into())
+            .await;
+        server
+            .post("/commit")
+            .bytes(commit(&source, 2, &sid2, &[2, 3]).into())
+            .await;
+
+        // forking at an earlier LSN only references the segments live at that LSN
+        let fork1 = fork(&server, &source, Some(1)).await;
+        assert_eq!(
+            latest_segments(&server, &fork1).await,
+            vec![(sid1.clone(), vec![1, 2], Some(source.clone()))]
+        );
+
+        // forking the latest snapshot references the live pages in each segment
+        let fork2 = fork(&server, &source, None).await;
+        let expected = vec![
+            (sid1.clone(), vec![1], Some(source.clone())),
+            (sid2.clone(), vec![2, 3], Some(source.clone())),
+        ];
+        let mut sorted = expected.clone();
+        sorted.sort();
+        assert_eq!(latest_segments(&server, &fork2).await, sorted);
+
+        // the fork's first commit isn't attributed to the source's client
+        let req = ForkVolumeRequest {
+            source_vid: source.copy_to_bytes(),
+            lsn: None,
+        };
+        let resp = server
+            .post("/fork_volume")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let snapshot = ForkVolumeResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_ne!(snapshot.cid().unwrap(), &cid);
+
+        // a fork of a fork continues to refer to the volume storing the pages,
+        // including on other metastores which load the fork record from the store
+        server
+            .post("/commit")
+            .bytes(commit(&fork2, 2, &sid3, &[3]).into())
+            .await;
+        let fork3 = fork(&other, &fork2, None).await;
+        let mut expected = vec![
+            (sid1.clone(), vec![1], Some(source.clone())),
+            (sid2.clone(), vec![2], Some(source.clone())),
+            (sid3.clone(), vec![3], Some(fork2.clone())),
+        ];
+        expected.sort();
+        assert_eq!(latest_segments(&server, &fork3).await, expected);
+        assert_eq!(latest_segments(&other, &fork3).await, expected);
+
+        // segments committed to the fork itself belong to the fork
+        assert_eq!(
+            latest_segments(&server, &fork2).await,
+            vec![(sid3.clone(), vec![3], None)]
+        );
+
+        // the source is unaffected by its forks
+        assert_eq!(
+            latest_segments(&server, &source).await,
+            vec![(sid2, vec![2, 3], None)]
+        );
+
+        // deleted volumes can't be forked
+        let req = DeleteVolumeRequest { vid: source.copy_to_bytes() };
+        server
+            .post("/delete_volume")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let req = ForkVolumeRequest {
+            source_vid: source.copy_to_bytes(),
+            lsn: None,
+        };
+        let resp = server
+            .post("/fork_volume")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        let err = GraftErr::decode(resp.into_bytes()).unwrap();
+        assert_eq!(err.code(), GraftErrCode::VolumeDeleted);
+    }
+}
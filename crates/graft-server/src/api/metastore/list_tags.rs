@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::ResultExt;
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{ListTagsRequest, ListTagsResponse, Tag};
+
+use crate::api::{error::ApiErr, extractors::Protobuf, response::ProtoResponse};
+
+use super::MetastoreApiState;
+
+#[tracing::instrument(name = "metastore/v1/list_tags", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<ListTagsRequest>,
+) -> Result<ProtoResponse<ListTagsResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+
+    tracing::info!(?vid);
+
+    state.check_deleted(&vid)?;
+
+    let tags = state.store.list_tags(&vid).await.or_into_ctx()?;
+
+    Ok(ProtoResponse::new(ListTagsResponse {
+        tags: tags
+            .into_iter()
+            .map(|(name, lsn)| Tag { name: name.to_string(), lsn: lsn.into() })
+            .collect(),
+    }))
+}
@@ -0,0 +1,280 @@
+use std::{pin::pin, sync::Arc};
+
+use axum::extract::State;
+use bytes::Bytes;
+use culprit::ResultExt;
+use futures::TryStreamExt;
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{ListVolumesRequest, ListVolumesResponse, ListedVolume};
+
+use crate::api::{error::ApiErr, extractors::Protobuf, response::ProtoResponse};
+
+use super::MetastoreApiState;
+
+/// The number of Volumes returned by a listing which doesn't specify a limit
+const DEFAULT_LIST_LIMIT: usize = 100;
+
+/// The maximum number of Volumes returned by a single listing
+const MAX_LIST_LIMIT: usize = 1000;
+
+/// Lists Volumes in order by `VolumeId`. The first page of a listing discovers
+/// Volumes by listing the object store and caches their IDs in the catalog, so
+/// that later pages can be served from the catalog. Snapshots are also served
+/// from the catalog when possible to avoid checking every listed Volume for
+/// updates, so a Volume deleted by another metastore is listed until its
+/// tombstone commit reaches this metastore's catalog. Cached labels are
+/// checked against the store as other metastores may have updated them.
+#[tracing::instrument(name = "metastore/v1/list_volumes", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<ListVolumesRequest>,
+) -> Result<ProtoResponse<ListVolumesResponse>, ApiErr> {
+    let limit = match req.limit as usize {
+        0 => DEFAULT_LIST_LIMIT,
+        limit => limit.min(MAX_LIST_LIMIT),
+    };
+    let start_after: Option<VolumeId> = if req.page_token.is_empty() {
+        None
+    } else {
+        Some(req.page_token.try_into()?)
+    };
+    let prefix = req.prefix;
+
+    tracing::info!(?prefix, limit, ?start_after);
+
+    // refresh the cached VolumeIds on the first page of a listing, or if the
+    // previous page was listed by another metastore
+    let refresh = match &start_after {
+        Some(after) => !state.catalog.contains_volume_id(after).or_into_ctx()?,
+        None => true,
+    };
+    if refresh {
+        let mut listing = pin!(state.store.list_volumes(&prefix, start_after.as_ref()));
+        while let Some(vid) = listing.try_next().await.or_into_ctx()? {
+            state.catalog.insert_volume_id(&vid).or_into_ctx()?;
+        }
+    }
+
+    let volumes: Vec<VolumeId> = state
+        .catalog
+        .volume_ids(start_after.as_ref())
+        .skip_while(|vid| vid.as_ref().is_ok_and(|vid| vid.pretty() < prefix))
+        .take_while(|vid| {
+            vid.as_ref()
+                .map_or(true, |vid| vid.pretty().starts_with(&prefix))
+        })
+        .collect::<Result<_, _>>()
+        .or_into_ctx()?;
+
+    let mut listed = Vec::with_capacity(limit);
+    let mut last = None;
+    let mut next_page_token = Bytes::new();
+    for vid in volumes {
+        let snapshot = match state.catalog.latest_snapshot(&vid).or_into_ctx()? {
+            Some(snapshot) => Some(snapshot),
+            None => state
+                .updater
+                .snapshot(&state.store, &state.catalog, &vid, None)
+                .await
+                .or_into_ctx()?,
+        };
+        // the Volume may have been purged since it was listed
+        let Some(snapshot) = snapshot else {
+            state.catalog.remove_volume_id(&vid).or_into_ctx()?;
+            continue;
+        };
+        // deleted Volumes end with a tombstone commit
+        if snapshot.is_tombstone() {
+            continue;
+        }
+
+        let labels = state.labels(&vid).await?;
+        if !req
+            .labels
+            .iter()
+            .all(|(key, value)| labels.labels.get(key) == Some(value))
+        {
+            continue;
+        }
+
+        // only return a page token if another Volume follows this page
+        if listed.len() == limit {
+            next_page_token = last
+                .as_ref()
+                .map_or_else(Bytes::new, VolumeId::copy_to_bytes);
+            break;
+        }
+
+        listed.push(ListedVolume {
+            snapshot: Some(snapshot.into_snapshot()),
+            labels: Some(labels),
+        });
+        last = Some(vid);
+    }
+
+    Ok(ProtoResponse::new(ListVolumesResponse {
+        volumes: listed,
+        next_page_token,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::SystemTime};
+
+    use axum::handler::Handler;
+    use axum_test::TestServer;
+    use graft_core::{gid::ClientId, lsn::LSN, page_count::PageCount};
+    use graft_proto::metastore::v1::VolumeLabels;
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        volume::{
+            catalog::VolumeCatalog,
+            commit::{CommitBuilder, CommitMeta},
+            store::VolumeStore,
+            updater::VolumeCatalogUpdater,
+        },
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_list_volumes() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let test_server = |store: Arc<VolumeStore>| {
+            let state = Arc::new(MetastoreApiState::new(
+                store,
+                VolumeCatalog::open_temporary().unwrap(),
+                VolumeCatalogUpdater::new(8),
+            ));
+            TestServer::builder()
+                .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+                .expect_success_by_default()
+                .build(handler.with_state(state).into_make_service())
+                .unwrap()
+        };
+        let server = test_server(store.clone());
+        let other = test_server(store.clone());
+
+        // create four volumes where volume i has i + 1 commits
+        let cid = ClientId::random();
+        let mut vids: Vec<VolumeId> = (0..4).map(|_| VolumeId::random()).collect();
+        vids.sort();
+        for (i, vid) in vids.iter().enumerate() {
+            for lsn in 1..=(i as u64 + 1) {
+                let meta = CommitMeta::new(
+                    vid.clone(),
+                    cid.clone(),
+                    LSN::new(lsn),
+                    LSN::FIRST,
+                    PageCount::ZERO,
+                    SystemTime::now(),
+                );
+                let commit = CommitBuilder::new_with_capacity(meta, 0).build();
+                store.commit(commit).await.unwrap();
+            }
+        }
+
+        // deleted volumes aren't listed
+        let meta = CommitMeta::new(
+            vids[2].clone(),
+            cid.clone(),
+            LSN::new(4),
+            LSN::FIRST,
+            PageCount::ZERO,
+            SystemTime::now(),
+        )
+        .with_tombstone(true);
+        store
+            .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+            .await
+            .unwrap();
+
+        // volumes 0 and 3 belong to the same tenant
+        let tenant: HashMap<String, String> = [("tenant".into(), "a".into())].into();
+        for vid in [&vids[0], &vids[3]] {
+            let labels = VolumeLabels { labels: tenant.clone(), version: 1 };
+            assert!(store.put_labels(vid, &labels).await.unwrap());
+        }
+
+        let list_from =
+            async |server: &TestServer, prefix: &str, limit: u32, page_token, labels| {
+                let req = ListVolumesRequest {
+                    prefix: prefix.into(),
+                    limit,
+                    page_token,
+                    labels,
+                };
+                let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+                let resp = ListVolumesResponse::decode(resp.into_bytes()).unwrap();
+                let listed = resp
+                    .volumes
+                    .iter()
+                    .map(|v| v.snapshot.as_ref().unwrap())
+                    .map(|s| (s.vid().unwrap().clone(), s.lsn().unwrap()))
+                    .collect::<Vec<_>>();
+                (listed, resp.next_page_token)
+            };
+        let list_labeled = async |prefix: &str, limit: u32, page_token, labels| {
+            list_from(&server, prefix, limit, page_token, labels).await
+        };
+        let list = async |prefix: &str, limit: u32, page_token| {
+            list_labeled(prefix, limit, page_token, HashMap::default()).await
+        };
+
+        // paginate through the volumes
+        let (page, token) = list("", 2, Default::default()).await;
+        assert_eq!(
+            page,
+            vec![
+                (vids[0].clone(), LSN::new(1)),
+                (vids[1].clone(), LSN::new(2))
+            ]
+        );
+        let (page, next) = list("", 2, token.clone()).await;
+        assert_eq!(page, vec![(vids[3].clone(), LSN::new(4))]);
+        assert!(next.is_empty());
+
+        // later pages are served from the cached volume ids, which don't
+        // include volumes created since the first page
+        let late = VolumeId::random();
+        let meta = CommitMeta::new(
+            late.clone(),
+            cid.clone(),
+            LSN::FIRST,
+            LSN::FIRST,
+            PageCount::ZERO,
+            SystemTime::now(),
+        );
+        store
+            .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+            .await
+            .unwrap();
+        let (page, _) = list("", 10, token.clone()).await;
+        assert!(!page.iter().any(|(vid, _)| vid == &late));
+
+        // another metastore continues the listing by listing the store
+        let (page, _) = list_from(&other, "", 10, token, HashMap::default()).await;
+        assert_eq!(page.iter().any(|(vid, _)| vid == &late), late > vids[1]);
+
+        // filter by prefix
+        let (page, _) = list(&vids[1].pretty(), 0, Default::default()).await;
+        assert_eq!(page, vec![(vids[1].clone(), LSN::new(2))]);
+        let (page, _) = list(&vids[2].pretty(), 0, Default::default()).await;
+        assert!(page.is_empty());
+
+        // filter by labels
+        let (page, token) = list_labeled("", 1, Default::default(), tenant.clone()).await;
+        assert_eq!(page, vec![(vids[0].clone(), LSN::new(1))]);
+        let (page, token) = list_labeled("", 1, token, tenant).await;
+        assert_eq!(page, vec![(vids[3].clone(), LSN::new(4))]);
+        assert!(token.is_empty());
+
+        // zero isn't in the base58 alphabet so no volume matches
+        let (page, token) = list("0", 0, Default::default()).await;
+        assert!(page.is_empty() && token.is_empty());
+    }
+}
@@ -1,20 +1,58 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
 use axum::routing::post;
+use bytes::Bytes;
+use culprit::{Culprit, ResultExt};
+use graft_core::{SegmentId, VolumeId, gid::ClientId, lsn::LSN, tag_name::TagName};
+use graft_proto::{
+    common::v1::SegmentInfo,
+    metastore::v1::{VolumeFork, VolumeLabels},
+};
 
-use crate::volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater};
+use crate::volume::{
+    catalog::VolumeCatalog,
+    commit::{CommitBuilder, CommitMeta},
+    store::{DEFAULT_UNDELETE_WINDOW, VolumeStore, VolumeStoreErr},
+    updater::VolumeCatalogUpdater,
+};
 
-use super::routes::Routes;
+use super::{error::ApiErrCtx, routes::Routes};
 
+mod checkpoint;
 mod commit;
+mod compact;
+mod create_tag;
+mod delete_tag;
+mod delete_volume;
+mod fork_volume;
+mod list_tags;
+mod list_volumes;
+mod pull_checkpoint;
 mod pull_commits;
 mod pull_graft;
+mod resolve_name;
+mod revert_volume;
 mod snapshot;
+mod undelete_volume;
+mod update_labels;
+
+/// The default number of commits between automatic Volume checkpoints
+pub const DEFAULT_CHECKPOINT_INTERVAL: u64 = 1024;
 
 pub struct MetastoreApiState {
     store: Arc<VolumeStore>,
     catalog: VolumeCatalog,
     updater: VolumeCatalogUpdater,
+
+    /// the number of commits after which a commit will checkpoint the Volume
+    checkpoint_interval: u64,
+
+    /// how long a deleted Volume may be undeleted
+    undelete_window: Duration,
 }
 
 impl MetastoreApiState {
@@ -23,7 +61,25 @@ impl MetastoreApiState {
         catalog: VolumeCatalog,
         updater: VolumeCatalogUpdater,
     ) -> Self {
-        Self { store, catalog, updater }
+        Self {
+            store,
+            catalog,
+            updater,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            undelete_window: DEFAULT_UNDELETE_WINDOW,
+        }
+    }
+
+    /// Set the number of commits after which a commit will checkpoint the
+    /// Volume. Zero disables automatic checkpoints.
+    pub fn with_checkpoint_interval(self, checkpoint_interval: u64) -> Self {
+        Self { checkpoint_interval, ..self }
+    }
+
+    /// Set how long a deleted Volume may be undeleted. This should match the
+    /// garbage collector's undelete window.
+    pub fn with_undelete_window(self, undelete_window: Duration) -> Self {
+        Self { undelete_window, ..self }
     }
 
     pub fn store(&self) -> &VolumeStore {
@@ -37,6 +93,192 @@ impl MetastoreApiState {
     pub fn updater(&self) -> &VolumeCatalogUpdater {
         &self.updater
     }
+
+    pub fn checkpoint_interval(&self) -> u64 {
+        self.checkpoint_interval
+    }
+
+    /// Returns the time after which a Volume deleted at `deleted_at` can no
+    /// longer be undeleted
+    pub fn purge_at(&self, deleted_at: SystemTime) -> SystemTime {
+        deleted_at + self.undelete_window
+    }
+
+    /// Returns the Volume's labels, which are cached in the catalog once they
+    /// have been loaded from the store. As other metastores may update the
+    /// labels, the cached labels are only used if the store doesn't contain
+    /// the next version.
+    pub async fn labels(&self, vid: &VolumeId) -> Result<VolumeLabels, Culprit<ApiErrCtx>> {
+        if let Some(labels) = self.catalog.labels(vid).or_into_ctx()? {
+            let stale = self
+                .store
+                .labels_version_exists(vid, labels.version + 1)
+                .await
+                .or_into_ctx()?;
+            if !stale {
+                return Ok(labels);
+            }
+        }
+        let labels = self
+            .store
+            .get_labels(vid)
+            .await
+            .or_into_ctx()?
+            .unwrap_or_default();
+        self.catalog.insert_labels(vid, &labels).or_into_ctx()?;
+        Ok(labels)
+    }
+
+    /// Returns the Volume's fork record, or None if the Volume wasn't forked.
+    /// Fork records are cached in the catalog once they have been loaded from
+    /// the store, including the absence of one. As a fork record is written
+    /// before the forked Volume's first commit, this must only be called for
+    /// Volumes known to have a commit.
+    pub async fn fork(&self, vid: &VolumeId) -> Result<Option<VolumeFork>, Culprit<ApiErrCtx>> {
+        let fork = match self.catalog.fork(vid).or_into_ctx()? {
+            Some(fork) => fork,
+            None => {
+                let fork = self
+                    .store
+                    .get_fork(vid)
+                    .await
+                    .or_into_ctx()?
+                    .unwrap_or_default();
+                self.catalog.insert_fork(vid, &fork).or_into_ctx()?;
+                fork
+            }
+        };
+        Ok((!fork.source_vid.is_empty()).then_some(fork))
+    }
+
+    /// Maps each segment the Volume inherited from its fork source to the
+    /// Volume whose pages the segment contains. Empty if the Volume wasn't
+    /// forked.
+    pub async fn inherited_segments(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<HashMap<SegmentId, VolumeId>, Culprit<ApiErrCtx>> {
+        let Some(fork) = self.fork(vid).await? else {
+            return Ok(HashMap::default());
+        };
+        let mut inherited = HashMap::with_capacity(fork.segments.len());
+        for segment in fork.segments {
+            let sid = SegmentId::try_from(segment.sid).or_into_ctx()?;
+            let owner = VolumeId::try_from(segment.vid).or_into_ctx()?;
+            inherited.insert(sid, owner);
+        }
+        Ok(inherited)
+    }
+
+    /// Resolve the named tag to the LSN it points at. Returns None if `tag` is
+    /// empty, which means the request didn't specify a tag. Tags aren't
+    /// cached as they may be deleted and recreated by other metastores.
+    pub async fn resolve_tag(
+        &self,
+        vid: &VolumeId,
+        tag: String,
+    ) -> Result<Option<LSN>, Culprit<ApiErrCtx>> {
+        if tag.is_empty() {
+            return Ok(None);
+        }
+        let tag = TagName::new(tag)?;
+        match self.store.get_tag(vid, &tag).await.or_into_ctx()? {
+            Some(lsn) => Ok(Some(lsn)),
+            None => Err(Culprit::new_with_note(
+                ApiErrCtx::TagNotFound,
+                format!("volume {vid} has no tag {tag}"),
+            )),
+        }
+    }
+
+    /// Delete or restore the Volume by writing an empty commit on top of its
+    /// latest snapshot, which is a tombstone if `deleted` is set. Like any
+    /// other commit it's written to the next LSN, so it conflicts with
+    /// concurrent commits and is retried on top of them. Does nothing if the
+    /// latest snapshot is already in the requested state. Returns the
+    /// Volume's latest snapshot, or None if the Volume has no snapshots.
+    pub async fn commit_tombstone(
+        &self,
+        vid: &VolumeId,
+        deleted: bool,
+    ) -> Result<Option<CommitMeta>, Culprit<ApiErrCtx>> {
+        loop {
+            let Some(latest) = self
+                .updater
+                .snapshot(&self.store, &self.catalog, vid, None)
+                .await
+                .or_into_ctx()?
+            else {
+                return Ok(None);
+            };
+            if latest.is_tombstone() == deleted {
+                return Ok(Some(latest));
+            }
+
+            let meta = CommitMeta::new(
+                vid.clone(),
+                ClientId::random(),
+                latest.lsn().saturating_next(),
+                latest.checkpoint(),
+                latest.page_count(),
+                SystemTime::now(),
+            )
+            .with_page_size(latest.page_size())
+            .with_tombstone(deleted);
+            let commit = CommitBuilder::new_with_capacity(meta, 0).build();
+
+            match self.store.commit(commit.clone()).await {
+                Ok(()) => {
+                    let mut batch = self.catalog.batch_insert();
+                    batch.insert_commit(&commit).or_into_ctx()?;
+                    batch.commit().or_into_ctx()?;
+                    return Ok(Some(commit.meta().clone()));
+                }
+                Err(err) if matches!(err.ctx(), VolumeStoreErr::CommitAlreadyExists) => {
+                    tracing::debug!(?vid, "retrying tombstone after a concurrent commit");
+                }
+                Err(err) => return Err(err.map_ctx(ApiErrCtx::from)),
+            }
+        }
+    }
+
+    /// Returns a `VolumeDeleted` error if the Volume's latest snapshot in the
+    /// catalog is a tombstone commit. This doesn't check the store, so a delete
+    /// made by another metastore is noticed once the catalog has replayed the
+    /// tombstone commit, which happens whenever a request loads the Volume's
+    /// latest snapshot.
+    pub fn check_deleted(&self, vid: &VolumeId) -> Result<(), Culprit<ApiErrCtx>> {
+        match self.catalog.latest_snapshot(vid).or_into_ctx()? {
+            Some(latest) => check_tombstone(vid, &latest),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns a `VolumeDeleted` error if the snapshot is a tombstone commit
+fn check_tombstone(vid: &VolumeId, snapshot: &CommitMeta) -> Result<(), Culprit<ApiErrCtx>> {
+    if snapshot.is_tombstone() {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::VolumeDeleted,
+            format!("volume {vid} was deleted at {:?}", snapshot.system_time()),
+        ));
+    }
+    Ok(())
+}
+
+/// Build the `SegmentInfo` for a segment referenced by a Volume, recording the
+/// Volume whose pages it contains if the segment was inherited from a fork
+/// source.
+fn segment_info(
+    inherited: &HashMap<SegmentId, VolumeId>,
+    sid: &SegmentId,
+    graft: Bytes,
+) -> SegmentInfo {
+    let info = SegmentInfo::new(sid, graft);
+    match inherited.get(sid) {
+        Some(owner) => info.with_vid(owner),
+        None => info,
+    }
 }
 
 pub fn metastore_routes() -> Routes<Arc<MetastoreApiState>> {
@@ -45,5 +287,24 @@ pub fn metastore_routes() -> Routes<Arc<MetastoreApiState>> {
         ("/metastore/v1/pull_graft", post(pull_graft::handler)),
         ("/metastore/v1/pull_commits", post(pull_commits::handler)),
         ("/metastore/v1/commit", post(commit::handler)),
+        ("/metastore/v1/resolve_name", post(resolve_name::handler)),
+        ("/metastore/v1/checkpoint", post(checkpoint::handler)),
+        (
+            "/metastore/v1/pull_checkpoint",
+            post(pull_checkpoint::handler),
+        ),
+        ("/metastore/v1/compact", post(compact::handler)),
+        ("/metastore/v1/delete_volume", post(delete_volume::handler)),
+        ("/metastore/v1/list_volumes", post(list_volumes::handler)),
+        (
+            "/metastore/v1/undelete_volume",
+            post(undelete_volume::handler),
+        ),
+        ("/metastore/v1/update_labels", post(update_labels::handler)),
+        ("/metastore/v1/fork_volume", post(fork_volume::handler)),
+        ("/metastore/v1/revert_volume", post(revert_volume::handler)),
+        ("/metastore/v1/create_tag", post(create_tag::handler)),
+        ("/metastore/v1/list_tags", post(list_tags::handler)),
+        ("/metastore/v1/delete_tag", post(delete_tag::handler)),
     ]
 }
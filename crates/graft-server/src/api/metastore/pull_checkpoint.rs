@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, lsn::LSN};
+use graft_proto::{
+    common::v1::Commit,
+    metastore::v1::{PullCheckpointRequest, PullCheckpointResponse},
+};
+use tryiter::TryIteratorExt;
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::{MetastoreApiState, segment_info};
+
+/// Returns the checkpoint written at the requested LSN along with the segments
+/// it contains.
+#[tracing::instrument(name = "metastore/v1/pull_checkpoint", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<PullCheckpointRequest>,
+) -> Result<ProtoResponse<PullCheckpointResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+
+    tracing::info!(?vid, ?lsn);
+
+    state.check_deleted(&vid)?;
+
+    // the first commit is an implicit checkpoint which is never written
+    if lsn != LSN::FIRST {
+        state
+            .updater
+            .update_checkpoint_from_store(&state.store, &state.catalog, &vid, lsn)
+            .await
+            .or_into_ctx()?;
+    }
+
+    let Some(meta) = state.catalog.checkpoint(vid.clone(), lsn).or_into_ctx()? else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} is missing checkpoint at {lsn}"),
+        )
+        .into());
+    };
+
+    let version = state
+        .catalog
+        .checkpoint_version(vid.clone(), lsn)
+        .or_into_ctx()?;
+
+    let inherited = state.inherited_segments(&vid).await?;
+    let mut segments = Vec::default();
+    let mut scan = state.catalog.scan_checkpoint(&vid, lsn);
+    while let Some((key, splinter)) = scan.try_next().or_into_ctx()? {
+        segments.push(segment_info(&inherited, key.sid(), splinter.into_inner()));
+    }
+
+    Ok(ProtoResponse::new(PullCheckpointResponse {
+        checkpoint: Some(Commit {
+            snapshot: Some(meta.into_snapshot()),
+            segments,
+            metadata: None,
+        }),
+        version: version.map(|v| v.as_str().to_owned()).unwrap_or_default(),
+    }))
+}
@@ -4,7 +4,7 @@ use axum::extract::State;
 use culprit::{Culprit, ResultExt};
 use graft_core::{VolumeId, lsn::LSNRangeExt};
 use graft_proto::{
-    common::v1::{Commit, SegmentInfo},
+    common::v1::Commit,
     metastore::v1::{PullCommitsRequest, PullCommitsResponse},
 };
 use tryiter::TryIteratorExt;
@@ -15,7 +15,7 @@ use crate::api::{
     response::ProtoResponse,
 };
 
-use super::MetastoreApiState;
+use super::{MetastoreApiState, check_tombstone, segment_info};
 
 /// Returns a list of segments added in the lsn range. This method will also
 /// return the latest Snapshot of the Volume. If no lsn range is specified, only
@@ -34,6 +34,8 @@ pub async fn handler(
 
     tracing::info!(?vid, ?lsns);
 
+    state.check_deleted(&vid)?;
+
     // load the snapshot at the end of the lsn range
     let snapshot = state
         .updater
@@ -48,6 +50,7 @@ pub async fn handler(
         )
         .into());
     };
+    check_tombstone(&vid, &snapshot)?;
 
     // resolve the start of the range, defaulting to the last checkpoint
     let checkpoint = snapshot.checkpoint();
@@ -70,19 +73,27 @@ pub async fn handler(
         commits: Vec::with_capacity(lsns.try_len().unwrap_or_default()),
     };
 
+    let inherited = state.inherited_segments(&vid).await?;
     let mut scan = state.catalog.scan_volume(&vid, &lsns);
     while let Some((meta, mut segments)) = scan.try_next().or_into_ctx()? {
         let mut segment_infos = Vec::default();
         while let Some((key, splinter)) = segments.try_next().or_into_ctx()? {
-            segment_infos.push(SegmentInfo {
-                sid: key.sid().copy_to_bytes(),
-                graft: splinter.into_inner(),
-            });
+            segment_infos.push(segment_info(&inherited, key.sid(), splinter.into_inner()));
         }
 
+        let metadata = if meta.has_metadata() {
+            state
+                .catalog
+                .commit_metadata(vid.clone(), meta.lsn())
+                .or_into_ctx()?
+        } else {
+            None
+        };
+
         result.commits.push(Commit {
             snapshot: Some(meta.into_snapshot()),
             segments: segment_infos,
+            metadata,
         });
     }
 
@@ -16,12 +16,12 @@ use crate::api::{
     response::ProtoResponse,
 };
 
-use super::MetastoreApiState;
+use super::{MetastoreApiState, check_tombstone};
 
 /// Returns a Graft in the lsn range. This method will also
 /// return the latest Snapshot of the Volume. If no lsn range is specified, it
 /// will return pages changed between the last checkpoint and the latest
-/// snapshot.
+/// snapshot. If a tag is specified, the range ends at the tagged LSN.
 #[tracing::instrument(name = "metastore/v1/pull_graft", skip(state, req))]
 pub async fn handler(
     State(state): State<Arc<MetastoreApiState>>,
@@ -29,12 +29,21 @@ pub async fn handler(
 ) -> Result<ProtoResponse<PullGraftResponse>, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
     let lsns = req.range;
-    let end_lsn = match lsns {
+    let mut end_lsn = match lsns {
         Some(l) => l.end().or_into_ctx()?,
         None => None,
     };
+    if !req.tag.is_empty() && end_lsn.is_some() {
+        return Err(ApiErrCtx::TagWithLsn.into());
+    }
+
+    tracing::info!(?vid, ?lsns, tag = req.tag);
 
-    tracing::info!(?vid, ?lsns);
+    state.check_deleted(&vid)?;
+
+    if let Some(tagged) = state.resolve_tag(&vid, req.tag).await? {
+        end_lsn = Some(tagged);
+    }
 
     // load the snapshot at the end of the lsn range
     let snapshot = state
@@ -50,9 +59,11 @@ pub async fn handler(
         )
         .into());
     };
+    check_tombstone(&vid, &snapshot)?;
 
     // resolve the start of the range, defaulting to the last checkpoint
     let checkpoint = snapshot.checkpoint();
+    let mut from_checkpoint = lsns.is_none();
     let start_lsn = match lsns {
         Some(l) => l.start().or_into_ctx()?,
         None => checkpoint,
@@ -82,11 +93,39 @@ pub async fn handler(
         .await
         .or_into_ctx()?;
 
+    // if some of the requested commits have been garbage collected we can't
+    // compute the exact set of changed pages, so instead we return every page
+    // reachable from the checkpoint which is a superset of the changes
+    if !from_checkpoint && !state.catalog.contains_range(&vid, &lsns).or_into_ctx()? {
+        tracing::debug!(
+            ?lsns,
+            "requested commits are missing; pulling from checkpoint"
+        );
+        from_checkpoint = true;
+    }
+
     // read the segments, and merge into a single splinter
-    let mut iter = state.catalog.scan_segments(&vid, &lsns);
     let mut graft = Splinter::default();
-    while let Some((_, segment_graft)) = iter.try_next().or_into_ctx()? {
-        graft.merge(&segment_graft);
+    if from_checkpoint {
+        // when pulling from the checkpoint we include every page in the
+        // checkpoint rather than just the pages changed by the checkpoint's
+        // commit
+        state
+            .updater
+            .update_checkpoint_from_store(&state.store, &state.catalog, &vid, checkpoint)
+            .await
+            .or_into_ctx()?;
+        let mut iter = state
+            .catalog
+            .scan_from_checkpoint(&vid, checkpoint, snapshot.lsn());
+        while let Some((_, segment_graft)) = iter.try_next().or_into_ctx()? {
+            graft.merge(&segment_graft);
+        }
+    } else {
+        let mut iter = state.catalog.scan_segments(&vid, &lsns);
+        while let Some((_, segment_graft)) = iter.try_next().or_into_ctx()? {
+            graft.merge(&segment_graft);
+        }
     }
 
     Ok(ProtoResponse::new(PullGraftResponse {
@@ -140,7 +179,11 @@ mod tests {
         let cid = ClientId::random();
 
         // case 1: catalog and store are empty
-        let req = PullGraftRequest { vid: vid.copy_to_bytes(), range: None };
+        let req = PullGraftRequest {
+            vid: vid.copy_to_bytes(),
+            range: None,
+            tag: String::new(),
+        };
         let resp = server
             .post("/")
             .bytes(req.encode_to_vec().into())
@@ -174,6 +217,7 @@ mod tests {
         let req = PullGraftRequest {
             vid: vid.copy_to_bytes(),
             range: Some(LsnRange::from_range(lsns.clone())),
+            tag: String::new(),
         };
         let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
         let resp = PullGraftResponse::decode(resp.into_bytes()).unwrap();
@@ -195,7 +239,11 @@ mod tests {
         objstore.reset_hits().await;
 
         // request all the segments
-        let req = PullGraftRequest { vid: vid.copy_to_bytes(), range: None };
+        let req = PullGraftRequest {
+            vid: vid.copy_to_bytes(),
+            range: None,
+            tag: String::new(),
+        };
         let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
         let resp = PullGraftResponse::decode(resp.into_bytes()).unwrap();
         let splinter = Splinter::from_bytes(resp.graft).unwrap();
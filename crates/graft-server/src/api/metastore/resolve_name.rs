@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, volume_name::VolumeName};
+use graft_proto::metastore::v1::{ResolveNameRequest, ResolveNameResponse};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+#[tracing::instrument(name = "metastore/v1/resolve_name", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<ResolveNameRequest>,
+) -> Result<ProtoResponse<ResolveNameResponse>, ApiErr> {
+    let name = VolumeName::new(req.namespace, req.name)?;
+
+    tracing::info!(%name, create = req.create);
+
+    if let Some(vid) = state.store.resolve_name(&name).await.or_into_ctx()? {
+        return Ok(ProtoResponse::new(ResolveNameResponse {
+            vid: vid.copy_to_bytes(),
+            created: false,
+        }));
+    }
+
+    if !req.create {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::NameNotFound,
+            format!("volume name {name} does not exist"),
+        )
+        .into());
+    }
+
+    let (vid, created) = state
+        .store
+        .create_name(&name, &VolumeId::random())
+        .await
+        .or_into_ctx()?;
+    if created {
+        tracing::info!(%name, ?vid, "registered volume name");
+    }
+
+    Ok(ProtoResponse::new(ResolveNameResponse {
+        vid: vid.copy_to_bytes(),
+        created,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{handler::Handler, http::StatusCode};
+    use axum_test::TestServer;
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_resolve_name() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let state = Arc::new(MetastoreApiState::new(
+            store,
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let req = |name: &str, create| ResolveNameRequest {
+            namespace: "acme".into(),
+            name: name.into(),
+            create,
+        };
+
+        // resolving a missing name fails
+        let resp = server
+            .post("/")
+            .bytes(req("customers.db", false).encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        // creating the name registers a new volume id
+        let resp = server
+            .post("/")
+            .bytes(req("customers.db", true).encode_to_vec().into())
+            .await;
+        let resp = ResolveNameResponse::decode(resp.into_bytes()).unwrap();
+        assert!(resp.created);
+        let vid = VolumeId::try_from(resp.vid).unwrap();
+
+        // subsequent requests resolve to the same volume id
+        for create in [false, true] {
+            let resp = server
+                .post("/")
+                .bytes(req("customers.db", create).encode_to_vec().into())
+                .await;
+            let resp = ResolveNameResponse::decode(resp.into_bytes()).unwrap();
+            assert!(!resp.created);
+            assert_eq!(VolumeId::try_from(resp.vid).unwrap(), vid);
+        }
+
+        // invalid names are rejected
+        let resp = server
+            .post("/")
+            .bytes(req("bad/name", true).encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
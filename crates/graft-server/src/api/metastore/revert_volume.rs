@@ -0,0 +1,286 @@
+use std::{sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{SegmentId, VolumeId, gid::ClientId, lsn::LSN};
+use graft_proto::metastore::v1::{RevertVolumeRequest, RevertVolumeResponse};
+use splinter_rs::{
+    Splinter,
+    ops::{Cut, Merge},
+};
+
+use crate::{
+    api::{
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    volume::commit::{CommitBuilder, CommitMeta},
+};
+
+use super::{MetastoreApiState, check_tombstone};
+
+/// Commits a new snapshot on top of the Volume's latest snapshot which maps
+/// every page to the segment containing it at the target LSN. Pages which are
+/// present in the latest snapshot but not at the target LSN are mapped to the
+/// empty segment.
+///
+/// The revert commit is its own checkpoint, as otherwise readers would find
+/// pages in commits made after the target LSN. Since the commit contains every
+/// page in the Volume, it isn't written separately as a checkpoint.
+#[tracing::instrument(name = "metastore/v1/revert_volume", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<RevertVolumeRequest>,
+) -> Result<ProtoResponse<RevertVolumeResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let cid = ClientId::try_from(req.cid).or_into_culprit("failed to parse ClientId")?;
+    let target_lsn = LSN::try_from(req.target_lsn).or_into_ctx()?;
+
+    tracing::info!(?vid, ?cid, ?target_lsn);
+
+    state.check_deleted(&vid)?;
+
+    let Some(latest) = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &vid, None)
+        .await
+        .or_into_ctx()?
+    else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} does not exist"),
+        )
+        .into());
+    };
+    check_tombstone(&vid, &latest)?;
+    let Some(target) = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &vid, Some(target_lsn))
+        .await
+        .or_into_ctx()?
+    else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} is missing snapshot at {target_lsn}"),
+        )
+        .into());
+    };
+
+    let mut live = state
+        .updater
+        .live_segments(&state.store, &state.catalog, &target)
+        .await
+        .or_into_ctx()?;
+
+    // clear pages which have been written since the target LSN
+    let mut cleared = Splinter::default();
+    let latest_live = state
+        .updater
+        .live_segments(&state.store, &state.catalog, &latest)
+        .await
+        .or_into_ctx()?;
+    for pages in latest_live.values() {
+        cleared.merge(pages);
+    }
+    for pages in live.values() {
+        let _ = cleared.cut(pages);
+    }
+    if !cleared.is_empty() {
+        live.entry(SegmentId::EMPTY).or_default().merge(&cleared);
+    }
+
+    let commit_lsn = latest.lsn().saturating_next();
+    let meta = CommitMeta::new(
+        vid.clone(),
+        cid,
+        commit_lsn,
+        commit_lsn,
+        target.page_count(),
+        SystemTime::now(),
+    )
+    .with_page_size(target.page_size());
+    let mut commit = CommitBuilder::new_with_capacity(meta, live.len());
+    for (sid, pages) in live {
+        commit.write_graft(sid, pages.serialize_to_bytes());
+    }
+    let commit = commit.build();
+
+    // fails if another commit landed since we loaded the latest snapshot
+    state.store.commit(commit.clone()).await.or_into_ctx()?;
+
+    let mut batch = state.catalog.batch_insert();
+    batch.insert_commit(&commit).or_into_ctx()?;
+    batch.insert_checkpoint(&commit, None).or_into_ctx()?;
+    batch.commit().or_into_ctx()?;
+
+    tracing::info!("reverted volume {vid} to lsn {target_lsn} at lsn {commit_lsn}");
+
+    Ok(ProtoResponse::new(RevertVolumeResponse {
+        snapshot: Some(commit.into_snapshot()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_proto::{
+        common::v1::{Commit, SegmentInfo},
+        metastore::v1::{
+            CommitRequest, PullCheckpointRequest, PullCheckpointResponse, PullCommitsRequest,
+            PullCommitsResponse,
+        },
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF,
+            metastore::{commit, pull_checkpoint, pull_commits},
+        },
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    fn test_server(state: MetastoreApiState) -> TestServer {
+        let router = Router::new()
+            .route("/commit", post(commit::handler))
+            .route("/pull_commits", post(pull_commits::handler))
+            .route("/pull_checkpoint", post(pull_checkpoint::handler))
+            .route("/revert_volume", post(handler))
+            .with_state(Arc::new(state));
+        TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap()
+    }
+
+    fn segments(commit: &Commit) -> Vec<(SegmentId, Vec<u32>)> {
+        let mut segments: Vec<_> = commit
+            .segments
+            .iter()
+            .map(|info| {
+                (
+                    info.sid().unwrap().clone(),
+                    info.graft().unwrap().iter().collect(),
+                )
+            })
+            .collect();
+        segments.sort();
+        segments
+    }
+
+    #[graft_test::test]
+    async fn test_revert_volume() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let server = test_server(MetastoreApiState::new(
+            store.clone(),
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+        // a second metastore sharing the store
+        let other = test_server(MetastoreApiState::new(
+            store,
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let commit = |lsn: u64, page_count: u32, sid: &SegmentId, pages: &[u32]| {
+            CommitRequest {
+                vid: vid.copy_to_bytes(),
+                cid: cid.copy_to_bytes(),
+                snapshot_lsn: (lsn > 1).then(|| lsn - 1),
+                page_count,
+                segments: vec![SegmentInfo::new(
+                    sid,
+                    Splinter::from_iter(pages.iter().copied()).serialize_to_bytes(),
+                )],
+                page_size: 0,
+                metadata: None,
+            }
+            .encode_to_vec()
+        };
+        let revert = |target_lsn: u64| {
+            RevertVolumeRequest {
+                vid: vid.copy_to_bytes(),
+                cid: cid.copy_to_bytes(),
+                target_lsn,
+            }
+            .encode_to_vec()
+        };
+
+        let (sid1, sid2) = (SegmentId::random(), SegmentId::random());
+        server
+            .post("/commit")
+            .bytes(commit(1, 2, &sid1, &[1, 2]).into())
+            .await;
+        server
+            .post("/commit")
+            .bytes(commit(2, 4, &sid2, &[2, 3, 4]).into())
+            .await;
+
+        // reverting to a missing LSN fails
+        let resp = server
+            .post("/revert_volume")
+            .bytes(revert(5).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        // reverting shrinks the volume and clears pages written since the target
+        let resp = server.post("/revert_volume").bytes(revert(1).into()).await;
+        let snapshot = RevertVolumeResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), 3);
+        assert_eq!(snapshot.checkpoint().unwrap(), 3);
+        assert_eq!(snapshot.pages(), 2);
+
+        let expected = vec![(SegmentId::EMPTY, vec![3, 4]), (sid1.clone(), vec![1, 2])];
+        for server in [&server, &other] {
+            // the revert commit is pulled like any other commit
+            let req = PullCommitsRequest { vid: vid.copy_to_bytes(), range: None };
+            let resp = server
+                .post("/pull_commits")
+                .bytes(req.encode_to_vec().into())
+                .await;
+            let resp = PullCommitsResponse::decode(resp.into_bytes()).unwrap();
+            assert_eq!(resp.commits.len(), 1);
+            assert_eq!(segments(&resp.commits[0]), expected);
+
+            // and doubles as the checkpoint at its LSN
+            let req = PullCheckpointRequest { vid: vid.copy_to_bytes(), lsn: 3 };
+            let resp = server
+                .post("/pull_checkpoint")
+                .bytes(req.encode_to_vec().into())
+                .await;
+            let resp = PullCheckpointResponse::decode(resp.into_bytes()).unwrap();
+            assert_eq!(segments(&resp.checkpoint.unwrap()), expected);
+        }
+
+        // reverting grows the volume back, restoring pages from their segments
+        let resp = other.post("/revert_volume").bytes(revert(2).into()).await;
+        let snapshot = RevertVolumeResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), 4);
+        assert_eq!(snapshot.pages(), 4);
+        let req = PullCheckpointRequest { vid: vid.copy_to_bytes(), lsn: 4 };
+        let resp = server
+            .post("/pull_checkpoint")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        let resp = PullCheckpointResponse::decode(resp.into_bytes()).unwrap();
+        let mut expected = vec![(sid1, vec![1]), (sid2, vec![2, 3, 4])];
+        expected.sort();
+        assert_eq!(segments(&resp.checkpoint.unwrap()), expected);
+    }
+}
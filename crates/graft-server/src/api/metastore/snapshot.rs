@@ -11,7 +11,7 @@ use crate::api::{
     response::ProtoResponse,
 };
 
-use super::MetastoreApiState;
+use super::{MetastoreApiState, check_tombstone};
 
 #[tracing::instrument(name = "metastore/v1/snapshot", skip(state, req))]
 pub async fn handler(
@@ -19,9 +19,18 @@ pub async fn handler(
     Protobuf(req): Protobuf<SnapshotRequest>,
 ) -> Result<ProtoResponse<SnapshotResponse>, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
-    let lsn: Option<LSN> = req.lsn.map(LSN::try_from).transpose().or_into_ctx()?;
+    let mut lsn: Option<LSN> = req.lsn.map(LSN::try_from).transpose().or_into_ctx()?;
+    if !req.tag.is_empty() && lsn.is_some() {
+        return Err(ApiErrCtx::TagWithLsn.into());
+    }
+
+    tracing::info!(?vid, ?lsn, tag = req.tag);
 
-    tracing::info!(?vid, ?lsn);
+    state.check_deleted(&vid)?;
+
+    if let Some(tagged) = state.resolve_tag(&vid, req.tag).await? {
+        lsn = Some(tagged);
+    }
 
     let snapshot = state
         .updater
@@ -30,8 +39,10 @@ pub async fn handler(
         .or_into_ctx()?;
 
     if let Some(snapshot) = snapshot {
+        check_tombstone(&vid, &snapshot)?;
         Ok(ProtoResponse::new(SnapshotResponse {
             snapshot: Some(snapshot.into_snapshot()),
+            labels: Some(state.labels(&vid).await?),
         }))
     } else {
         return Err(Culprit::new_with_note(
@@ -89,7 +100,11 @@ mod tests {
         // case 1: catalog and store are empty
 
         // request latest
-        let req = SnapshotRequest { vid: vid.copy_to_bytes(), lsn: None };
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: String::new(),
+        };
         let resp = server
             .post("/")
             .bytes(req.encode_to_vec().into())
@@ -98,7 +113,11 @@ mod tests {
         assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
 
         // request specific
-        let req = SnapshotRequest { vid: vid.copy_to_bytes(), lsn: Some(10) };
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: Some(10),
+            tag: String::new(),
+        };
         let resp = server
             .post("/")
             .bytes(req.encode_to_vec().into())
@@ -123,7 +142,11 @@ mod tests {
         store.commit(commit.build()).await.unwrap();
 
         // request latest
-        let req = SnapshotRequest { vid: vid.copy_to_bytes(), lsn: None };
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            tag: String::new(),
+        };
         let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
         let resp = SnapshotResponse::decode(resp.into_bytes()).unwrap();
         let snapshot = resp.snapshot.unwrap();
@@ -0,0 +1,62 @@
+use std::{sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{UndeleteVolumeRequest, UndeleteVolumeResponse};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+/// Restores a deleted Volume by committing on top of its tombstone commit.
+/// Volumes can only be undeleted before their undelete window expires, after
+/// which the garbage collector may have started purging them.
+#[tracing::instrument(name = "metastore/v1/undelete_volume", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<UndeleteVolumeRequest>,
+) -> Result<ProtoResponse<UndeleteVolumeResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+
+    tracing::info!(?vid);
+
+    let Some(latest) = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &vid, None)
+        .await
+        .or_into_ctx()?
+    else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} does not exist"),
+        )
+        .into());
+    };
+    if latest.is_tombstone() {
+        let purge_at = state.purge_at(latest.system_time());
+        if purge_at <= SystemTime::now() {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::UndeleteExpired,
+                format!("volume {vid} could only be undeleted until {purge_at:?}"),
+            )
+            .into());
+        }
+    }
+
+    let Some(snapshot) = state.commit_tombstone(&vid, false).await? else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} does not exist"),
+        )
+        .into());
+    };
+
+    Ok(ProtoResponse::new(UndeleteVolumeResponse {
+        snapshot: Some(snapshot.into_snapshot()),
+    }))
+}
@@ -0,0 +1,261 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{UpdateLabelsRequest, UpdateLabelsResponse, VolumeLabels};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::{MetastoreApiState, check_tombstone};
+
+/// The maximum number of labels attached to a Volume
+const MAX_LABELS: usize = 64;
+
+/// The maximum length of a label key in bytes
+const MAX_LABEL_KEY_LEN: usize = 128;
+
+/// The maximum length of a label value in bytes
+const MAX_LABEL_VALUE_LEN: usize = 1024;
+
+/// Replaces the Volume's labels if their current version matches the expected
+/// version. Each update writes a new version of the labels to the store which
+/// fails if another metastore concurrently wrote the same version.
+#[tracing::instrument(name = "metastore/v1/update_labels", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<UpdateLabelsRequest>,
+) -> Result<ProtoResponse<UpdateLabelsResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let expected_version = req.expected_version;
+
+    tracing::info!(?vid, expected_version, num_labels = req.labels.len());
+
+    validate_labels(&req.labels)?;
+    state.check_deleted(&vid)?;
+
+    let Some(latest) = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &vid, None)
+        .await
+        .or_into_ctx()?
+    else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {vid} does not exist"),
+        )
+        .into());
+    };
+    check_tombstone(&vid, &latest)?;
+
+    // the cached labels may be stale, so we check the version against the
+    // store and refresh the cache while we're at it
+    let current = state
+        .store
+        .get_labels(&vid)
+        .await
+        .or_into_ctx()?
+        .unwrap_or_default();
+    state.catalog.insert_labels(&vid, &current).or_into_ctx()?;
+    if current.version != expected_version {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::LabelsVersionConflict,
+            format!(
+                "expected labels version {expected_version} but found {}",
+                current.version
+            ),
+        )
+        .into());
+    }
+
+    let labels = VolumeLabels {
+        labels: req.labels,
+        version: current.version + 1,
+    };
+    if !state.store.put_labels(&vid, &labels).await.or_into_ctx()? {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::LabelsVersionConflict,
+            format!("labels version {} was written concurrently", labels.version),
+        )
+        .into());
+    }
+    state.catalog.insert_labels(&vid, &labels).or_into_ctx()?;
+
+    Ok(ProtoResponse::new(UpdateLabelsResponse {
+        labels: Some(labels),
+    }))
+}
+
+fn validate_labels(labels: &HashMap<String, String>) -> Result<(), Culprit<ApiErrCtx>> {
+    if labels.len() > MAX_LABELS {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidLabels,
+            format!("volumes may have at most {MAX_LABELS} labels"),
+        ));
+    }
+    for (key, value) in labels {
+        if key.is_empty() || key.len() > MAX_LABEL_KEY_LEN {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidLabels,
+                format!("label keys must be between 1 and {MAX_LABEL_KEY_LEN} bytes"),
+            ));
+        }
+        if value.len() > MAX_LABEL_VALUE_LEN {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidLabels,
+                format!("label {key} exceeds {MAX_LABEL_VALUE_LEN} bytes"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use axum::{Router, http::StatusCode, routing::post};
+    use axum_test::TestServer;
+    use graft_core::{gid::ClientId, lsn::LSN, page_count::PageCount};
+    use graft_proto::{
+        common::v1::{GraftErr, GraftErrCode},
+        metastore::v1::{SnapshotRequest, SnapshotResponse},
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::{extractors::CONTENT_TYPE_PROTOBUF, metastore::snapshot},
+        volume::{
+            catalog::VolumeCatalog,
+            commit::{CommitBuilder, CommitMeta},
+            store::VolumeStore,
+            updater::VolumeCatalogUpdater,
+        },
+    };
+
+    use super::*;
+
+    fn test_server(store: Arc<VolumeStore>) -> TestServer {
+        let state = MetastoreApiState::new(
+            store,
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        );
+        let router = Router::new()
+            .route("/snapshot", post(snapshot::handler))
+            .route("/update_labels", post(handler))
+            .with_state(Arc::new(state));
+        TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router.into_make_service())
+            .unwrap()
+    }
+
+    #[graft_test::test]
+    async fn test_update_labels() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let server = test_server(store.clone());
+        let other = test_server(store.clone());
+
+        let vid = VolumeId::random();
+        let update = |labels: &[(&str, &str)], expected_version| {
+            UpdateLabelsRequest {
+                vid: vid.copy_to_bytes(),
+                labels: labels
+                    .iter()
+                    .map(|&(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                expected_version,
+            }
+            .encode_to_vec()
+        };
+        let labels = async |server: &TestServer| {
+            let req = SnapshotRequest {
+                vid: vid.copy_to_bytes(),
+                lsn: None,
+                tag: String::new(),
+            };
+            let resp = server
+                .post("/snapshot")
+                .bytes(req.encode_to_vec().into())
+                .await;
+            SnapshotResponse::decode(resp.into_bytes())
+                .unwrap()
+                .labels
+                .unwrap()
+        };
+
+        // missing volumes can't be labeled
+        let resp = server
+            .post("/update_labels")
+            .bytes(update(&[("owner", "carl")], 0).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        let meta = CommitMeta::new(
+            vid.clone(),
+            ClientId::random(),
+            LSN::FIRST,
+            LSN::FIRST,
+            PageCount::ZERO,
+            SystemTime::now(),
+        );
+        store
+            .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+            .await
+            .unwrap();
+
+        // volumes start without labels
+        assert_eq!(labels(&server).await, VolumeLabels::default());
+        assert_eq!(labels(&other).await, VolumeLabels::default());
+
+        let resp = server
+            .post("/update_labels")
+            .bytes(update(&[("owner", "carl")], 0).into())
+            .await;
+        let updated = UpdateLabelsResponse::decode(resp.into_bytes())
+            .unwrap()
+            .labels
+            .unwrap();
+        assert_eq!(updated.version, 1);
+        assert_eq!(labels(&server).await, updated);
+
+        // the other metastore has cached the old labels, but its update is
+        // still checked against the latest version
+        let resp = other
+            .post("/update_labels")
+            .bytes(update(&[("owner", "sam")], 0).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::CONFLICT);
+        let err = GraftErr::decode(resp.into_bytes()).unwrap();
+        assert_eq!(err.code(), GraftErrCode::VersionConflict);
+        assert_eq!(labels(&other).await, updated);
+
+        other
+            .post("/update_labels")
+            .bytes(update(&[("owner", "sam"), ("tenant", "a")], 1).into())
+            .await;
+        assert_eq!(store.get_labels(&vid).await.unwrap().unwrap().version, 2);
+
+        // the first metastore notices its cached labels are stale
+        let latest = labels(&server).await;
+        assert_eq!(latest.version, 2);
+        assert_eq!(latest, labels(&other).await);
+
+        // invalid labels are rejected
+        let resp = server
+            .post("/update_labels")
+            .bytes(update(&[("", "empty")], 2).into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
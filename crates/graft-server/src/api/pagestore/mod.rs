@@ -1,4 +1,4 @@
-use graft_client::MetastoreClient;
+use graft_client::nonblocking::MetastoreClient;
 use graft_core::{PageIdx, VolumeId, page::Page};
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
@@ -27,6 +27,9 @@ pub struct PagestoreApiState<C> {
     metastore: MetastoreClient,
     updater: VolumeCatalogUpdater,
     volume_write_limiter: Limiter<VolumeId>,
+
+    /// notifies the segment compactor about Volumes which have been written to
+    compactor_tx: Option<mpsc::Sender<VolumeId>>,
 }
 
 impl<C> PagestoreApiState<C> {
@@ -45,9 +48,15 @@ impl<C> PagestoreApiState<C> {
             metastore,
             updater,
             volume_write_limiter: Limiter::new(write_concurrency),
+            compactor_tx: None,
         }
     }
 
+    /// Notify the segment compactor whenever a Volume is written to
+    pub fn with_compactor(self, compactor_tx: mpsc::Sender<VolumeId>) -> Self {
+        Self { compactor_tx: Some(compactor_tx), ..self }
+    }
+
     pub async fn write_pages(
         &self,
         vid: VolumeId,
@@ -61,6 +70,14 @@ impl<C> PagestoreApiState<C> {
         rx.await.expect("write pages response channel closed")
     }
 
+    /// Let the segment compactor know that the Volume has new segments. This
+    /// is best effort; if the compactor is busy the notification is dropped.
+    pub fn notify_compactor(&self, vid: &VolumeId) {
+        if let Some(tx) = &self.compactor_tx {
+            let _ = tx.try_send(vid.clone());
+        }
+    }
+
     pub fn catalog(&self) -> &VolumeCatalog {
         &self.catalog
     }
@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use axum::{extract::State, response::IntoResponse};
-use culprit::ResultExt;
+use culprit::{Culprit, ResultExt};
 use futures::{FutureExt, TryStreamExt, stream::FuturesUnordered};
-use graft_core::{VolumeId, lsn::LSN};
+use graft_core::{VolumeId, lsn::LSN, tag_name::TagName};
 use graft_proto::pagestore::v1::{PageAtIdx, ReadPagesRequest, ReadPagesResponse};
 use splinter_rs::{Splinter, ops::Cut};
 
@@ -25,11 +25,17 @@ pub async fn handler<C: Cache>(
     Protobuf(req): Protobuf<ReadPagesRequest>,
 ) -> Result<impl IntoResponse, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
-    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+    let tag = if req.tag.is_empty() {
+        None
+    } else if req.lsn != 0 {
+        return Err(ApiErrCtx::TagWithLsn.into());
+    } else {
+        Some(TagName::new(req.tag)?)
+    };
     let mut graft = Splinter::from_bytes(req.graft).or_into_ctx()?;
     let num_pages = graft.cardinality();
 
-    tracing::info!(?vid, ?lsn, num_pages);
+    tracing::info!(?vid, lsn = req.lsn, ?tag, num_pages);
 
     if num_pages > MAX_PAGES {
         return Err(ApiErrCtx::GraftTooLarge.into());
@@ -38,6 +44,26 @@ pub async fn handler<C: Cache>(
         return Err(ApiErrCtx::ZeroPageIdx.into());
     }
 
+    // tags are resolved by the metastore
+    let lsn = match tag {
+        None => LSN::try_from(req.lsn).or_into_ctx()?,
+        Some(tag) => {
+            let Some(snapshot) = state
+                .metastore_client()
+                .snapshot_at_tag(&vid, &tag)
+                .await
+                .or_into_ctx()?
+            else {
+                return Err(Culprit::new_with_note(
+                    ApiErrCtx::TagNotFound,
+                    format!("volume {vid} has no tag {tag}"),
+                )
+                .into());
+            };
+            snapshot.lsn().or_into_ctx()?
+        }
+    };
+
     // ensure we've replayed the catalog up to the requested LSN
     state
         .updater()
@@ -53,20 +79,37 @@ pub async fn handler<C: Cache>(
         .expect("missing snapshot after update");
     let checkpoint = snapshot.checkpoint();
 
+    // ensure we have the current version of the checkpoint the snapshot is
+    // based on, as another node may have compacted it
+    state
+        .updater()
+        .update_checkpoint_from_metastore(
+            state.metastore_client(),
+            state.catalog(),
+            &vid,
+            checkpoint,
+        )
+        .await
+        .or_into_ctx()?;
+
     let mut loading = FuturesUnordered::new();
 
-    let segments = state.catalog().scan_segments(&vid, &(checkpoint..=lsn));
+    let segments = state.catalog().scan_from_checkpoint(&vid, checkpoint, lsn);
     for result in segments {
         let (key, splinter) = result.or_into_ctx()?;
 
         let cut = graft.cut(&splinter);
-        if !cut.is_empty() {
+        // pages in the empty segment are omitted from the response, which
+        // clients read as empty pages
+        if !cut.is_empty() && !key.sid().is_empty() {
             let sid = key.sid().clone();
+            // segments inherited from a fork source contain the source's pages
+            let owner = state.catalog().segment_owner(&vid, &sid).or_into_ctx()?;
             loading.push(
                 state
                     .loader()
                     .load_segment(sid)
-                    .map(move |result| result.map(|segment| (segment, cut)))
+                    .map(move |result| result.map(|segment| (segment, owner, cut)))
                     .boxed(),
             );
         }
@@ -78,12 +121,12 @@ pub async fn handler<C: Cache>(
     }
 
     let mut result = ReadPagesResponse { pages: Vec::with_capacity(num_pages) };
-    while let Some((segment, cut)) = loading.try_next().await.or_into_ctx()? {
+    while let Some((segment, owner, cut)) = loading.try_next().await.or_into_ctx()? {
         let segment = ClosedSegment::from_bytes(&segment).or_into_ctx()?;
 
         for pageidx in cut.iter() {
             let page = segment
-                .find_page(&vid, pageidx.try_into()?)
+                .find_page(&owner, pageidx.try_into()?)
                 .expect("bug: failed to find expected pageidx in segment; index out of sync");
             result.pages.push(PageAtIdx { pageidx, data: page.into() });
         }
@@ -99,7 +142,7 @@ mod tests {
     use axum::handler::Handler;
     use axum_test::TestServer;
     use bytes::Bytes;
-    use graft_client::{MetastoreClient, NetClient};
+    use graft_client::nonblocking::{MetastoreClient, NetClient};
     use graft_core::{
         PageIdx,
         gid::{ClientId, SegmentId},
@@ -141,7 +184,7 @@ mod tests {
 
         let (page_tx, _) = mpsc::channel(128);
 
-        let client = NetClient::new_with_proxy(None, None);
+        let client = NetClient::new(None);
         let metastore_uri = "http://127.0.0.1:3000".parse().unwrap();
 
         let state = Arc::new(PagestoreApiState::new(
@@ -205,14 +248,8 @@ mod tests {
                     SystemTime::now(),
                 ),
                 vec![
-                    SegmentInfo {
-                        sid: sid1.copy_to_bytes(),
-                        graft: graft1.serialize_to_bytes(),
-                    },
-                    SegmentInfo {
-                        sid: sid2.copy_to_bytes(),
-                        graft: graft2.serialize_to_bytes(),
-                    },
+                    SegmentInfo::new(&sid1, graft1.serialize_to_bytes()),
+                    SegmentInfo::new(&sid2, graft2.serialize_to_bytes()),
                 ],
             )
             .unwrap();
@@ -223,6 +260,7 @@ mod tests {
             vid: vid.copy_to_bytes(),
             lsn: lsn.into(),
             graft: (1u32..=5).collect::<Splinter>().serialize_to_bytes(),
+            tag: String::new(),
         };
         let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
         if resp.status_code() != 200 {
@@ -3,9 +3,10 @@ use std::sync::Arc;
 use axum::{extract::State, response::IntoResponse};
 use culprit::{Culprit, ResultExt};
 use futures::future::try_join_all;
-use graft_core::{VolumeId, page::Page};
+use graft_core::{PageIdx, VolumeId, page::Page};
 use graft_proto::{
     common::v1::SegmentInfo,
+    decode_page_size,
     pagestore::v1::{WritePagesRequest, WritePagesResponse},
 };
 use hashbrown::HashSet;
@@ -22,6 +23,7 @@ pub async fn handler<C>(
     Protobuf(req): Protobuf<WritePagesRequest>,
 ) -> Result<impl IntoResponse, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
+    let page_size = decode_page_size(req.page_size).or_into_ctx()?;
     let expected_pages = req.pages.len();
 
     // acquire a permit to write to the volume.
@@ -32,7 +34,7 @@ pub async fn handler<C>(
     tracing::info!(?vid, expected_pages);
 
     let mut seen = HashSet::with_capacity(req.pages.len());
-    let mut pages = Vec::with_capacity(req.pages.len());
+    let mut pages: Vec<(PageIdx, Page)> = Vec::with_capacity(req.pages.len());
     for page in req.pages {
         let pageidx = page.pageidx().or_into_ctx()?;
         let page: Page = Page::try_from(page.data).or_into_ctx()?;
@@ -45,6 +47,18 @@ pub async fn handler<C>(
             .into());
         }
 
+        // every page written to a volume must have the volume's page size
+        if page.size() != page_size {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::PageSizeMismatch,
+                format!(
+                    "page index {pageidx} has size {}; expected {page_size}",
+                    page.size()
+                ),
+            )
+            .into());
+        }
+
         pages.push((pageidx, page));
     }
 
@@ -56,15 +70,14 @@ pub async fn handler<C>(
 
     for (sid, graft, event) in response {
         tracing::trace!("write_pages handler received segment {sid} for volume {vid}",);
-        let sid = sid.copy_to_bytes();
         received_pages += graft.cardinality();
-        let graft = graft.serialize_to_bytes();
-        segments.push(SegmentInfo { sid, graft });
+        segments.push(SegmentInfo::new(&sid, graft.serialize_to_bytes()));
         events.push(event);
     }
 
     // wait for all segments to be written
     try_join_all(events).await?;
+    state.notify_compactor(&vid);
 
     assert_eq!(
         received_pages, expected_pages,
@@ -81,7 +94,8 @@ mod tests {
     use axum::handler::Handler;
     use axum_test::TestServer;
     use bytes::Bytes;
-    use graft_client::{MetastoreClient, NetClient};
+    use graft_client::nonblocking::{MetastoreClient, NetClient};
+    use graft_core::page::PageSize;
     use graft_proto::pagestore::v1::PageAtIdx;
     use object_store::memory::InMemory;
     use prost::Message;
@@ -121,7 +135,7 @@ mod tests {
         SegmentUploaderTask::new(Default::default(), store_rx, store.clone(), cache.clone())
             .testonly_spawn();
 
-        let client = NetClient::new_with_proxy(None, None);
+        let client = NetClient::new(None);
         let metastore_uri = "http://127.0.0.1:3000".parse().unwrap();
 
         let state = Arc::new(PagestoreApiState::new(
@@ -145,6 +159,7 @@ mod tests {
         let req1 = WritePagesRequest {
             vid: VolumeId::random().copy_to_bytes(),
             pages: vec![PageAtIdx { pageidx: 1, data: page.clone() }],
+            page_size: 0,
         };
 
         let req2 = WritePagesRequest {
@@ -153,6 +168,7 @@ mod tests {
                 PageAtIdx { pageidx: 1, data: page.clone() },
                 PageAtIdx { pageidx: 2, data: page.clone() },
             ],
+            page_size: 0,
         };
 
         let local = tokio::task::LocalSet::new();
@@ -185,5 +201,48 @@ mod tests {
         assert_eq!(graft.cardinality(), 2);
         assert!(graft.contains(1));
         assert!(graft.contains(2));
+
+        // pages with different sizes can't be written in the same request
+        let req3 = WritePagesRequest {
+            vid: VolumeId::random().copy_to_bytes(),
+            pages: vec![
+                PageAtIdx { pageidx: 1, data: page.clone() },
+                PageAtIdx {
+                    pageidx: 2,
+                    data: Page::test_filled_sized(2, PageSize::Kb8).into(),
+                },
+            ],
+            page_size: 0,
+        };
+        server
+            .post("/")
+            .bytes(req3.encode_to_vec().into())
+            .expect_failure()
+            .await;
+
+        // every page must match the expected page size
+        let req4 = WritePagesRequest {
+            vid: VolumeId::random().copy_to_bytes(),
+            pages: vec![PageAtIdx { pageidx: 1, data: page.clone() }],
+            page_size: PageSize::Kb8.as_u32(),
+        };
+        server
+            .post("/")
+            .bytes(req4.encode_to_vec().into())
+            .expect_failure()
+            .await;
+
+        // pages with a non-default size may be written
+        let req5 = WritePagesRequest {
+            vid: VolumeId::random().copy_to_bytes(),
+            pages: vec![PageAtIdx {
+                pageidx: 1,
+                data: Page::test_filled_sized(2, PageSize::Kb8).into(),
+            }],
+            page_size: PageSize::Kb8.as_u32(),
+        };
+        let resp5 = server.post("/").bytes(req5.encode_to_vec().into()).await;
+        let resp5 = WritePagesResponse::decode(resp5.into_bytes()).unwrap();
+        assert_eq!(resp5.segments.len(), 1, "expected 1 segment");
     }
 }
@@ -5,7 +5,7 @@ use futures::FutureExt;
 use graft_server::{
     api::{
         auth::AuthState,
-        metastore::{MetastoreApiState, metastore_routes},
+        metastore::{DEFAULT_CHECKPOINT_INTERVAL, MetastoreApiState, metastore_routes},
         routes::build_router,
         task::ApiServerTask,
     },
@@ -14,7 +14,8 @@ use graft_server::{
     supervisor::Supervisor,
     volume::{
         catalog::{VolumeCatalog, VolumeCatalogConfig},
-        store::VolumeStore,
+        gc::{GarbageCollector, GarbageCollectorConfig, GarbageCollectorTask},
+        store::{DEFAULT_UNDELETE_WINDOW, VolumeStore},
         updater::VolumeCatalogUpdater,
     },
 };
@@ -30,8 +31,20 @@ struct MetastoreConfig {
     objectstore: Option<ObjectStoreConfig>,
     auth: Option<AuthState>,
 
+    /// garbage collection is disabled unless configured. Segments are only
+    /// collected if the pagestore shares the metastore's object store.
+    gc: Option<GarbageCollectorConfig>,
+
     port: u16,
     catalog_update_concurrency: usize,
+
+    /// the number of commits after which a Volume is automatically
+    /// checkpointed; zero disables automatic checkpoints
+    checkpoint_interval: u64,
+
+    /// how long a deleted Volume may be undeleted before the garbage collector
+    /// purges it
+    undelete_window_secs: u64,
 }
 
 #[derive(Debug)]
@@ -53,6 +66,8 @@ impl config::Source for ConfigDefaults {
 
         set_default!("port", 3001);
         set_default!("catalog_update_concurrency", 16);
+        set_default!("checkpoint_interval", DEFAULT_CHECKPOINT_INTERVAL);
+        set_default!("undelete_window_secs", DEFAULT_UNDELETE_WINDOW.as_secs());
 
         Ok(map)
     }
@@ -105,18 +120,43 @@ async fn main() {
         .unwrap_or_default()
         .build()
         .expect("failed to build object store");
-    let store = Arc::new(VolumeStore::new(store));
+    let volume_store = Arc::new(VolumeStore::new(store.clone()));
     let catalog = VolumeCatalog::open_config(config.catalog.unwrap_or_default())
         .expect("failed to open volume catalog");
     let updater = VolumeCatalogUpdater::new(config.catalog_update_concurrency);
+    let undelete_window = Duration::from_secs(config.undelete_window_secs);
+
+    let mut registry = Registry::default();
+    let mut supervisor = Supervisor::default();
 
-    let state = Arc::new(MetastoreApiState::new(store, catalog, updater));
-    let router = build_router(Registry::default(), config.auth, state, metastore_routes());
+    if let Some(gc) = config.gc {
+        let collector = GarbageCollector::new(
+            registry.garbage_collector(),
+            store,
+            volume_store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(config.catalog_update_concurrency),
+            Duration::from_secs(gc.grace_period_secs),
+            Duration::from_secs(gc.retention_secs),
+        )
+        .with_undelete_window(undelete_window);
+        supervisor.spawn(GarbageCollectorTask::new(
+            collector,
+            Duration::from_secs(gc.interval_secs),
+            gc.dry_run,
+        ));
+    }
+
+    let state = Arc::new(
+        MetastoreApiState::new(volume_store, catalog, updater)
+            .with_checkpoint_interval(config.checkpoint_interval)
+            .with_undelete_window(undelete_window),
+    );
+    let router = build_router(registry, config.auth, state, metastore_routes());
 
     let addr = format!("0.0.0.0:{}", config.port);
     tracing::info!("listening on {}", addr);
 
-    let mut supervisor = Supervisor::default();
     supervisor.spawn(ApiServerTask::new(
         "metastore-api-server",
         TcpListener::bind(addr).await.unwrap(),
@@ -2,7 +2,7 @@ use std::{sync::Arc, time::Duration};
 
 use config::{Config, FileFormat};
 use futures::{FutureExt, select};
-use graft_client::{MetastoreClient, NetClient};
+use graft_client::nonblocking::{MetastoreClient, NetClient};
 use graft_core::byte_unit::ByteUnit;
 use graft_server::{
     api::{
@@ -15,6 +15,7 @@ use graft_server::{
     object_store_util::ObjectStoreConfig,
     segment::{
         cache::disk::{DiskCache, DiskCacheConfig},
+        compactor::{SegmentCompactor, SegmentCompactorTask},
         loader::SegmentLoader,
         uploader::SegmentUploaderTask,
         writer::SegmentWriterTask,
@@ -47,6 +48,9 @@ struct PagestoreConfig {
     catalog_update_concurrency: usize,
     download_concurrency: usize,
     write_concurrency: usize,
+
+    /// how often to compact recently written volumes; 0 disables compaction
+    compaction_interval_secs: u64,
 }
 
 #[derive(Debug)]
@@ -78,6 +82,7 @@ impl config::Source for ConfigDefaults {
         set_default!("catalog_update_concurrency", 16);
         set_default!("download_concurrency", 16);
         set_default!("write_concurrency", 16);
+        set_default!("compaction_interval_secs", 60);
 
         Ok(map)
     }
@@ -154,10 +159,30 @@ async fn main() {
     supervisor.spawn(SegmentWriterTask::new(
         registry.segment_writer(),
         page_rx,
-        store_tx,
+        store_tx.clone(),
         Duration::from_secs(1),
     ));
 
+    let compactor_tx = if config.compaction_interval_secs > 0 {
+        let (compactor_tx, compactor_rx) = mpsc::channel(128);
+        let compactor = SegmentCompactor::new(
+            registry.segment_compactor(),
+            catalog.clone(),
+            SegmentLoader::new(store.clone(), cache.clone(), config.download_concurrency),
+            metastore.clone(),
+            VolumeCatalogUpdater::new(config.catalog_update_concurrency),
+            store_tx,
+        );
+        supervisor.spawn(SegmentCompactorTask::new(
+            compactor,
+            compactor_rx,
+            Duration::from_secs(config.compaction_interval_secs),
+        ));
+        Some(compactor_tx)
+    } else {
+        None
+    };
+
     supervisor.spawn(SegmentUploaderTask::new(
         registry.segment_uploader(),
         store_rx,
@@ -165,14 +190,18 @@ async fn main() {
         cache,
     ));
 
-    let state = Arc::new(PagestoreApiState::new(
+    let mut state = PagestoreApiState::new(
         page_tx,
         catalog,
         loader,
         metastore,
         updater,
         config.write_concurrency,
-    ));
+    );
+    if let Some(compactor_tx) = compactor_tx {
+        state = state.with_compactor(compactor_tx);
+    }
+    let state = Arc::new(state);
     let router = build_router(registry, config.auth, state, pagestore_routes());
 
     let addr = format!("0.0.0.0:{}", config.port);
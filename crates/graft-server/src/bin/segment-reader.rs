@@ -1,11 +1,8 @@
 use std::{fmt::Debug, io, path::PathBuf, process::exit};
 
-use clap::{Parser, Subcommand, arg};
+use clap::{Parser, Subcommand};
 use culprit::{Culprit, ResultExt};
-use graft_core::{
-    PageIdx, VolumeId,
-    page::{PAGESIZE, Page},
-};
+use graft_core::{PageIdx, VolumeId, page::Page};
 use graft_server::segment::closed::{ClosedSegment, SegmentValidationErr};
 use thiserror::Error;
 
@@ -107,7 +104,8 @@ fn main_inner() -> Result<(), Culprit<SegmentReaderErr>> {
         }
         Commands::Read { vid, pageidx } => {
             if let Some(page) = segment.find_page(&vid, pageidx) {
-                print_page(page, PAGESIZE.as_usize())
+                let len = page.len();
+                print_page(page, len)
             } else {
                 return Err(Culprit::new(SegmentReaderErr::PageNotFound));
             }
@@ -1,6 +1,7 @@
 pub mod segment {
     pub mod cache;
     pub mod closed;
+    pub mod compactor;
     pub mod index;
     pub mod loader;
     pub mod open;
@@ -24,6 +25,7 @@ pub mod api {
 pub mod volume {
     pub mod catalog;
     pub mod commit;
+    pub mod gc;
     pub mod kv;
     pub mod store;
     pub mod updater;
@@ -2,7 +2,13 @@ use std::{result::Result, sync::Arc};
 
 use measured::MetricGroup;
 
-use crate::segment::{uploader::SegmentUploaderMetrics, writer::SegmentWriterMetrics};
+use crate::{
+    segment::{
+        compactor::SegmentCompactorMetrics, uploader::SegmentUploaderMetrics,
+        writer::SegmentWriterMetrics,
+    },
+    volume::gc::GarbageCollectorMetrics,
+};
 
 #[derive(Default, MetricGroup)]
 pub struct Registry {
@@ -11,6 +17,12 @@ pub struct Registry {
 
     #[metric(namespace = "segment_uploader")]
     segment_uploader: Option<Arc<SegmentUploaderMetrics>>,
+
+    #[metric(namespace = "segment_compactor")]
+    segment_compactor: Option<Arc<SegmentCompactorMetrics>>,
+
+    #[metric(namespace = "garbage_collector")]
+    garbage_collector: Option<Arc<GarbageCollectorMetrics>>,
 }
 
 impl Registry {
@@ -25,4 +37,16 @@ impl Registry {
             .get_or_insert_with(|| Arc::new(SegmentUploaderMetrics::default()))
             .clone()
     }
+
+    pub fn segment_compactor(&mut self) -> Arc<SegmentCompactorMetrics> {
+        self.segment_compactor
+            .get_or_insert_with(|| Arc::new(SegmentCompactorMetrics::default()))
+            .clone()
+    }
+
+    pub fn garbage_collector(&mut self) -> Arc<GarbageCollectorMetrics> {
+        self.garbage_collector
+            .get_or_insert_with(|| Arc::new(GarbageCollectorMetrics::default()))
+            .clone()
+    }
 }
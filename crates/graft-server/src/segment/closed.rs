@@ -33,6 +33,12 @@ pub const SEGMENT_MAX_PAGES: PageCount = PageCount::new(4090);
 // the maximum number of volumes a segment can store pages for
 pub const SEGMENT_MAX_VOLUMES: usize = 400;
 
+// every page in the segment has the default page size
+const SEGMENT_VERSION_DEFAULT_PAGESIZE: u8 = 0;
+
+// the segment index ends with a table containing each volume's page size
+const SEGMENT_VERSION_PAGE_SIZES: u8 = 1;
+
 #[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout)]
 #[repr(C)]
 pub struct SegmentFooter {
@@ -45,8 +51,11 @@ pub struct SegmentFooter {
     /// size of the index in bytes
     index_size: U16,
 
+    /// the segment format version
+    version: u8,
+
     /// pad the footer to 32 bytes for future use
-    _padding: [u8; 8],
+    _padding: [u8; 7],
 
     /// the last 4 bytes of the footer are reserved for a magic number
     magic: U32,
@@ -55,10 +64,17 @@ pub struct SegmentFooter {
 static_assertions::assert_eq_size!(SegmentFooter, [u8; 32]);
 
 impl SegmentFooter {
-    pub fn new(sid: SegmentId, volumes: usize, index_size: ByteUnit) -> Self {
+    /// Create a new `SegmentFooter`. `page_sizes` must be true if the segment
+    /// index contains a page size table.
+    pub fn new(sid: SegmentId, volumes: usize, index_size: ByteUnit, page_sizes: bool) -> Self {
         assert!(volumes <= u16::MAX as usize);
         assert!(index_size.as_usize() <= u16::MAX as usize);
         Self {
+            version: if page_sizes {
+                SEGMENT_VERSION_PAGE_SIZES
+            } else {
+                SEGMENT_VERSION_DEFAULT_PAGESIZE
+            },
             _padding: Default::default(),
             sid,
             volumes: U16::new(volumes as u16),
@@ -78,13 +94,25 @@ impl SegmentFooter {
     fn index_size(&self) -> ByteUnit {
         self.index_size.get().into()
     }
+
+    fn has_page_sizes(&self) -> bool {
+        self.version == SEGMENT_VERSION_PAGE_SIZES
+    }
 }
 
 static_assertions::const_assert_eq!(size_of::<SegmentFooter>(), 32);
 
-pub fn closed_segment_size(volumes: usize, pages: PageCount) -> ByteUnit {
-    let index_size = SegmentIndexBuilder::serialized_size(volumes, pages);
-    (PAGESIZE * pages.to_usize()) + index_size + size_of::<SegmentFooter>()
+/// Calculate the size of a closed segment containing `pages` pages which take
+/// up `page_bytes` bytes. `page_sizes` must be true if any of the segment's
+/// volumes don't use the default page size.
+pub fn closed_segment_size(
+    volumes: usize,
+    pages: PageCount,
+    page_bytes: ByteUnit,
+    page_sizes: bool,
+) -> ByteUnit {
+    let index_size = SegmentIndexBuilder::serialized_size(volumes, pages, page_sizes);
+    page_bytes + index_size + size_of::<SegmentFooter>()
 }
 
 #[derive(Debug, Error)]
@@ -97,6 +125,8 @@ pub enum SegmentValidationErr {
     CorruptFooter(ZerocopyErr),
     #[error("invalid magic number")]
     Magic,
+    #[error("unknown segment version")]
+    UnknownVersion,
     #[error("corrupt segment index")]
     CorruptIndex(ZerocopyErr),
     #[error("page storage length must be a multiple of {}", PAGESIZE)]
@@ -130,24 +160,31 @@ impl<'a> ClosedSegment<'a> {
             return Err(Culprit::new(SegmentValidationErr::Magic));
         }
 
+        if footer.version > SEGMENT_VERSION_PAGE_SIZES {
+            return Err(Culprit::new_with_note(
+                SegmentValidationErr::UnknownVersion,
+                format!("segment version {}", footer.version),
+            ));
+        }
+
         let (page_data, index_data) = data
             .split_at_checked(data.len() - footer.index_size().as_usize())
             .ok_or_else(|| Culprit::new(SegmentValidationErr::TooSmall))?;
 
         // load the index
-        let index = SegmentIndex::from_bytes(index_data, footer.volumes())
+        let index = SegmentIndex::from_bytes(index_data, footer.volumes(), footer.has_page_sizes())
             .or_ctx(SegmentValidationErr::CorruptIndex)?;
 
-        // validate pages
+        // validate pages; every page size is a multiple of the default page size
         if page_data.len() % PAGESIZE != 0 {
             return Err(Culprit::new(SegmentValidationErr::InvalidPageSize));
         }
-        if page_data.len() / PAGESIZE != index.pages().to_usize() {
-            let actual = (page_data.len() / PAGESIZE).as_usize();
-            let expected = index.pages().to_usize();
+        if page_data.len() != index.page_bytes() {
+            let actual = ByteUnit::new(page_data.len() as u64);
+            let expected = index.page_bytes();
             return Err(Culprit::new_with_note(
                 SegmentValidationErr::InvalidPageCount,
-                format!("segment contains {actual} pages; expected {expected}"),
+                format!("segment contains {actual} of pages; expected {expected}"),
             ));
         }
 
@@ -168,27 +205,16 @@ impl<'a> ClosedSegment<'a> {
     }
 
     pub fn find_page(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<Page> {
-        self.index.lookup(vid, pageidx).map(|local_offset| {
-            let start = local_offset * PAGESIZE;
-            let end = start + PAGESIZE;
-            (&self.page_data[start.range(end)])
-                .try_into()
-                .expect("invalid page")
-        })
+        self.index
+            .lookup(vid, pageidx)
+            .map(|range| (&self.page_data[range]).try_into().expect("invalid page"))
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (&VolumeId, PageIdx, Page)> {
-        self.index
-            .iter()
-            .zip(0usize..)
-            .map(|((vid, pageidx), local_offset)| {
-                let start = local_offset * PAGESIZE;
-                let end = start + PAGESIZE;
-                let page = (&self.page_data[start.range(end)])
-                    .try_into()
-                    .expect("invalid page");
-                (vid, pageidx, page)
-            })
+        self.index.iter().map(|(vid, pageidx, range)| {
+            let page = (&self.page_data[range]).try_into().expect("invalid page");
+            (vid, pageidx, page)
+        })
     }
 }
 
@@ -206,7 +232,7 @@ mod tests {
 
     use assert_matches::assert_matches;
     use bytes::BytesMut;
-    use graft_core::pageidx;
+    use graft_core::{page::PageSize, pageidx};
     use zerocopy::U16;
 
     use crate::bytes_vec::BytesVec;
@@ -241,6 +267,7 @@ mod tests {
             sid: SegmentId::random(),
             volumes: U16::new(0),
             index_size: U16::new(0),
+            version: 0,
             _padding: Default::default(),
             magic: U32::from_bytes([0x00, 0x3B, 0x41, 0x00]),
         };
@@ -256,6 +283,7 @@ mod tests {
             sid: SegmentId::random(),
             volumes: U16::new(0),
             index_size: U16::new(0),
+            version: 0,
             _padding: Default::default(),
             magic: U32::from_bytes([0x00, 0x3B, 0x41, 0x00]),
         };
@@ -273,6 +301,7 @@ mod tests {
             sid: SegmentId::random(),
             volumes: U16::new(0),
             index_size: U16::new(0),
+            version: 0,
             _padding: Default::default(),
             magic: SEGMENT_MAGIC,
         };
@@ -286,16 +315,17 @@ mod tests {
         // test invalid page count err
         let mut buf = BytesVec::default();
         buf.put(BytesMut::zeroed(PAGESIZE.as_usize()).freeze());
-        let mut index = SegmentIndexBuilder::new_with_capacity(1, PageCount::new(2));
+        let mut index = SegmentIndexBuilder::new_with_capacity(1, PageCount::new(2), false);
         let vid = VolumeId::random();
-        index.insert(&vid, pageidx!(1));
-        index.insert(&vid, pageidx!(2));
+        index.insert(&vid, pageidx!(1), PageSize::DEFAULT);
+        index.insert(&vid, pageidx!(2), PageSize::DEFAULT);
         let index_size = index.finish(&mut buf);
         buf.put_slice(
             SegmentFooter {
                 sid: SegmentId::random(),
                 volumes: U16::new(1),
                 index_size: U16::new(index_size.as_u16()),
+                version: 0,
                 _padding: Default::default(),
                 magic: SEGMENT_MAGIC,
             }
@@ -306,11 +336,32 @@ mod tests {
             ClosedSegment::from_bytes(&bytes).unwrap_err().ctx(),
             SegmentValidationErr::InvalidPageCount
         );
+
+        // test unknown version err
+        let footer = SegmentFooter {
+            sid: SegmentId::random(),
+            volumes: U16::new(0),
+            index_size: U16::new(0),
+            version: SEGMENT_VERSION_PAGE_SIZES + 1,
+            _padding: Default::default(),
+            magic: SEGMENT_MAGIC,
+        };
+        assert_matches!(
+            ClosedSegment::from_bytes(footer.as_bytes())
+                .unwrap_err()
+                .ctx(),
+            SegmentValidationErr::UnknownVersion
+        );
     }
 
     #[graft_test::test]
     fn test_segment_max_pages() {
-        let size_at_max = closed_segment_size(SEGMENT_MAX_VOLUMES, SEGMENT_MAX_PAGES);
+        let size_at_max = closed_segment_size(
+            SEGMENT_MAX_VOLUMES,
+            SEGMENT_MAX_PAGES,
+            PAGESIZE * SEGMENT_MAX_PAGES.to_usize(),
+            false,
+        );
         println!("size_at_max: {:?}", size_at_max);
         // size_at_max should be within one page of the max segment size
         assert!(size_at_max <= SEGMENT_MAX_SIZE && size_at_max >= (SEGMENT_MAX_SIZE - PAGESIZE));
@@ -0,0 +1,342 @@
+//! The segment compactor rewrites the live pages of a Volume's small segments
+//! into fewer, denser segments.
+//!
+//! Each push to the pagestore produces at least one segment, which often only
+//! contains a handful of pages. Over time reads fan out over many tiny
+//! segments. The compactor periodically visits recently written Volumes, loads
+//! the pages contained by the checkpoint of the Volume's latest snapshot from
+//! any small segments, and writes them into new segments. The metastore then
+//! atomically replaces that checkpoint with one that reads those pages from the
+//! new segments. Every snapshot based on the checkpoint, including those read
+//! by clients, picks up the compacted segments once it notices the
+//! checkpoint's version has changed.
+
+use std::{collections::HashSet, convert::Infallible, sync::Arc};
+
+use culprit::{Culprit, ResultExt};
+use event_listener::Event;
+use futures::future::try_join_all;
+use graft_client::nonblocking::MetastoreClient;
+use graft_core::{PageIdx, SegmentId, VolumeId, lsn::LSN, page::PageSize};
+use graft_proto::common::v1::SegmentInfo;
+use measured::{Counter, CounterVec, MetricGroup};
+use splinter_rs::Splinter;
+use thiserror::Error;
+use tokio::{
+    sync::mpsc,
+    time::{Duration, Instant, sleep_until},
+};
+
+use crate::{
+    metrics::labels::ResultLabelSet,
+    supervisor::{SupervisedTask, TaskCfg, TaskCtx},
+    volume::{
+        catalog::{VolumeCatalog, VolumeCatalogErr},
+        commit::{CommitMeta, TryFromSnapshotErr},
+        store::CheckpointVersion,
+        updater::{UpdateErr, VolumeCatalogUpdater},
+    },
+};
+
+use super::{
+    cache::Cache,
+    closed::{ClosedSegment, SEGMENT_MAX_PAGES, SEGMENT_MAX_SIZE, SegmentValidationErr},
+    loader::{SegmentLoader, SegmentLoaderErr},
+    open::OpenSegment,
+    uploader::{SegmentUploadErr, StoreSegmentMsg},
+};
+
+/// Segments containing fewer live pages than this are rewritten
+const COMPACT_MAX_PAGES: usize = SEGMENT_MAX_PAGES.to_usize() / 4;
+
+/// Segments containing fewer live bytes than this are rewritten
+const COMPACT_MAX_BYTES: usize = SEGMENT_MAX_SIZE.as_usize() / 4;
+
+#[derive(MetricGroup)]
+#[metric(new())]
+pub struct SegmentCompactorMetrics {
+    /// Number of Volume compactions, broken down by result
+    compactions: CounterVec<ResultLabelSet>,
+
+    /// Number of page bytes written into compacted segments
+    written_bytes: Counter,
+
+    /// Number of compacted segments written
+    written_segments: Counter,
+
+    /// Number of segments which are no longer referenced by a Volume's
+    /// checkpoint after compaction
+    removed_segments: Counter,
+}
+
+impl Default for SegmentCompactorMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CompactorErr {
+    #[error("graft client request failed")]
+    ClientErr(#[from] graft_client::ClientErr),
+
+    #[error("failed to update catalog")]
+    UpdateErr(#[from] UpdateErr),
+
+    #[error("catalog error")]
+    CatalogErr(#[from] VolumeCatalogErr),
+
+    #[error("invalid snapshot")]
+    InvalidSnapshot(#[from] TryFromSnapshotErr),
+
+    #[error("failed to load segment")]
+    LoaderErr(#[from] SegmentLoaderErr),
+
+    #[error("failed to validate segment")]
+    SegmentValidationErr(#[from] SegmentValidationErr),
+
+    #[error("failed to upload segment")]
+    SegmentUploadErr(#[from] SegmentUploadErr),
+
+    #[error("segment is missing a page referenced by the catalog")]
+    MissingPage,
+
+    #[error("output channel is closed")]
+    OutputChannelClosed,
+}
+
+pub struct SegmentCompactor<C> {
+    metrics: Arc<SegmentCompactorMetrics>,
+    catalog: VolumeCatalog,
+    loader: SegmentLoader<C>,
+    metastore: MetastoreClient,
+    updater: VolumeCatalogUpdater,
+
+    /// compacted segments are uploaded by the segment uploader
+    output: mpsc::Sender<StoreSegmentMsg>,
+}
+
+impl<C: Cache> SegmentCompactor<C> {
+    pub fn new(
+        metrics: Arc<SegmentCompactorMetrics>,
+        catalog: VolumeCatalog,
+        loader: SegmentLoader<C>,
+        metastore: MetastoreClient,
+        updater: VolumeCatalogUpdater,
+        output: mpsc::Sender<StoreSegmentMsg>,
+    ) -> Self {
+        Self {
+            metrics,
+            catalog,
+            loader,
+            metastore,
+            updater,
+            output,
+        }
+    }
+
+    /// Compact the small segments referenced by the checkpoint of the Volume's
+    /// latest snapshot. Returns the number of segments which are no longer
+    /// referenced by the checkpoint.
+    #[tracing::instrument(name = "compact volume", skip(self))]
+    pub async fn compact_volume(&self, vid: &VolumeId) -> Result<usize, Culprit<CompactorErr>> {
+        let result = self.compact_volume_inner(vid).await;
+        self.metrics.compactions.inc((&result).into());
+        result
+    }
+
+    async fn compact_volume_inner(&self, vid: &VolumeId) -> Result<usize, Culprit<CompactorErr>> {
+        let Some(snapshot) = self.metastore.snapshot(vid, None).await.or_into_ctx()? else {
+            return Ok(0);
+        };
+        let snapshot = CommitMeta::try_from(snapshot).or_into_ctx()?;
+        let page_size = snapshot.page_size();
+
+        // the first commit is an implicit checkpoint which can't be replaced;
+        // the Volume is compacted once a checkpoint has been written
+        let lsn = snapshot.checkpoint();
+        if lsn == LSN::FIRST {
+            return Ok(0);
+        }
+
+        // ensure the catalog contains the latest snapshot and the current
+        // version of it's checkpoint, which another node may have compacted
+        self.updater
+            .update_catalog_from_metastore(&self.metastore, &self.catalog, vid, snapshot.lsn())
+            .await
+            .or_into_ctx()?;
+        self.updater
+            .refresh_checkpoint_from_metastore(&self.metastore, &self.catalog, vid, lsn)
+            .await
+            .or_into_ctx()?;
+        let checkpoint = self
+            .catalog
+            .checkpoint(vid.clone(), lsn)
+            .or_into_ctx()?
+            .expect("missing checkpoint after update");
+
+        let candidates: Vec<(SegmentId, Splinter)> = self
+            .catalog
+            .live_segments(&checkpoint)
+            .or_into_ctx()?
+            .into_iter()
+            .filter(|(sid, pages)| !sid.is_empty() && is_small(pages, page_size))
+            .collect();
+
+        // compaction only helps if we can combine multiple segments
+        if candidates.len() < 2 {
+            return Ok(0);
+        }
+
+        tracing::debug!(num_candidates = candidates.len(), "compacting segments");
+
+        // copy the live pages into new segments
+        let mut segments = vec![];
+        let mut segment = OpenSegment::default();
+        let mut graft = Splinter::default();
+        for (sid, pages) in &candidates {
+            let data = self.loader.load_segment(sid.clone()).await.or_into_ctx()?;
+            let closed = ClosedSegment::from_bytes(&data).or_into_ctx()?;
+            let owner = self.catalog.segment_owner(vid, sid).or_into_ctx()?;
+
+            for pageidx in pages.iter() {
+                let pageidx = PageIdx::try_from(pageidx).or_ctx(|_| CompactorErr::MissingPage)?;
+                let page = closed.find_page(&owner, pageidx).ok_or_else(|| {
+                    Culprit::new_with_note(
+                        CompactorErr::MissingPage,
+                        format!("segment {sid} is missing page {pageidx}"),
+                    )
+                })?;
+
+                if !segment.has_space_for(vid, page_size) {
+                    segments.push((std::mem::take(&mut segment), std::mem::take(&mut graft)));
+                }
+                segment
+                    .insert(vid.clone(), pageidx, page)
+                    .expect("bug: segment is full");
+                graft.insert(pageidx.to_u32());
+            }
+        }
+        segments.push((segment, graft));
+
+        // upload the new segments
+        let mut uploads = Vec::with_capacity(segments.len());
+        let mut infos = Vec::with_capacity(segments.len());
+        for (segment, graft) in segments {
+            self.metrics
+                .written_bytes
+                .inc_by((graft.cardinality() * page_size.as_usize()) as u64);
+            infos.push(SegmentInfo::new(segment.sid(), graft.serialize_to_bytes()));
+
+            let event = Event::with_tag();
+            uploads.push(event.listen());
+            self.output
+                .send(StoreSegmentMsg::new(segment, event))
+                .await
+                .or_ctx(|_| CompactorErr::OutputChannelClosed)?;
+        }
+        try_join_all(uploads).await.or_into_ctx()?;
+        let written = infos.len();
+
+        // replace the volume's checkpoint with one that references the new segments
+        let resp = self
+            .metastore
+            .compact(vid, lsn, infos)
+            .await
+            .or_into_ctx()?;
+        let checkpoint = resp
+            .checkpoint
+            .expect("missing checkpoint after compaction");
+        let meta = CommitMeta::try_from(checkpoint.snapshot.expect("missing checkpoint snapshot"))
+            .or_into_ctx()?;
+        let version = (!resp.version.is_empty()).then(|| CheckpointVersion::new(resp.version));
+        let mut batch = self.catalog.batch_insert();
+        batch
+            .insert_checkpoint_snapshot(vid.clone(), meta, version.as_ref(), checkpoint.segments)
+            .or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+
+        self.metrics.written_segments.inc_by(written as u64);
+        self.metrics
+            .removed_segments
+            .inc_by(candidates.len() as u64);
+
+        tracing::debug!(removed = candidates.len(), written, "compacted segments");
+
+        Ok(candidates.len())
+    }
+}
+
+fn is_small(pages: &Splinter, page_size: PageSize) -> bool {
+    let count = pages.cardinality();
+    count < COMPACT_MAX_PAGES && count * page_size.as_usize() < COMPACT_MAX_BYTES
+}
+
+/// Periodically compacts every Volume which has been written to since the
+/// last compaction.
+pub struct SegmentCompactorTask<C> {
+    compactor: SegmentCompactor<C>,
+
+    /// receives the ids of Volumes which have been written to
+    input: mpsc::Receiver<VolumeId>,
+
+    /// Volumes written to during the current interval. Clients commit
+    /// segments after they are written, so we wait an additional interval
+    /// before compacting a Volume to give the commit time to land.
+    dirty: HashSet<VolumeId>,
+
+    /// Volumes to compact at the end of the current interval
+    ready: HashSet<VolumeId>,
+
+    interval: Duration,
+}
+
+impl<C: Cache + 'static> SupervisedTask for SegmentCompactorTask<C> {
+    type Err = Infallible;
+
+    fn cfg(&self) -> TaskCfg {
+        TaskCfg { name: "segment-compactor" }
+    }
+
+    async fn run(mut self, ctx: TaskCtx) -> Result<(), Culprit<Infallible>> {
+        let mut next_compaction = Instant::now() + self.interval;
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = ctx.wait_shutdown() => break,
+
+                Some(vid) = self.input.recv() => {
+                    self.dirty.insert(vid);
+                }
+
+                _ = sleep_until(next_compaction) => {
+                    let ready = std::mem::replace(&mut self.ready, std::mem::take(&mut self.dirty));
+                    for vid in ready {
+                        if let Err(err) = self.compactor.compact_volume(&vid).await {
+                            tracing::error!("failed to compact volume {vid:?}\n{err:?}");
+                        }
+                    }
+                    next_compaction = Instant::now() + self.interval;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: Cache> SegmentCompactorTask<C> {
+    pub fn new(
+        compactor: SegmentCompactor<C>,
+        input: mpsc::Receiver<VolumeId>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            compactor,
+            input,
+            dirty: HashSet::default(),
+            ready: HashSet::default(),
+            interval,
+        }
+    }
+}
@@ -12,13 +12,19 @@
 //! The list of `PageIdxs` is stored in the same order as pages are stored in
 //! this segment, and the index requires that each set of `PageIdxs` corresponding
 //! to a Volume is sorted.
+//!
+//! Segments containing Volumes which don't use the default page size end the
+//! index with a table containing each Volume's `PageSize`, stored in the same
+//! order as the Volume Index. Without this table every page is assumed to be
+//! [`PAGESIZE`].
 
-use std::mem::size_of;
+use std::{mem::size_of, ops::Range};
 
 use bytes::{Buf, Bytes, BytesMut};
 use graft_core::{
     PageCount, PageIdx, VolumeId,
     byte_unit::ByteUnit,
+    page::{PAGESIZE, PageSize},
     zerocopy_ext::{TryFromBytesExt, ZerocopyErr},
 };
 use zerocopy::{
@@ -53,19 +59,56 @@ impl VolumeMeta {
 pub struct SegmentIndex<'a> {
     volume_index: &'a [VolumeMeta],
     page_idxs: &'a [U32<LittleEndian>],
+    page_sizes: Option<&'a [PageSize]>,
+
+    /// The byte offset of the first page for each Volume in the segment
+    offsets: Vec<usize>,
 }
 
 impl<'a> SegmentIndex<'a> {
-    pub fn from_bytes(data: &'a [u8], volumes: usize) -> Result<Self, ZerocopyErr> {
+    /// Load a `SegmentIndex` from bytes. If `page_sizes` is true, the index ends
+    /// with a table containing each Volume's `PageSize`.
+    pub fn from_bytes(
+        data: &'a [u8],
+        volumes: usize,
+        page_sizes: bool,
+    ) -> Result<Self, ZerocopyErr> {
         let volume_index_size = volumes * size_of::<VolumeMeta>();
+        let page_sizes_size = if page_sizes {
+            volumes * size_of::<PageSize>()
+        } else {
+            0
+        };
         assert!(
-            data.len() >= volume_index_size,
+            data.len() >= volume_index_size + page_sizes_size,
             "segment must be at least as long as the volume index"
         );
         let (volume_index, page_idxs) = data.split_at(volume_index_size);
+        let (page_idxs, page_sizes_data) = page_idxs.split_at(page_idxs.len() - page_sizes_size);
+
+        let volume_index: &[VolumeMeta] =
+            TryFromBytesExt::try_ref_from_unaligned_bytes(volume_index)?;
+        let page_sizes: Option<&[PageSize]> = if page_sizes {
+            Some(TryFromBytesExt::try_ref_from_unaligned_bytes(
+                page_sizes_data,
+            )?)
+        } else {
+            None
+        };
+
+        let mut offsets = Vec::with_capacity(volumes);
+        let mut offset = 0;
+        for (i, meta) in volume_index.iter().enumerate() {
+            offsets.push(offset);
+            let page_size = page_sizes.map_or(PAGESIZE.as_usize(), |s| s[i].as_usize());
+            offset += meta.pages.get() as usize * page_size;
+        }
+
         Ok(Self {
-            volume_index: TryFromBytesExt::try_ref_from_unaligned_bytes(volume_index)?,
+            volume_index,
             page_idxs: TryFromBytesExt::try_ref_from_unaligned_bytes(page_idxs)?,
+            page_sizes,
+            offsets,
         })
     }
 
@@ -77,9 +120,25 @@ impl<'a> SegmentIndex<'a> {
         self.page_idxs.is_empty()
     }
 
-    /// Lookup the local offset of a page by (`VolumeId`, `PageIdx`)
-    /// The returned value can be used to index into the segment's page list
-    pub fn lookup(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<usize> {
+    /// The total size of all of the pages referenced by this index
+    pub fn page_bytes(&self) -> ByteUnit {
+        let total = match (self.volume_index.last(), self.offsets.last()) {
+            (Some(meta), Some(offset)) => {
+                offset + meta.pages.get() as usize * self.page_size(self.volume_index.len() - 1)
+            }
+            _ => 0,
+        };
+        ByteUnit::new(total as u64)
+    }
+
+    fn page_size(&self, meta_idx: usize) -> usize {
+        self.page_sizes
+            .map_or(PAGESIZE.as_usize(), |s| s[meta_idx].as_usize())
+    }
+
+    /// Lookup the byte range of a page by (`VolumeId`, `PageIdx`)
+    /// The returned range can be used to index into the segment's page data
+    pub fn lookup(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<Range<usize>> {
         let meta_idx = self
             .volume_index
             .binary_search_by(|meta| meta.vid.cmp(vid))
@@ -94,20 +153,33 @@ impl<'a> SegmentIndex<'a> {
             .binary_search(&pageidx.into())
             .ok()?;
 
-        Some(start + relative_offset)
+        let page_size = self.page_size(meta_idx);
+        let offset = self.offsets[meta_idx] + relative_offset * page_size;
+        Some(offset..offset + page_size)
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (&VolumeId, PageIdx)> {
-        self.volume_index.iter().flat_map(|meta| {
-            let start = meta.start.get() as usize;
-            let end = start + (meta.pages.get() as usize);
-            self.page_idxs[start..end].iter().map(move |&pageidx| {
-                (
-                    &meta.vid,
-                    PageIdx::try_from(pageidx).expect("bug: PageIdx should never be zero"),
-                )
+    /// Iterate over every page in the index along with the page's byte range
+    pub fn iter(&self) -> impl Iterator<Item = (&VolumeId, PageIdx, Range<usize>)> {
+        self.volume_index
+            .iter()
+            .enumerate()
+            .flat_map(move |(meta_idx, meta)| {
+                let start = meta.start.get() as usize;
+                let end = start + (meta.pages.get() as usize);
+                let page_size = self.page_size(meta_idx);
+                let offset = self.offsets[meta_idx];
+                self.page_idxs[start..end]
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, &pageidx)| {
+                        let page_offset = offset + i * page_size;
+                        (
+                            &meta.vid,
+                            PageIdx::try_from(pageidx).expect("bug: PageIdx should never be zero"),
+                            page_offset..page_offset + page_size,
+                        )
+                    })
             })
-        })
     }
 }
 
@@ -145,35 +217,54 @@ impl<T: IntoBytes + Immutable> TypedByteArray<T> {
 pub struct SegmentIndexBuilder {
     volume_index: TypedByteArray<VolumeMeta>,
     page_idxs: TypedByteArray<U32<LittleEndian>>,
+    page_sizes: Option<TypedByteArray<PageSize>>,
 
     current: Option<VolumeMeta>,
+    current_size: PageSize,
     pages: u16,
     last_pageidx: Option<PageIdx>,
 }
 
 impl SegmentIndexBuilder {
-    pub fn new_with_capacity(volumes: usize, pages: PageCount) -> Self {
+    /// Create a new `SegmentIndexBuilder`. If `page_sizes` is false, every
+    /// inserted page must have the default page size.
+    pub fn new_with_capacity(volumes: usize, pages: PageCount, page_sizes: bool) -> Self {
         Self {
             volume_index: TypedByteArray::with_capacity(volumes),
             page_idxs: TypedByteArray::with_capacity(pages.to_usize()),
+            page_sizes: page_sizes.then(|| TypedByteArray::with_capacity(volumes)),
             current: None,
+            current_size: PageSize::DEFAULT,
             pages: 0,
             last_pageidx: None,
         }
     }
 
-    pub fn serialized_size(num_volumes: usize, num_pages: PageCount) -> ByteUnit {
+    pub fn serialized_size(num_volumes: usize, num_pages: PageCount, page_sizes: bool) -> ByteUnit {
         let volume_index_size = TypedByteArray::<VolumeMeta>::serialized_size(num_volumes) as u64;
         let page_idxs_size =
             TypedByteArray::<U32<LittleEndian>>::serialized_size(num_pages.to_usize()) as u64;
-        ByteUnit::new(volume_index_size + page_idxs_size)
+        let page_sizes_size = if page_sizes {
+            TypedByteArray::<PageSize>::serialized_size(num_volumes) as u64
+        } else {
+            0
+        };
+        ByteUnit::new(volume_index_size + page_idxs_size + page_sizes_size)
     }
 
-    pub fn insert(&mut self, vid: &VolumeId, pageidx: PageIdx) {
-        let current = self.current.get_or_insert_with(|| VolumeMeta {
-            vid: vid.clone(),
-            start: self.pages.into(),
-            pages: 0.into(),
+    pub fn insert(&mut self, vid: &VolumeId, pageidx: PageIdx, page_size: PageSize) {
+        assert!(
+            self.page_sizes.is_some() || page_size.is_default(),
+            "bug: page sizes must be enabled to insert pages with a non-default size"
+        );
+
+        let current = self.current.get_or_insert_with(|| {
+            self.current_size = page_size;
+            VolumeMeta {
+                vid: vid.clone(),
+                start: self.pages.into(),
+                pages: 0.into(),
+            }
         });
 
         // If the VolumeId has changed, write the current VolumeMeta to the volume_index
@@ -191,9 +282,18 @@ impl SegmentIndexBuilder {
                 },
             );
             self.volume_index.push(last);
+            if let Some(page_sizes) = self.page_sizes.as_mut() {
+                page_sizes.push(self.current_size);
+            }
+            self.current_size = page_size;
             self.last_pageidx = None;
         }
 
+        assert_eq!(
+            page_size, self.current_size,
+            "bug: every page in a volume must have the same size"
+        );
+
         // verify that pageidx is larger than the last pageidx, while also updating the last pageidx
         if let Some(last_pageidx) = self.last_pageidx.replace(pageidx) {
             assert!(
@@ -212,13 +312,21 @@ impl SegmentIndexBuilder {
 
     pub fn finish(self, out: &mut BytesVec) -> ByteUnit {
         let mut volume_index = self.volume_index;
+        let mut page_sizes = self.page_sizes;
         if let Some(current) = self.current {
             volume_index.push(current);
+            if let Some(page_sizes) = page_sizes.as_mut() {
+                page_sizes.push(self.current_size);
+            }
         }
         let (volume_index, page_idxs) = (volume_index.freeze(), self.page_idxs.freeze());
-        let size = volume_index.len() + page_idxs.len();
+        let page_sizes = page_sizes.map(TypedByteArray::freeze).unwrap_or_default();
+        let size = volume_index.len() + page_idxs.len() + page_sizes.len();
         out.put(volume_index);
         out.put(page_idxs);
+        if !page_sizes.is_empty() {
+            out.put(page_sizes);
+        }
         size.into()
     }
 }
@@ -232,7 +340,7 @@ mod tests {
 
     #[graft_test::test]
     fn test_segment_index_sanity() {
-        let mut builder = SegmentIndexBuilder::new_with_capacity(3, 300.into());
+        let mut builder = SegmentIndexBuilder::new_with_capacity(3, 300.into(), false);
 
         let mut vids = vec![VolumeId::random(), VolumeId::random(), VolumeId::random()];
         vids.sort();
@@ -240,7 +348,7 @@ mod tests {
         // insert 100 page indexes for each vid
         for vid in &vids {
             for i in 1..=100 {
-                builder.insert(vid, PageIdx::new(i));
+                builder.insert(vid, PageIdx::new(i), PageSize::DEFAULT);
             }
         }
 
@@ -249,20 +357,66 @@ mod tests {
         assert_eq!(data.remaining(), size);
         assert_eq!(
             size,
-            SegmentIndexBuilder::serialized_size(vids.len(), 300.into()).as_usize()
+            SegmentIndexBuilder::serialized_size(vids.len(), 300.into(), false).as_usize()
         );
         let data = data.into_bytes();
 
-        let index =
-            SegmentIndex::from_bytes(&data, vids.len()).expect("failed to load segment index");
+        let index = SegmentIndex::from_bytes(&data, vids.len(), false)
+            .expect("failed to load segment index");
+        assert_eq!(index.page_bytes(), PAGESIZE * 300);
 
         // lookup all the offsets
         for (volume_offset, vid) in vids.iter().enumerate() {
             for i in 0..100 {
                 let pageidx = PageIdx::new(i + 1);
-                let local_offset = index.lookup(vid, pageidx).expect("page not found");
-                assert_eq!(local_offset, (volume_offset * 100) + i as usize);
+                let range = index.lookup(vid, pageidx).expect("page not found");
+                let local_offset = (volume_offset * 100) + i as usize;
+                assert_eq!(range.start, local_offset * PAGESIZE.as_usize());
+                assert_eq!(range.len(), PAGESIZE.as_usize());
             }
         }
     }
+
+    #[graft_test::test]
+    fn test_segment_index_page_sizes() {
+        let mut builder = SegmentIndexBuilder::new_with_capacity(3, 30.into(), true);
+
+        let mut vids = [VolumeId::random(), VolumeId::random(), VolumeId::random()];
+        vids.sort();
+        let sizes = [PageSize::Kb16, PageSize::Kb4, PageSize::Kb64];
+
+        // insert 10 page indexes for each vid
+        for (vid, size) in vids.iter().zip(sizes) {
+            for i in 1..=10 {
+                builder.insert(vid, PageIdx::new(i), size);
+            }
+        }
+
+        let mut data = BytesVec::default();
+        let size = builder.finish(&mut data);
+        assert_eq!(
+            size,
+            SegmentIndexBuilder::serialized_size(vids.len(), 30.into(), true).as_usize()
+        );
+        let data = data.into_bytes();
+
+        let index = SegmentIndex::from_bytes(&data, vids.len(), true)
+            .expect("failed to load segment index");
+        let expected_bytes: usize = sizes.iter().map(|s| s.as_usize() * 10).sum();
+        assert_eq!(index.page_bytes(), expected_bytes);
+
+        // every page should be directly after the previous page
+        let mut offset = 0;
+        for (vid, size) in vids.iter().zip(sizes) {
+            for i in 1..=10 {
+                let range = index.lookup(vid, PageIdx::new(i)).expect("page not found");
+                assert_eq!(range, offset..offset + size.as_usize());
+                offset = range.end;
+            }
+        }
+
+        let ranges: Vec<_> = index.iter().map(|(_, _, range)| range).collect();
+        assert_eq!(ranges.len(), 30);
+        assert_eq!(ranges.last().unwrap().end, expected_bytes);
+    }
 }
@@ -4,7 +4,10 @@ use std::{collections::BTreeMap, fmt::Debug};
 
 use culprit::Culprit;
 use graft_core::{
-    PageIdx, SegmentId, VolumeId, byte_unit::ByteUnit, page::Page, page_count::PageCount,
+    PageIdx, SegmentId, VolumeId,
+    byte_unit::ByteUnit,
+    page::{Page, PageSize},
+    page_count::PageCount,
 };
 use splinter_rs::Splinter;
 use thiserror::Error;
@@ -13,7 +16,10 @@ use zerocopy::IntoBytes;
 use crate::bytes_vec::BytesVec;
 
 use super::{
-    closed::{SEGMENT_MAX_PAGES, SEGMENT_MAX_VOLUMES, SegmentFooter, closed_segment_size},
+    closed::{
+        SEGMENT_MAX_PAGES, SEGMENT_MAX_SIZE, SEGMENT_MAX_VOLUMES, SegmentFooter,
+        closed_segment_size,
+    },
     index::SegmentIndexBuilder,
 };
 
@@ -46,12 +52,47 @@ impl Debug for OpenSegment {
 }
 
 impl OpenSegment {
-    /// returns true if the segment can accept another page for the specified volume
-    pub fn has_space_for(&self, vid: &VolumeId) -> bool {
-        match self.index.contains_key(vid) {
-            true => self.pages() < SEGMENT_MAX_PAGES,
-            false => self.volumes() < SEGMENT_MAX_VOLUMES && self.pages() < SEGMENT_MAX_PAGES,
+    /// returns true if the segment can accept another page of the specified
+    /// size for the specified volume
+    pub fn has_space_for(&self, vid: &VolumeId, page_size: PageSize) -> bool {
+        self.space_for(vid, page_size) > 0
+    }
+
+    /// returns the number of pages of the specified size the segment can
+    /// accept for the specified volume
+    fn space_for(&self, vid: &VolumeId, page_size: PageSize) -> usize {
+        let mut volumes = self.volumes();
+        if !self.index.contains_key(vid) {
+            if volumes >= SEGMENT_MAX_VOLUMES {
+                return 0;
+            }
+            volumes += 1;
         }
+
+        let pages = self.pages();
+        let page_sizes = self.has_page_sizes() || !page_size.is_default();
+        let size = closed_segment_size(volumes, pages, self.page_bytes(), page_sizes);
+        let with_page = closed_segment_size(
+            volumes,
+            pages.saturating_incr(),
+            self.page_bytes() + page_size.as_byte_unit(),
+            page_sizes,
+        );
+        let per_page = with_page - size;
+
+        let space_by_size =
+            SEGMENT_MAX_SIZE.as_usize().saturating_sub(size.as_usize()) / per_page.as_usize();
+        let space_by_pages = SEGMENT_MAX_PAGES.to_usize() - pages.to_usize();
+        space_by_size.min(space_by_pages)
+    }
+
+    /// returns true if the segment can't accept any more pages for the
+    /// volumes it contains
+    pub fn is_full(&self) -> bool {
+        self.index
+            .keys()
+            .next()
+            .is_some_and(|vid| !self.has_space_for(vid, PageSize::DEFAULT))
     }
 
     /// returns true if the segment contains pages for the specified volume
@@ -64,21 +105,24 @@ impl OpenSegment {
     pub fn batch_insert(
         &mut self,
         vid: VolumeId,
+        page_size: PageSize,
         pages: impl ExactSizeIterator<Item = (PageIdx, Page)>,
     ) -> Result<Splinter, Culprit<SegmentFullErr>> {
+        // calculate how many pages we can insert
+        let space = self.space_for(&vid, page_size);
+
         // early exit if segment can't fit a write to this volume
-        if !self.has_space_for(&vid) {
+        if space == 0 {
             return Err(Culprit::new(SegmentFullErr));
         }
-
-        // calculate how many pages we can insert
-        let space = SEGMENT_MAX_PAGES.to_usize() - self.pages().to_usize();
+        self.check_page_size(&vid, page_size);
 
         let mut graft = Splinter::default();
 
         // insert pages
         let index = self.index.entry(vid).or_default();
-        index.extend(pages.take(space).inspect(|(idx, _)| {
+        index.extend(pages.take(space).inspect(|(idx, page)| {
+            assert_eq!(page.size(), page_size, "bug: page size mismatch");
             graft.insert(idx.to_u32());
         }));
 
@@ -91,13 +135,46 @@ impl OpenSegment {
         pageidx: PageIdx,
         page: Page,
     ) -> Result<(), Culprit<SegmentFullErr>> {
-        if !self.has_space_for(&vid) {
+        if !self.has_space_for(&vid, page.size()) {
             return Err(Culprit::new(SegmentFullErr));
         }
+        self.check_page_size(&vid, page.size());
         self.index.entry(vid).or_default().insert(pageidx, page);
         Ok(())
     }
 
+    fn check_page_size(&self, vid: &VolumeId, page_size: PageSize) {
+        if let Some(existing) = self.volume_page_size(vid) {
+            assert_eq!(
+                existing, page_size,
+                "bug: every page in a volume must have the same size"
+            );
+        }
+    }
+
+    /// returns the page size of the specified volume if the segment contains
+    /// pages for it
+    fn volume_page_size(&self, vid: &VolumeId) -> Option<PageSize> {
+        self.index.get(vid)?.values().next().map(|page| page.size())
+    }
+
+    /// returns true if any volume in the segment doesn't use the default page size
+    fn has_page_sizes(&self) -> bool {
+        self.index.keys().any(|vid| {
+            self.volume_page_size(vid)
+                .is_some_and(|size| !size.is_default())
+        })
+    }
+
+    /// returns the total size of the pages in the segment
+    fn page_bytes(&self) -> ByteUnit {
+        self.index
+            .values()
+            .flat_map(|pages| pages.values())
+            .map(|page| page.size().as_byte_unit())
+            .fold(ByteUnit::ZERO, |a, b| a + b)
+    }
+
     #[inline]
     pub fn sid(&self) -> &SegmentId {
         &self.sid
@@ -127,21 +204,27 @@ impl OpenSegment {
     }
 
     pub fn serialized_size(&self) -> ByteUnit {
-        closed_segment_size(self.volumes(), self.pages())
+        closed_segment_size(
+            self.volumes(),
+            self.pages(),
+            self.page_bytes(),
+            self.has_page_sizes(),
+        )
     }
 
     pub fn serialize(self) -> (SegmentId, BytesVec) {
         let volumes = self.volumes();
         let pages = self.pages();
-        // +2 for the index, +1 for the footer
-        let mut data = BytesVec::with_capacity(pages.to_usize() + 2 + 1);
-        let mut index_builder = SegmentIndexBuilder::new_with_capacity(volumes, pages);
+        let page_sizes = self.has_page_sizes();
+        // +3 for the index, +1 for the footer
+        let mut data = BytesVec::with_capacity(pages.to_usize() + 3 + 1);
+        let mut index_builder = SegmentIndexBuilder::new_with_capacity(volumes, pages, page_sizes);
 
         // write pages to buffer while building index
         for (vid, pages) in self.index {
             for (off, page) in pages {
+                index_builder.insert(&vid, off, page.size());
                 data.put(page.into());
-                index_builder.insert(&vid, off);
             }
         }
 
@@ -149,12 +232,12 @@ impl OpenSegment {
         let index_size = index_builder.finish(&mut data);
         debug_assert_eq!(
             index_size,
-            SegmentIndexBuilder::serialized_size(volumes, pages),
+            SegmentIndexBuilder::serialized_size(volumes, pages, page_sizes),
             "index size mismatch"
         );
 
         // write out the footer
-        let footer = SegmentFooter::new(self.sid.clone(), volumes, index_size);
+        let footer = SegmentFooter::new(self.sid.clone(), volumes, index_size, page_sizes);
         data.put_slice(footer.as_bytes());
 
         (self.sid, data)
@@ -242,8 +325,9 @@ mod tests {
         }
 
         // the segment should not be able to accept any more pages for any volume
-        assert!(!open_segment.has_space_for(&vids[0]));
-        assert!(!open_segment.has_space_for(&VolumeId::random()));
+        assert!(!open_segment.has_space_for(&vids[0], PageSize::DEFAULT));
+        assert!(!open_segment.has_space_for(&VolumeId::random(), PageSize::DEFAULT));
+        assert!(open_segment.is_full());
 
         let expected_size = open_segment.serialized_size();
         let (_, buf) = open_segment.serialize();
@@ -282,9 +366,10 @@ mod tests {
         }
 
         // the segment should be able to accept one more page for an existing volume
-        assert!(open_segment.has_space_for(&vids[0]));
+        assert!(open_segment.has_space_for(&vids[0], PageSize::DEFAULT));
         // but not for a new volume
-        assert!(!open_segment.has_space_for(&VolumeId::random()));
+        assert!(!open_segment.has_space_for(&VolumeId::random(), PageSize::DEFAULT));
+        assert!(!open_segment.is_full());
 
         // insert a page for the last volume
         open_segment
@@ -292,8 +377,8 @@ mod tests {
             .expect("expected segment to accept one more page");
 
         // the segment should not be able to accept any more pages for any volume
-        assert!(!open_segment.has_space_for(&vids[0]));
-        assert!(!open_segment.has_space_for(&VolumeId::random()));
+        assert!(!open_segment.has_space_for(&vids[0], PageSize::DEFAULT));
+        assert!(!open_segment.has_space_for(&VolumeId::random(), PageSize::DEFAULT));
 
         // insert one more page; should fail
         let err = open_segment
@@ -301,4 +386,81 @@ mod tests {
             .expect_err("expected segment to be full");
         assert_matches!(err.ctx(), SegmentFullErr);
     }
+
+    #[graft_test::test]
+    fn test_segment_page_sizes() {
+        let mut open_segment = OpenSegment::default();
+
+        let vid4 = VolumeId::random();
+        let vid16 = VolumeId::random();
+        let page4 = Page::test_filled(1);
+        let page16 = Page::test_filled_sized(2, PageSize::Kb16);
+
+        let graft = open_segment
+            .batch_insert(
+                vid4.clone(),
+                PageSize::Kb4,
+                [(pageidx!(1), page4.clone()), (pageidx!(2), page4.clone())].into_iter(),
+            )
+            .unwrap();
+        assert_eq!(graft.cardinality(), 2);
+        let graft = open_segment
+            .batch_insert(
+                vid16.clone(),
+                PageSize::Kb16,
+                [(pageidx!(1), page16.clone()), (pageidx!(3), page16.clone())].into_iter(),
+            )
+            .unwrap();
+        assert_eq!(graft.cardinality(), 2);
+
+        let expected_size = open_segment.serialized_size();
+        let (_, buf) = open_segment.serialize();
+        assert_eq!(buf.remaining(), expected_size);
+
+        let buf = buf.into_bytes();
+        let closed_segment = ClosedSegment::from_bytes(&buf).unwrap();
+        assert_eq!(closed_segment.pages(), 4);
+        assert_eq!(closed_segment.find_page(&vid4, pageidx!(2)), Some(page4));
+        assert_eq!(
+            closed_segment.find_page(&vid16, pageidx!(1)),
+            Some(page16.clone())
+        );
+        assert_eq!(closed_segment.find_page(&vid16, pageidx!(3)), Some(page16));
+        assert_eq!(closed_segment.find_page(&vid16, pageidx!(2)), None);
+        assert!(closed_segment.iter().all(|(vid, _, page)| {
+            page.size()
+                == if vid == &vid4 {
+                    PageSize::Kb4
+                } else {
+                    PageSize::Kb16
+                }
+        }));
+    }
+
+    #[graft_test::test]
+    fn test_full_segment_large_pages() {
+        let mut open_segment = OpenSegment::default();
+        let vid = VolumeId::random();
+        let page = Page::test_filled_sized(1, PageSize::Kb64);
+
+        // a segment can only fit a few 64 KB pages
+        let pages = SEGMENT_MAX_PAGES.iter().map(|idx| (idx, page.clone()));
+        let graft = open_segment
+            .batch_insert(vid.clone(), PageSize::Kb64, pages)
+            .unwrap();
+        assert_eq!(graft.cardinality(), 255);
+        assert!(!open_segment.has_space_for(&vid, PageSize::Kb64));
+
+        // but there is still room for a smaller page from another volume
+        assert!(open_segment.has_space_for(&VolumeId::random(), PageSize::Kb4));
+        assert!(!open_segment.is_full());
+
+        let expected_size = open_segment.serialized_size();
+        assert!(expected_size <= SEGMENT_MAX_SIZE);
+        let (_, buf) = open_segment.serialize();
+        assert_eq!(buf.remaining(), expected_size);
+        let buf = buf.into_bytes();
+        let closed_segment = ClosedSegment::from_bytes(&buf).unwrap();
+        assert_eq!(closed_segment.pages(), 255);
+    }
 }
@@ -4,7 +4,10 @@ use std::sync::Arc;
 
 use culprit::{Culprit, ResultExt};
 use event_listener::Event;
-use graft_core::{PageIdx, SegmentId, VolumeId, page::Page};
+use graft_core::{
+    PageIdx, SegmentId, VolumeId,
+    page::{Page, PageSize},
+};
 use measured::{Counter, MetricGroup};
 use splinter_rs::Splinter;
 use thiserror::Error;
@@ -17,10 +20,7 @@ use super::{
     open::OpenSegment,
     uploader::{SegmentUploadEvent, SegmentUploadListener, StoreSegmentMsg},
 };
-use crate::{
-    segment::closed::SEGMENT_MAX_PAGES,
-    supervisor::{SupervisedTask, TaskCfg, TaskCtx},
-};
+use crate::supervisor::{SupervisedTask, TaskCfg, TaskCtx};
 
 #[derive(Debug, Error)]
 pub enum WriterErr {
@@ -149,20 +149,28 @@ impl SegmentWriterTask {
         tracing::trace!("writing {} pages to volume {:?}", req.pages.len(), req.vid);
         self.metrics.page_writes.inc_by(req.pages.len() as u64);
 
+        // every page in a write request has the same size
+        let page_size = req
+            .pages
+            .first()
+            .map_or(PageSize::DEFAULT, |(_, page)| page.size());
+
         let mut segments = Vec::new();
         let mut pages = req.pages.into_iter();
         loop {
             // flush current segment if full or it already contains the current volume.
             // this ensures that two separate write requests to the same volume
             // can't end up in the same segment
-            if !self.segment.has_space_for(&req.vid) || self.segment.contains_vid(&req.vid) {
+            if !self.segment.has_space_for(&req.vid, page_size)
+                || self.segment.contains_vid(&req.vid)
+            {
                 self.handle_flush().await?
             }
 
             // write as many pages as possible to the current segment
             let graft = self
                 .segment
-                .batch_insert(req.vid.clone(), &mut pages)
+                .batch_insert(req.vid.clone(), page_size, &mut pages)
                 .expect("segment was just verified to have space");
             segments.push((self.segment.sid().clone(), graft, self.event.listen()));
 
@@ -173,7 +181,7 @@ impl SegmentWriterTask {
         }
 
         // if the segment is full of pages, we can trigger an early flush
-        if self.segment.pages() == SEGMENT_MAX_PAGES {
+        if self.segment.is_full() {
             self.handle_flush().await?;
         }
 
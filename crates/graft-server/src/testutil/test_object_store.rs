@@ -1,6 +1,7 @@
 use std::{
     fmt::{Debug, Display},
     ops::Range,
+    sync::Mutex,
 };
 
 use async_trait::async_trait;
@@ -11,7 +12,6 @@ use object_store::{
     GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts,
     PutOptions, PutPayload, PutResult, Result, memory::InMemory, path::Path,
 };
-use tokio::sync::Mutex;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ObjectStoreOp {
@@ -39,26 +39,25 @@ impl Display for TestObjectStore {
 
 impl TestObjectStore {
     async fn hit(&self, op: ObjectStoreOp) {
-        let mut hits = self.hits.lock().await;
-        *hits.entry(op).or_insert(0) += 1;
+        self.hit_blocking(op);
     }
 
     fn hit_blocking(&self, op: ObjectStoreOp) {
-        let mut hits = self.hits.blocking_lock();
+        let mut hits = self.hits.lock().unwrap();
         *hits.entry(op).or_insert(0) += 1;
     }
 
     pub async fn all_hits(&self) -> HashMap<ObjectStoreOp, usize> {
-        self.hits.lock().await.clone()
+        self.hits.lock().unwrap().clone()
     }
 
     pub async fn count_hits(&self, op: ObjectStoreOp) -> usize {
-        let hits = self.hits.lock().await;
+        let hits = self.hits.lock().unwrap();
         *hits.get(&op).unwrap_or(&0)
     }
 
     pub async fn reset_hits(&self) {
-        let mut hits = self.hits.lock().await;
+        let mut hits = self.hits.lock().unwrap();
         hits.clear();
     }
 }
@@ -1,7 +1,8 @@
 use std::{
+    collections::BTreeMap,
     fmt::Debug,
     io,
-    ops::RangeBounds,
+    ops::{Bound, RangeBounds},
     path::{Path, PathBuf},
 };
 
@@ -11,19 +12,28 @@ use fjall::{
     Batch, Config, Keyspace, KvSeparationOptions, Partition, PartitionCreateOptions, Slice,
 };
 use graft_core::{
+    SegmentId,
     gid::VolumeId,
     lsn::LSN,
     zerocopy_ext::{TryFromBytesExt, ZerocopyErr},
 };
-use graft_proto::common::v1::SegmentInfo;
+use graft_proto::{
+    common::v1::{CommitMetadata, SegmentInfo},
+    metastore::v1::{VolumeFork, VolumeLabels},
+};
+use prost::Message;
 use serde::{Deserialize, Serialize};
-use splinter_rs::SplinterRef;
+use splinter_rs::{
+    Splinter, SplinterRef,
+    ops::{Cut, Merge},
+};
 use tryiter::TryIteratorExt;
 use zerocopy::{ConvertError, IntoBytes, SizeError, TryFromBytes};
 
 use super::{
     commit::{Commit, CommitMeta, GraftValidationErr},
-    kv::{CommitKey, SegmentKey},
+    kv::{CommitKey, SegmentKey, VolumeSegmentKey},
+    store::CheckpointVersion,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -45,6 +55,18 @@ pub enum VolumeCatalogErr {
 
     #[error("graft validation error")]
     GraftValidationErr(#[from] GraftValidationErr),
+
+    #[error("failed to decode volume labels")]
+    LabelsDecodeErr,
+
+    #[error("failed to decode commit metadata")]
+    CommitMetadataDecodeErr,
+
+    #[error("failed to decode volume fork record")]
+    ForkDecodeErr,
+
+    #[error("failed to decode checkpoint version")]
+    CheckpointVersionDecodeErr,
 }
 
 impl<A, S, V> From<ConvertError<A, S, V>> for VolumeCatalogErr {
@@ -106,6 +128,34 @@ pub struct VolumeCatalog {
 
     /// maps `kv::SegmentKey` to Graft
     segments: Partition,
+
+    /// maps `kv::CommitKey` to the commit's encoded `CommitMetadata`, for
+    /// commits which have metadata
+    commit_metadata: Partition,
+
+    /// maps `kv::CommitKey` to `commit::CommitMeta` for each checkpoint
+    checkpoints: Partition,
+
+    /// maps `kv::SegmentKey` to Graft for each checkpoint
+    checkpoint_segments: Partition,
+
+    /// maps `kv::CommitKey` to the `store::CheckpointVersion` each checkpoint
+    /// was loaded from, for checkpoints which were loaded from the store
+    checkpoint_versions: Partition,
+
+    /// maps `VolumeId` to the Volume's encoded `VolumeLabels`
+    labels: Partition,
+
+    /// contains the `VolumeId` of every Volume discovered by listing the store
+    volume_ids: Partition,
+
+    /// maps `VolumeId` to the Volume's encoded `VolumeFork`, which is empty if
+    /// the Volume wasn't forked
+    forks: Partition,
+
+    /// maps `kv::VolumeSegmentKey` to the `VolumeId` whose pages the segment
+    /// contains, for segments a forked Volume inherited from its source
+    segment_owners: Partition,
 }
 
 impl VolumeCatalog {
@@ -128,7 +178,43 @@ impl VolumeCatalog {
             PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
         )?;
 
-        Ok(Self { keyspace, volumes, segments })
+        let commit_metadata =
+            keyspace.open_partition("commit_metadata", PartitionCreateOptions::default())?;
+
+        let checkpoints =
+            keyspace.open_partition("checkpoints", PartitionCreateOptions::default())?;
+
+        let checkpoint_segments = keyspace.open_partition(
+            "checkpoint_segments",
+            PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
+        )?;
+
+        let checkpoint_versions =
+            keyspace.open_partition("checkpoint_versions", PartitionCreateOptions::default())?;
+
+        let labels = keyspace.open_partition("labels", PartitionCreateOptions::default())?;
+
+        let volume_ids =
+            keyspace.open_partition("volume_ids", PartitionCreateOptions::default())?;
+
+        let forks = keyspace.open_partition("forks", PartitionCreateOptions::default())?;
+
+        let segment_owners =
+            keyspace.open_partition("segment_owners", PartitionCreateOptions::default())?;
+
+        Ok(Self {
+            keyspace,
+            volumes,
+            segments,
+            commit_metadata,
+            checkpoints,
+            checkpoint_segments,
+            checkpoint_versions,
+            labels,
+            volume_ids,
+            forks,
+            segment_owners,
+        })
     }
 
     pub fn batch_insert(&self) -> VolumeCatalogBatch {
@@ -136,6 +222,11 @@ impl VolumeCatalog {
             batch: self.keyspace.batch(),
             volumes: self.volumes.clone(),
             segments: self.segments.clone(),
+            commit_metadata: self.commit_metadata.clone(),
+            checkpoints: self.checkpoints.clone(),
+            checkpoint_segments: self.checkpoint_segments.clone(),
+            checkpoint_versions: self.checkpoint_versions.clone(),
+            segment_owners: self.segment_owners.clone(),
         }
     }
 
@@ -180,8 +271,7 @@ impl VolumeCatalog {
     ) -> Result<Option<CommitMeta>, Culprit<VolumeCatalogErr>> {
         if let Some(bytes) = self.volumes.get(CommitKey::new(vid, lsn))? {
             Ok(Some(
-                CommitMeta::try_read_from_bytes(&bytes)
-                    .or_into_culprit("failed to decode CommitMeta")?,
+                CommitMeta::from_bytes(&bytes).or_into_culprit("failed to decode CommitMeta")?,
             ))
         } else {
             Ok(None)
@@ -200,12 +290,127 @@ impl VolumeCatalog {
             .rev()
             .err_into()
             .map_ok(|(_, bytes)| {
-                CommitMeta::try_read_from_bytes(&bytes)
-                    .or_into_culprit("failed to decode CommitMeta")
+                CommitMeta::from_bytes(&bytes).or_into_culprit("failed to decode CommitMeta")
             })
             .try_next()
     }
 
+    /// Return the metadata stored with the commit at the provided LSN, or None
+    /// if the commit has no metadata or isn't in the catalog
+    pub fn commit_metadata(
+        &self,
+        vid: VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<CommitMetadata>, Culprit<VolumeCatalogErr>> {
+        if let Some(bytes) = self.commit_metadata.get(CommitKey::new(vid, lsn))? {
+            let metadata = CommitMetadata::decode(bytes.as_ref())
+                .or_ctx(|_| VolumeCatalogErr::CommitMetadataDecodeErr)?;
+            Ok(Some(metadata))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the Volume's cached labels, or None if they haven't been cached
+    pub fn labels(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<VolumeLabels>, Culprit<VolumeCatalogErr>> {
+        if let Some(bytes) = self.labels.get(vid.as_bytes())? {
+            let labels = VolumeLabels::decode(bytes.as_ref())
+                .or_ctx(|_| VolumeCatalogErr::LabelsDecodeErr)?;
+            Ok(Some(labels))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Cache the Volume's labels unless a newer version is already cached
+    pub fn insert_labels(
+        &self,
+        vid: &VolumeId,
+        labels: &VolumeLabels,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        if self
+            .labels(vid)?
+            .is_none_or(|cached| cached.version <= labels.version)
+        {
+            self.labels.insert(vid.as_bytes(), labels.encode_to_vec())?;
+        }
+        Ok(())
+    }
+
+    pub fn contains_volume_id(&self, vid: &VolumeId) -> Result<bool, Culprit<VolumeCatalogErr>> {
+        Ok(self.volume_ids.contains_key(vid.as_bytes())?)
+    }
+
+    pub fn insert_volume_id(&self, vid: &VolumeId) -> Result<(), Culprit<VolumeCatalogErr>> {
+        self.volume_ids.insert(vid.as_bytes(), [])?;
+        Ok(())
+    }
+
+    pub fn remove_volume_id(&self, vid: &VolumeId) -> Result<(), Culprit<VolumeCatalogErr>> {
+        self.volume_ids.remove(vid.as_bytes())?;
+        Ok(())
+    }
+
+    /// Scan the cached `VolumeId`s in order, starting after `start_after`
+    pub fn volume_ids(
+        &self,
+        start_after: Option<&VolumeId>,
+    ) -> impl Iterator<Item = Result<VolumeId, Culprit<VolumeCatalogErr>>> + use<> {
+        let start = start_after.map_or(Bound::Unbounded, |vid| {
+            Bound::Excluded(vid.as_bytes().to_vec())
+        });
+        self.volume_ids
+            .snapshot()
+            .range((start, Bound::Unbounded))
+            .map(|kv| {
+                let (key, _) = kv?;
+                let vid: &VolumeId = key.as_ref().try_into()?;
+                Ok(vid.clone())
+            })
+    }
+
+    /// Returns the Volume's cached fork record, or None if it hasn't been
+    /// cached. Volumes which weren't forked have an empty fork record.
+    pub fn fork(&self, vid: &VolumeId) -> Result<Option<VolumeFork>, Culprit<VolumeCatalogErr>> {
+        if let Some(bytes) = self.forks.get(vid.as_bytes())? {
+            let fork =
+                VolumeFork::decode(bytes.as_ref()).or_ctx(|_| VolumeCatalogErr::ForkDecodeErr)?;
+            Ok(Some(fork))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Cache the Volume's fork record. A Volume's fork record never changes.
+    pub fn insert_fork(
+        &self,
+        vid: &VolumeId,
+        fork: &VolumeFork,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        self.forks.insert(vid.as_bytes(), fork.encode_to_vec())?;
+        Ok(())
+    }
+
+    /// Returns the Volume whose pages are stored in a segment referenced by
+    /// the specified Volume. This is the Volume itself unless the segment was
+    /// inherited from the Volume's fork source.
+    pub fn segment_owner(
+        &self,
+        vid: &VolumeId,
+        sid: &SegmentId,
+    ) -> Result<VolumeId, Culprit<VolumeCatalogErr>> {
+        let key = VolumeSegmentKey::new(vid.clone(), sid.clone());
+        if let Some(bytes) = self.segment_owners.get(key)? {
+            let owner: &VolumeId = bytes.as_ref().try_into()?;
+            Ok(owner.clone())
+        } else {
+            Ok(vid.clone())
+        }
+    }
+
     /// scan the catalog for segments in the specified Volume. Segments are
     /// scanned in reverse order by LSN.
     pub fn scan_segments<R: RangeBounds<LSN>>(
@@ -219,6 +424,126 @@ impl VolumeCatalog {
         SegmentsIter { scan }
     }
 
+    /// Returns true if the catalog contains the checkpoint written at the
+    /// specified LSN. The first commit to a Volume is an implicit checkpoint
+    /// and is never written to the catalog.
+    pub fn contains_checkpoint(
+        &self,
+        vid: VolumeId,
+        lsn: LSN,
+    ) -> Result<bool, Culprit<VolumeCatalogErr>> {
+        Ok(lsn == LSN::FIRST || self.checkpoints.contains_key(CommitKey::new(vid, lsn))?)
+    }
+
+    /// Return the checkpoint for the specified Volume at the provided LSN.
+    pub fn checkpoint(
+        &self,
+        vid: VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<CommitMeta>, Culprit<VolumeCatalogErr>> {
+        if let Some(bytes) = self.checkpoints.get(CommitKey::new(vid, lsn))? {
+            Ok(Some(
+                CommitMeta::from_bytes(&bytes).or_into_culprit("failed to decode CommitMeta")?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Return the version of the stored checkpoint which the catalog's copy of
+    /// the checkpoint at the provided LSN was loaded from, if known.
+    pub fn checkpoint_version(
+        &self,
+        vid: VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<CheckpointVersion>, Culprit<VolumeCatalogErr>> {
+        let Some(bytes) = self.checkpoint_versions.get(CommitKey::new(vid, lsn))? else {
+            return Ok(None);
+        };
+        let version =
+            std::str::from_utf8(&bytes).or_ctx(|_| VolumeCatalogErr::CheckpointVersionDecodeErr)?;
+        Ok(Some(CheckpointVersion::new(version)))
+    }
+
+    /// Return the latest checkpoint in the catalog for the specified Volume.
+    pub fn latest_checkpoint(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<CommitMeta>, Culprit<VolumeCatalogErr>> {
+        self.checkpoints
+            .snapshot()
+            .prefix(vid)
+            .rev()
+            .err_into()
+            .map_ok(|(_, bytes)| {
+                CommitMeta::from_bytes(&bytes).or_into_culprit("failed to decode CommitMeta")
+            })
+            .try_next()
+    }
+
+    /// scan the segments contained by the checkpoint at the specified LSN
+    pub fn scan_checkpoint(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+    ) -> impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>> + use<>
+    {
+        let scan = self
+            .checkpoint_segments
+            .snapshot()
+            .prefix(CommitKey::new(vid.clone(), lsn));
+        SegmentsIter { scan }
+    }
+
+    /// Scan every segment needed to read the Volume at `lsn`, starting from
+    /// the provided checkpoint. Segments are scanned in reverse order by LSN,
+    /// ending with the segments contained by the checkpoint. The checkpoint
+    /// must already be in the catalog.
+    pub fn scan_from_checkpoint(
+        &self,
+        vid: &VolumeId,
+        checkpoint: LSN,
+        lsn: LSN,
+    ) -> impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>> + use<>
+    {
+        // the first commit is an implicit checkpoint, so we scan it like any
+        // other commit
+        let start = if checkpoint == LSN::FIRST {
+            LSN::FIRST
+        } else {
+            checkpoint.saturating_next()
+        };
+        self.scan_segments(vid, &(start..=lsn))
+            .chain(self.scan_checkpoint(vid, checkpoint))
+    }
+
+    /// Consolidate the snapshot's checkpoint with every commit since, mapping
+    /// each segment to the pages it contains which haven't been overwritten by
+    /// a later commit.
+    pub fn live_segments(
+        &self,
+        snapshot: &CommitMeta,
+    ) -> Result<BTreeMap<SegmentId, Splinter>, Culprit<VolumeCatalogErr>> {
+        let mut seen = Splinter::default();
+        let mut live: BTreeMap<SegmentId, Splinter> = BTreeMap::new();
+
+        let scan = self.scan_from_checkpoint(snapshot.vid(), snapshot.checkpoint(), snapshot.lsn());
+        for result in scan {
+            let (key, graft) = result?;
+
+            // only keep pages which haven't been overwritten by a later commit
+            let mut pages = Splinter::default();
+            pages.merge(&graft);
+            let _ = pages.cut(&seen);
+            if !pages.is_empty() {
+                seen.merge(&pages);
+                live.entry(key.sid().clone()).or_default().merge(&pages);
+            }
+        }
+
+        Ok(live)
+    }
+
     /// scan the catalog for commits in the specified Volume in order by lsn
     #[allow(clippy::type_complexity)]
     pub fn scan_volume<R: RangeBounds<LSN>>(
@@ -245,8 +570,8 @@ impl VolumeCatalog {
             .map_ok(move |(key, meta)| {
                 let key = CommitKey::try_read_from_bytes(&key)
                     .or_into_culprit("failed to decode CommitKey")?;
-                let meta = CommitMeta::try_read_from_bytes(&meta)
-                    .or_into_culprit("failed to decode CommitMeta")?;
+                let meta =
+                    CommitMeta::from_bytes(&meta).or_into_culprit("failed to decode CommitMeta")?;
 
                 // scan segments for this commit
                 let segments = self.segments.snapshot_at(seqno).prefix(key);
@@ -261,6 +586,11 @@ pub struct VolumeCatalogBatch {
     batch: Batch,
     volumes: Partition,
     segments: Partition,
+    commit_metadata: Partition,
+    checkpoints: Partition,
+    checkpoint_segments: Partition,
+    checkpoint_versions: Partition,
+    segment_owners: Partition,
 }
 
 impl VolumeCatalogBatch {
@@ -276,6 +606,14 @@ impl VolumeCatalogBatch {
             commit.meta().as_bytes(),
         );
 
+        if let Some(metadata) = commit.metadata() {
+            self.batch.insert(
+                &self.commit_metadata,
+                commit_key.as_bytes(),
+                metadata.encode_to_vec(),
+            );
+        }
+
         let mut iter = commit.iter_grafts();
         while let Some((sid, graft)) = iter.try_next().or_into_ctx()? {
             let key = SegmentKey::new(commit_key.clone(), sid);
@@ -296,12 +634,135 @@ impl VolumeCatalogBatch {
         self.batch
             .insert(&self.volumes, commit_key.as_bytes(), snapshot);
         for segment in segments {
+            self.insert_segment_owner(commit_key.vid(), &segment)?;
             let key = SegmentKey::new(commit_key.clone(), segment.sid.try_into()?);
             self.batch.insert(&self.segments, key, segment.graft);
         }
         Ok(())
     }
 
+    /// Insert a checkpoint, recording the version of the stored checkpoint it
+    /// was loaded from or written to if known.
+    pub fn insert_checkpoint<T: Buf + Clone>(
+        &mut self,
+        checkpoint: &Commit<T>,
+        version: Option<&CheckpointVersion>,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let commit_key = CommitKey::new(checkpoint.vid().clone(), checkpoint.meta().lsn());
+        self.clear_checkpoint(&commit_key)?;
+
+        if let Some(version) = version {
+            self.batch.insert(
+                &self.checkpoint_versions,
+                commit_key.as_bytes(),
+                version.as_str(),
+            );
+        }
+
+        self.batch.insert(
+            &self.checkpoints,
+            commit_key.as_bytes(),
+            checkpoint.meta().as_bytes(),
+        );
+
+        let mut iter = checkpoint.iter_grafts();
+        while let Some((sid, graft)) = iter.try_next().or_into_ctx()? {
+            let key = SegmentKey::new(commit_key.clone(), sid);
+            self.batch
+                .insert(&self.checkpoint_segments, key, graft.into_inner());
+        }
+
+        Ok(())
+    }
+
+    pub fn insert_checkpoint_snapshot(
+        &mut self,
+        vid: VolumeId,
+        checkpoint: CommitMeta,
+        version: Option<&CheckpointVersion>,
+        segments: Vec<SegmentInfo>,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let commit_key = CommitKey::new(vid, checkpoint.lsn());
+        self.clear_checkpoint(&commit_key)?;
+
+        if let Some(version) = version {
+            self.batch.insert(
+                &self.checkpoint_versions,
+                commit_key.as_bytes(),
+                version.as_str(),
+            );
+        }
+
+        self.batch
+            .insert(&self.checkpoints, commit_key.as_bytes(), checkpoint);
+        for segment in segments {
+            self.insert_segment_owner(commit_key.vid(), &segment)?;
+            let key = SegmentKey::new(commit_key.clone(), segment.sid.try_into()?);
+            self.batch
+                .insert(&self.checkpoint_segments, key, segment.graft);
+        }
+        Ok(())
+    }
+
+    /// Record the Volume whose pages the segment contains if it was inherited
+    /// from a fork source
+    fn insert_segment_owner(
+        &mut self,
+        vid: &VolumeId,
+        segment: &SegmentInfo,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        if let Some(owner) = segment.vid().or_into_ctx()? {
+            let key = VolumeSegmentKey::new(vid.clone(), segment.sid().or_into_ctx()?.clone());
+            self.batch
+                .insert(&self.segment_owners, key.as_bytes(), owner.as_bytes());
+        }
+        Ok(())
+    }
+
+    /// Remove a commit and its segments from the catalog
+    pub fn remove_commit(
+        &mut self,
+        vid: VolumeId,
+        lsn: LSN,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let commit_key = CommitKey::new(vid, lsn);
+        for kv in self.segments.prefix(&commit_key) {
+            let (key, _) = kv?;
+            self.batch.remove(&self.segments, key);
+        }
+        self.batch
+            .remove(&self.commit_metadata, commit_key.as_bytes());
+        self.batch.remove(&self.volumes, commit_key.as_bytes());
+        Ok(())
+    }
+
+    /// Remove a checkpoint and its segments from the catalog
+    pub fn remove_checkpoint(
+        &mut self,
+        vid: VolumeId,
+        lsn: LSN,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let commit_key = CommitKey::new(vid, lsn);
+        self.clear_checkpoint(&commit_key)?;
+        self.batch.remove(&self.checkpoints, commit_key.as_bytes());
+        Ok(())
+    }
+
+    /// Checkpoints may be rewritten with different segments, so we remove any
+    /// existing segments and version before inserting a checkpoint.
+    fn clear_checkpoint(
+        &mut self,
+        commit_key: &CommitKey,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        self.batch
+            .remove(&self.checkpoint_versions, commit_key.as_bytes());
+        for kv in self.checkpoint_segments.prefix(commit_key) {
+            let (key, _) = kv?;
+            self.batch.remove(&self.checkpoint_segments, key);
+        }
+        Ok(())
+    }
+
     pub fn commit(self) -> Result<(), Culprit<VolumeCatalogErr>> {
         self.batch.commit()?;
         Ok(())
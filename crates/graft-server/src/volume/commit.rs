@@ -12,11 +12,16 @@ use graft_core::{
     SegmentId, VolumeId,
     gid::{ClientId, GidParseErr},
     lsn::{InvalidLSN, LSN},
+    page::{PageSize, PageSizeErr},
     page_count::PageCount,
     zerocopy_ext::ZerocopyErr,
 };
-use graft_proto::common::v1::Snapshot;
+use graft_proto::{
+    common::v1::{CommitMetadata, Snapshot},
+    decode_page_size,
+};
 use object_store::{PutPayload, path::Path};
+use prost::Message;
 use prost_types::TimestampError;
 use splinter_rs::SplinterRef;
 use thiserror::Error;
@@ -32,6 +37,14 @@ pub fn commit_key_path(vid: &VolumeId, lsn: LSN) -> Path {
     commit_key_path_prefix(vid).child(lsn.format_fixed_hex())
 }
 
+pub fn checkpoint_key_path_prefix(vid: &VolumeId) -> Path {
+    Path::parse(format!("checkpoints/{}", vid.pretty())).expect("invalid object_store path")
+}
+
+pub fn checkpoint_key_path(vid: &VolumeId, lsn: LSN) -> Path {
+    checkpoint_key_path_prefix(vid).child(lsn.format_fixed_hex())
+}
+
 fn time_to_millis(time: SystemTime) -> u64 {
     time.duration_since(SystemTime::UNIX_EPOCH)
         .unwrap()
@@ -59,6 +72,14 @@ impl From<ParseIntError> for CommitKeyParseErr {
 }
 
 pub fn parse_commit_key(key: &Path) -> Result<(VolumeId, LSN), Culprit<CommitKeyParseErr>> {
+    parse_key("volumes", key)
+}
+
+pub fn parse_checkpoint_key(key: &Path) -> Result<(VolumeId, LSN), Culprit<CommitKeyParseErr>> {
+    parse_key("checkpoints", key)
+}
+
+fn parse_key(root: &str, key: &Path) -> Result<(VolumeId, LSN), Culprit<CommitKeyParseErr>> {
     macro_rules! invalid_key {
         () => {
             Culprit::new_with_note(
@@ -69,7 +90,7 @@ pub fn parse_commit_key(key: &Path) -> Result<(VolumeId, LSN), Culprit<CommitKey
     }
 
     let mut parts = key.parts();
-    if parts.next().as_ref().map(|p| p.as_ref()) != Some("volumes") {
+    if parts.next().as_ref().map(|p| p.as_ref()) != Some(root) {
         return Err(invalid_key!());
     }
     let vid: VolumeId = parts
@@ -88,9 +109,26 @@ pub fn parse_commit_key(key: &Path) -> Result<(VolumeId, LSN), Culprit<CommitKey
 #[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout, PartialEq, Eq)]
 #[repr(u32)]
 enum CommitMagic {
-    Magic = 0x71DB116B,
+    /// Commits written before the page size was recorded. Their header is
+    /// missing the trailing page size and padding, and every page has the
+    /// default page size.
+    MagicV1 = 0x71DB116B,
+
+    Magic = 0x71DB116C,
 }
 
+/// The size of a `CommitMeta` written with `CommitMagic::MagicV1`
+const COMMIT_META_V1_SIZE: usize = 64;
+
+/// Set in `CommitMeta::flags` when the header is followed by the commit's
+/// encoded `CommitMetadata`, prefixed by its length as a big endian u32
+const FLAG_HAS_METADATA: u8 = 1;
+
+/// Set in `CommitMeta::flags` when the commit deletes the Volume. Tombstone
+/// commits don't change any pages, and are written to the commit log so that
+/// deleting a Volume conflicts with any concurrent commit.
+const FLAG_TOMBSTONE: u8 = 2;
+
 impl Debug for CommitMagic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "CommitMagic")
@@ -107,11 +145,16 @@ pub struct CommitMeta {
     lsn: LSN,
     checkpoint_lsn: LSN,
     timestamp: u64,
+    page_size: PageSize,
+    flags: u8,
+    _padding: [u8; 6],
 }
 
-static_assertions::const_assert_eq!(size_of::<CommitMeta>(), 64);
+static_assertions::const_assert_eq!(size_of::<CommitMeta>(), 72);
 
 impl CommitMeta {
+    /// Create a new `CommitMeta` for a Volume with the default page size. Use
+    /// [`CommitMeta::with_page_size`] to pick a different page size.
     pub fn new(
         vid: VolumeId,
         cid: ClientId,
@@ -132,7 +175,58 @@ impl CommitMeta {
             checkpoint_lsn: checkpoint,
             page_count,
             timestamp: time_to_millis(timestamp),
+            page_size: PageSize::DEFAULT,
+            flags: 0,
+            _padding: Default::default(),
+        }
+    }
+
+    pub fn with_page_size(self, page_size: PageSize) -> Self {
+        Self { page_size, ..self }
+    }
+
+    /// Mark the commit as a tombstone, which deletes the Volume
+    pub fn with_tombstone(self, tombstone: bool) -> Self {
+        let flags = if tombstone {
+            self.flags | FLAG_TOMBSTONE
+        } else {
+            self.flags & !FLAG_TOMBSTONE
+        };
+        Self { flags, ..self }
+    }
+
+    /// Returns the encoded size of the `CommitMeta` at the start of data, or
+    /// None if data doesn't start with a valid magic number.
+    fn encoded_size(data: &[u8]) -> Option<usize> {
+        let magic = data.first_chunk::<4>()?;
+        if magic == CommitMagic::MagicV1.as_bytes() {
+            Some(COMMIT_META_V1_SIZE)
+        } else if magic == CommitMagic::Magic.as_bytes() {
+            Some(size_of::<CommitMeta>())
+        } else {
+            None
+        }
+    }
+
+    /// Decode a `CommitMeta`, upgrading commits which were written before the
+    /// page size was recorded.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ZerocopyErr> {
+        if data.len() == COMMIT_META_V1_SIZE {
+            let mut buf = [0; size_of::<CommitMeta>()];
+            buf[..COMMIT_META_V1_SIZE].copy_from_slice(data);
+            buf[COMMIT_META_V1_SIZE] = PageSize::DEFAULT as u8;
+            let meta = Self::try_read_from_bytes(&buf)?;
+            if meta.magic != CommitMagic::MagicV1 {
+                return Err(ZerocopyErr::InvalidData);
+            }
+            return Ok(Self { magic: CommitMagic::Magic, ..meta });
+        }
+
+        let meta = Self::try_read_from_bytes(data)?;
+        if meta.magic != CommitMagic::Magic {
+            return Err(ZerocopyErr::InvalidData);
         }
+        Ok(meta)
     }
 
     #[inline]
@@ -165,6 +259,23 @@ impl CommitMeta {
         self.timestamp
     }
 
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    /// Returns true if the commit was written with a `CommitMetadata`
+    #[inline]
+    pub fn has_metadata(&self) -> bool {
+        self.flags & FLAG_HAS_METADATA != 0
+    }
+
+    /// Returns true if the commit deleted the Volume
+    #[inline]
+    pub fn is_tombstone(&self) -> bool {
+        self.flags & FLAG_TOMBSTONE != 0
+    }
+
     #[inline]
     pub fn system_time(&self) -> SystemTime {
         millis_to_time(self.timestamp())
@@ -179,6 +290,7 @@ impl CommitMeta {
             self.page_count,
             self.system_time(),
         )
+        .with_page_size(self.page_size)
     }
 }
 
@@ -204,6 +316,9 @@ pub enum TryFromSnapshotErr {
 
     #[error("invalid timestamp: {0}")]
     InvalidTimestamp(#[from] TimestampError),
+
+    #[error("invalid page size: {0}")]
+    InvalidPageSize(#[from] PageSizeErr),
 }
 
 impl TryFrom<Snapshot> for CommitMeta {
@@ -218,7 +333,8 @@ impl TryFrom<Snapshot> for CommitMeta {
             snapshot.checkpoint().or_into_ctx()?,
             snapshot.pages(),
             ts,
-        ))
+        )
+        .with_page_size(decode_page_size(snapshot.page_size).or_into_ctx()?))
     }
 }
 
@@ -231,6 +347,7 @@ pub struct GraftHeader {
 
 pub struct CommitBuilder {
     meta: CommitMeta,
+    metadata: Option<CommitMetadata>,
     grafts: BytesVec,
 }
 
@@ -238,10 +355,24 @@ impl CommitBuilder {
     pub fn new_with_capacity(meta: CommitMeta, capacity: usize) -> Self {
         Self {
             meta,
+            metadata: None,
             grafts: BytesVec::with_capacity(capacity),
         }
     }
 
+    /// Store the provided `CommitMetadata` with the commit. Empty metadata is
+    /// not stored.
+    pub fn with_metadata(self, metadata: Option<CommitMetadata>) -> Self {
+        let metadata = metadata.filter(|m| !m.is_empty());
+        let mut meta = self.meta;
+        if metadata.is_some() {
+            meta.flags |= FLAG_HAS_METADATA;
+        } else {
+            meta.flags &= !FLAG_HAS_METADATA;
+        }
+        Self { meta, metadata, ..self }
+    }
+
     pub fn write_graft(&mut self, sid: SegmentId, graft: Bytes) {
         let header = GraftHeader {
             sid,
@@ -252,25 +383,35 @@ impl CommitBuilder {
     }
 
     pub fn build(self) -> Commit<BytesVec> {
-        Commit { header: self.meta, grafts: self.grafts }
+        Commit {
+            header: self.meta,
+            metadata: self.metadata,
+            grafts: self.grafts,
+        }
     }
 }
 
 #[derive(Debug, Error)]
-#[error("corrupt commit header: {0}")]
-pub struct CommitValidationErr(ZerocopyErr);
+pub enum CommitValidationErr {
+    #[error("corrupt commit header: {0}")]
+    CorruptHeader(ZerocopyErr),
+
+    #[error("corrupt commit metadata")]
+    CorruptMetadata,
+}
 
 impl<A, S, V> From<ConvertError<A, S, V>> for CommitValidationErr {
     #[inline]
     #[track_caller]
     fn from(value: ConvertError<A, S, V>) -> Self {
-        Self(value.into())
+        Self::CorruptHeader(value.into())
     }
 }
 
 #[derive(Clone)]
 pub struct Commit<T> {
     header: CommitMeta,
+    metadata: Option<CommitMetadata>,
     grafts: T,
 }
 
@@ -285,6 +426,11 @@ impl<T> Commit<T> {
         &self.header
     }
 
+    #[inline]
+    pub fn metadata(&self) -> Option<&CommitMetadata> {
+        self.metadata.as_ref()
+    }
+
     pub fn into_snapshot(self) -> Snapshot {
         self.header.into_snapshot()
     }
@@ -292,13 +438,41 @@ impl<T> Commit<T> {
 
 impl<T: Buf + Clone> Commit<T> {
     pub fn from_bytes(mut data: T) -> Result<Self, Culprit<CommitValidationErr>> {
-        if data.remaining() < size_of::<CommitMeta>() {
-            return Err(Culprit::new(CommitValidationErr(ZerocopyErr::InvalidSize)));
+        let mut magic = [0; 4];
+        if data.remaining() < magic.len() {
+            return Err(Culprit::new(CommitValidationErr::CorruptHeader(
+                ZerocopyErr::InvalidSize,
+            )));
+        }
+        data.clone().copy_to_slice(&mut magic);
+        let size = CommitMeta::encoded_size(&magic).ok_or_else(|| {
+            Culprit::new(CommitValidationErr::CorruptHeader(ZerocopyErr::InvalidData))
+        })?;
+
+        if data.remaining() < size {
+            return Err(Culprit::new(CommitValidationErr::CorruptHeader(
+                ZerocopyErr::InvalidSize,
+            )));
         }
-        let header = data.copy_to_bytes(size_of::<CommitMeta>());
-        let header = CommitMeta::try_read_from_bytes(&header)?;
+        let header = data.copy_to_bytes(size);
+        let header = CommitMeta::from_bytes(&header).map_err(CommitValidationErr::CorruptHeader)?;
+
+        let metadata = if header.has_metadata() {
+            if data.remaining() < size_of::<u32>() {
+                return Err(Culprit::new(CommitValidationErr::CorruptMetadata));
+            }
+            let len = data.get_u32() as usize;
+            if data.remaining() < len {
+                return Err(Culprit::new(CommitValidationErr::CorruptMetadata));
+            }
+            let metadata = CommitMetadata::decode(data.copy_to_bytes(len))
+                .or_ctx(|_| CommitValidationErr::CorruptMetadata)?;
+            Some(metadata)
+        } else {
+            None
+        };
 
-        Ok(Self { header, grafts: data })
+        Ok(Self { header, metadata, grafts: data })
     }
 
     pub fn iter_grafts(&self) -> GraftIter<T> {
@@ -309,7 +483,16 @@ impl<T: Buf + Clone> Commit<T> {
 impl Commit<BytesVec> {
     pub fn into_payload(self) -> PutPayload {
         let header = Bytes::copy_from_slice(self.header.as_bytes());
-        once(header).chain(self.grafts).collect()
+        let metadata = self.metadata.map(|metadata| {
+            let len = metadata.encoded_len() as u32;
+            let mut buf = Vec::with_capacity(size_of::<u32>() + len as usize);
+            buf.extend_from_slice(&len.to_be_bytes());
+            metadata
+                .encode(&mut buf)
+                .expect("buffer has sufficient capacity");
+            Bytes::from(buf)
+        });
+        once(header).chain(metadata).chain(self.grafts).collect()
     }
 }
 
@@ -395,6 +578,11 @@ mod tests {
         let (parsed_vid, parsed_lsn) = parse_commit_key(&path).unwrap();
         assert_eq!(parsed_vid, vid);
         assert_eq!(parsed_lsn, lsn);
+        assert!(parse_checkpoint_key(&path).is_err());
+
+        let path = checkpoint_key_path(&vid, lsn);
+        assert_eq!(parse_checkpoint_key(&path).unwrap(), (vid, lsn));
+        assert!(parse_commit_key(&path).is_err());
     }
 
     #[test]
@@ -405,4 +593,79 @@ mod tests {
         // we can only compare their millisecond values, since nanos are truncated
         assert_eq!(millis, time_to_millis(round_trip));
     }
+
+    #[test]
+    fn test_commit_meta_v1_compat() {
+        let meta = CommitMeta::new(
+            VolumeId::random(),
+            ClientId::random(),
+            LSN::new(3),
+            LSN::FIRST,
+            PageCount::new(10),
+            SystemTime::now(),
+        );
+
+        // build a commit using the v1 header layout
+        let mut commit = meta.as_bytes()[..COMMIT_META_V1_SIZE].to_vec();
+        commit[..4].copy_from_slice(CommitMagic::MagicV1.as_bytes());
+        let mut builder = CommitBuilder::new_with_capacity(meta.clone(), 1);
+        builder.write_graft(SegmentId::random(), Bytes::from_static(b"graft"));
+        let grafts = builder.build().grafts.into_bytes();
+        commit.extend_from_slice(&grafts);
+
+        let decoded = Commit::from_bytes(Bytes::from(commit)).unwrap();
+        assert_eq!(decoded.meta(), &meta);
+        assert_eq!(decoded.meta().page_size(), PageSize::DEFAULT);
+        assert_eq!(decoded.grafts, grafts);
+
+        // the current layout round trips
+        let meta = meta.with_page_size(PageSize::Kb32);
+        let decoded = CommitMeta::from_bytes(meta.as_bytes()).unwrap();
+        assert_eq!(decoded, meta);
+    }
+
+    #[test]
+    fn test_commit_metadata_roundtrip() {
+        let meta = CommitMeta::new(
+            VolumeId::random(),
+            ClientId::random(),
+            LSN::new(3),
+            LSN::FIRST,
+            PageCount::new(10),
+            SystemTime::now(),
+        );
+        let sid = SegmentId::random();
+        let build = |metadata| {
+            let mut builder =
+                CommitBuilder::new_with_capacity(meta.clone(), 1).with_metadata(metadata);
+            let graft = splinter_rs::Splinter::from_iter([1u32]).serialize_to_bytes();
+            builder.write_graft(sid.clone(), graft);
+            let payload: Bytes = builder.build().into_payload().into();
+            Commit::from_bytes(payload).unwrap()
+        };
+
+        // commits without metadata don't set the flag
+        let decoded = build(None);
+        assert!(!decoded.meta().has_metadata());
+        assert_eq!(decoded.metadata(), None);
+        assert_eq!(decoded.meta(), &meta);
+
+        // empty metadata isn't stored
+        let decoded = build(Some(CommitMetadata::default()));
+        assert!(!decoded.meta().has_metadata());
+
+        let metadata = CommitMetadata {
+            message: "hello".into(),
+            author: "carl".into(),
+            json: r#"{"a":1}"#.into(),
+        };
+        let decoded = build(Some(metadata.clone()));
+        assert!(decoded.meta().has_metadata());
+        assert_eq!(decoded.metadata(), Some(&metadata));
+
+        // the grafts follow the metadata
+        let grafts: Vec<_> = decoded.iter_grafts().collect::<Result<_, _>>().unwrap();
+        assert_eq!(grafts.len(), 1);
+        assert_eq!(grafts[0].0, sid);
+    }
 }
@@ -0,0 +1,955 @@
+//! Garbage collection of objects which are no longer reachable from any
+//! Volume.
+//!
+//! Each Volume retains every commit and checkpoint starting at its
+//! *retention floor*: the checkpoint of the Volume's latest snapshot, or the
+//! checkpoint of the oldest commit made within the retention period if that is
+//! older. Every snapshot committed within the retention period, along with the
+//! latest snapshot, thus remains readable. Commits and checkpoints before the
+//! floor are unreachable, as are segments which aren't referenced by any
+//! retained commit or checkpoint. Segments which were uploaded but never
+//! committed are also unreachable.
+//!
+//! Deleted Volumes are purged once their undelete window expires: every commit
+//! and checkpoint other than the Volume's tombstone commit becomes unreachable,
+//! along with any segments which only they reference. Tombstone commits are
+//! never collected so that commits to a purged Volume continue to be rejected.
+//!
+//! Forked Volumes reference segments inherited from their source, which remain
+//! reachable while any retained commit or checkpoint of the fork references
+//! them, even once the source is purged. Fork records are never collected.
+//!
+//! Tagged snapshots are retained regardless of the retention period, along
+//! with every commit and checkpoint after the tagged snapshot's checkpoint.
+//! Tags of purged Volumes are collected with the rest of the Volume.
+//!
+//! Only the latest version of each Volume's labels is retained.
+//!
+//! Objects are only deleted once the garbage collector has observed them to be
+//! continuously unreachable for the grace period. This gives in-flight commits
+//! time to land after their segments are uploaded, and allows pagestores to
+//! keep reading from segments referenced by checkpoints they've cached.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    convert::Infallible,
+    sync::Arc,
+    time::SystemTime,
+};
+
+use culprit::{Culprit, ResultExt};
+use futures::TryStreamExt;
+use graft_core::{SegmentId, VolumeId, lsn::LSN, tag_name::TagName};
+use measured::{Counter, CounterVec, MetricGroup};
+use object_store::{ObjectMeta, ObjectStore, path::Path};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::{Duration, Instant, sleep};
+
+use crate::{
+    metrics::labels::ResultLabelSet,
+    supervisor::{SupervisedTask, TaskCfg, TaskCtx},
+};
+
+use super::{
+    catalog::{VolumeCatalog, VolumeCatalogErr},
+    commit::{parse_checkpoint_key, parse_commit_key},
+    store::{
+        DEFAULT_UNDELETE_WINDOW, VolumeStore, VolumeStoreErr, parse_labels_key, parse_tag_key,
+    },
+    updater::{UpdateErr, VolumeCatalogUpdater},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GarbageCollectorConfig {
+    /// how often to collect garbage
+    pub interval_secs: u64,
+
+    /// how long an object must be unreachable before it's deleted
+    pub grace_period_secs: u64,
+
+    /// snapshots committed within this period remain readable
+    pub retention_secs: u64,
+
+    /// report unreachable objects without deleting them
+    pub dry_run: bool,
+}
+
+impl Default for GarbageCollectorConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 60 * 60,
+            grace_period_secs: 24 * 60 * 60,
+            retention_secs: 7 * 24 * 60 * 60,
+            dry_run: false,
+        }
+    }
+}
+
+#[derive(MetricGroup)]
+#[metric(new())]
+pub struct GarbageCollectorMetrics {
+    /// Number of garbage collection runs, broken down by result
+    runs: CounterVec<ResultLabelSet>,
+
+    /// Number of deleted commits
+    deleted_commits: Counter,
+
+    /// Number of deleted checkpoints
+    deleted_checkpoints: Counter,
+
+    /// Number of deleted segments
+    deleted_segments: Counter,
+
+    /// Number of deleted label versions
+    deleted_labels: Counter,
+
+    /// Number of deleted tags
+    deleted_tags: Counter,
+
+    /// Number of bytes reclaimed by deleting objects
+    deleted_bytes: Counter,
+}
+
+impl Default for GarbageCollectorMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GcErr {
+    #[error("object store error")]
+    ObjectStoreErr,
+
+    #[error("failed to update catalog")]
+    UpdateErr(#[from] UpdateErr),
+
+    #[error("volume store error")]
+    StoreErr(#[from] VolumeStoreErr),
+
+    #[error("catalog error")]
+    CatalogErr(#[from] VolumeCatalogErr),
+}
+
+impl From<object_store::Error> for GcErr {
+    fn from(_: object_store::Error) -> Self {
+        GcErr::ObjectStoreErr
+    }
+}
+
+/// The outcome of a garbage collection run. In dry-run mode the deleted counts
+/// describe the objects which would have been deleted.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GcReport {
+    pub dry_run: bool,
+
+    /// the number of Volumes with at least one commit, excluding purged
+    /// Volumes
+    pub volumes: usize,
+
+    /// the number of deleted Volumes being purged
+    pub purged_volumes: usize,
+
+    /// the number of segments referenced by a retained commit or checkpoint
+    pub live_segments: usize,
+
+    /// the number of unreachable objects which are still within the grace
+    /// period
+    pub pending: usize,
+
+    pub deleted_commits: usize,
+    pub deleted_checkpoints: usize,
+    pub deleted_segments: usize,
+    pub deleted_labels: usize,
+    pub deleted_tags: usize,
+    pub deleted_bytes: u64,
+}
+
+#[derive(Debug, Clone)]
+enum Garbage {
+    Commit(VolumeId, LSN),
+    Checkpoint(VolumeId, LSN),
+    Segment,
+    Labels,
+    Tag,
+}
+
+/// The objects in the store, grouped by kind
+#[derive(Default)]
+struct Listing {
+    commits: BTreeMap<VolumeId, BTreeMap<LSN, ObjectMeta>>,
+    checkpoints: BTreeMap<VolumeId, BTreeMap<LSN, ObjectMeta>>,
+    segments: HashMap<SegmentId, ObjectMeta>,
+    labels: BTreeMap<VolumeId, BTreeMap<u64, ObjectMeta>>,
+    tags: BTreeMap<VolumeId, BTreeMap<TagName, ObjectMeta>>,
+}
+
+pub struct GarbageCollector {
+    metrics: Arc<GarbageCollectorMetrics>,
+
+    /// the object store containing commits, checkpoints, and segments
+    objstore: Arc<dyn ObjectStore>,
+    store: Arc<VolumeStore>,
+    catalog: VolumeCatalog,
+    updater: VolumeCatalogUpdater,
+
+    grace_period: Duration,
+    retention: Duration,
+    undelete_window: Duration,
+
+    /// tracks when each unreachable object was first observed
+    unreachable: HashMap<Path, Instant>,
+}
+
+impl GarbageCollector {
+    pub fn new(
+        metrics: Arc<GarbageCollectorMetrics>,
+        objstore: Arc<dyn ObjectStore>,
+        store: Arc<VolumeStore>,
+        catalog: VolumeCatalog,
+        updater: VolumeCatalogUpdater,
+        grace_period: Duration,
+        retention: Duration,
+    ) -> Self {
+        Self {
+            metrics,
+            objstore,
+            store,
+            catalog,
+            updater,
+            grace_period,
+            retention,
+            undelete_window: DEFAULT_UNDELETE_WINDOW,
+            unreachable: HashMap::default(),
+        }
+    }
+
+    /// Set how long a deleted Volume may be undeleted before it's purged
+    pub fn with_undelete_window(self, undelete_window: Duration) -> Self {
+        Self { undelete_window, ..self }
+    }
+
+    /// Find and delete unreachable objects. In dry-run mode unreachable
+    /// objects are reported but not deleted.
+    #[tracing::instrument(name = "garbage collection", skip(self))]
+    pub async fn collect(&mut self, dry_run: bool) -> Result<GcReport, Culprit<GcErr>> {
+        let result = self.collect_inner(dry_run).await;
+        self.metrics.runs.inc((&result).into());
+        result
+    }
+
+    async fn collect_inner(&mut self, dry_run: bool) -> Result<GcReport, Culprit<GcErr>> {
+        let now = Instant::now();
+        let horizon = SystemTime::now()
+            .checked_sub(self.retention)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+
+        let mut listing = self.list().await?;
+        let mut report = GcReport { dry_run, ..GcReport::default() };
+
+        let mut garbage: Vec<(Garbage, ObjectMeta)> = vec![];
+        let mut live: HashSet<SegmentId> = HashSet::default();
+
+        // every commit and checkpoint of a purged Volume other than its
+        // tombstone commit is unreachable
+        let vids: Vec<VolumeId> = listing.commits.keys().cloned().collect();
+        for vid in &vids {
+            let commits = &listing.commits[vid];
+            self.updater
+                .update_catalog_from_store(
+                    &self.store,
+                    &self.catalog,
+                    vid,
+                    commits.keys().max().copied(),
+                )
+                .await
+                .or_into_ctx()?;
+            let Some(latest) = self.catalog.latest_snapshot(vid).or_into_ctx()? else {
+                continue;
+            };
+            let deleted_at = latest.system_time();
+            if !latest.is_tombstone() || deleted_at + self.undelete_window > SystemTime::now() {
+                continue;
+            }
+            let mut commits = listing.commits.remove(vid).unwrap_or_default();
+            commits.remove(&latest.lsn());
+            let checkpoints = listing.checkpoints.remove(vid).unwrap_or_default();
+            let labels = listing.labels.remove(vid).unwrap_or_default();
+            let tags = listing.tags.remove(vid).unwrap_or_default();
+            if commits.is_empty() && checkpoints.is_empty() && labels.is_empty() && tags.is_empty()
+            {
+                continue;
+            }
+            tracing::trace!(?vid, ?deleted_at, "purging deleted volume");
+            report.purged_volumes += 1;
+            for (lsn, meta) in commits {
+                garbage.push((Garbage::Commit(vid.clone(), lsn), meta));
+            }
+            for (lsn, meta) in checkpoints {
+                garbage.push((Garbage::Checkpoint(vid.clone(), lsn), meta));
+            }
+            for meta in labels.into_values() {
+                garbage.push((Garbage::Labels, meta));
+            }
+            for meta in tags.into_values() {
+                garbage.push((Garbage::Tag, meta));
+            }
+        }
+
+        // label versions which have been replaced are unreachable
+        for mut versions in std::mem::take(&mut listing.labels).into_values() {
+            versions.pop_last();
+            for meta in versions.into_values() {
+                garbage.push((Garbage::Labels, meta));
+            }
+        }
+
+        report.volumes = listing.commits.len();
+
+        for (vid, commits) in &listing.commits {
+            let mut tagged = vec![];
+            for tag in listing
+                .tags
+                .get(vid)
+                .into_iter()
+                .flat_map(|tags| tags.keys())
+            {
+                // the tag may have been deleted since it was listed
+                if let Some(lsn) = self.store.get_tag(vid, tag).await.or_into_ctx()? {
+                    tagged.push(lsn);
+                }
+            }
+            let floor = self.retention_floor(vid, commits, &tagged, horizon).await?;
+            tracing::trace!(?vid, ?floor, "resolved retention floor");
+
+            for (&lsn, meta) in commits.range(..floor) {
+                garbage.push((Garbage::Commit(vid.clone(), lsn), meta.clone()));
+            }
+            for result in self.catalog.scan_segments(vid, &(floor..)) {
+                live.insert(result.or_into_ctx()?.0.sid().clone());
+            }
+
+            let checkpoints = listing.checkpoints.remove(vid).unwrap_or_default();
+            for (lsn, meta) in checkpoints {
+                if lsn < floor {
+                    garbage.push((Garbage::Checkpoint(vid.clone(), lsn), meta));
+                    continue;
+                }
+                self.updater
+                    .update_checkpoint_from_store(&self.store, &self.catalog, vid, lsn)
+                    .await
+                    .or_into_ctx()?;
+                for result in self.catalog.scan_checkpoint(vid, lsn) {
+                    live.insert(result.or_into_ctx()?.0.sid().clone());
+                }
+            }
+        }
+
+        // checkpoints belonging to Volumes without any commits are unreachable
+        for (vid, checkpoints) in listing.checkpoints {
+            for (lsn, meta) in checkpoints {
+                garbage.push((Garbage::Checkpoint(vid.clone(), lsn), meta));
+            }
+        }
+
+        report.live_segments = live.len();
+        for (sid, meta) in listing.segments {
+            if !live.contains(&sid) {
+                garbage.push((Garbage::Segment, meta));
+            }
+        }
+
+        // forget objects which are no longer unreachable
+        let paths: HashSet<&Path> = garbage.iter().map(|(_, meta)| &meta.location).collect();
+        self.unreachable.retain(|path, _| paths.contains(path));
+
+        // commits are deleted in order so that each Volume's remaining commits
+        // are always contiguous
+        for (kind, meta) in garbage {
+            let since = *self.unreachable.entry(meta.location.clone()).or_insert(now);
+            if now.duration_since(since) < self.grace_period {
+                report.pending += 1;
+                continue;
+            }
+
+            if !dry_run {
+                self.delete(&kind, &meta.location).await?;
+                self.unreachable.remove(&meta.location);
+            }
+
+            report.deleted_bytes += meta.size;
+            match kind {
+                Garbage::Commit(..) => report.deleted_commits += 1,
+                Garbage::Checkpoint(..) => report.deleted_checkpoints += 1,
+                Garbage::Segment => report.deleted_segments += 1,
+                Garbage::Labels => report.deleted_labels += 1,
+                Garbage::Tag => report.deleted_tags += 1,
+            }
+        }
+
+        if !dry_run {
+            self.metrics
+                .deleted_commits
+                .inc_by(report.deleted_commits as u64);
+            self.metrics
+                .deleted_checkpoints
+                .inc_by(report.deleted_checkpoints as u64);
+            self.metrics
+                .deleted_segments
+                .inc_by(report.deleted_segments as u64);
+            self.metrics
+                .deleted_labels
+                .inc_by(report.deleted_labels as u64);
+            self.metrics.deleted_tags.inc_by(report.deleted_tags as u64);
+            self.metrics.deleted_bytes.inc_by(report.deleted_bytes);
+        }
+
+        Ok(report)
+    }
+
+    /// List every commit, checkpoint, segment, label version, and tag in the
+    /// object store
+    async fn list(&self) -> Result<Listing, Culprit<GcErr>> {
+        let mut listing = Listing::default();
+        let mut list = self.objstore.list(None);
+        while let Some(meta) = list.try_next().await? {
+            if let Ok((vid, lsn)) = parse_commit_key(&meta.location) {
+                listing.commits.entry(vid).or_default().insert(lsn, meta);
+            } else if let Ok((vid, lsn)) = parse_checkpoint_key(&meta.location) {
+                listing
+                    .checkpoints
+                    .entry(vid)
+                    .or_default()
+                    .insert(lsn, meta);
+            } else if let Ok(sid) = meta.location.as_ref().parse::<SegmentId>() {
+                listing.segments.insert(sid, meta);
+            } else if let Some((vid, version)) = parse_labels_key(&meta.location) {
+                listing.labels.entry(vid).or_default().insert(version, meta);
+            } else if let Some((vid, tag)) = parse_tag_key(&meta.location) {
+                listing.tags.entry(vid).or_default().insert(tag, meta);
+            }
+        }
+        Ok(listing)
+    }
+
+    /// Returns the LSN of the oldest commit and checkpoint which must be
+    /// retained for the Volume.
+    async fn retention_floor(
+        &self,
+        vid: &VolumeId,
+        commits: &BTreeMap<LSN, ObjectMeta>,
+        tagged: &[LSN],
+        horizon: SystemTime,
+    ) -> Result<LSN, Culprit<GcErr>> {
+        self.updater
+            .update_catalog_from_store(
+                &self.store,
+                &self.catalog,
+                vid,
+                commits.keys().max().copied(),
+            )
+            .await
+            .or_into_ctx()?;
+
+        let Some(latest) = self.catalog.latest_snapshot(vid).or_into_ctx()? else {
+            // retain everything if we can't load the Volume
+            return Ok(LSN::FIRST);
+        };
+        let mut floor = latest.checkpoint();
+
+        for &lsn in commits.keys().filter(|&&lsn| lsn < floor) {
+            match self.catalog.snapshot(vid.clone(), lsn).or_into_ctx()? {
+                Some(snapshot) if snapshot.system_time() < horizon => {}
+                Some(snapshot) => {
+                    floor = floor.min(snapshot.checkpoint());
+                    break;
+                }
+                None => {
+                    // retain everything if the catalog is missing commits
+                    tracing::warn!(?vid, ?lsn, "catalog is missing commit");
+                    return Ok(LSN::FIRST);
+                }
+            }
+        }
+
+        // tagged snapshots are retained regardless of when they were committed
+        for &lsn in tagged {
+            if lsn >= floor || !commits.contains_key(&lsn) {
+                continue;
+            }
+            match self.catalog.snapshot(vid.clone(), lsn).or_into_ctx()? {
+                Some(snapshot) => floor = floor.min(snapshot.checkpoint()),
+                None => {
+                    tracing::warn!(?vid, ?lsn, "catalog is missing tagged commit");
+                    return Ok(LSN::FIRST);
+                }
+            }
+        }
+
+        Ok(floor)
+    }
+
+    async fn delete(&self, kind: &Garbage, path: &Path) -> Result<(), Culprit<GcErr>> {
+        tracing::debug!(?kind, %path, "deleting unreachable object");
+        match self.objstore.delete(path).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut batch = self.catalog.batch_insert();
+        match kind {
+            Garbage::Commit(vid, lsn) => batch.remove_commit(vid.clone(), *lsn).or_into_ctx()?,
+            Garbage::Checkpoint(vid, lsn) => {
+                batch.remove_checkpoint(vid.clone(), *lsn).or_into_ctx()?
+            }
+            Garbage::Segment | Garbage::Labels | Garbage::Tag => return Ok(()),
+        }
+        batch.commit().or_into_ctx()
+    }
+}
+
+/// Periodically collects garbage
+pub struct GarbageCollectorTask {
+    gc: GarbageCollector,
+    interval: Duration,
+    dry_run: bool,
+}
+
+impl GarbageCollectorTask {
+    pub fn new(gc: GarbageCollector, interval: Duration, dry_run: bool) -> Self {
+        Self { gc, interval, dry_run }
+    }
+}
+
+impl SupervisedTask for GarbageCollectorTask {
+    type Err = Infallible;
+
+    fn cfg(&self) -> TaskCfg {
+        TaskCfg { name: "garbage-collector" }
+    }
+
+    async fn run(mut self, ctx: TaskCtx) -> Result<(), Culprit<Infallible>> {
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = ctx.wait_shutdown() => break,
+
+                _ = sleep(self.interval) => {
+                    match self.gc.collect(self.dry_run).await {
+                        Ok(report) => tracing::info!(?report, "collected garbage"),
+                        Err(err) => tracing::error!("garbage collection failed\n{err:?}"),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graft_core::{gid::ClientId, page_count::PageCount};
+    use graft_proto::metastore::v1::VolumeLabels;
+    use object_store::{PutPayload, memory::InMemory};
+    use splinter_rs::Splinter;
+
+    use crate::volume::commit::{CommitBuilder, CommitMeta, commit_key_path};
+
+    use super::*;
+
+    const SEGMENT: &[u8] = b"segment";
+
+    async fn put_segment(objstore: &InMemory) -> SegmentId {
+        let sid = SegmentId::random();
+        objstore
+            .put(&Path::from(sid.pretty()), PutPayload::from_static(SEGMENT))
+            .await
+            .unwrap();
+        sid
+    }
+
+    #[graft_test::test]
+    async fn test_gc() {
+        let objstore = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(objstore.clone()));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let updater = VolumeCatalogUpdater::new(8);
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // each commit overwrites page 0 with a new segment, and the volume is
+        // checkpointed at lsn 3
+        let mut segments = vec![];
+        for lsn in 1..=4 {
+            let sid = put_segment(&objstore).await;
+            let checkpoint = if lsn == 4 { LSN::new(3) } else { LSN::FIRST };
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                LSN::new(lsn),
+                checkpoint,
+                PageCount::new(1),
+                SystemTime::now(),
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            commit.write_graft(
+                sid.clone(),
+                Splinter::from_iter([0u32]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+            segments.push(sid);
+
+            if lsn == 3 {
+                let snapshot = updater
+                    .snapshot(&store, &catalog, &vid, None)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                updater
+                    .write_checkpoint(&store, &catalog, &snapshot)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        // a segment which was never committed
+        put_segment(&objstore).await;
+
+        let count_objects = async || {
+            objstore
+                .list(None)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+                .len()
+        };
+        assert_eq!(count_objects().await, 10);
+
+        let mut gc = GarbageCollector::new(
+            Arc::new(GarbageCollectorMetrics::default()),
+            objstore.clone(),
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+            Duration::from_secs(3600),
+            Duration::ZERO,
+        );
+
+        // commits 1 and 2 along with their segments and the orphaned segment
+        // are unreachable, but are within the grace period
+        let expected = GcReport {
+            dry_run: true,
+            volumes: 1,
+            live_segments: 2,
+            pending: 5,
+            ..GcReport::default()
+        };
+        assert_eq!(gc.collect(true).await.unwrap(), expected);
+
+        // once the grace period elapses a dry run reports the garbage
+        tokio::time::advance(Duration::from_secs(3600)).await;
+        let mut deleted_bytes = 3 * SEGMENT.len() as u64;
+        for lsn in [1, 2] {
+            let path = commit_key_path(&vid, LSN::new(lsn));
+            deleted_bytes += objstore.head(&path).await.unwrap().size;
+        }
+        let expected = GcReport {
+            pending: 0,
+            deleted_commits: 2,
+            deleted_segments: 3,
+            deleted_bytes,
+            ..expected
+        };
+        assert_eq!(gc.collect(true).await.unwrap(), expected);
+        assert_eq!(count_objects().await, 10);
+
+        // and a real run deletes it
+        let expected = GcReport { dry_run: false, ..expected };
+        assert_eq!(gc.collect(false).await.unwrap(), expected);
+        assert_eq!(count_objects().await, 5);
+        for sid in &segments[2..] {
+            objstore.head(&Path::from(sid.pretty())).await.unwrap();
+        }
+        assert!(!catalog.contains_snapshot(vid.clone(), LSN::new(2)).unwrap());
+
+        // nothing is left to collect
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(
+            report.pending + report.deleted_commits + report.deleted_segments,
+            0
+        );
+
+        // the volume can still be loaded into an empty catalog
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let snapshot = updater
+            .snapshot(&store, &catalog, &vid, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(snapshot.lsn(), LSN::new(4));
+        assert!(
+            catalog
+                .contains_range(&vid, &(LSN::new(3)..=LSN::new(4)))
+                .unwrap()
+        );
+    }
+
+    #[graft_test::test]
+    async fn test_gc_deleted_volume() {
+        let objstore = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(objstore.clone()));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        // two volumes with two commits each, each writing to a new segment
+        let cid = ClientId::random();
+        let vids = [VolumeId::random(), VolumeId::random()];
+        for vid in &vids {
+            for lsn in 1..=2 {
+                let meta = CommitMeta::new(
+                    vid.clone(),
+                    cid.clone(),
+                    LSN::new(lsn),
+                    LSN::FIRST,
+                    PageCount::new(1),
+                    SystemTime::now(),
+                );
+                let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+                commit.write_graft(
+                    put_segment(&objstore).await,
+                    Splinter::from_iter([lsn as u32]).serialize_to_bytes(),
+                );
+                store.commit(commit.build()).await.unwrap();
+            }
+        }
+        for vid in &vids {
+            for version in 1..=2 {
+                let labels = VolumeLabels { version, ..VolumeLabels::default() };
+                store.put_labels(vid, &labels).await.unwrap();
+            }
+        }
+        let tag: TagName = "v1".parse().unwrap();
+        for vid in &vids {
+            store.put_tag(vid, &tag, LSN::FIRST).await.unwrap();
+        }
+        let meta = CommitMeta::new(
+            vids[0].clone(),
+            cid.clone(),
+            LSN::new(3),
+            LSN::FIRST,
+            PageCount::new(1),
+            SystemTime::now(),
+        )
+        .with_tombstone(true);
+        store
+            .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+            .await
+            .unwrap();
+
+        let mut gc = GarbageCollector::new(
+            Arc::new(GarbageCollectorMetrics::default()),
+            objstore.clone(),
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+            Duration::ZERO,
+            Duration::ZERO,
+        );
+
+        // nothing is purged within the undelete window, but replaced label
+        // versions are collected
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(report.volumes, 2);
+        assert_eq!(report.purged_volumes, 0);
+        assert_eq!(report.deleted_commits, 0);
+        assert_eq!(report.deleted_labels, 2);
+        assert_eq!(report.live_segments, 4);
+        assert_eq!(
+            store.get_labels(&vids[1]).await.unwrap().unwrap().version,
+            2
+        );
+
+        // once the window expires the deleted volume is purged, but its
+        // tombstone commit remains
+        let mut gc = gc.with_undelete_window(Duration::ZERO);
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(report.volumes, 1);
+        assert_eq!(report.purged_volumes, 1);
+        assert_eq!(report.deleted_commits, 2);
+        assert_eq!(report.deleted_segments, 2);
+        assert_eq!(report.deleted_labels, 1);
+        assert_eq!(report.deleted_tags, 1);
+        assert_eq!(report.live_segments, 2);
+        assert_eq!(store.get_tag(&vids[0], &tag).await.unwrap(), None);
+        assert_eq!(
+            store.get_tag(&vids[1], &tag).await.unwrap(),
+            Some(LSN::FIRST)
+        );
+        assert_eq!(
+            store.oldest_commit(&vids[0]).await.unwrap(),
+            Some(LSN::new(3))
+        );
+        assert_eq!(
+            store.oldest_commit(&vids[1]).await.unwrap(),
+            Some(LSN::FIRST)
+        );
+
+        // purged volumes aren't reported again
+        assert_eq!(gc.collect(false).await.unwrap().purged_volumes, 0);
+    }
+
+    #[graft_test::test]
+    async fn test_gc_tagged_snapshot() {
+        let objstore = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(objstore.clone()));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        // each commit overwrites page 0 with a new segment and is its own
+        // checkpoint
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        for lsn in 1..=3 {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                LSN::new(lsn),
+                LSN::new(lsn),
+                PageCount::new(1),
+                SystemTime::now(),
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            commit.write_graft(
+                put_segment(&objstore).await,
+                Splinter::from_iter([0u32]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+        }
+        let tag: TagName = "release".parse().unwrap();
+        store.put_tag(&vid, &tag, LSN::FIRST).await.unwrap();
+
+        let mut gc = GarbageCollector::new(
+            Arc::new(GarbageCollectorMetrics::default()),
+            objstore.clone(),
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+            Duration::ZERO,
+            Duration::ZERO,
+        );
+
+        // the tagged snapshot is retained outside of the retention period
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(report.deleted_commits, 0);
+        assert_eq!(report.deleted_segments, 0);
+        assert_eq!(report.live_segments, 3);
+        assert_eq!(store.oldest_commit(&vid).await.unwrap(), Some(LSN::FIRST));
+
+        // until the tag is deleted
+        store.delete_tag(&vid, &tag).await.unwrap();
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(report.deleted_commits, 2);
+        assert_eq!(report.deleted_segments, 2);
+        assert_eq!(report.live_segments, 1);
+        assert_eq!(store.oldest_commit(&vid).await.unwrap(), Some(LSN::new(3)));
+    }
+
+    #[graft_test::test]
+    async fn test_gc_compacted_checkpoint() {
+        let objstore = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(objstore.clone()));
+        let updater = VolumeCatalogUpdater::new(8);
+
+        // two metastores share the store, but each has its own catalog
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let other = VolumeCatalog::open_temporary().unwrap();
+
+        // commits 1 through 3 each write a page to a new segment, the volume is
+        // checkpointed at lsn 3, and commit 4 overwrites page 0
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let mut segments = vec![];
+        for lsn in 1..=4 {
+            let sid = put_segment(&objstore).await;
+            let checkpoint = if lsn == 4 { LSN::new(3) } else { LSN::FIRST };
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                LSN::new(lsn),
+                checkpoint,
+                PageCount::new(3),
+                SystemTime::now(),
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            commit.write_graft(
+                sid.clone(),
+                Splinter::from_iter([(lsn as u32 - 1) % 3]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+            segments.push(sid);
+
+            if lsn == 3 {
+                let snapshot = updater
+                    .snapshot(&store, &catalog, &vid, None)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                updater
+                    .write_checkpoint(&store, &catalog, &snapshot)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        // the other metastore collects garbage, caching the checkpoint
+        let mut gc = GarbageCollector::new(
+            Arc::new(GarbageCollectorMetrics::default()),
+            objstore.clone(),
+            store.clone(),
+            other.clone(),
+            VolumeCatalogUpdater::new(8),
+            Duration::ZERO,
+            Duration::ZERO,
+        );
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(report.deleted_commits, 2);
+        assert_eq!(report.deleted_segments, 0);
+        assert_eq!(report.live_segments, 4);
+
+        // the first metastore compacts the checkpoint into a single segment
+        let compacted = put_segment(&objstore).await;
+        let snapshot = updater
+            .snapshot(&store, &catalog, &vid, Some(LSN::new(3)))
+            .await
+            .unwrap()
+            .unwrap();
+        updater
+            .write_compacted_checkpoint(
+                &store,
+                &catalog,
+                &snapshot,
+                vec![(compacted.clone(), Splinter::from_iter([0u32, 1, 2]))],
+            )
+            .await
+            .unwrap();
+
+        // the other metastore notices the rewritten checkpoint, so the
+        // compacted segment is retained while the segments it replaced are
+        // collected, other than commit 3's which is above the retention floor
+        let report = gc.collect(false).await.unwrap();
+        assert_eq!(report.deleted_segments, 2);
+        assert_eq!(report.live_segments, 3);
+        objstore
+            .head(&Path::from(compacted.pretty()))
+            .await
+            .unwrap();
+        for sid in &segments[..2] {
+            assert!(objstore.head(&Path::from(sid.pretty())).await.is_err());
+        }
+        for sid in &segments[2..] {
+            objstore.head(&Path::from(sid.pretty())).await.unwrap();
+        }
+    }
+}
@@ -120,3 +120,34 @@ impl Clone for SegmentKey {
         }
     }
 }
+
+#[derive(KnownLayout, Immutable, TryFromBytes, IntoBytes, Unaligned)]
+#[repr(C, packed)]
+pub struct VolumeSegmentKey {
+    vid: VolumeId,
+    sid: SegmentId,
+}
+
+impl VolumeSegmentKey {
+    pub fn new(vid: VolumeId, sid: SegmentId) -> Self {
+        Self { vid, sid }
+    }
+}
+
+impl AsRef<[u8]> for VolumeSegmentKey {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Display for VolumeSegmentKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.vid, self.sid)
+    }
+}
+
+impl Debug for VolumeSegmentKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
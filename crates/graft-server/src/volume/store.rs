@@ -1,7 +1,9 @@
 use std::{
+    collections::BTreeMap,
     future::{self},
     ops::RangeBounds,
     sync::Arc,
+    time::Duration,
 };
 
 use bytes::Bytes;
@@ -12,16 +14,60 @@ use futures::{
 };
 use graft_core::{
     VolumeId,
+    gid::GidParseErr,
     lsn::{LSN, LSNRangeExt},
+    tag_name::TagName,
+    volume_name::VolumeName,
 };
-use object_store::{Attributes, ObjectStore, PutMode, PutOptions, TagSet};
+use graft_proto::metastore::v1::{VolumeFork, VolumeLabels};
+use object_store::{
+    Attributes, ObjectMeta, ObjectStore, PutMode, PutOptions, PutPayload, TagSet, path::Path,
+};
+use prost::Message;
 
 use crate::{bytes_vec::BytesVec, volume::commit::CommitValidationErr};
 
-use super::commit::{Commit, CommitKeyParseErr, commit_key_path};
+use super::commit::{
+    Commit, CommitKeyParseErr, checkpoint_key_path, commit_key_path, commit_key_path_prefix,
+    parse_commit_key,
+};
 
 const REPLAY_CONCURRENCY: usize = 5;
 
+/// The default amount of time a deleted Volume may be undeleted before it's
+/// purged
+pub const DEFAULT_UNDELETE_WINDOW: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Identifies the contents of a checkpoint object. Compacting a Volume
+/// rewrites the checkpoint at an existing LSN, so a cached checkpoint is only
+/// valid while its version matches the object in the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointVersion(String);
+
+impl CheckpointVersion {
+    pub fn new(version: impl Into<String>) -> Self {
+        Self(version.into())
+    }
+
+    fn from_meta(meta: &ObjectMeta) -> Self {
+        match meta.e_tag.as_ref().or(meta.version.as_ref()) {
+            Some(version) => Self(version.clone()),
+            // fall back to the object's modification time for stores which
+            // don't version objects
+            None => Self(format!(
+                "{}-{}",
+                meta.last_modified.timestamp_micros(),
+                meta.size
+            )),
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum VolumeStoreErr {
     #[error("object store error")]
@@ -38,6 +84,18 @@ pub enum VolumeStoreErr {
 
     #[error("Failed to parse commit key: {0}")]
     CommitKeyParseErr(#[from] CommitKeyParseErr),
+
+    #[error("corrupt volume name entry: {0}")]
+    CorruptName(#[from] GidParseErr),
+
+    #[error("corrupt volume labels")]
+    CorruptLabels,
+
+    #[error("corrupt volume fork record")]
+    CorruptFork,
+
+    #[error("corrupt volume tag")]
+    CorruptTag,
 }
 
 impl From<object_store::Error> for VolumeStoreErr {
@@ -50,6 +108,92 @@ impl From<object_store::Error> for VolumeStoreErr {
     }
 }
 
+/// Names are stored as one object per name containing the named `VolumeId`
+pub fn name_key_path(name: &VolumeName) -> Path {
+    Path::from_iter(["names", name.namespace(), name.name()])
+}
+
+/// Returns the offset to list the keys under `root` from, which sorts before
+/// the keys of every Volume whose ID starts with `prefix` and after
+/// `start_after`
+fn list_offset(root: &Path, prefix: &str, start_after: Option<Path>) -> Path {
+    // drop the last character of the prefix so the offset also sorts before
+    // a key which exactly matches the prefix
+    let mut chars = prefix.chars();
+    chars.next_back();
+    let offset = if chars.as_str().is_empty() {
+        root.clone()
+    } else {
+        root.child(chars.as_str())
+    };
+    start_after.map_or(offset.clone(), |after| after.max(offset))
+}
+
+/// Limit a stream of `VolumeId`s listed in order to those starting with `prefix`
+fn with_prefix<'a, S>(
+    volumes: S,
+    prefix: &'a str,
+) -> impl Stream<Item = Result<VolumeId, Culprit<VolumeStoreErr>>> + 'a
+where
+    S: Stream<Item = Result<VolumeId, Culprit<VolumeStoreErr>>> + 'a,
+{
+    volumes
+        .try_skip_while(move |vid| future::ready(Ok(vid.pretty().as_str() < prefix)))
+        .try_take_while(move |vid| future::ready(Ok(vid.pretty().starts_with(prefix))))
+}
+
+/// Each version of a Volume's labels is stored as a separate object
+pub fn labels_key_path_prefix(vid: &VolumeId) -> Path {
+    Path::from_iter(["labels", vid.pretty().as_str()])
+}
+
+pub fn labels_key_path(vid: &VolumeId, version: u64) -> Path {
+    labels_key_path_prefix(vid).child(format!("{version:016x}"))
+}
+
+/// Parse a labels key into its `VolumeId` and version, returning None if the
+/// key doesn't refer to a version of a Volume's labels
+pub fn parse_labels_key(key: &Path) -> Option<(VolumeId, u64)> {
+    let mut parts = key.parts();
+    if parts.next()?.as_ref() != "labels" {
+        return None;
+    }
+    let vid = parts.next()?.as_ref().parse().ok()?;
+    let version = u64::from_str_radix(parts.next()?.as_ref(), 16).ok()?;
+    parts.next().is_none().then_some((vid, version))
+}
+
+/// Forked Volumes have a record of the Volume they were forked from
+pub fn fork_key_path(vid: &VolumeId) -> Path {
+    Path::from_iter(["forks", vid.pretty().as_str()])
+}
+
+/// Tags are stored as one object per tag containing the tagged LSN
+pub fn tag_key_path_prefix(vid: &VolumeId) -> Path {
+    Path::from_iter(["tags", vid.pretty().as_str()])
+}
+
+pub fn tag_key_path(vid: &VolumeId, tag: &TagName) -> Path {
+    tag_key_path_prefix(vid).child(tag.as_str())
+}
+
+/// Parse a tag key into its `VolumeId` and tag name, returning None if the key
+/// isn't a tag
+pub fn parse_tag_key(key: &Path) -> Option<(VolumeId, TagName)> {
+    let mut parts = key.parts();
+    if parts.next()?.as_ref() != "tags" {
+        return None;
+    }
+    let vid = parts.next()?.as_ref().parse().ok()?;
+    let tag = parts.next()?.as_ref().parse().ok()?;
+    parts.next().is_none().then_some((vid, tag))
+}
+
+fn decode_tag(bytes: &[u8]) -> Result<LSN, Culprit<VolumeStoreErr>> {
+    let lsn = <[u8; 8]>::try_from(bytes).or_ctx(|_| VolumeStoreErr::CorruptTag)?;
+    LSN::try_from(u64::from_be_bytes(lsn)).or_ctx(|_| VolumeStoreErr::CorruptTag)
+}
+
 pub struct VolumeStore {
     store: Arc<dyn ObjectStore>,
 }
@@ -76,6 +220,312 @@ impl VolumeStore {
         Ok(())
     }
 
+    /// Write a checkpoint to the store, returning its version. Unless
+    /// `replace` is set, an existing checkpoint at the same LSN is left in
+    /// place and `VolumeStoreErr::CommitAlreadyExists` is returned.
+    pub async fn put_checkpoint(
+        &self,
+        checkpoint: Commit<BytesVec>,
+        replace: bool,
+    ) -> Result<Option<CheckpointVersion>, Culprit<VolumeStoreErr>> {
+        let key = checkpoint_key_path(checkpoint.vid(), checkpoint.meta().lsn());
+        let mode = if replace {
+            PutMode::Overwrite
+        } else {
+            PutMode::Create
+        };
+        let result = self
+            .store
+            .put_opts(
+                &key,
+                checkpoint.into_payload(),
+                PutOptions {
+                    mode,
+                    tags: TagSet::default(),
+                    attributes: Attributes::default(),
+                    extensions: Default::default(),
+                },
+            )
+            .await?;
+        Ok(result.e_tag.or(result.version).map(CheckpointVersion))
+    }
+
+    /// Load the checkpoint written at the specified LSN along with its
+    /// version, returning None if no checkpoint exists.
+    pub async fn get_checkpoint(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<(Commit<Bytes>, CheckpointVersion)>, Culprit<VolumeStoreErr>> {
+        match self.store.get(&checkpoint_key_path(vid, lsn)).await {
+            Ok(res) => {
+                let version = CheckpointVersion::from_meta(&res.meta);
+                let commit = Commit::from_bytes(res.bytes().await?).or_into_ctx()?;
+                Ok(Some((commit, version)))
+            }
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Return the version of the checkpoint written at the specified LSN,
+    /// returning None if no checkpoint exists.
+    pub async fn checkpoint_version(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+    ) -> Result<Option<CheckpointVersion>, Culprit<VolumeStoreErr>> {
+        match self.store.head(&checkpoint_key_path(vid, lsn)).await {
+            Ok(meta) => Ok(Some(CheckpointVersion::from_meta(&meta))),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Resolve a Volume name into a `VolumeId`, returning None if the name has
+    /// not been registered.
+    pub async fn resolve_name(
+        &self,
+        name: &VolumeName,
+    ) -> Result<Option<VolumeId>, Culprit<VolumeStoreErr>> {
+        match self.store.get(&name_key_path(name)).await {
+            Ok(res) => {
+                let vid = VolumeId::try_from(res.bytes().await?).or_into_ctx()?;
+                Ok(Some(vid))
+            }
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Register a name for the specified `VolumeId` if the name is not already
+    /// registered. Returns the `VolumeId` the name resolves to along with
+    /// whether or not this call registered it.
+    pub async fn create_name(
+        &self,
+        name: &VolumeName,
+        vid: &VolumeId,
+    ) -> Result<(VolumeId, bool), Culprit<VolumeStoreErr>> {
+        let key = name_key_path(name);
+        let result = self
+            .store
+            .put_opts(
+                &key,
+                PutPayload::from_bytes(vid.copy_to_bytes()),
+                PutOptions {
+                    mode: PutMode::Create,
+                    tags: TagSet::default(),
+                    attributes: Attributes::default(),
+                    extensions: Default::default(),
+                },
+            )
+            .await;
+        match result {
+            Ok(_) => Ok((vid.clone(), true)),
+            // another request won the race to register this name
+            Err(object_store::Error::AlreadyExists { .. }) => {
+                let existing = self.resolve_name(name).await?.ok_or_else(|| {
+                    Culprit::new_with_note(
+                        VolumeStoreErr::ObjectStoreErr,
+                        format!("volume name {name} disappeared after creation"),
+                    )
+                })?;
+                Ok((existing, false))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Load the latest version of the Volume's labels, returning None if the
+    /// Volume has never been labeled.
+    pub async fn get_labels(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<VolumeLabels>, Culprit<VolumeStoreErr>> {
+        let mut list = self.store.list(Some(&labels_key_path_prefix(vid)));
+        let mut latest = None;
+        while let Some(meta) = list.try_next().await? {
+            if let Some((_, version)) = parse_labels_key(&meta.location) {
+                latest = latest.max(Some(version));
+            }
+        }
+        let Some(version) = latest else {
+            return Ok(None);
+        };
+
+        let bytes = self
+            .store
+            .get(&labels_key_path(vid, version))
+            .await?
+            .bytes()
+            .await?;
+        let labels = VolumeLabels::decode(bytes).or_ctx(|_| VolumeStoreErr::CorruptLabels)?;
+        Ok(Some(labels))
+    }
+
+    /// Returns true if the specified version of the Volume's labels exists
+    pub async fn labels_version_exists(
+        &self,
+        vid: &VolumeId,
+        version: u64,
+    ) -> Result<bool, Culprit<VolumeStoreErr>> {
+        match self.store.head(&labels_key_path(vid, version)).await {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::NotFound { .. }) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write a new version of the Volume's labels. Returns false without
+    /// writing anything if the version already exists.
+    pub async fn put_labels(
+        &self,
+        vid: &VolumeId,
+        labels: &VolumeLabels,
+    ) -> Result<bool, Culprit<VolumeStoreErr>> {
+        let result = self
+            .store
+            .put_opts(
+                &labels_key_path(vid, labels.version),
+                PutPayload::from_bytes(labels.encode_to_vec().into()),
+                PutOptions {
+                    mode: PutMode::Create,
+                    tags: TagSet::default(),
+                    attributes: Attributes::default(),
+                    extensions: Default::default(),
+                },
+            )
+            .await;
+        match result {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write the fork record of a newly forked Volume. Fails if the Volume
+    /// already has a fork record.
+    pub async fn put_fork(
+        &self,
+        vid: &VolumeId,
+        fork: &VolumeFork,
+    ) -> Result<(), Culprit<VolumeStoreErr>> {
+        self.store
+            .put_opts(
+                &fork_key_path(vid),
+                PutPayload::from_bytes(fork.encode_to_vec().into()),
+                PutOptions {
+                    mode: PutMode::Create,
+                    tags: TagSet::default(),
+                    attributes: Attributes::default(),
+                    extensions: Default::default(),
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the Volume's fork record, or None if the Volume wasn't forked
+    /// from another Volume.
+    pub async fn get_fork(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<VolumeFork>, Culprit<VolumeStoreErr>> {
+        match self.store.get(&fork_key_path(vid)).await {
+            Ok(res) => {
+                let bytes = res.bytes().await?;
+                let fork = VolumeFork::decode(bytes).or_ctx(|_| VolumeStoreErr::CorruptFork)?;
+                Ok(Some(fork))
+            }
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Point the tag at the given LSN if the tag doesn't already exist.
+    /// Returns the LSN the tag points at, which will differ from `lsn` if the
+    /// tag already existed.
+    pub async fn put_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+        lsn: LSN,
+    ) -> Result<LSN, Culprit<VolumeStoreErr>> {
+        let result = self
+            .store
+            .put_opts(
+                &tag_key_path(vid, tag),
+                PutPayload::from_bytes(Bytes::copy_from_slice(&u64::from(lsn).to_be_bytes())),
+                PutOptions {
+                    mode: PutMode::Create,
+                    tags: TagSet::default(),
+                    attributes: Attributes::default(),
+                    extensions: Default::default(),
+                },
+            )
+            .await;
+        match result {
+            Ok(_) => Ok(lsn),
+            Err(object_store::Error::AlreadyExists { .. }) => {
+                self.get_tag(vid, tag).await?.ok_or_else(|| {
+                    Culprit::new_with_note(
+                        VolumeStoreErr::ObjectStoreErr,
+                        format!("tag {tag} for volume {vid} disappeared after creation"),
+                    )
+                })
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns the LSN the tag points at, or None if the tag doesn't exist.
+    pub async fn get_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+    ) -> Result<Option<LSN>, Culprit<VolumeStoreErr>> {
+        match self.store.get(&tag_key_path(vid, tag)).await {
+            Ok(res) => Ok(Some(decode_tag(&res.bytes().await?)?)),
+            Err(object_store::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// List every tag of the Volume in order by name
+    pub async fn list_tags(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<BTreeMap<TagName, LSN>, Culprit<VolumeStoreErr>> {
+        let mut list = self.store.list(Some(&tag_key_path_prefix(vid)));
+        let mut tags = BTreeMap::new();
+        while let Some(meta) = list.try_next().await? {
+            let Some((_, tag)) = parse_tag_key(&meta.location) else {
+                continue;
+            };
+            // the tag may have been deleted since it was listed
+            if let Some(lsn) = self.get_tag(vid, &tag).await? {
+                tags.insert(tag, lsn);
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Delete the tag, returning the LSN it pointed at or None if the tag
+    /// doesn't exist.
+    pub async fn delete_tag(
+        &self,
+        vid: &VolumeId,
+        tag: &TagName,
+    ) -> Result<Option<LSN>, Culprit<VolumeStoreErr>> {
+        let Some(lsn) = self.get_tag(vid, tag).await? else {
+            return Ok(None);
+        };
+        match self.store.delete(&tag_key_path(vid, tag)).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(Some(lsn)),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Replay all commits for a volume contained by the specified LSN range.
     pub fn replay_ordered<'a, R: RangeBounds<LSN> + 'a>(
         &'a self,
@@ -101,6 +551,56 @@ impl VolumeStore {
             .map_ok(|result| result.unwrap())
     }
 
+    /// List the Volumes with at least one commit whose ID starts with `prefix`
+    /// in order by `VolumeId`, starting after `start_after`
+    pub fn list_volumes<'a>(
+        &self,
+        prefix: &'a str,
+        start_after: Option<&VolumeId>,
+    ) -> impl Stream<Item = Result<VolumeId, Culprit<VolumeStoreErr>>> + 'a {
+        let root = Path::from("volumes");
+        let offset = list_offset(
+            &root,
+            prefix,
+            start_after.map(|vid| commit_key_path(vid, LSN::LAST)),
+        );
+
+        // every commit is listed, so we only keep the first key of each Volume
+        let mut last = None;
+        let volumes = self
+            .store
+            .list_with_offset(Some(&root), &offset)
+            .map_err(Culprit::from_err)
+            .and_then(|meta| {
+                future::ready(
+                    parse_commit_key(&meta.location)
+                        .or_into_ctx()
+                        .map(|(vid, _)| vid),
+                )
+            })
+            .try_filter(move |vid| {
+                let first = last.as_ref() != Some(vid);
+                last = Some(vid.clone());
+                future::ready(first)
+            });
+        with_prefix(volumes, prefix)
+    }
+
+    /// Find the oldest commit for a Volume which hasn't been garbage
+    /// collected. Returns None if the Volume has no commits.
+    pub async fn oldest_commit(
+        &self,
+        vid: &VolumeId,
+    ) -> Result<Option<LSN>, Culprit<VolumeStoreErr>> {
+        let mut list = self.store.list(Some(&commit_key_path_prefix(vid)));
+        let mut oldest = None;
+        while let Some(meta) = list.try_next().await? {
+            let (_, lsn) = parse_commit_key(&meta.location).or_into_ctx()?;
+            oldest = Some(oldest.map_or(lsn, |oldest: LSN| oldest.min(lsn)));
+        }
+        Ok(oldest)
+    }
+
     pub async fn get_commit(
         &self,
         vid: &VolumeId,
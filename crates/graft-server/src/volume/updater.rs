@@ -1,21 +1,27 @@
-use std::{fmt::Debug, ops::RangeBounds};
+use std::{collections::BTreeMap, fmt::Debug, ops::RangeBounds};
 
 use culprit::{Culprit, ResultExt};
 use futures::TryStreamExt;
-use graft_client::MetastoreClient;
+use graft_client::nonblocking::MetastoreClient;
 use graft_core::{
-    VolumeId,
+    SegmentId, VolumeId,
     lsn::{LSN, LSNRangeExt},
 };
-use tokio::task::spawn_blocking;
+use splinter_rs::{
+    Splinter,
+    ops::{Cut, Merge},
+};
 use tracing::{Instrument, Level, field};
 
-use crate::limiter::{Limiter, Permit};
+use crate::{
+    bytes_vec::BytesVec,
+    limiter::{Limiter, Permit},
+};
 
 use super::{
     catalog::{VolumeCatalog, VolumeCatalogErr},
-    commit::CommitMeta,
-    store::{VolumeStore, VolumeStoreErr},
+    commit::{Commit, CommitBuilder, CommitMeta},
+    store::{CheckpointVersion, VolumeStore, VolumeStoreErr},
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -28,6 +34,12 @@ pub enum UpdateErr {
 
     #[error("client error")]
     ClientErr(#[from] graft_client::ClientErr),
+
+    #[error("checkpoint missing")]
+    CheckpointMissing,
+
+    #[error("invalid compaction")]
+    InvalidCompaction,
 }
 
 pub struct VolumeCatalogUpdater {
@@ -121,8 +133,20 @@ impl VolumeCatalogUpdater {
         let lsns = start_lsn..;
 
         // update the catalog from the store
-        self.replay_commits_from_store(store, catalog, permit, vid, &lsns)
-            .await
+        self.replay_commits_from_store(store, catalog, &permit, vid, &lsns)
+            .await?;
+
+        // the volume's earliest commits may have been garbage collected, in
+        // which case we replay starting at the oldest remaining commit
+        if catalog_lsn.is_none() && catalog.latest_snapshot(vid).or_into_ctx()?.is_none() {
+            if let Some(oldest) = store.oldest_commit(vid).await.or_into_ctx()? {
+                tracing::debug!(?vid, ?oldest, "replaying volume from oldest commit");
+                self.replay_commits_from_store(store, catalog, &permit, vid, &(oldest..))
+                    .await?;
+            }
+        }
+
+        Ok(())
     }
 
     pub async fn update_catalog_from_store_in_range<R: RangeBounds<LSN> + Debug>(
@@ -152,7 +176,7 @@ impl VolumeCatalogUpdater {
         }
 
         // update the catalog from the store
-        self.replay_commits_from_store(store, catalog, permit, vid, lsns)
+        self.replay_commits_from_store(store, catalog, &permit, vid, lsns)
             .await
     }
 
@@ -161,7 +185,7 @@ impl VolumeCatalogUpdater {
         &self,
         store: &VolumeStore,
         catalog: &VolumeCatalog,
-        _permit: Permit<'_>,
+        _permit: &Permit<'_>,
         vid: &VolumeId,
         lsns: &R,
     ) -> Result<(), Culprit<UpdateErr>> {
@@ -234,15 +258,7 @@ impl VolumeCatalogUpdater {
         // use an async block in order to leverage Future::instrument
         async move {
             // update the catalog from the client
-            // TODO: switch this to an async client once one exists
-            let commits = {
-                let client = client.clone();
-                let vid = vid.clone();
-                spawn_blocking(move || client.pull_commits(&vid, lsns))
-                    .await
-                    .expect("spawn_blocking failed")
-                    .or_into_ctx()?
-            };
+            let commits = client.pull_commits(vid, lsns).await.or_into_ctx()?;
 
             if !commits.is_empty() {
                 // only create a batch if we have commits to replay
@@ -268,4 +284,269 @@ impl VolumeCatalogUpdater {
         .instrument(span)
         .await
     }
+
+    /// Ensure the catalog contains the checkpoint at the specified LSN, loading
+    /// it from the store if necessary.
+    ///
+    /// Compaction rewrites the checkpoint at an existing LSN, possibly via
+    /// another metastore. So a cached checkpoint is only used while its version
+    /// matches the checkpoint in the store, otherwise it's reloaded.
+    pub async fn update_checkpoint_from_store(
+        &self,
+        store: &VolumeStore,
+        catalog: &VolumeCatalog,
+        vid: &VolumeId,
+        checkpoint: LSN,
+    ) -> Result<(), Culprit<UpdateErr>> {
+        match store
+            .checkpoint_version(vid, checkpoint)
+            .await
+            .or_into_ctx()?
+        {
+            Some(version) => {
+                let cached = catalog
+                    .checkpoint_version(vid.clone(), checkpoint)
+                    .or_into_ctx()?;
+                if cached == Some(version) {
+                    return Ok(());
+                }
+            }
+            None => {
+                if catalog
+                    .contains_checkpoint(vid.clone(), checkpoint)
+                    .or_into_ctx()?
+                {
+                    return Ok(());
+                }
+            }
+        }
+
+        let (commit, version) = match store.get_checkpoint(vid, checkpoint).await.or_into_ctx()? {
+            Some((commit, version)) => (commit, Some(version)),
+            // a revert commit is its own checkpoint and contains every page in
+            // the Volume, so it's only written as a commit
+            None => match store.get_commit(vid, checkpoint).await.or_into_ctx()? {
+                Some(commit) if commit.meta().checkpoint() == checkpoint => (commit, None),
+                _ => {
+                    return Err(Culprit::new_with_note(
+                        UpdateErr::CheckpointMissing,
+                        format!("volume {vid} is missing checkpoint at {checkpoint}"),
+                    ));
+                }
+            },
+        };
+
+        let mut batch = catalog.batch_insert();
+        batch
+            .insert_checkpoint(&commit, version.as_ref())
+            .or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+        Ok(())
+    }
+
+    /// Ensure the catalog contains the current version of the checkpoint at the
+    /// specified LSN, loading it from the metastore if it's missing or has been
+    /// compacted since it was loaded.
+    pub async fn update_checkpoint_from_metastore(
+        &self,
+        client: &MetastoreClient,
+        catalog: &VolumeCatalog,
+        vid: &VolumeId,
+        checkpoint: LSN,
+    ) -> Result<(), Culprit<UpdateErr>> {
+        // the first commit is an implicit checkpoint which is never compacted
+        if checkpoint == LSN::FIRST {
+            return Ok(());
+        }
+        self.refresh_checkpoint_from_metastore(client, catalog, vid, checkpoint)
+            .await?;
+        Ok(())
+    }
+
+    /// Reload the checkpoint at the specified LSN from the metastore, replacing
+    /// the cached checkpoint if its version has changed since it was loaded.
+    /// This happens when another node compacts the checkpoint, after which the
+    /// segments it used to reference are eventually removed.
+    ///
+    /// Returns true if the catalog was updated.
+    pub async fn refresh_checkpoint_from_metastore(
+        &self,
+        client: &MetastoreClient,
+        catalog: &VolumeCatalog,
+        vid: &VolumeId,
+        checkpoint: LSN,
+    ) -> Result<bool, Culprit<UpdateErr>> {
+        let Some(resp) = client
+            .pull_checkpoint(vid, checkpoint)
+            .await
+            .or_into_ctx()?
+        else {
+            return Err(Culprit::new_with_note(
+                UpdateErr::CheckpointMissing,
+                format!("volume {vid} is missing checkpoint at {checkpoint}"),
+            ));
+        };
+
+        // an empty version means the checkpoint is implicit and never changes
+        let version = (!resp.version.is_empty()).then(|| CheckpointVersion::new(resp.version));
+        if catalog
+            .contains_checkpoint(vid.clone(), checkpoint)
+            .or_into_ctx()?
+            && catalog
+                .checkpoint_version(vid.clone(), checkpoint)
+                .or_into_ctx()?
+                == version
+        {
+            return Ok(false);
+        }
+
+        let commit = resp.checkpoint.expect("missing checkpoint");
+        let snapshot = commit.snapshot.expect("missing snapshot");
+        let meta: CommitMeta = snapshot.try_into().expect("invalid snapshot");
+        let mut batch = catalog.batch_insert();
+        batch
+            .insert_checkpoint_snapshot(vid.clone(), meta, version.as_ref(), commit.segments)
+            .or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+        Ok(true)
+    }
+
+    /// Map each segment needed to read the Volume at the provided snapshot to
+    /// the pages it contains, loading the snapshot's checkpoint and every
+    /// commit since into the catalog as needed.
+    pub async fn live_segments(
+        &self,
+        store: &VolumeStore,
+        catalog: &VolumeCatalog,
+        snapshot: &CommitMeta,
+    ) -> Result<BTreeMap<SegmentId, Splinter>, Culprit<UpdateErr>> {
+        let vid = snapshot.vid();
+        self.update_checkpoint_from_store(store, catalog, vid, snapshot.checkpoint())
+            .await?;
+        self.update_catalog_from_store_in_range(
+            store,
+            catalog,
+            vid,
+            &(snapshot.checkpoint()..=snapshot.lsn()),
+        )
+        .await?;
+        catalog.live_segments(snapshot).or_into_ctx()
+    }
+
+    /// Write a checkpoint of the Volume at the provided snapshot to the store
+    /// and catalog. Returns the checkpoint's `CommitMeta`.
+    ///
+    /// If a checkpoint already exists at the snapshot's LSN it's kept, as it
+    /// may have been compacted since it was written.
+    pub async fn write_checkpoint(
+        &self,
+        store: &VolumeStore,
+        catalog: &VolumeCatalog,
+        snapshot: &CommitMeta,
+    ) -> Result<CommitMeta, Culprit<UpdateErr>> {
+        let checkpoint = self
+            .build_checkpoint(store, catalog, snapshot, vec![])
+            .await?;
+        let meta = checkpoint.meta().clone();
+
+        match store.put_checkpoint(checkpoint.clone(), false).await {
+            Ok(version) => {
+                let mut batch = catalog.batch_insert();
+                batch
+                    .insert_checkpoint(&checkpoint, version.as_ref())
+                    .or_into_ctx()?;
+                batch.commit().or_into_ctx()?;
+                tracing::debug!(vid = ?meta.vid(), lsn = ?meta.lsn(), "wrote volume checkpoint");
+            }
+            Err(err) if matches!(err.ctx(), VolumeStoreErr::CommitAlreadyExists) => {
+                self.update_checkpoint_from_store(store, catalog, meta.vid(), meta.lsn())
+                    .await?;
+            }
+            Err(err) => return Err(err.map_ctx(UpdateErr::from)),
+        }
+
+        Ok(meta)
+    }
+
+    /// Write a checkpoint of the Volume at the provided snapshot to the store
+    /// and catalog, reading the pages in each compacted segment from that
+    /// segment rather than the segments they were originally committed to.
+    /// Every compacted page must be present in the Volume at the snapshot, and
+    /// appear in only one compacted segment.
+    ///
+    /// Any existing checkpoint at the same LSN is replaced. Other catalogs
+    /// notice the replacement as the stored checkpoint's version changes.
+    pub async fn write_compacted_checkpoint(
+        &self,
+        store: &VolumeStore,
+        catalog: &VolumeCatalog,
+        snapshot: &CommitMeta,
+        compacted: Vec<(SegmentId, Splinter)>,
+    ) -> Result<Commit<BytesVec>, Culprit<UpdateErr>> {
+        let checkpoint = self
+            .build_checkpoint(store, catalog, snapshot, compacted)
+            .await?;
+
+        let version = store
+            .put_checkpoint(checkpoint.clone(), true)
+            .await
+            .or_into_ctx()?;
+
+        let mut batch = catalog.batch_insert();
+        batch
+            .insert_checkpoint(&checkpoint, version.as_ref())
+            .or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+
+        tracing::debug!(vid = ?snapshot.vid(), lsn = ?snapshot.lsn(), "wrote compacted volume checkpoint");
+        Ok(checkpoint)
+    }
+
+    /// Build a checkpoint of the Volume at the provided snapshot, reading the
+    /// pages in each compacted segment from that segment.
+    async fn build_checkpoint(
+        &self,
+        store: &VolumeStore,
+        catalog: &VolumeCatalog,
+        snapshot: &CommitMeta,
+        compacted: Vec<(SegmentId, Splinter)>,
+    ) -> Result<Commit<BytesVec>, Culprit<UpdateErr>> {
+        let vid = snapshot.vid();
+        let mut live = self.live_segments(store, catalog, snapshot).await?;
+
+        if !compacted.is_empty() {
+            let mut remaining = live.values().fold(Splinter::default(), |mut acc, pages| {
+                acc.merge(pages);
+                acc
+            });
+            for (sid, pages) in compacted {
+                if remaining.cut(&pages) != pages {
+                    return Err(Culprit::new_with_note(
+                        UpdateErr::InvalidCompaction,
+                        format!("segment {sid} contains pages which aren't live in volume {vid}"),
+                    ));
+                }
+                for existing in live.values_mut() {
+                    let _ = existing.cut(&pages);
+                }
+                live.insert(sid, pages);
+            }
+            live.retain(|_, pages| !pages.is_empty());
+        }
+
+        let meta = CommitMeta::new(
+            vid.clone(),
+            snapshot.cid().clone(),
+            snapshot.lsn(),
+            snapshot.lsn(),
+            snapshot.page_count(),
+            snapshot.system_time(),
+        )
+        .with_page_size(snapshot.page_size());
+        let mut checkpoint = CommitBuilder::new_with_capacity(meta, live.len());
+        for (sid, pages) in live {
+            checkpoint.write_graft(sid, pages.serialize_to_bytes());
+        }
+        Ok(checkpoint.build())
+    }
 }
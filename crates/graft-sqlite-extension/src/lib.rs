@@ -6,9 +6,9 @@ use std::{
 use config::{Config, FileFormat};
 use graft_client::{
     ClientPair, MetastoreClient, NetClient, PagestoreClient,
-    runtime::{runtime::Runtime, storage::Storage},
+    runtime::{runtime::Runtime, storage::Storage, sync::PushCoalescing},
 };
-use graft_core::ClientId;
+use graft_core::{ClientId, byte_unit::ByteUnit};
 use graft_sqlite::vfs::GraftVfs;
 use graft_tracing::{TracingConsumer, init_tracing_with_writer};
 use serde::Deserialize;
@@ -50,6 +50,19 @@ struct ExtensionConfig {
     #[serde(default = "default_autosync")]
     autosync: bool,
 
+    /// wait until a volume has been quiet for this many milliseconds before
+    /// pushing its local commits
+    push_quiet_ms: Option<u64>,
+
+    /// push no later than this many milliseconds after the first local commit
+    push_max_delay_ms: Option<u64>,
+
+    /// push once this many local commits are pending
+    push_max_commits: Option<u64>,
+
+    /// push once the pending local commits have changed this many bytes
+    push_max_bytes: Option<ByteUnit>,
+
     #[serde(default = "ClientId::random")]
     client_id: ClientId,
 
@@ -59,6 +72,11 @@ struct ExtensionConfig {
 
     #[serde(default = "bool::default")]
     make_default: bool,
+
+    /// access volumes through the graft-agent listening on this socket
+    /// rather than opening local storage in this process
+    #[cfg(unix)]
+    agent_socket: Option<PathBuf>,
 }
 
 pub fn setup_log_file(path: PathBuf, cid: &ClientId) {
@@ -128,6 +146,15 @@ fn init_vfs() -> Result<(RegisterOpts, GraftVfs), InitErr> {
         setup_log_file(path, &config.client_id);
     }
 
+    #[cfg(unix)]
+    if let Some(path) = config.agent_socket {
+        let agent = graft_agent::AgentClient::connect(path).map_err(|c| c.into_err())?;
+        return Ok((
+            RegisterOpts { make_default: config.make_default },
+            GraftVfs::new(agent),
+        ));
+    }
+
     let client = NetClient::new(config.token);
     let metastore_client = MetastoreClient::new(config.metastore, client.clone());
     let pagestore_client = PagestoreClient::new(config.pagestore, client.clone());
@@ -140,6 +167,23 @@ fn init_vfs() -> Result<(RegisterOpts, GraftVfs), InitErr> {
         .start_sync_task(Duration::from_secs(1), 8, config.autosync, "graft-sync")
         .map_err(|c| c.into_err())?;
 
+    let mut push_coalescing = PushCoalescing::default();
+    if let Some(ms) = config.push_quiet_ms {
+        push_coalescing = push_coalescing.with_quiet_period(Duration::from_millis(ms));
+    }
+    if let Some(ms) = config.push_max_delay_ms {
+        push_coalescing = push_coalescing.with_max_delay(Duration::from_millis(ms));
+    }
+    if let Some(max_commits) = config.push_max_commits {
+        push_coalescing = push_coalescing.with_max_commits(max_commits);
+    }
+    if let Some(max_bytes) = config.push_max_bytes {
+        push_coalescing = push_coalescing.with_max_bytes(max_bytes);
+    }
+    if push_coalescing != PushCoalescing::default() {
+        runtime.set_push_coalescing(push_coalescing);
+    }
+
     Ok((
         RegisterOpts { make_default: config.make_default },
         GraftVfs::new(runtime),
@@ -0,0 +1,270 @@
+//! The VFS accesses volumes through either a [`Runtime`] which owns local
+//! storage, or on unix a `graft-agent` which shares local storage between
+//! many processes. The types in this module dispatch to whichever client the
+//! VFS was created with.
+
+use std::{borrow::Cow, fmt::Debug};
+
+use culprit::Result;
+use graft_client::{
+    ClientErr,
+    oracle::Oracle,
+    runtime::{
+        runtime::Runtime,
+        storage::{page::PageStatus, snapshot::Snapshot, volume_state::VolumeConfig},
+        volume_handle::VolumeHandle,
+        volume_reader::{VolumeRead, VolumeReader},
+        volume_writer::{VolumeWrite, VolumeWriter},
+    },
+};
+use graft_core::{
+    PageIdx, VolumeId,
+    page::{Page, PageSize},
+    page_count::PageCount,
+};
+
+#[cfg(unix)]
+use graft_agent::{AgentClient, AgentVolumeHandle, AgentVolumeReader, AgentVolumeWriter};
+
+/// Match on every variant of one of the enums in this module, binding the
+/// wrapped value to `$inner`
+macro_rules! dispatch {
+    ($value:expr, $enum:ident, $inner:ident => $body:expr) => {
+        match $value {
+            $enum::Runtime($inner) => $body,
+            #[cfg(unix)]
+            $enum::Agent($inner) => $body,
+        }
+    };
+}
+
+#[derive(Clone)]
+pub enum VfsClient {
+    Runtime(Runtime),
+    #[cfg(unix)]
+    Agent(AgentClient),
+}
+
+impl VfsClient {
+    /// The local runtime, or None if volumes are accessed through a
+    /// `graft-agent`
+    pub fn runtime(&self) -> Option<&Runtime> {
+        match self {
+            VfsClient::Runtime(runtime) => Some(runtime),
+            #[cfg(unix)]
+            VfsClient::Agent(_) => None,
+        }
+    }
+
+    /// Open a volume. The agent ignores `config` if the volume already exists.
+    pub fn open_volume(
+        &self,
+        vid: &VolumeId,
+        config: VolumeConfig,
+    ) -> Result<VfsVolume, ClientErr> {
+        match self {
+            VfsClient::Runtime(runtime) => runtime.open_volume(vid, config).map(VfsVolume::Runtime),
+            #[cfg(unix)]
+            VfsClient::Agent(agent) => agent.open_volume(vid, config).map(VfsVolume::Agent),
+        }
+    }
+}
+
+impl From<Runtime> for VfsClient {
+    fn from(runtime: Runtime) -> Self {
+        VfsClient::Runtime(runtime)
+    }
+}
+
+#[cfg(unix)]
+impl From<AgentClient> for VfsClient {
+    fn from(agent: AgentClient) -> Self {
+        VfsClient::Agent(agent)
+    }
+}
+
+#[derive(Clone)]
+pub enum VfsVolume {
+    Runtime(VolumeHandle),
+    #[cfg(unix)]
+    Agent(AgentVolumeHandle),
+}
+
+impl VfsVolume {
+    pub fn vid(&self) -> &VolumeId {
+        dispatch!(self, VfsVolume, handle => handle.vid())
+    }
+
+    pub fn page_size(&self) -> PageSize {
+        dispatch!(self, VfsVolume, handle => handle.page_size())
+    }
+
+    /// The local volume handle, or None if the volume is accessed through a
+    /// `graft-agent`
+    pub fn local(&self) -> Option<&VolumeHandle> {
+        match self {
+            VfsVolume::Runtime(handle) => Some(handle),
+            #[cfg(unix)]
+            VfsVolume::Agent(_) => None,
+        }
+    }
+
+    pub fn snapshot(&self) -> Result<Option<Snapshot>, ClientErr> {
+        dispatch!(self, VfsVolume, handle => handle.snapshot())
+    }
+
+    pub fn reader(&self) -> Result<VfsReader, ClientErr> {
+        match self {
+            VfsVolume::Runtime(handle) => handle.reader().map(VfsReader::Runtime),
+            #[cfg(unix)]
+            VfsVolume::Agent(handle) => handle.reader().map(VfsReader::Agent),
+        }
+    }
+
+    pub fn writer_at(&self, snapshot: Option<Snapshot>) -> VfsWriter {
+        match self {
+            VfsVolume::Runtime(handle) => VfsWriter::Runtime(handle.writer_at(snapshot)),
+            #[cfg(unix)]
+            VfsVolume::Agent(handle) => VfsWriter::Agent(handle.writer_at(snapshot)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum VfsReader {
+    Runtime(VolumeReader),
+    #[cfg(unix)]
+    Agent(AgentVolumeReader),
+}
+
+impl Debug for VfsReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("VfsReader").field(&self.snapshot()).finish()
+    }
+}
+
+impl VolumeRead for VfsReader {
+    fn vid(&self) -> &VolumeId {
+        dispatch!(self, VfsReader, reader => reader.vid())
+    }
+
+    fn snapshot(&self) -> Option<&Snapshot> {
+        dispatch!(self, VfsReader, reader => reader.snapshot())
+    }
+
+    fn page_size(&self) -> PageSize {
+        dispatch!(self, VfsReader, reader => reader.page_size())
+    }
+
+    fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
+        dispatch!(self, VfsReader, reader => reader.read(oracle, pageidx))
+    }
+
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        dispatch!(self, VfsReader, reader => reader.status(pageidx))
+    }
+}
+
+pub enum VfsWriter {
+    Runtime(VolumeWriter),
+    #[cfg(unix)]
+    Agent(AgentVolumeWriter),
+}
+
+impl VfsWriter {
+    pub fn pages(&self) -> PageCount {
+        dispatch!(self, VfsWriter, writer => writer.pages())
+    }
+}
+
+impl Debug for VfsWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("VfsWriter").field(&self.snapshot()).finish()
+    }
+}
+
+impl VolumeRead for VfsWriter {
+    fn vid(&self) -> &VolumeId {
+        dispatch!(self, VfsWriter, writer => writer.vid())
+    }
+
+    fn snapshot(&self) -> Option<&Snapshot> {
+        dispatch!(self, VfsWriter, writer => writer.snapshot())
+    }
+
+    fn page_size(&self) -> PageSize {
+        dispatch!(self, VfsWriter, writer => writer.page_size())
+    }
+
+    fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
+        dispatch!(self, VfsWriter, writer => writer.read(oracle, pageidx))
+    }
+
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        dispatch!(self, VfsWriter, writer => writer.status(pageidx))
+    }
+}
+
+impl VolumeWrite for VfsWriter {
+    type CommitOutput = VfsReader;
+
+    fn write(&mut self, pageidx: PageIdx, page: Page) {
+        dispatch!(self, VfsWriter, writer => writer.write(pageidx, page))
+    }
+
+    fn truncate(&mut self, pages: PageCount) {
+        dispatch!(self, VfsWriter, writer => writer.truncate(pages))
+    }
+
+    fn commit(self) -> Result<VfsReader, ClientErr> {
+        match self {
+            VfsWriter::Runtime(writer) => writer.commit().map(VfsReader::Runtime),
+            #[cfg(unix)]
+            VfsWriter::Agent(writer) => writer.commit().map(VfsReader::Agent),
+        }
+    }
+}
+
+/// Reads from either a reader or a writer, mirroring
+/// [`graft_client::runtime::volume_reader::VolumeReadRef`]
+pub enum VfsReadRef<'a> {
+    Reader(Cow<'a, VfsReader>),
+    Writer(&'a VfsWriter),
+}
+
+impl VolumeRead for VfsReadRef<'_> {
+    fn vid(&self) -> &VolumeId {
+        match self {
+            VfsReadRef::Reader(reader) => reader.vid(),
+            VfsReadRef::Writer(writer) => writer.vid(),
+        }
+    }
+
+    fn snapshot(&self) -> Option<&Snapshot> {
+        match self {
+            VfsReadRef::Reader(reader) => reader.snapshot(),
+            VfsReadRef::Writer(writer) => writer.snapshot(),
+        }
+    }
+
+    fn page_size(&self) -> PageSize {
+        match self {
+            VfsReadRef::Reader(reader) => reader.page_size(),
+            VfsReadRef::Writer(writer) => writer.page_size(),
+        }
+    }
+
+    fn read<O: Oracle>(&self, oracle: &mut O, pageidx: PageIdx) -> Result<Page, ClientErr> {
+        match self {
+            VfsReadRef::Reader(reader) => reader.read(oracle, pageidx),
+            VfsReadRef::Writer(writer) => writer.read(oracle, pageidx),
+        }
+    }
+
+    fn status(&self, pageidx: PageIdx) -> Result<PageStatus, ClientErr> {
+        match self {
+            VfsReadRef::Reader(reader) => reader.status(pageidx),
+            VfsReadRef::Writer(writer) => writer.status(pageidx),
+        }
+    }
+}
@@ -6,26 +6,23 @@ use std::{
     sync::Arc,
 };
 
-use bytes::BytesMut;
 use culprit::{Culprit, Result, ResultExt};
-use graft_client::{
-    oracle::LeapOracle,
-    runtime::{
-        storage::snapshot::Snapshot,
-        volume_handle::VolumeHandle,
-        volume_reader::{VolumeRead, VolumeReadRef, VolumeReader},
-        volume_writer::{VolumeWrite, VolumeWriter},
-    },
+use graft_client::runtime::{
+    storage::snapshot::Snapshot, volume_reader::VolumeRead, volume_writer::VolumeWrite,
 };
 use graft_core::{
     PageIdx, VolumeId,
-    page::{PAGESIZE, Page},
+    page::{Page, PageSize},
     page_count::PageCount,
 };
 use parking_lot::{Mutex, MutexGuard};
 use sqlite_plugin::flags::{LockLevel, OpenOpts};
 
-use crate::vfs::ErrCtx;
+use crate::{
+    client::{VfsClient, VfsReadRef, VfsReader, VfsVolume, VfsWriter},
+    oracle::BTreeOracle,
+    vfs::ErrCtx,
+};
 
 use super::VfsFile;
 
@@ -36,8 +33,8 @@ const VERSION_VALID_FOR_NUMBER_OFFSET: usize = 92;
 #[derive(Debug)]
 enum VolFileState {
     Idle,
-    Shared { reader: VolumeReader },
-    Reserved { writer: VolumeWriter },
+    Shared { reader: VfsReader },
+    Reserved { writer: Box<VfsWriter> },
     Committing,
 }
 
@@ -53,12 +50,12 @@ impl VolFileState {
 }
 
 pub struct VolFile {
-    handle: VolumeHandle,
+    handle: VfsVolume,
     opts: OpenOpts,
 
     reserved: Arc<Mutex<()>>,
     state: VolFileState,
-    oracle: Box<LeapOracle>,
+    oracle: Box<BTreeOracle>,
 }
 
 impl Debug for VolFile {
@@ -68,7 +65,7 @@ impl Debug for VolFile {
 }
 
 impl VolFile {
-    pub fn new(handle: VolumeHandle, opts: OpenOpts, reserved: Arc<Mutex<()>>) -> Self {
+    pub fn new(handle: VfsVolume, opts: OpenOpts, reserved: Arc<Mutex<()>>) -> Self {
         Self {
             handle,
             opts,
@@ -87,13 +84,13 @@ impl VolFile {
         }
     }
 
-    pub fn reader(&self) -> Result<VolumeReadRef<'_>, ErrCtx> {
+    pub fn reader(&self) -> Result<VfsReadRef<'_>, ErrCtx> {
         match &self.state {
-            VolFileState::Idle => Ok(VolumeReadRef::Reader(Cow::Owned(
+            VolFileState::Idle => Ok(VfsReadRef::Reader(Cow::Owned(
                 self.handle.reader().or_into_ctx()?,
             ))),
-            VolFileState::Shared { reader, .. } => Ok(VolumeReadRef::Reader(Cow::Borrowed(reader))),
-            VolFileState::Reserved { writer, .. } => Ok(VolumeReadRef::Writer(writer)),
+            VolFileState::Shared { reader, .. } => Ok(VfsReadRef::Reader(Cow::Borrowed(reader))),
+            VolFileState::Reserved { writer, .. } => Ok(VfsReadRef::Writer(writer)),
             VolFileState::Committing => ErrCtx::InvalidVolumeState.into(),
         }
     }
@@ -113,7 +110,7 @@ impl VolFile {
         self.handle.vid()
     }
 
-    pub fn handle(&self) -> &VolumeHandle {
+    pub fn handle(&self) -> &VfsVolume {
         &self.handle
     }
 
@@ -121,7 +118,35 @@ impl VolFile {
         self.opts
     }
 
-    pub fn close(self) -> VolumeHandle {
+    /// Change the page size of the Volume if it's empty and no transaction is
+    /// open. Like `pragma page_size`, this is ignored once the database has
+    /// been created. Volumes opened through the agent can't be resized.
+    pub fn set_page_size(&mut self, client: &VfsClient, page_size: PageSize) -> Result<(), ErrCtx> {
+        if page_size == self.handle.page_size()
+            || !matches!(self.state, VolFileState::Idle)
+            || self.handle.snapshot().or_into_ctx()?.is_some()
+        {
+            return Ok(());
+        }
+        let Some(runtime) = client.runtime() else {
+            return Err(Culprit::new_with_note(
+                ErrCtx::AgentUnsupported,
+                format!(
+                    "unable to change the page size of volume {} to {page_size}",
+                    self.vid()
+                ),
+            ));
+        };
+        let config = runtime
+            .volume_config(self.vid())
+            .or_into_ctx()?
+            .unwrap_or_default()
+            .with_page_size(page_size);
+        self.handle = client.open_volume(self.vid(), config).or_into_ctx()?;
+        Ok(())
+    }
+
+    pub fn close(self) -> VfsVolume {
         self.handle
     }
 }
@@ -191,7 +216,7 @@ impl VfsFile for VolFile {
                         self.handle.writer_at(latest_snapshot)
                     };
 
-                    self.state = VolFileState::Reserved { writer };
+                    self.state = VolFileState::Reserved { writer: Box::new(writer) };
 
                     // Explicitly leak the reserved lock
                     // SAFETY: we depend on SQLite to release the lock when it's done
@@ -288,19 +313,21 @@ impl VfsFile for VolFile {
             VolFileState::Reserved { writer, .. } => writer.pages(),
             VolFileState::Committing => return ErrCtx::InvalidVolumeState.into(),
         };
-        Ok((PAGESIZE * pages.to_usize()).as_usize())
+        Ok((self.handle.page_size().as_byte_unit() * pages.to_usize()).as_usize())
     }
 
     fn read(&mut self, offset: usize, data: &mut [u8]) -> Result<usize, ErrCtx> {
+        let page_size = self.handle.page_size().as_byte_unit();
+
         // locate the page offset of the requested page
-        let page_idx: PageIdx = ((offset / PAGESIZE.as_usize()) + 1)
+        let page_idx: PageIdx = ((offset / page_size.as_usize()) + 1)
             .try_into()
             .expect("offset out of volume range");
         // local_offset is the offset *within* the requested page
-        let local_offset = offset % PAGESIZE;
+        let local_offset = offset % page_size;
 
         assert!(
-            local_offset + data.len() <= PAGESIZE,
+            local_offset + data.len() <= page_size,
             "read must not cross page boundary"
         );
 
@@ -325,6 +352,9 @@ impl VfsFile for VolFile {
             VolFileState::Committing => return ErrCtx::InvalidVolumeState.into(),
         };
 
+        // let the oracle learn the structure of the database
+        self.oracle.observe_page(page_idx, &page);
+
         let range = local_offset.as_usize()..(local_offset + data.len()).as_usize();
         data.copy_from_slice(&page[range]);
 
@@ -358,13 +388,14 @@ impl VfsFile for VolFile {
             ));
         };
 
+        let page_size = self.handle.page_size();
         assert_eq!(
-            size % PAGESIZE.as_usize(),
+            size % page_size.as_usize(),
             0,
-            "size must be an even multiple of {PAGESIZE}"
+            "size must be an even multiple of {page_size}"
         );
 
-        let pages: PageCount = (size / PAGESIZE.as_usize())
+        let pages: PageCount = (size / page_size.as_usize())
             .try_into()
             .expect("size too large");
 
@@ -380,21 +411,23 @@ impl VfsFile for VolFile {
             ));
         };
 
+        let page_size = self.handle.page_size().as_byte_unit();
+
         // locate the requested page index
-        let page_idx: PageIdx = ((offset / PAGESIZE.as_usize()) + 1)
+        let page_idx: PageIdx = ((offset / page_size.as_usize()) + 1)
             .try_into()
             .expect("offset out of volume range");
         // local_offset is the offset *within* the requested page
-        let local_offset = offset % PAGESIZE;
+        let local_offset = offset % page_size;
 
         assert!(
-            local_offset + data.len() <= PAGESIZE,
+            local_offset + data.len() <= page_size,
             "write must not cross page boundary"
         );
 
         // if this is a write to the first page, and the write only changes the
         // file change counter and the version valid for number, we can ignore this write
-        if page_idx == PageIdx::FIRST && data.len() == PAGESIZE && local_offset == 0 {
+        if page_idx == PageIdx::FIRST && data.len() == page_size && local_offset == 0 {
             let existing: Page = writer.read(self.oracle.as_mut(), page_idx).or_into_ctx()?;
 
             debug_assert_eq!(data.len(), existing.len(), "page size mismatch");
@@ -420,23 +453,10 @@ impl VfsFile for VolFile {
             }
         }
 
-        let page = if data.len() == PAGESIZE {
-            // writing a full page
-            Page::try_from(data).expect("data is a full page")
-        } else {
-            // writing a partial page
-            // we need to read and then update the page
-            let mut page: BytesMut = writer
-                .read(self.oracle.as_mut(), page_idx)
-                .or_into_ctx()?
-                .into();
-            // SAFETY: we already verified that the write does not cross a page boundary
-            let range = local_offset.as_usize()..(local_offset + data.len()).as_usize();
-            page[range].copy_from_slice(data);
-            page.try_into().expect("we did not change the page size")
-        };
-
-        writer.write(page_idx, page);
+        // partial page writes are merged with the existing page contents
+        writer
+            .write_at(self.oracle.as_mut(), offset as u64, data)
+            .or_into_ctx()?;
         Ok(data.len())
     }
 }
@@ -1,3 +1,5 @@
+pub mod client;
 pub mod file;
+pub mod oracle;
 pub mod pragma;
 pub mod vfs;
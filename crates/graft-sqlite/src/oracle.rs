@@ -0,0 +1,417 @@
+//! A prefetch [`Oracle`] which understands the structure of `SQLite` databases.
+//!
+//! `SQLite` stores tables and indexes as b-trees. Interior b-tree pages contain
+//! the page numbers of their children, and large cells spill into chains of
+//! overflow pages. [`BTreeOracle`] parses every page `SQLite` reads to learn
+//! these relationships, and uses them to prefetch pages `SQLite` is likely to
+//! read next. Reads which don't follow a known relationship fall back to
+//! [`LeapOracle`].
+//!
+//! The b-tree page format is documented here:
+//! <https://www.sqlite.org/fileformat2.html#b_tree_pages>
+
+use std::collections::HashMap;
+
+use graft_client::oracle::{LeapOracle, Oracle};
+use graft_core::PageIdx;
+
+/// The size of the database header stored at the start of the first page
+const DB_HEADER_SIZE: usize = 100;
+
+/// The offset of the number of reserved bytes per page in the database header
+const RESERVED_SPACE_OFFSET: usize = 20;
+
+/// The maximum number of sibling pages to prefetch after a b-tree page miss
+const MAX_SIBLINGS: usize = 8;
+
+/// The maximum number of overflow pages to prefetch after an overflow page miss
+const MAX_OVERFLOW: usize = 16;
+
+/// The maximum number of relationships to track before forgetting all of them
+const MAX_TRACKED: usize = 16384;
+
+const INTERIOR_INDEX: u8 = 0x02;
+const INTERIOR_TABLE: u8 = 0x05;
+const LEAF_INDEX: u8 = 0x0A;
+const LEAF_TABLE: u8 = 0x0D;
+
+#[derive(Debug, Default, Clone)]
+pub struct BTreeOracle {
+    /// the number of reserved bytes at the end of each page
+    reserved: usize,
+    /// the children of each parsed interior page, in key order
+    children: HashMap<PageIdx, Vec<PageIdx>>,
+    /// the parent of each child page referenced by a parsed interior page
+    parents: HashMap<PageIdx, PageIdx>,
+    /// the expected length of each overflow chain, keyed by the chain's first page
+    overflow: HashMap<PageIdx, usize>,
+    /// the most recently read page which has a known parent
+    last_child: Option<PageIdx>,
+    /// the fallback oracle used when a read has no known relationship
+    leap: LeapOracle,
+}
+
+impl BTreeOracle {
+    /// Parse a page which `SQLite` has read, recording any child or overflow
+    /// pages it references. `observe_page` should be called for every page
+    /// read, regardless of whether the read was a cache hit.
+    pub fn observe_page(&mut self, pageidx: PageIdx, page: &[u8]) {
+        if self.parents.contains_key(&pageidx) {
+            self.last_child = Some(pageidx);
+        }
+
+        let header_offset = if pageidx == PageIdx::FIRST {
+            if let Some(&reserved) = page.get(RESERVED_SPACE_OFFSET) {
+                self.reserved = reserved as usize;
+            }
+            DB_HEADER_SIZE
+        } else {
+            0
+        };
+
+        // overflow pages and freelist pages don't have a recognizable header,
+        // so we only parse pages which look like b-tree pages
+        let Some(btree) = BTreePage::parse(page, header_offset) else {
+            return;
+        };
+
+        if self.children.len() >= MAX_TRACKED || self.overflow.len() >= MAX_TRACKED {
+            self.children.clear();
+            self.parents.clear();
+            self.overflow.clear();
+            self.last_child = None;
+        }
+
+        let usable = page.len().saturating_sub(self.reserved);
+        let mut children = Vec::new();
+        for cell in btree.cells() {
+            if let Some(child) = cell.child {
+                children.push(child);
+            }
+            if let Some((head, pages)) = cell.overflow(btree.kind, usable) {
+                self.overflow.insert(head, pages);
+            }
+        }
+        if let Some(right) = btree.right_child {
+            children.push(right);
+        }
+
+        if !children.is_empty() {
+            for &child in &children {
+                self.parents.insert(child, pageidx);
+            }
+            self.children.insert(pageidx, children);
+        }
+    }
+
+    /// Predict pages which are related to `pageidx` by the b-tree structure
+    fn predict_related(&self, pageidx: PageIdx) -> Vec<PageIdx> {
+        // a miss on the start of an overflow chain usually means SQLite is
+        // about to read the entire chain. Overflow pages are not linked until
+        // they are read, so we assume they were allocated sequentially.
+        if let Some(&pages) = self.overflow.get(&pageidx) {
+            return (1..pages.min(MAX_OVERFLOW + 1))
+                .filter_map(|i| PageIdx::try_new(pageidx.to_u32().checked_add(i as u32)?))
+                .collect();
+        }
+
+        // a miss on a b-tree page right after reading its left sibling means
+        // SQLite is scanning the tree, so we prefetch the following siblings.
+        // Otherwise the miss is likely part of a lookup which won't read any
+        // other children.
+        if let Some(siblings) = self
+            .parents
+            .get(&pageidx)
+            .and_then(|p| self.children.get(p))
+        {
+            if let Some(pos) = siblings.iter().position(|&s| s == pageidx) {
+                if pos > 0 && self.last_child == Some(siblings[pos - 1]) {
+                    return siblings[pos + 1..]
+                        .iter()
+                        .take(MAX_SIBLINGS)
+                        .copied()
+                        .collect();
+                }
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+impl Oracle for BTreeOracle {
+    fn observe_cache_hit(&mut self, pageidx: PageIdx) {
+        self.leap.observe_cache_hit(pageidx);
+    }
+
+    fn predict_next(&mut self, pageidx: PageIdx) -> impl Iterator<Item = PageIdx> {
+        let mut prediction = self.predict_related(pageidx);
+        // always update the fallback oracle so it keeps tracking access trends
+        let leap = self.leap.predict_next(pageidx);
+        if prediction.is_empty() {
+            prediction.extend(leap);
+        }
+        prediction.into_iter()
+    }
+}
+
+struct BTreePage<'a> {
+    page: &'a [u8],
+    kind: u8,
+    /// the offset of the b-tree page header
+    header_offset: usize,
+    num_cells: usize,
+    right_child: Option<PageIdx>,
+}
+
+impl<'a> BTreePage<'a> {
+    fn parse(page: &'a [u8], header_offset: usize) -> Option<Self> {
+        let kind = *page.get(header_offset)?;
+        let interior = match kind {
+            INTERIOR_INDEX | INTERIOR_TABLE => true,
+            LEAF_INDEX | LEAF_TABLE => false,
+            _ => return None,
+        };
+        let num_cells = read_u16(page, header_offset + 3)? as usize;
+        let right_child = if interior {
+            Some(PageIdx::try_new(read_u32(page, header_offset + 8)?)?)
+        } else {
+            None
+        };
+
+        // reject pages whose cell pointer array doesn't fit in the page
+        let header_size = if interior { 12 } else { 8 };
+        if header_offset + header_size + num_cells * 2 > page.len() {
+            return None;
+        }
+
+        Some(Self {
+            page,
+            kind,
+            header_offset,
+            num_cells,
+            right_child,
+        })
+    }
+
+    fn is_interior(&self) -> bool {
+        matches!(self.kind, INTERIOR_INDEX | INTERIOR_TABLE)
+    }
+
+    /// Iterate over every well formed cell in the page
+    fn cells(&self) -> impl Iterator<Item = Cell<'a>> + '_ {
+        let header_size = if self.is_interior() { 12 } else { 8 };
+        let pointers = self.header_offset + header_size;
+        (0..self.num_cells).filter_map(move |i| {
+            let offset = read_u16(self.page, pointers + i * 2)? as usize;
+            Cell::parse(self.page, self.kind, offset)
+        })
+    }
+}
+
+struct Cell<'a> {
+    page: &'a [u8],
+    /// the left child of an interior cell
+    child: Option<PageIdx>,
+    /// the total size of the cell's payload and the offset it starts at
+    payload: Option<(usize, usize)>,
+}
+
+impl<'a> Cell<'a> {
+    fn parse(page: &'a [u8], kind: u8, offset: usize) -> Option<Self> {
+        let mut cursor = offset;
+        let child = if matches!(kind, INTERIOR_INDEX | INTERIOR_TABLE) {
+            let child = PageIdx::try_new(read_u32(page, cursor)?)?;
+            cursor += 4;
+            Some(child)
+        } else {
+            None
+        };
+
+        // interior table cells contain only a child pointer and a rowid
+        let payload = if kind == INTERIOR_TABLE {
+            None
+        } else {
+            let (size, len) = read_varint(page.get(cursor..)?)?;
+            cursor += len;
+            if kind == LEAF_TABLE {
+                // skip the rowid
+                let (_, len) = read_varint(page.get(cursor..)?)?;
+                cursor += len;
+            }
+            Some((usize::try_from(size).ok()?, cursor))
+        };
+
+        Some(Self { page, child, payload })
+    }
+
+    /// Returns the first overflow page and the expected number of overflow
+    /// pages if this cell's payload spills onto overflow pages.
+    fn overflow(&self, kind: u8, usable: usize) -> Option<(PageIdx, usize)> {
+        let (size, offset) = self.payload?;
+        let local = local_payload(kind, usable, size)?;
+        if local >= size {
+            return None;
+        }
+        let head = PageIdx::try_new(read_u32(self.page, offset + local)?)?;
+        let pages = (size - local).div_ceil(usable.checked_sub(4)?);
+        Some((head, pages))
+    }
+}
+
+/// Computes the number of payload bytes stored on a b-tree page, as described
+/// in the "Cell Payload Overflow Pages" section of the file format docs.
+fn local_payload(kind: u8, usable: usize, size: usize) -> Option<usize> {
+    let max_local = if kind == LEAF_TABLE {
+        usable.checked_sub(35)?
+    } else {
+        ((usable.checked_sub(12)? * 64) / 255).checked_sub(23)?
+    };
+    if size <= max_local {
+        return Some(size);
+    }
+    let min_local = ((usable.checked_sub(12)? * 32) / 255).checked_sub(23)?;
+    let local = min_local + (size - min_local) % usable.checked_sub(4)?;
+    Some(if local <= max_local { local } else { min_local })
+}
+
+fn read_u16(page: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        page.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(page: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        page.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// Reads a `SQLite` varint, returning the value and the number of bytes read
+fn read_varint(buf: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (i, &byte) in buf.iter().take(9).enumerate() {
+        if i == 8 {
+            // the ninth byte contributes all eight bits
+            return Some(((value << 8) | byte as u64, 9));
+        }
+        value = (value << 7) | (byte & 0x7F) as u64;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use graft_core::{page::PAGESIZE, pageidx};
+
+    use super::*;
+
+    /// Build a b-tree page containing the provided cells
+    fn btree_page(kind: u8, right_child: Option<u32>, cells: &[Vec<u8>]) -> Vec<u8> {
+        let mut page = vec![0; PAGESIZE.as_usize()];
+        page[0] = kind;
+        page[3..5].copy_from_slice(&(cells.len() as u16).to_be_bytes());
+        let header_size = if let Some(right) = right_child {
+            page[8..12].copy_from_slice(&right.to_be_bytes());
+            12
+        } else {
+            8
+        };
+
+        // write cells backwards from the end of the page
+        let mut end = page.len();
+        for (i, cell) in cells.iter().enumerate() {
+            let start = end - cell.len();
+            page[start..end].copy_from_slice(cell);
+            let ptr = header_size + i * 2;
+            page[ptr..ptr + 2].copy_from_slice(&(start as u16).to_be_bytes());
+            end = start;
+        }
+        page
+    }
+
+    fn predict(oracle: &mut BTreeOracle, pageidx: PageIdx) -> Vec<u32> {
+        oracle.predict_next(pageidx).map(|p| p.to_u32()).collect()
+    }
+
+    #[test]
+    fn test_read_varint() {
+        assert_eq!(read_varint(&[0x00]), Some((0, 1)));
+        assert_eq!(read_varint(&[0x7F]), Some((127, 1)));
+        assert_eq!(read_varint(&[0x81, 0x00]), Some((128, 2)));
+        assert_eq!(read_varint(&[0x82, 0x80, 0x01]), Some((0x8001, 3)));
+        assert_eq!(read_varint(&[0xFF; 9]), Some((u64::MAX, 9)));
+        assert_eq!(read_varint(&[0x81]), None);
+    }
+
+    #[test]
+    fn test_btree_oracle_children() {
+        let mut oracle = BTreeOracle::default();
+
+        // interior table cells are a child pointer followed by a rowid
+        let cells = [10u32, 20, 30]
+            .iter()
+            .map(|child| {
+                let mut cell = child.to_be_bytes().to_vec();
+                cell.push(0x01);
+                cell
+            })
+            .collect::<Vec<_>>();
+        let page = btree_page(INTERIOR_TABLE, Some(40), &cells);
+        oracle.observe_page(pageidx!(2), &page);
+
+        // a lookup which reads a single child doesn't prefetch anything
+        assert_eq!(predict(&mut oracle, pageidx!(20)), Vec::<u32>::new());
+        let leaf = btree_page(LEAF_TABLE, None, &[]);
+        oracle.observe_page(pageidx!(20), &leaf);
+
+        // reading the next child starts a scan which prefetches the rest
+        oracle.observe_page(pageidx!(10), &leaf);
+        assert_eq!(predict(&mut oracle, pageidx!(20)), vec![30, 40]);
+    }
+
+    #[test]
+    fn test_btree_oracle_overflow() {
+        let mut oracle = BTreeOracle::default();
+        let usable = PAGESIZE.as_usize();
+
+        // a leaf table cell whose payload spills onto 3 overflow pages
+        let payload = 3 * usable;
+        let local = local_payload(LEAF_TABLE, usable, payload).unwrap();
+        assert_eq!((payload - local).div_ceil(usable - 4), 3);
+        let mut cell = vec![0x80 | (payload >> 7) as u8, (payload & 0x7F) as u8];
+        cell.push(0x01);
+        cell.extend(std::iter::repeat_n(0xAB, local));
+        cell.extend(50u32.to_be_bytes());
+
+        // small cells don't overflow
+        let small = vec![0x02, 0x02, 0xCD, 0xCD];
+
+        let page = btree_page(LEAF_TABLE, None, &[small, cell]);
+        oracle.observe_page(pageidx!(3), &page);
+        assert_eq!(oracle.overflow.len(), 1);
+        assert_eq!(predict(&mut oracle, pageidx!(50)), vec![51, 52]);
+    }
+
+    #[test]
+    fn test_btree_oracle_ignores_other_pages() {
+        let mut oracle = BTreeOracle::default();
+
+        // overflow and freelist pages start with a page number
+        let mut page = vec![0; PAGESIZE.as_usize()];
+        page[..4].copy_from_slice(&7u32.to_be_bytes());
+        oracle.observe_page(pageidx!(5), &page);
+
+        // a corrupt cell count is ignored
+        let page = btree_page(INTERIOR_INDEX, Some(3), &[]);
+        let mut page = page;
+        page[3..5].copy_from_slice(&u16::MAX.to_be_bytes());
+        oracle.observe_page(pageidx!(6), &page);
+
+        assert!(oracle.children.is_empty());
+        assert!(oracle.overflow.is_empty());
+    }
+}
@@ -1,14 +1,19 @@
 use culprit::{Culprit, ResultExt};
 use graft_client::runtime::{
-    runtime::Runtime, storage::page::PageStatus, volume_reader::VolumeRead,
+    runtime::Runtime, storage::page::PageStatus, volume_handle::VolumeHandle,
+    volume_reader::VolumeRead,
 };
+use graft_core::lsn::LSN;
 use sqlite_plugin::{
     vars::SQLITE_ERROR,
     vfs::{Pragma, PragmaErr},
 };
-use std::{fmt::Write, time::Instant};
+use std::{
+    fmt::Write,
+    time::{Duration, Instant, SystemTime},
+};
 
-use crate::{file::vol_file::VolFile, vfs::ErrCtx};
+use crate::{client::VfsClient, file::vol_file::VolFile, vfs::ErrCtx};
 
 pub enum GraftPragma {
     /// `pragma graft_status;`
@@ -20,6 +25,9 @@ pub enum GraftPragma {
     /// `pragma graft_pages;`
     Pages,
 
+    /// `pragma graft_stats;`
+    Stats,
+
     /// `pragma graft_pull;`
     Pull,
 
@@ -32,10 +40,35 @@ pub enum GraftPragma {
     /// `pragma graft_reset;`
     Reset,
 
+    /// `pragma graft_wait_for_lsn = "LSN[,TIMEOUT_MS]";`
+    WaitForLsn { lsn: LSN, timeout: Duration },
+
     /// `pragma graft_version;`
     Version,
 }
 
+/// The default timeout for `pragma graft_wait_for_lsn`
+const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn parse_wait_for_lsn(arg: &str) -> Result<GraftPragma, PragmaErr> {
+    let invalid = |err: &dyn std::fmt::Debug| {
+        PragmaErr::Fail(
+            SQLITE_ERROR,
+            Some(format!("expected LSN[,TIMEOUT_MS]: {err:?}")),
+        )
+    };
+    let (lsn, timeout) = match arg.split_once(',') {
+        Some((lsn, timeout)) => {
+            let ms: u64 = timeout.trim().parse().map_err(|err| invalid(&err))?;
+            (lsn, Duration::from_millis(ms))
+        }
+        None => (arg, DEFAULT_WAIT_TIMEOUT),
+    };
+    let lsn: u64 = lsn.trim().parse().map_err(|err| invalid(&err))?;
+    let lsn = LSN::try_from(lsn).map_err(|err| invalid(&err))?;
+    Ok(GraftPragma::WaitForLsn { lsn, timeout })
+}
+
 impl TryFrom<&Pragma<'_>> for GraftPragma {
     type Error = PragmaErr;
 
@@ -46,6 +79,7 @@ impl TryFrom<&Pragma<'_>> for GraftPragma {
                     "status" => Ok(GraftPragma::Status),
                     "snapshot" => Ok(GraftPragma::Snapshot),
                     "pages" => Ok(GraftPragma::Pages),
+                    "stats" => Ok(GraftPragma::Stats),
                     "pull" => Ok(GraftPragma::Pull),
                     "reset" => Ok(GraftPragma::Reset),
                     "sync" => {
@@ -56,6 +90,10 @@ impl TryFrom<&Pragma<'_>> for GraftPragma {
                         Ok(GraftPragma::SetAutosync(autosync))
                     }
                     "sync_errors" => Ok(GraftPragma::SyncErrors),
+                    "wait_for_lsn" => {
+                        let arg = p.arg.ok_or(PragmaErr::required_arg(p))?;
+                        parse_wait_for_lsn(arg)
+                    }
                     "version" => Ok(GraftPragma::Version),
                     _ => Err(PragmaErr::Fail(
                         SQLITE_ERROR,
@@ -68,32 +106,53 @@ impl TryFrom<&Pragma<'_>> for GraftPragma {
     }
 }
 
+/// Most pragmas inspect or control the local runtime, which isn't available
+/// when volumes are accessed through graft-agent
+fn require_runtime(client: &VfsClient) -> Result<&Runtime, Culprit<ErrCtx>> {
+    client
+        .runtime()
+        .ok_or_else(|| Culprit::new(ErrCtx::AgentUnsupported))
+}
+
+fn require_local(file: &VolFile) -> Result<&VolumeHandle, Culprit<ErrCtx>> {
+    file.handle()
+        .local()
+        .ok_or_else(|| Culprit::new(ErrCtx::AgentUnsupported))
+}
+
 impl GraftPragma {
     pub fn eval(
         self,
-        runtime: &Runtime,
+        client: &VfsClient,
         file: &mut VolFile,
     ) -> Result<Option<String>, Culprit<ErrCtx>> {
         match self {
             GraftPragma::Status => {
                 let mut out = "Graft Status\n".to_string();
-                writeln!(&mut out, "Client ID: {}", runtime.cid())?;
+                match client.runtime() {
+                    Some(runtime) => writeln!(&mut out, "Client ID: {}", runtime.cid())?,
+                    None => writeln!(&mut out, "Client: graft-agent")?,
+                }
                 writeln!(&mut out, "Volume ID: {}", file.vid())?;
                 if let Some(snapshot) = file.snapshot_or_latest()? {
                     writeln!(&mut out, "Current snapshot: {snapshot}")?;
                 } else {
                     writeln!(&mut out, "Current snapshot: None")?;
                 }
-                writeln!(&mut out, "Autosync: {}", runtime.get_autosync())?;
-                writeln!(
-                    &mut out,
-                    "Volume status: {:?}",
-                    file.handle().status().or_into_ctx()?
-                )?;
+                if let Some(runtime) = client.runtime() {
+                    writeln!(&mut out, "Autosync: {}", runtime.get_autosync())?;
+                }
+                if let Some(handle) = file.handle().local() {
+                    writeln!(
+                        &mut out,
+                        "Volume status: {:?}",
+                        handle.status().or_into_ctx()?
+                    )?;
+                }
                 Ok(Some(out))
             }
             GraftPragma::SyncErrors => {
-                let sync_errs = runtime.drain_recent_sync_errors();
+                let sync_errs = require_runtime(client)?.drain_recent_sync_errors();
                 let mut out = "Recent sync errors:\n".to_string();
                 for (when, err) in sync_errs {
                     let since = Instant::now() - when;
@@ -107,7 +166,7 @@ impl GraftPragma {
 
             GraftPragma::Snapshot => Ok(file.snapshot_or_latest()?.map(|s| s.to_string())),
             GraftPragma::SetAutosync(autosync) => {
-                runtime.set_autosync(autosync);
+                require_runtime(client)?.set_autosync(autosync);
                 Ok(None)
             }
             GraftPragma::Pages => {
@@ -142,14 +201,42 @@ impl GraftPragma {
                 }
                 Ok(Some(out))
             }
+            GraftPragma::Stats => {
+                let stats = require_local(file)?.stats().or_into_ctx()?;
+                let fmt_time = |time: Option<SystemTime>| match time {
+                    Some(time) => {
+                        let since = time.elapsed().unwrap_or_default();
+                        format!("{}s ago", since.as_secs())
+                    }
+                    None => "never".to_string(),
+                };
+
+                let mut out = "Graft Stats\n".to_string();
+                writeln!(&mut out, "Pages: {}", stats.pages)?;
+                writeln!(&mut out, "Available pages: {}", stats.available_pages)?;
+                writeln!(&mut out, "Pending pages: {}", stats.pending_pages)?;
+                writeln!(&mut out, "Empty pages: {}", stats.empty_pages)?;
+                writeln!(&mut out, "Local bytes: {}", stats.local_bytes)?;
+                writeln!(&mut out, "Pending commits: {}", stats.pending_commits)?;
+                writeln!(&mut out, "Pending bytes: {}", stats.pending_bytes)?;
+                writeln!(&mut out, "Last push: {}", fmt_time(stats.last_push))?;
+                writeln!(&mut out, "Last pull: {}", fmt_time(stats.last_pull))?;
+                Ok(Some(out))
+            }
             GraftPragma::Pull => {
                 file.pull()?;
                 Ok(None)
             }
             GraftPragma::Reset => {
-                file.handle().reset_to_remote().or_into_ctx()?;
+                require_local(file)?.reset_to_remote().or_into_ctx()?;
                 Ok(None)
             }
+            GraftPragma::WaitForLsn { lsn, timeout } => {
+                let snapshot = require_local(file)?
+                    .wait_for_remote_lsn(lsn, timeout)
+                    .or_into_ctx()?;
+                Ok(Some(snapshot.to_string()))
+            }
 
             GraftPragma::Version => {
                 const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -19,7 +19,12 @@ use graft_client::{
         },
     },
 };
-use graft_core::{VolumeId, gid::GidParseErr};
+use graft_core::{
+    VolumeId,
+    gid::GidParseErr,
+    page::{PageSize, PageSizeErr},
+    volume_name::{VolumeName, VolumeNameErr},
+};
 use graft_tracing::TracingConsumer;
 use parking_lot::Mutex;
 use sqlite_plugin::{
@@ -35,6 +40,7 @@ use thiserror::Error;
 use tryiter::TryIteratorExt;
 
 use crate::{
+    client::VfsClient,
     file::{FileHandle, VfsFile, mem_file::MemFile, vol_file::VolFile},
     pragma::GraftPragma,
 };
@@ -47,6 +53,9 @@ pub enum ErrCtx {
     #[error("Failed to parse VolumeId: {0}")]
     GidParseErr(#[from] GidParseErr),
 
+    #[error("Invalid Volume name: {0}")]
+    InvalidVolumeName(#[from] VolumeNameErr),
+
     #[error("Unknown Pragma")]
     UnknownPragma,
 
@@ -65,6 +74,9 @@ pub enum ErrCtx {
     #[error("Invalid volume state")]
     InvalidVolumeState,
 
+    #[error("Not supported when accessing volumes through graft-agent")]
+    AgentUnsupported,
+
     #[error(transparent)]
     FmtErr(#[from] std::fmt::Error),
 }
@@ -87,9 +99,10 @@ impl ErrCtx {
     fn sqlite_err(&self) -> SqliteErr {
         match self {
             ErrCtx::UnknownPragma => SQLITE_NOTFOUND,
-            ErrCtx::CantOpen => SQLITE_CANTOPEN,
+            ErrCtx::CantOpen | ErrCtx::InvalidVolumeName(_) => SQLITE_CANTOPEN,
             ErrCtx::Busy => SQLITE_BUSY,
             ErrCtx::BusySnapshot => SQLITE_BUSY_SNAPSHOT,
+            ErrCtx::AgentUnsupported => vars::SQLITE_ERROR,
             ErrCtx::Client(err) => Self::map_client_err(err),
             _ => SQLITE_INTERNAL,
         }
@@ -115,6 +128,7 @@ impl ErrCtx {
                 _ => SQLITE_INTERNAL,
             },
             ClientErr::IoErr(kind) => SQLITE_IOERR,
+            ClientErr::RemoteLsnTimeout => SQLITE_BUSY,
             _ => SQLITE_INTERNAL,
         }
     }
@@ -136,13 +150,40 @@ impl<T> From<ErrCtx> for culprit::Result<T, ErrCtx> {
 }
 
 pub struct GraftVfs {
-    runtime: Runtime,
+    client: VfsClient,
     locks: Mutex<HashMap<VolumeId, Arc<Mutex<()>>>>,
+
+    /// page sizes of Volumes which don't exist locally, recorded when their
+    /// path is canonicalized and consumed when they are opened
+    page_sizes: Mutex<HashMap<VolumeId, PageSize>>,
 }
 
 impl GraftVfs {
-    pub fn new(runtime: Runtime) -> Self {
-        Self { runtime, locks: Default::default() }
+    /// Create a VFS from either a [`Runtime`] or, on unix, an
+    /// [`graft_agent::AgentClient`]
+    pub fn new(client: impl Into<VfsClient>) -> Self {
+        Self {
+            client: client.into(),
+            locks: Default::default(),
+            page_sizes: Default::default(),
+        }
+    }
+
+    /// Look up the page size of a Volume which doesn't exist locally. Falls
+    /// back to the default page size if the Volume doesn't exist remotely or
+    /// the remote is unreachable.
+    fn remote_page_size(runtime: &Runtime, vid: &VolumeId) -> culprit::Result<PageSize, ErrCtx> {
+        match runtime.clients().metastore().snapshot(vid, None) {
+            Ok(Some(snapshot)) => snapshot
+                .page_size()
+                .map_err(|err| err.map_ctx(|err: PageSizeErr| ErrCtx::Client(err.into()))),
+            Ok(None) => Ok(PageSize::DEFAULT),
+            Err(err) if err.ctx().is_network_err() => {
+                tracing::warn!("failed to fetch the page size of Volume {vid}: {err}");
+                Ok(PageSize::DEFAULT)
+            }
+            Err(err) => Err(err.map_ctx(ErrCtx::from)),
+        }
     }
 }
 
@@ -187,7 +228,7 @@ impl Vfs for GraftVfs {
         let make_writer = move || writer.clone();
         graft_tracing::init_tracing_with_writer(
             TracingConsumer::Tool,
-            Some(self.runtime.cid().short()),
+            self.client.runtime().map(|runtime| runtime.cid().short()),
             make_writer,
         );
     }
@@ -197,10 +238,41 @@ impl Vfs for GraftVfs {
         path: std::borrow::Cow<'a, str>,
     ) -> VfsResult<std::borrow::Cow<'a, str>> {
         if path == "random" {
-            Ok(VolumeId::random().pretty().into())
-        } else {
-            Ok(path)
+            // new Volumes don't exist remotely, so there is no page size to
+            // look up
+            let vid = VolumeId::random();
+            let pretty = vid.pretty();
+            self.page_sizes.lock().insert(vid, PageSize::DEFAULT);
+            return Ok(pretty.into());
         }
+        if path.parse::<VolumeId>().is_ok() {
+            return Ok(path);
+        }
+
+        // resolve human-readable names into Volume IDs, registering the
+        // name if it doesn't exist yet
+        ErrCtx::wrap(move || {
+            let name: VolumeName = path.parse()?;
+            let Some(runtime) = self.client.runtime() else {
+                return Err(Culprit::new_with_note(
+                    ErrCtx::AgentUnsupported,
+                    format!("unable to resolve volume name {name}, open the volume by ID"),
+                ));
+            };
+            let vid = runtime
+                .resolve_volume_name(&name, true)
+                .or_into_ctx()?
+                .expect("volume name is created if missing");
+            tracing::debug!("resolved volume name {name} to {vid}");
+
+            // look up the page size now so that opening the Volume doesn't
+            // require another round trip to the metastore
+            if runtime.volume_config(&vid).or_into_ctx()?.is_none() {
+                let page_size = Self::remote_page_size(runtime, &vid)?;
+                self.page_sizes.lock().insert(vid.clone(), page_size);
+            }
+            Ok(vid.pretty().into())
+        })
     }
 
     fn pragma(
@@ -210,7 +282,31 @@ impl Vfs for GraftVfs {
     ) -> Result<Option<String>, PragmaErr> {
         tracing::trace!("pragma: file={handle:?}, pragma={pragma:?}");
         if let FileHandle::VolFile(file) = handle {
-            match GraftPragma::try_from(&pragma)?.eval(&self.runtime, file) {
+            if pragma.name.eq_ignore_ascii_case("page_size") {
+                // resize empty Volumes to match, then let SQLite handle the pragma
+                if let Some(arg) = pragma.arg {
+                    let page_size = arg
+                        .trim()
+                        .parse::<u32>()
+                        .ok()
+                        .and_then(|size| PageSize::try_from(size).ok())
+                        .ok_or_else(|| {
+                            PragmaErr::Fail(
+                                vars::SQLITE_ERROR,
+                                Some(format!("unsupported Graft page size: {arg}")),
+                            )
+                        })?;
+                    if let Err(err) = file.set_page_size(&self.client, page_size) {
+                        return Err(PragmaErr::Fail(
+                            err.ctx().sqlite_err(),
+                            Some(format!("{err:?}")),
+                        ));
+                    }
+                }
+                return Err(PragmaErr::NotFound);
+            }
+
+            match GraftPragma::try_from(&pragma)?.eval(&self.client, file) {
                 Ok(val) => Ok(val),
                 Err(err) => Err(PragmaErr::Fail(
                     err.ctx().sqlite_err(),
@@ -225,10 +321,11 @@ impl Vfs for GraftVfs {
     fn access(&self, path: &str, flags: AccessFlags) -> VfsResult<bool> {
         tracing::trace!("access: path={path:?}; flags={flags:?}");
         ErrCtx::wrap(move || {
-            if let Ok(vid) = path.parse::<VolumeId>() {
-                Ok(self.runtime.volume_exists(vid).or_into_ctx()?)
-            } else {
-                Ok(false)
+            match (path.parse::<VolumeId>(), self.client.runtime()) {
+                (Ok(vid), Some(runtime)) => Ok(runtime.volume_exists(vid).or_into_ctx()?),
+                // the agent creates volumes when they are opened, so we report
+                // them as missing like any other file
+                _ => Ok(false),
             }
         })
     }
@@ -244,10 +341,23 @@ impl Vfs for GraftVfs {
                     // get or create a reserved lock for this Volume
                     let reserved_lock = self.locks.lock().entry(vid.clone()).or_default().clone();
 
-                    let handle = self
-                        .runtime
-                        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
-                        .or_into_ctx()?;
+                    // preserve the page size of existing Volumes, and adopt
+                    // the page size of Volumes which only exist remotely,
+                    // unless it was already looked up while canonicalizing
+                    // the path. The agent takes care of this itself.
+                    let page_size = self.page_sizes.lock().remove(&vid);
+                    let config = match self.client.runtime() {
+                        Some(runtime) => match runtime.volume_config(&vid).or_into_ctx()? {
+                            Some(config) => config,
+                            None => VolumeConfig::default().with_page_size(match page_size {
+                                Some(page_size) => page_size,
+                                None => Self::remote_page_size(runtime, &vid)?,
+                            }),
+                        },
+                        None => VolumeConfig::default(),
+                    }
+                    .with_sync(SyncDirection::Both);
+                    let handle = self.client.open_volume(&vid, config).or_into_ctx()?;
                     return Ok(VolFile::new(handle, opts, reserved_lock).into());
                 }
             }
@@ -263,9 +373,10 @@ impl Vfs for GraftVfs {
             match handle {
                 FileHandle::MemFile(_) => Ok(()),
                 FileHandle::VolFile(vol_file) => {
-                    if vol_file.opts().delete_on_close() {
+                    let delete = vol_file.opts().delete_on_close();
+                    if let Some(runtime) = self.client.runtime().filter(|_| delete) {
                         // TODO: do we want to actually delete volumes? or mark them for deletion?
-                        self.runtime
+                        runtime
                             .update_volume_config(vol_file.vid(), |conf| {
                                 conf.with_sync(SyncDirection::Disabled)
                             })
@@ -297,9 +408,9 @@ impl Vfs for GraftVfs {
     fn delete(&self, path: &str) -> VfsResult<()> {
         tracing::trace!("delete: path={path:?}");
         ErrCtx::wrap(|| {
-            if let Ok(vid) = path.parse() {
+            if let (Ok(vid), Some(runtime)) = (path.parse(), self.client.runtime()) {
                 // TODO: do we want to actually delete volumes? or mark them for deletion?
-                self.runtime
+                runtime
                     .update_volume_config(&vid, |conf| conf.with_sync(SyncDirection::Disabled))
                     .or_into_ctx()?;
             }
@@ -6,7 +6,7 @@ use std::{
 };
 
 use culprit::Culprit;
-use graft_client::{ClientPair, MetastoreClient, NetClient, PagestoreClient};
+use graft_client::{ClientPair, MetastoreClient, NetClient, PagestoreClient, nonblocking};
 use graft_core::{
     PageCount, PageIdx,
     page::{PAGESIZE, Page},
@@ -22,7 +22,10 @@ use graft_server::{
     metrics::registry::Registry,
     object_store_util::ObjectStoreConfig,
     segment::{
-        cache::mem::MemCache, loader::SegmentLoader, uploader::SegmentUploaderTask,
+        cache::mem::MemCache,
+        compactor::{SegmentCompactor, SegmentCompactorTask},
+        loader::SegmentLoader,
+        uploader::SegmentUploaderTask,
         writer::SegmentWriterTask,
     },
     supervisor::{ShutdownErr, Supervisor},
@@ -58,6 +61,8 @@ pub fn setup_test() {
 }
 
 pub struct GraftBackend {
+    metastore: Url,
+    pagestore: Url,
     shutdown_tx: oneshot::Sender<Duration>,
     result_rx: oneshot::Receiver<Result<(), Culprit<ShutdownErr>>>,
     handle: JoinHandle<()>,
@@ -77,12 +82,10 @@ pub fn start_graft_backend() -> (GraftBackend, ClientPair) {
     let net_client = NetClient::new_with_proxy(None, None);
 
     let mut supervisor = Supervisor::default();
-    let metastore = runtime.block_on(run_metastore(net_client.clone(), &mut supervisor));
-    let pagestore = runtime.block_on(run_pagestore(
-        net_client.clone(),
-        metastore.clone(),
-        &mut supervisor,
-    ));
+    let metastore_url = runtime.block_on(run_metastore(&mut supervisor));
+    let metastore = MetastoreClient::new(metastore_url.clone(), net_client.clone());
+    let pagestore_url = runtime.block_on(run_pagestore(metastore_url.clone(), &mut supervisor));
+    let pagestore = PagestoreClient::new(pagestore_url.clone(), net_client);
 
     let builder = std::thread::Builder::new().name("graft-backend".to_string());
 
@@ -98,12 +101,27 @@ pub fn start_graft_backend() -> (GraftBackend, ClientPair) {
         .expect("failed to spawn backend thread");
 
     (
-        GraftBackend { shutdown_tx, result_rx, handle },
+        GraftBackend {
+            metastore: metastore_url,
+            pagestore: pagestore_url,
+            shutdown_tx,
+            result_rx,
+            handle,
+        },
         ClientPair::new(metastore, pagestore),
     )
 }
 
 impl GraftBackend {
+    /// Construct a pair of async clients connected to this backend
+    pub fn async_clients(&self) -> nonblocking::ClientPair {
+        let net_client = async_net_client();
+        nonblocking::ClientPair::new(
+            nonblocking::MetastoreClient::new(self.metastore.clone(), net_client.clone()),
+            nonblocking::PagestoreClient::new(self.pagestore.clone(), net_client),
+        )
+    }
+
     pub fn shutdown(self, timeout: Duration) -> Result<(), Culprit<ShutdownErr>> {
         self.shutdown_tx
             .send(timeout)
@@ -117,7 +135,19 @@ impl GraftBackend {
     }
 }
 
-pub async fn run_metastore(net_client: NetClient, supervisor: &mut Supervisor) -> MetastoreClient {
+/// Construct an async `NetClient` which connects directly to the test backend
+fn async_net_client() -> nonblocking::NetClient {
+    nonblocking::NetClient::new_with_client(
+        None,
+        reqwest::Client::builder()
+            .no_proxy()
+            .build()
+            .expect("failed to build http client"),
+    )
+}
+
+/// Run a metastore on the supervisor, returning its endpoint
+pub async fn run_metastore(supervisor: &mut Supervisor) -> Url {
     let obj_store = ObjectStoreConfig::Memory.build().unwrap();
     let vol_store = Arc::new(VolumeStore::new(obj_store));
     let catalog = VolumeCatalog::open_temporary().unwrap();
@@ -128,14 +158,13 @@ pub async fn run_metastore(net_client: NetClient, supervisor: &mut Supervisor) -
     let port = listener.local_addr().unwrap().port();
     let endpoint = Url::parse(&format!("http://127.0.0.1:{port}")).unwrap();
     supervisor.spawn(ApiServerTask::new("metastore-api", listener, router));
-    MetastoreClient::new(endpoint, net_client)
+    endpoint
 }
 
-pub async fn run_pagestore(
-    net_client: NetClient,
-    metastore: MetastoreClient,
-    supervisor: &mut Supervisor,
-) -> PagestoreClient {
+/// Run a pagestore connected to the metastore on the supervisor, returning its
+/// endpoint
+pub async fn run_pagestore(metastore_url: Url, supervisor: &mut Supervisor) -> Url {
+    let metastore = nonblocking::MetastoreClient::new(metastore_url, async_net_client());
     let mut registry = Registry::default();
     let obj_store = ObjectStoreConfig::Memory.build().unwrap();
     let cache = Arc::new(MemCache::default());
@@ -149,10 +178,25 @@ pub async fn run_pagestore(
     supervisor.spawn(SegmentWriterTask::new(
         registry.segment_writer(),
         page_rx,
-        store_tx,
+        store_tx.clone(),
         Duration::from_secs(1),
     ));
 
+    let (compactor_tx, compactor_rx) = mpsc::channel(128);
+    let compactor = SegmentCompactor::new(
+        registry.segment_compactor(),
+        catalog.clone(),
+        SegmentLoader::new(obj_store.clone(), cache.clone(), 8),
+        metastore.clone(),
+        VolumeCatalogUpdater::new(10),
+        store_tx,
+    );
+    supervisor.spawn(SegmentCompactorTask::new(
+        compactor,
+        compactor_rx,
+        Duration::from_millis(100),
+    ));
+
     supervisor.spawn(SegmentUploaderTask::new(
         registry.segment_uploader(),
         store_rx,
@@ -160,22 +204,17 @@ pub async fn run_pagestore(
         cache,
     ));
 
-    let state = Arc::new(PagestoreApiState::new(
-        page_tx,
-        catalog.clone(),
-        loader,
-        metastore,
-        updater,
-        10,
-    ));
+    let state = Arc::new(
+        PagestoreApiState::new(page_tx, catalog.clone(), loader, metastore, updater, 10)
+            .with_compactor(compactor_tx),
+    );
     let router = build_router(registry, None, state, pagestore_routes());
 
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let port = listener.local_addr().unwrap().port();
     let endpoint = Url::parse(&format!("http://127.0.0.1:{port}")).unwrap();
     supervisor.spawn(ApiServerTask::new("pagestore-api", listener, router));
-
-    PagestoreClient::new(endpoint, net_client)
+    endpoint
 }
 
 #[derive(Debug, Clone, Copy)]
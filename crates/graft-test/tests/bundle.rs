@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use graft_client::{
+    ClientErr,
+    oracle::NoopOracle,
+    runtime::{
+        bundle::{
+            Bundle, Commits, LocalCommits, read_bundle, read_bundle_with_limit, write_bundle,
+        },
+        runtime::Runtime,
+        storage::{
+            Storage,
+            page::PageStatus,
+            volume_state::{SyncDirection, VolumeConfig},
+        },
+        volume_reader::VolumeRead,
+        volume_writer::VolumeWrite,
+    },
+};
+use graft_core::{
+    PageIdx, VolumeId, byte_unit::ByteUnit, gid::ClientId, lsn::LSN, page::Page, pageidx,
+};
+use graft_test::start_graft_backend;
+
+#[graft_test::test]
+fn test_offline_bundles() {
+    let (backend, clients) = start_graft_backend();
+    let open = |runtime: &Runtime, vid: &VolumeId| {
+        runtime
+            .open_volume(vid, VolumeConfig::new(SyncDirection::Disabled))
+            .unwrap()
+    };
+
+    // the offline client never contacts the remote
+    let offline = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_temporary().unwrap(),
+    );
+    let online = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_temporary().unwrap(),
+    );
+
+    // commit a few times to the offline client
+    let vid = VolumeId::random();
+    let handle = open(&offline, &vid);
+    assert!(offline.export_local_bundle(&vid).unwrap().is_none());
+    for i in 1..=3u8 {
+        let mut writer = handle.writer().unwrap();
+        writer.write(PageIdx::try_from(i as u32).unwrap(), Page::test_filled(i));
+        writer.commit().unwrap();
+    }
+
+    // transfer the local commits through a file
+    let bundle = offline.export_local_bundle(&vid).unwrap().unwrap();
+    let mut file = Vec::new();
+    write_bundle(&mut file, &bundle).unwrap();
+    let bundle = read_bundle(&mut file.as_slice()).unwrap();
+
+    // importing the bundle commits all of the local commits to the remote
+    let remote = online.import_bundle(bundle.clone()).unwrap();
+    assert_eq!(remote.lsn().unwrap(), LSN::FIRST);
+    assert_eq!(remote.pages(), 3);
+
+    // importing it again is idempotent
+    assert_eq!(online.import_bundle(bundle).unwrap(), remote);
+
+    // the exported commits remain pending until the exporter is reset
+    let snapshot = handle.snapshot().unwrap().unwrap();
+    assert_eq!(snapshot.remote(), None);
+    offline
+        .start_sync_task(Duration::from_secs(60), 8, false, "offline-sync")
+        .unwrap();
+    let handle = open(&offline, &vid);
+    handle.reset_to_remote().unwrap();
+    let snapshot = handle.snapshot().unwrap().unwrap();
+    assert_eq!(snapshot.remote(), Some(LSN::FIRST));
+    assert_eq!(snapshot.pages(), 3);
+    assert!(offline.export_local_bundle(&vid).unwrap().is_none());
+    let reader = handle.reader().unwrap();
+    for i in 1..=3u8 {
+        let pageidx = PageIdx::try_from(i as u32).unwrap();
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx).unwrap(),
+            Page::test_filled(i)
+        );
+    }
+    offline.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+
+    // export the remote commits and import them into another offline client
+    let bundle = online.export_remote_bundle(&vid, ..).unwrap().unwrap();
+    let offline2 = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_temporary().unwrap(),
+    );
+    let handle2 = open(&offline2, &vid);
+    assert_eq!(
+        offline2
+            .import_bundle(bundle.clone())
+            .unwrap()
+            .lsn()
+            .unwrap(),
+        LSN::FIRST
+    );
+    let snapshot = handle2.snapshot().unwrap().unwrap();
+    assert_eq!(snapshot.remote(), Some(LSN::FIRST));
+    assert_eq!(snapshot.pages(), 3);
+
+    // every page is available without contacting the remote
+    let reader = handle2.reader().unwrap();
+    for i in 1..=3u8 {
+        let pageidx = PageIdx::try_from(i as u32).unwrap();
+        assert!(matches!(
+            reader.status(pageidx).unwrap(),
+            PageStatus::Available(_)
+        ));
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx).unwrap(),
+            Page::test_filled(i)
+        );
+    }
+
+    // re-importing an already applied bundle is a no-op
+    offline2.import_bundle(bundle).unwrap();
+    assert_eq!(handle2.snapshot().unwrap().unwrap(), snapshot);
+
+    // a bundle which skips remote commits is rejected
+    let mut writer = handle2.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(0x42));
+    writer.commit().unwrap();
+    let bundle = offline2.export_local_bundle(&vid).unwrap().unwrap();
+    assert_eq!(
+        online.import_bundle(bundle).unwrap().lsn().unwrap(),
+        LSN::new(2)
+    );
+
+    let offline3 = Runtime::new(
+        ClientId::random(),
+        clients,
+        Storage::open_temporary().unwrap(),
+    );
+    open(&offline3, &vid);
+    let bundle = online
+        .export_remote_bundle(&vid, LSN::new(2)..)
+        .unwrap()
+        .unwrap();
+    let err = offline3.import_bundle(bundle).unwrap_err();
+    assert!(matches!(err.ctx(), ClientErr::InvalidBundle(_)), "{err:?}");
+
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_read_bundle_limit() {
+    let bundle = Bundle {
+        vid: VolumeId::random().copy_to_bytes(),
+        commits: Some(Commits::Local(LocalCommits {
+            cid: ClientId::random().copy_to_bytes(),
+            page_count: 1,
+            ..Default::default()
+        })),
+    };
+    let mut file = Vec::new();
+    write_bundle(&mut file, &bundle).unwrap();
+
+    let limit = ByteUnit::new(file.len() as u64);
+    assert_eq!(
+        read_bundle_with_limit(&mut file.as_slice(), limit).unwrap(),
+        bundle
+    );
+
+    let limit = ByteUnit::new(file.len() as u64 - 1);
+    let err = read_bundle_with_limit(&mut file.as_slice(), limit).unwrap_err();
+    assert!(matches!(err.ctx(), ClientErr::InvalidBundle(_)), "{err:?}");
+}
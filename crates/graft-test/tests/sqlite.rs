@@ -10,7 +10,7 @@ use graft_client::runtime::{
         volume_state::{SyncDirection, VolumeConfig, VolumeStatus},
     },
 };
-use graft_core::{ClientId, VolumeId};
+use graft_core::{ClientId, VolumeId, page::PageSize, volume_name::VolumeName};
 use graft_sqlite::vfs::GraftVfs;
 use graft_test::start_graft_backend;
 use rusqlite::{Connection, OpenFlags};
@@ -265,3 +265,244 @@ fn wait_for_change<T: Eq>(timeout: Duration, baseline: T, mut cb: impl FnMut() -
         sleep(Duration::from_millis(100));
     }
 }
+
+#[graft_test::test]
+fn test_sqlite_volume_names() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime1 = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_temporary().unwrap(),
+    );
+    register_static(
+        c"graft-names-1".to_owned(),
+        GraftVfs::new(runtime1.clone()),
+        RegisterOpts { make_default: false },
+    )
+    .expect("failed to register vfs");
+
+    // open a database by name, which registers the name
+    let sqlite = Connection::open_with_flags_and_vfs(
+        "customers.db",
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
+        c"graft-names-1",
+    )
+    .unwrap();
+    sqlite
+        .execute_batch("CREATE TABLE customers (name TEXT); INSERT INTO customers VALUES ('acme');")
+        .unwrap();
+    let name: VolumeName = "customers.db".parse().unwrap();
+    let vid = runtime1
+        .resolve_volume_name(&name, false)
+        .unwrap()
+        .expect("name should be registered");
+    assert_eq!(sqlite.path(), Some(vid.pretty().as_str()));
+
+    // a second client opening the same name gets the same volume
+    let runtime2 = Runtime::new(
+        ClientId::random(),
+        clients,
+        Storage::open_temporary().unwrap(),
+    );
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume_by_name(&name, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    assert_eq!(handle2.vid(), &vid);
+
+    // names are namespaced
+    let other: VolumeName = "other/customers.db".parse().unwrap();
+    assert_eq!(runtime2.resolve_volume_name(&other, false).unwrap(), None);
+    let handle3 = runtime2
+        .open_volume_by_name(&other, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    assert_ne!(handle3.vid(), &vid);
+
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_sqlite_page_size() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime1 = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_temporary().unwrap(),
+    );
+    runtime1
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+    register_static(
+        c"graft-page-size-1".to_owned(),
+        GraftVfs::new(runtime1.clone()),
+        RegisterOpts { make_default: false },
+    )
+    .expect("failed to register vfs");
+
+    // choose the page size of a new database using `pragma page_size`
+    let vid = VolumeId::random();
+    let sqlite1 = Connection::open_with_flags_and_vfs(
+        vid.pretty(),
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
+        c"graft-page-size-1",
+    )
+    .unwrap();
+    sqlite1
+        .execute_batch(
+            r#"
+            PRAGMA page_size = 8192;
+            CREATE TABLE t1 (value TEXT);
+            INSERT INTO t1 VALUES ('hello');
+            "#,
+        )
+        .unwrap();
+    let page_size: u32 = sqlite1
+        .query_row("PRAGMA page_size", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(page_size, 8192);
+    let config = runtime1.volume_config(&vid).unwrap().unwrap();
+    assert_eq!(config.page_size(), PageSize::Kb8);
+
+    // unsupported page sizes are rejected
+    let err = sqlite1
+        .execute_batch("PRAGMA page_size = 1024")
+        .unwrap_err();
+    assert!(err.to_string().contains("unsupported Graft page size"));
+
+    // once the database exists the page size can't change
+    sqlite1.execute_batch("PRAGMA page_size = 16384").unwrap();
+    let config = runtime1.volume_config(&vid).unwrap().unwrap();
+    assert_eq!(config.page_size(), PageSize::Kb8);
+
+    runtime1
+        .open_volume(&vid, config)
+        .unwrap()
+        .sync_with_remote(SyncDirection::Push)
+        .unwrap();
+
+    // a second client opening the volume adopts the remote page size
+    let runtime2 = Runtime::new(
+        ClientId::random(),
+        clients,
+        Storage::open_temporary().unwrap(),
+    );
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+    register_static(
+        c"graft-page-size-2".to_owned(),
+        GraftVfs::new(runtime2.clone()),
+        RegisterOpts { make_default: false },
+    )
+    .expect("failed to register vfs");
+    let sqlite2 = Connection::open_with_flags_and_vfs(
+        vid.pretty(),
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
+        c"graft-page-size-2",
+    )
+    .unwrap();
+    let config = runtime2.volume_config(&vid).unwrap().unwrap();
+    assert_eq!(config.page_size(), PageSize::Kb8);
+    runtime2
+        .open_volume(&vid, config)
+        .unwrap()
+        .sync_with_remote(SyncDirection::Pull)
+        .unwrap();
+
+    let value: String = sqlite2
+        .query_row("SELECT value FROM t1", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(value, "hello");
+    let page_size: u32 = sqlite2
+        .query_row("PRAGMA page_size", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(page_size, 8192);
+
+    runtime1.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[cfg(unix)]
+#[graft_test::test]
+fn test_sqlite_agent() {
+    use std::os::unix::net::UnixListener;
+
+    use graft_agent::{AgentClient, AgentServer};
+
+    let (backend, clients) = start_graft_backend();
+
+    // a single agent owns the local storage
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients,
+        Storage::open_temporary().unwrap(),
+    );
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-agent")
+        .unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("agent.sock");
+    AgentServer::new(runtime.clone())
+        .spawn(UnixListener::bind(&socket).unwrap())
+        .unwrap();
+
+    // which is shared by two VFS instances
+    for name in [c"graft-agent-1", c"graft-agent-2"] {
+        register_static(
+            name.to_owned(),
+            GraftVfs::new(AgentClient::connect(&socket).unwrap()),
+            RegisterOpts { make_default: false },
+        )
+        .expect("failed to register vfs");
+    }
+
+    let vid = VolumeId::random();
+    let open = |vfs| {
+        Connection::open_with_flags_and_vfs(
+            vid.pretty(),
+            OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
+            vfs,
+        )
+        .unwrap()
+    };
+    let sqlite1 = open(c"graft-agent-1");
+    let sqlite2 = open(c"graft-agent-2");
+
+    sqlite1
+        .execute_batch("CREATE TABLE t1 (value TEXT); INSERT INTO t1 VALUES ('hello');")
+        .unwrap();
+    let value: String = sqlite2
+        .query_row("SELECT value FROM t1", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(value, "hello");
+
+    // the volume was created by the agent
+    let config = runtime.volume_config(&vid).unwrap().unwrap();
+    assert_eq!(config.sync(), SyncDirection::Both);
+
+    // status is reported without the local runtime, but pragmas which need
+    // it are not supported
+    let status: String = sqlite2
+        .query_row("PRAGMA graft_status", [], |row| row.get(0))
+        .unwrap();
+    assert!(status.contains(&vid.pretty()));
+    let err = sqlite2.execute_batch("PRAGMA graft_stats").unwrap_err();
+    assert!(err.to_string().contains("graft-agent"), "{err}");
+
+    // volume names can't be resolved through the agent
+    Connection::open_with_flags_and_vfs(
+        "customers.db",
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE,
+        c"graft-agent-1",
+    )
+    .unwrap_err();
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
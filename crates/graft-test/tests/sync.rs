@@ -1,6 +1,10 @@
-use std::time::Duration;
+use std::{
+    thread::sleep,
+    time::{Duration, Instant},
+};
 
 use graft_client::{
+    ClientErr,
     oracle::NoopOracle,
     runtime::{
         runtime::Runtime,
@@ -8,12 +12,22 @@ use graft_client::{
             Storage,
             volume_state::{SyncDirection, VolumeConfig},
         },
+        sync::PushCoalescing,
         volume_reader::VolumeRead,
         volume_writer::VolumeWrite,
     },
 };
-use graft_core::{PageIdx, VolumeId, gid::ClientId, page::Page};
+use graft_core::{
+    PageIdx, VolumeId,
+    gid::ClientId,
+    lsn::LSN,
+    page::{Page, PageSize},
+    pageidx,
+    tag_name::TagName,
+};
+use graft_proto::common::v1::CommitMetadata;
 use graft_test::start_graft_backend;
+use splinter_rs::Splinter;
 
 #[graft_test::test]
 fn test_client_sync_sanity() {
@@ -79,3 +93,756 @@ fn test_client_sync_sanity() {
     runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
     backend.shutdown(Duration::from_secs(5)).unwrap();
 }
+
+#[graft_test::test]
+fn test_wait_for_remote_lsn() {
+    let (backend, clients) = start_graft_backend();
+
+    // both clients sync manually
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+    let runtime2 = Runtime::new(ClientId::random(), clients, Storage::open_memory().unwrap());
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    // push a commit from the first client and learn its remote LSN
+    let page = Page::test_filled(0x42);
+    let mut writer = handle.writer().unwrap();
+    writer.write(PageIdx::FIRST, page.clone());
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let remote_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+
+    // the second client pulls until it has the remote LSN
+    let snapshot = handle2
+        .wait_for_remote_lsn(remote_lsn, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!(snapshot.remote(), Some(remote_lsn));
+    let reader = handle2.reader_at(Some(snapshot));
+    assert_eq!(reader.read(&mut NoopOracle, PageIdx::FIRST).unwrap(), page);
+
+    // waiting for a LSN which doesn't exist yet times out
+    let err = handle2
+        .wait_for_remote_lsn(remote_lsn.next().unwrap(), Duration::from_millis(500))
+        .unwrap_err();
+    assert!(matches!(err.ctx(), ClientErr::RemoteLsnTimeout));
+
+    // the pull is bounded by the timeout, and the sync task keeps working
+    // once the caller stops waiting for it
+    let err = handle2
+        .wait_for_remote_lsn(remote_lsn.next().unwrap(), Duration::from_millis(1))
+        .unwrap_err();
+    assert!(matches!(err.ctx(), ClientErr::RemoteLsnTimeout));
+    handle2.sync_with_remote(SyncDirection::Pull).unwrap();
+    assert!(runtime2.drain_recent_sync_errors().is_empty());
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_commit_metadata() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+
+    let metadata = |message: &str, author: &str, json: &str| CommitMetadata {
+        message: message.into(),
+        author: author.into(),
+        json: json.into(),
+    };
+
+    // invalid json is rejected locally
+    let mut writer = handle.writer().unwrap();
+    writer.write(PageIdx::FIRST, Page::test_filled(0x42));
+    writer.set_commit_metadata(metadata("bad", "", "{"));
+    assert!(writer.commit().is_err());
+
+    // make two local commits which are pushed as a single remote commit
+    for (i, meta) in [
+        metadata("first", "alice", r#"{"step":1}"#),
+        metadata("second", "bob", r#"{"step":2}"#),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut writer = handle.writer().unwrap();
+        writer.write(PageIdx::FIRST, Page::test_filled(i as u8));
+        writer.set_commit_metadata(meta);
+        writer.commit().unwrap();
+    }
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+
+    let commits = clients.metastore().pull_commits(&vid, ..).unwrap();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(
+        commits[0].metadata,
+        Some(metadata(
+            "first\nsecond",
+            "alice, bob",
+            r#"[{"step":1},{"step":2}]"#
+        ))
+    );
+
+    // commits without metadata don't return any
+    let mut writer = handle.writer().unwrap();
+    writer.write(PageIdx::FIRST, Page::test_filled(0x99));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let commits = clients.metastore().pull_commits(&vid, ..).unwrap();
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[1].metadata, None);
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_fork_volume() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    let runtime2 = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    for runtime in [&runtime, &runtime2] {
+        runtime
+            .start_sync_task(Duration::from_secs(1), 8, false, "sync")
+            .unwrap();
+    }
+
+    // write two versions of the source volume
+    let source = VolumeId::random();
+    let handle = runtime
+        .open_volume(&source, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(1));
+    writer.write(pageidx!(2), Page::test_filled(2));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let first_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(2), Page::test_filled(3));
+    writer.write(pageidx!(3), Page::test_filled(4));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+
+    // fork the first version and read it through another client
+    let fork = clients
+        .metastore()
+        .fork_volume(&source, Some(first_lsn))
+        .unwrap();
+    let fork_vid = fork.vid().unwrap().clone();
+    assert_eq!(fork.pages(), 2);
+    let fork_handle = runtime2
+        .open_volume(&fork_vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    fork_handle.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = fork_handle.reader().unwrap();
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(1)).unwrap(),
+        Page::test_filled(1)
+    );
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(2)).unwrap(),
+        Page::test_filled(2)
+    );
+
+    // writes to the fork don't affect the source
+    let mut writer = fork_handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(5));
+    writer.commit().unwrap();
+    fork_handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let snapshot = clients
+        .metastore()
+        .snapshot(&source, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(snapshot.pages(), 3);
+
+    // a fork of the fork reads pages inherited from both volumes
+    let fork2 = clients.metastore().fork_volume(&fork_vid, None).unwrap();
+    let fork2_handle = runtime2
+        .open_volume(fork2.vid().unwrap(), VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    fork2_handle.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = fork2_handle.reader().unwrap();
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(1)).unwrap(),
+        Page::test_filled(5)
+    );
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(2)).unwrap(),
+        Page::test_filled(2)
+    );
+
+    for runtime in [runtime, runtime2] {
+        runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    }
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_revert_volume() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    let runtime2 = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    for runtime in [&runtime, &runtime2] {
+        runtime
+            .start_sync_task(Duration::from_secs(1), 8, false, "sync")
+            .unwrap();
+    }
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(1));
+    writer.write(pageidx!(2), Page::test_filled(2));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let target_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+
+    // overwrite a page and grow the volume
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(3));
+    writer.write(pageidx!(3), Page::test_filled(4));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let remote_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+    handle2
+        .wait_for_remote_lsn(remote_lsn, Duration::from_secs(5))
+        .unwrap();
+    let reader = handle2.reader().unwrap();
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(3)).unwrap(),
+        Page::test_filled(4)
+    );
+
+    let snapshot = clients
+        .metastore()
+        .revert_volume(&vid, &ClientId::random(), target_lsn)
+        .unwrap();
+    let revert_lsn = snapshot.lsn().unwrap();
+    assert_eq!(revert_lsn, remote_lsn.next().unwrap());
+
+    // both clients pull the revert like any other commit
+    for handle in [&handle, &handle2] {
+        let snapshot = handle
+            .wait_for_remote_lsn(revert_lsn, Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(snapshot.pages(), 2);
+        let reader = handle.reader_at(Some(snapshot));
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx!(1)).unwrap(),
+            Page::test_filled(1)
+        );
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx!(2)).unwrap(),
+            Page::test_filled(2)
+        );
+    }
+
+    // pages cleared by the revert read as empty once the volume grows again
+    let mut writer = handle2.writer().unwrap();
+    writer.write(pageidx!(4), Page::test_filled(5));
+    writer.commit().unwrap();
+    handle2.sync_with_remote(SyncDirection::Push).unwrap();
+    let remote_lsn = handle2.snapshot().unwrap().unwrap().remote().unwrap();
+    let snapshot = handle
+        .wait_for_remote_lsn(remote_lsn, Duration::from_secs(5))
+        .unwrap();
+    let reader = handle.reader_at(Some(snapshot));
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(3)).unwrap(),
+        Page::empty(PageSize::DEFAULT)
+    );
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(4)).unwrap(),
+        Page::test_filled(5)
+    );
+
+    for runtime in [runtime, runtime2] {
+        runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    }
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_volume_tags() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync")
+        .unwrap();
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(1));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let tagged_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+
+    let tag: TagName = "release-2026-10".parse().unwrap();
+    let created = clients
+        .metastore()
+        .create_tag(&vid, &tag, tagged_lsn)
+        .unwrap();
+    assert_eq!(created.lsn, u64::from(tagged_lsn));
+
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(2));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let remote_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+
+    // the tag can't be moved to the new LSN
+    let err = clients
+        .metastore()
+        .create_tag(&vid, &tag, remote_lsn)
+        .unwrap_err();
+    assert!(err.ctx().is_tag_exists());
+
+    // the tag resolves to the tagged snapshot and its pages
+    let snapshot = clients
+        .metastore()
+        .snapshot_at_tag(&vid, &tag)
+        .unwrap()
+        .unwrap();
+    assert_eq!(snapshot.lsn().unwrap(), tagged_lsn);
+    let graft = Splinter::from_iter([1u32]).serialize_to_bytes();
+    let pages = clients
+        .pagestore()
+        .read_pages_at_tag(&vid, &tag, graft.clone())
+        .unwrap();
+    assert_eq!(pages.len(), 1);
+    assert_eq!(
+        Page::try_from(pages[0].data.clone()).unwrap(),
+        Page::test_filled(1)
+    );
+
+    assert_eq!(clients.metastore().list_tags(&vid).unwrap(), vec![created]);
+
+    // deleted tags no longer resolve
+    assert!(
+        clients
+            .metastore()
+            .delete_tag(&vid, &tag)
+            .unwrap()
+            .is_some()
+    );
+    assert!(
+        clients
+            .metastore()
+            .delete_tag(&vid, &tag)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        clients
+            .metastore()
+            .snapshot_at_tag(&vid, &tag)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        clients
+            .pagestore()
+            .read_pages_at_tag(&vid, &tag, graft)
+            .is_err()
+    );
+    assert!(clients.metastore().list_tags(&vid).unwrap().is_empty());
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_page_size_sync() {
+    let (backend, clients) = start_graft_backend();
+    let page_size = PageSize::try_from(16384usize).unwrap();
+    let config = VolumeConfig::new(SyncDirection::Both).with_page_size(page_size);
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    let runtime2 = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    for runtime in [&runtime, &runtime2] {
+        runtime
+            .start_sync_task(Duration::from_secs(1), 8, false, "sync")
+            .unwrap();
+    }
+
+    let vid = VolumeId::random();
+    let handle = runtime.open_volume(&vid, config.clone()).unwrap();
+    let handle2 = runtime2.open_volume(&vid, config).unwrap();
+    assert_eq!(handle.page_size(), page_size);
+
+    // write a full page and a partial page, and push them to the remote
+    let page = Page::test_filled_sized(0x42, page_size);
+    let mut writer = handle.writer().unwrap();
+    writer.write(PageIdx::FIRST, page.clone());
+    writer
+        .write_at(&mut NoopOracle, page_size.as_u64() * 2 + 10, b"hello")
+        .unwrap();
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+    let remote_lsn = handle.snapshot().unwrap().unwrap().remote().unwrap();
+
+    // the second client fetches the pages from the pagestore
+    let snapshot = handle2
+        .wait_for_remote_lsn(remote_lsn, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!(snapshot.pages(), 3);
+    let reader = handle2.reader_at(Some(snapshot));
+    assert_eq!(reader.read(&mut NoopOracle, PageIdx::FIRST).unwrap(), page);
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(2)).unwrap(),
+        Page::empty(page_size)
+    );
+    let page3 = reader.read(&mut NoopOracle, pageidx!(3)).unwrap();
+    assert_eq!(page3.size(), page_size);
+    assert_eq!(&page3[10..15], b"hello");
+
+    // clients can't sync the volume with a different page size
+    let runtime3 = Runtime::new(ClientId::random(), clients, Storage::open_memory().unwrap());
+    runtime3
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-3")
+        .unwrap();
+    let handle3 = runtime3
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    assert!(handle3.sync_with_remote(SyncDirection::Pull).is_err());
+
+    for runtime in [runtime, runtime2, runtime3] {
+        runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    }
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_checkpoint_sync() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync")
+        .unwrap();
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+
+    // each commit writes a single page
+    let commit = |pageidx: PageIdx, fill: u8| {
+        let mut writer = handle.writer().unwrap();
+        writer.write(pageidx, Page::test_filled(fill));
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+    };
+    for i in 1..=4u8 {
+        commit(PageIdx::try_new(i.into()).unwrap(), i);
+    }
+
+    // checkpoint the volume, and then overwrite a page in the checkpoint
+    let checkpoint = clients.metastore().checkpoint(&vid).unwrap();
+    assert_eq!(checkpoint, 4);
+    commit(pageidx!(1), 0x42);
+    commit(pageidx!(5), 5);
+
+    // a new client can read every page starting from the checkpoint
+    let runtime2 = Runtime::new(ClientId::random(), clients, Storage::open_memory().unwrap());
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    handle2.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = handle2.reader().unwrap();
+    let snapshot = reader.snapshot().unwrap();
+    assert_eq!(snapshot.remote(), Some(LSN::new(6)));
+    assert_eq!(snapshot.pages(), 5);
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(1)).unwrap(),
+        Page::test_filled(0x42)
+    );
+    for i in 2..=5u8 {
+        assert_eq!(
+            reader
+                .read(&mut NoopOracle, PageIdx::try_new(i.into()).unwrap())
+                .unwrap(),
+            Page::test_filled(i)
+        );
+    }
+
+    for runtime in [runtime, runtime2] {
+        runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    }
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_segment_compaction() {
+    let (backend, clients) = start_graft_backend();
+
+    let runtime = Runtime::new(
+        ClientId::random(),
+        clients.clone(),
+        Storage::open_memory().unwrap(),
+    );
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync")
+        .unwrap();
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+
+    // each push writes a single page into a new segment
+    for i in 1..=4u8 {
+        let mut writer = handle.writer().unwrap();
+        writer.write(PageIdx::try_new(i.into()).unwrap(), Page::test_filled(i));
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+    }
+
+    // checkpoint the volume, the compactor only rewrites written checkpoints
+    // once a commit references them. Backend time is paused, so the compactor
+    // may handle the first push before its commit lands; the second push
+    // notifies it after the checkpoint is referenced.
+    let checkpoint = clients.metastore().checkpoint(&vid).unwrap();
+    assert_eq!(checkpoint, 4);
+    for i in 5..=6u8 {
+        let mut writer = handle.writer().unwrap();
+        writer.write(PageIdx::try_new(i.into()).unwrap(), Page::test_filled(i));
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+    }
+
+    // wait for the pagestore to compact the checkpoint's small segments into one
+    let is_compacted = || {
+        clients
+            .metastore()
+            .pull_checkpoint(&vid, LSN::new(4))
+            .unwrap()
+            .and_then(|r| r.checkpoint)
+            .is_some_and(|c| c.segments.len() == 1)
+    };
+    let mut attempts = 0;
+    while !is_compacted() {
+        attempts += 1;
+        assert!(attempts < 50, "volume was never compacted");
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    // a new client reads the checkpointed pages from the compacted segment
+    let runtime2 = Runtime::new(ClientId::random(), clients, Storage::open_memory().unwrap());
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    handle2.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = handle2.reader().unwrap();
+    assert_eq!(reader.snapshot().unwrap().remote(), Some(LSN::new(6)));
+    for i in 1..=6u8 {
+        assert_eq!(
+            reader
+                .read(&mut NoopOracle, PageIdx::try_new(i.into()).unwrap())
+                .unwrap(),
+            Page::test_filled(i)
+        );
+    }
+
+    for runtime in [runtime, runtime2] {
+        runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    }
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_push_coalescing_ticks() {
+    let (backend, clients) = start_graft_backend();
+
+    // tick frequently to ensure ticks don't bypass the quiet period
+    let runtime = Runtime::new(ClientId::random(), clients, Storage::open_memory().unwrap());
+    runtime
+        .start_sync_task(Duration::from_millis(50), 8, true, "sync-1")
+        .unwrap();
+    runtime
+        .set_push_coalescing(PushCoalescing::default().with_quiet_period(Duration::from_secs(2)));
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    let remote = || handle.snapshot().unwrap().and_then(|s| s.remote());
+
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(0x42));
+    writer.commit().unwrap();
+
+    // many ticks fire during the quiet period without pushing the commit
+    sleep(Duration::from_millis(500));
+    assert_eq!(remote(), None);
+
+    // but they still pull the volume
+    assert!(handle.stats().unwrap().last_pull.is_some());
+
+    // the commit is pushed once the volume has been quiet long enough
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while remote().is_none() {
+        assert!(Instant::now() < deadline, "commit was never pushed");
+        sleep(Duration::from_millis(50));
+    }
+    assert_eq!(remote(), Some(LSN::FIRST));
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_async_client_sync_sanity() {
+    use graft_client::nonblocking::{self, VolumeRead as _, VolumeWrite as _};
+
+    // the backend runs its own tokio runtime which can't be started from
+    // within an async context
+    let (backend, _) = tokio::task::spawn_blocking(start_graft_backend)
+        .await
+        .unwrap();
+
+    let storage = Storage::open_memory().unwrap();
+    let runtime = nonblocking::Runtime::new(ClientId::random(), backend.async_clients(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, true)
+        .unwrap();
+
+    // create a second client to sync to
+    let storage2 = Storage::open_memory().unwrap();
+    let runtime2 = nonblocking::Runtime::new(ClientId::random(), backend.async_clients(), storage2);
+    runtime2
+        .start_sync_task(Duration::from_millis(100), 8, true)
+        .unwrap();
+
+    // register the volume with both clients, pushing from client 1 to client 2
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+
+    let subscription = handle2.subscribe_to_remote_changes();
+
+    let page = Page::test_filled(0x42);
+    let pageidx = PageIdx::FIRST;
+
+    for i in 1..5 {
+        let mut writer = handle.writer().unwrap();
+        writer.write(pageidx, page.clone());
+        writer.commit().await.unwrap();
+
+        // wait for client 2 to receive the write
+        tokio::time::timeout(Duration::from_secs(5), subscription.notified())
+            .await
+            .expect("subscription failed");
+
+        let snapshot = handle2.snapshot().unwrap().unwrap();
+        assert_eq!(snapshot.local(), i);
+        assert_eq!(snapshot.pages(), 1);
+
+        // reading the page requires fetching it from the pagestore
+        let reader = handle2.reader_at(Some(snapshot));
+        let received = reader.read(&mut NoopOracle, pageidx).await.unwrap();
+        assert_eq!(received, page, "received page does not match written page");
+    }
+
+    // explicitly syncing should succeed even though there is nothing to do
+    handle2.sync_with_remote(SyncDirection::Both).await.unwrap();
+
+    // shutdown everything
+    runtime
+        .shutdown_sync_task(Duration::from_secs(5))
+        .await
+        .unwrap();
+    runtime2
+        .shutdown_sync_task(Duration::from_secs(5))
+        .await
+        .unwrap();
+    tokio::task::spawn_blocking(move || backend.shutdown(Duration::from_secs(5)))
+        .await
+        .unwrap()
+        .unwrap();
+}